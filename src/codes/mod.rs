@@ -0,0 +1,5 @@
+//! Error-correcting codes over FFT-friendly fields.
+
+pub mod reed_solomon;
+
+pub use reed_solomon::ReedSolomonCode;
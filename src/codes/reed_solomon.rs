@@ -0,0 +1,289 @@
+//! Reed–Solomon codes over a radix-2 multiplicative subgroup (or a coset of
+//! it), with systematic encoding and decoding via Gao's algorithm.
+
+use crate::{
+    fft::{DenseOrSparsePolynomial, DensePolynomial, EvaluationDomain, Radix2EvaluationDomain},
+    Error, FftField, Field, Vec,
+};
+
+/// A Reed–Solomon code of length `n` and dimension `k`, whose codewords are
+/// the evaluations of polynomials of degree less than `k` over the coset
+/// `offset * H`, where `H` is the multiplicative subgroup of order `n`.
+///
+/// Both `n` and `k` are powers of two. The encoding is systematic: the `i`-th
+/// message symbol appears at position `i * n / k` of the codeword.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReedSolomonCode<F: FftField> {
+    domain: Radix2EvaluationDomain<F>,
+    message_domain: Radix2EvaluationDomain<F>,
+    offset: F,
+}
+
+impl<F: FftField> ReedSolomonCode<F> {
+    /// Constructs a code of dimension `message_len` and length `codeword_len`
+    /// evaluated over the subgroup of order `codeword_len`. Returns `None` if
+    /// the lengths are not powers of two, if `message_len` is zero or larger
+    /// than `codeword_len`, or if the field does not have a subgroup of the
+    /// required order.
+    pub fn new(message_len: usize, codeword_len: usize) -> Option<Self> {
+        Self::new_coset(message_len, codeword_len, F::one())
+    }
+
+    /// Constructs a code as in `new`, but evaluated over the coset
+    /// `offset * H`. Returns `None` if `offset` is zero.
+    pub fn new_coset(message_len: usize, codeword_len: usize, offset: F) -> Option<Self> {
+        if message_len == 0
+            || message_len > codeword_len
+            || !message_len.is_power_of_two()
+            || !codeword_len.is_power_of_two()
+            || offset.is_zero()
+        {
+            return None;
+        }
+        Some(Self {
+            domain: Radix2EvaluationDomain::new(codeword_len)?,
+            message_domain: Radix2EvaluationDomain::new(message_len)?,
+            offset,
+        })
+    }
+
+    /// Returns the number of symbols in a message.
+    pub fn message_len(&self) -> usize {
+        self.message_domain.size()
+    }
+
+    /// Returns the number of symbols in a codeword.
+    pub fn codeword_len(&self) -> usize {
+        self.domain.size()
+    }
+
+    /// Returns the minimum distance `n - k + 1` of the code.
+    pub fn distance(&self) -> usize {
+        self.codeword_len() - self.message_len() + 1
+    }
+
+    /// Returns the coset offset of the evaluation points.
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    /// Returns the evaluation points `offset * g^i` of the code.
+    pub fn evaluation_points(&self) -> Vec<F> {
+        self.domain.elements().map(|e| self.offset * &e).collect()
+    }
+
+    /// Returns the positions of the codeword that carry the message symbols.
+    pub fn systematic_positions(&self) -> impl Iterator<Item = usize> {
+        let step = self.codeword_len() / self.message_len();
+        (0..self.message_len()).map(move |i| i * step)
+    }
+
+    /// Returns the number of errors that can be corrected in the presence of
+    /// `num_erasures` erasures.
+    pub fn max_errors(&self, num_erasures: usize) -> usize {
+        (self.distance() - 1).saturating_sub(num_erasures) / 2
+    }
+
+    /// Systematically encodes `message`.
+    ///
+    /// # Panics
+    /// This method panics if `message.len() != self.message_len()`.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        assert_eq!(
+            message.len(),
+            self.message_len(),
+            "message has the wrong length"
+        );
+        // Since the codeword positions `i * n / k` form the subgroup of order
+        // `k`, interpolating there and evaluating over the full subgroup
+        // yields a systematic encoding. Scaling all points by `offset`
+        // does not change the set of codewords.
+        let coeffs = self.message_domain.ifft(message);
+        self.domain.fft(&coeffs)
+    }
+
+    /// Encodes the message polynomial `poly` by evaluating it over the
+    /// evaluation points of the code.
+    ///
+    /// # Panics
+    /// This method panics if the degree of `poly` is at least
+    /// `self.message_len()`.
+    pub fn encode_polynomial(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        assert!(
+            poly.is_degree_at_most(self.message_len() - 1),
+            "message polynomial has too large a degree"
+        );
+        let mut coeffs = poly.coeffs.clone();
+        Radix2EvaluationDomain::distribute_powers(&mut coeffs, self.offset);
+        self.domain.fft(&coeffs)
+    }
+
+    /// Decodes `received`, correcting up to `self.max_errors(0)` errors, and
+    /// returns the message.
+    pub fn decode(&self, received: &[F]) -> crate::Result<Vec<F>> {
+        let received: Vec<_> = received.iter().copied().map(Some).collect();
+        self.decode_with_erasures(&received)
+    }
+
+    /// Decodes `received`, where erased symbols are `None`, and returns the
+    /// message. With `e` erasures, up to `self.max_errors(e)` errors are
+    /// corrected.
+    pub fn decode_with_erasures(&self, received: &[Option<F>]) -> crate::Result<Vec<F>> {
+        let poly = self.decode_unshifted(received)?;
+        Ok(self.message_domain.fft(&poly.coeffs))
+    }
+
+    /// Decodes `received`, where erased symbols are `None`, and returns the
+    /// message polynomial, i.e. the polynomial of degree less than
+    /// `self.message_len()` whose evaluations over `self.evaluation_points()`
+    /// form the corrected codeword.
+    pub fn decode_to_polynomial(
+        &self,
+        received: &[Option<F>],
+    ) -> crate::Result<DensePolynomial<F>> {
+        let mut poly = self.decode_unshifted(received)?;
+        let offset_inv = self.offset.inverse().unwrap();
+        Radix2EvaluationDomain::distribute_powers(&mut poly.coeffs, offset_inv);
+        Ok(poly)
+    }
+
+    /// Runs Gao's algorithm and returns the polynomial `q` of degree less
+    /// than `k` with `q(g^i)` equal to the `i`-th symbol of the corrected
+    /// codeword.
+    fn decode_unshifted(&self, received: &[Option<F>]) -> crate::Result<DensePolynomial<F>> {
+        let n = self.codeword_len();
+        let k = self.message_len();
+        if received.len() != n {
+            return Err(Error("received word has the wrong length"));
+        }
+        let erased: Vec<usize> = received
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.is_none())
+            .map(|(i, _)| i)
+            .collect();
+        let num_available = n - erased.len();
+        if num_available < k {
+            return Err(Error("too many erasures"));
+        }
+
+        // Interpolate the received word, with zeros in the erased positions.
+        let filled: Vec<F> = received.iter().map(|r| r.unwrap_or_else(F::zero)).collect();
+        let interpolant = DensePolynomial::from_coefficients_vec(self.domain.ifft(&filled));
+
+        // `g0` vanishes on the unerased evaluation points, and `g1`
+        // interpolates the received word on them.
+        let mut vanishing = vec![F::zero(); n + 1];
+        vanishing[0] = -F::one();
+        vanishing[n] = F::one();
+        let mut g0 = DensePolynomial::from_coefficients_vec(vanishing);
+        let mut g1 = interpolant;
+        if !erased.is_empty() {
+            let elements: Vec<F> = self.domain.elements().collect();
+            let locator = erased.iter().fold(
+                DensePolynomial::from_coefficients_vec(vec![F::one()]),
+                |acc, i| mul_by_linear(&acc, elements[*i]),
+            );
+            g0 = divide(&g0, &locator).0;
+            g1 = divide(&g1, &g0).1;
+        }
+        if g1.is_zero() {
+            return Ok(g1);
+        }
+
+        // Stop at the first remainder of degree less than `(n' + k) / 2`,
+        // where `n'` is the number of unerased positions.
+        let degree_bound = (num_available + k + 1) / 2;
+        let (g, _, v) = g0.partial_xgcd(&g1, degree_bound);
+        let (poly, remainder) = divide(&g, &v);
+        if !remainder.is_zero() || !poly.is_degree_at_most(k - 1) {
+            return Err(Error("too many errors"));
+        }
+
+        let codeword = self.domain.fft(&poly.coeffs);
+        let num_errors = received
+            .iter()
+            .zip(&codeword)
+            .filter(|(r, c)| r.map_or(false, |r| r != **c))
+            .count();
+        if 2 * num_errors + erased.len() >= self.distance() {
+            return Err(Error("too many errors"));
+        }
+        Ok(poly)
+    }
+}
+
+/// Returns `poly * (x - root)`.
+fn mul_by_linear<F: Field>(poly: &DensePolynomial<F>, root: F) -> DensePolynomial<F> {
+    let mut coeffs = vec![F::zero(); poly.coeffs.len() + 1];
+    for (i, c) in poly.coeffs.iter().enumerate() {
+        coeffs[i + 1] += c;
+        coeffs[i] -= &(root * c);
+    }
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns the quotient and remainder of `a` divided by `b`.
+fn divide<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    let a: DenseOrSparsePolynomial<F> = a.into();
+    let b: DenseOrSparsePolynomial<F> = b.into();
+    a.divide_with_q_and_r(&b).expect("division failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codes::ReedSolomonCode;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, UniformRand};
+    use rand::Rng;
+
+    #[test]
+    fn encode_is_systematic() {
+        let rng = &mut test_rng();
+        let code = ReedSolomonCode::<Fr>::new(8, 32).unwrap();
+        let message: Vec<_> = (0..8).map(|_| Fr::rand(rng)).collect();
+        let codeword = code.encode(&message);
+        for (i, pos) in code.systematic_positions().enumerate() {
+            assert_eq!(codeword[pos], message[i]);
+        }
+        assert_eq!(code.decode(&codeword).unwrap(), message);
+    }
+
+    #[test]
+    fn decode_errors_and_erasures() {
+        let rng = &mut test_rng();
+        let code = ReedSolomonCode::<Fr>::new_coset(8, 32, Fr::rand(rng)).unwrap();
+        let message: Vec<_> = (0..8).map(|_| Fr::rand(rng)).collect();
+        let codeword = code.encode(&message);
+        for num_erasures in 0..code.distance() {
+            let num_errors = code.max_errors(num_erasures);
+            let mut received: Vec<_> = codeword.iter().copied().map(Some).collect();
+            received
+                .iter_mut()
+                .take(num_erasures)
+                .for_each(|r| *r = None);
+            for _ in 0..num_errors {
+                let pos = rng.gen_range(num_erasures, 32);
+                received[pos] = Some(Fr::rand(rng));
+            }
+            assert_eq!(code.decode_with_erasures(&received).unwrap(), message);
+            let poly = code.decode_to_polynomial(&received).unwrap();
+            assert_eq!(code.encode_polynomial(&poly), codeword);
+        }
+    }
+
+    #[test]
+    fn decode_too_many_errors() {
+        let rng = &mut test_rng();
+        let code = ReedSolomonCode::<Fr>::new(4, 16).unwrap();
+        let message: Vec<_> = (0..4).map(|_| Fr::rand(rng)).collect();
+        let mut received = code.encode(&message);
+        for r in received.iter_mut().take(code.distance()) {
+            *r = Fr::rand(rng);
+        }
+        assert_ne!(code.decode(&received).ok(), Some(message));
+    }
+}
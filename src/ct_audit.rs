@@ -0,0 +1,280 @@
+//! Timing self-tests of the paths that are meant to run in constant time,
+//! enabled by the `ct-audit` feature, in the style of
+//! [dudect](https://eprint.iacr.org/2016/1123): the path is timed on inputs
+//! drawn at random from two classes, one fixed input and uniformly random
+//! ones, and Welch's t-test tells whether the two timing distributions
+//! differ. The crate times only the instrumented path, from inside, so the
+//! setup of the inputs does not add noise.
+//!
+//! The instrumented paths are `"wnaf"` and `"booth_recode"` for big
+//! integers, audited here, and `"inverse"`, `"sqrt"` and `"from_repr"` in
+//! prime fields, audited in `ff::ct_audit`. The tests are meant to be called
+//! from the test suites of users, on the hardware they target, and with
+//! optimizations, e.g.
+//!
+//! ```ignore
+//! #[test]
+//! fn inverse_is_constant_time() {
+//!     let report = ff::ct_audit::audit_inverse::<Fr, _>(&mut rng, 100_000).unwrap();
+//!     assert!(!report.leaks(), "{}", report);
+//! }
+//! ```
+//!
+//! Without the feature, the probes compile to nothing.
+
+use core::{cell::Cell, fmt, hint::black_box};
+use rand_core::RngCore;
+use std::time::Instant;
+
+use crate::{uint::Uint, Vec};
+
+/// The `|t|` above which a report tells of a leak: dudect's threshold for
+/// "definitely not constant time".
+pub const T_THRESHOLD: f64 = 10.0;
+
+/// The number of cropped copies of the measurements that are tested on top
+/// of all of them, each without the slowest ones from a percentile, since
+/// timing noise mostly makes measurements slower.
+const NUM_CROPS: usize = 20;
+
+thread_local! {
+    static ACTIVE: Cell<Option<&'static str>> = const { Cell::new(None) };
+    static ELAPSED: Cell<u64> = const { Cell::new(0) };
+    static HITS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Times the instrumented path `path` until it is dropped, if `measure` is
+/// auditing that path on this thread. Created by `ct_probe!`.
+pub struct Probe {
+    start: Option<Instant>,
+}
+
+impl Probe {
+    #[inline]
+    pub fn new(path: &'static str) -> Self {
+        let active = ACTIVE.with(|a| a.get() == Some(path));
+        Probe {
+            start: if active { Some(Instant::now()) } else { None },
+        }
+    }
+}
+
+impl Drop for Probe {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed().as_nanos() as u64;
+            ELAPSED.with(|e| e.set(e.get() + elapsed));
+            HITS.with(|h| h.set(h.get() + 1));
+        }
+    }
+}
+
+/// The class of an input in a timing test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Class {
+    /// The same input every time.
+    Fixed,
+    /// A fresh uniformly random input.
+    Random,
+}
+
+/// The outcome of a timing test.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingReport {
+    /// The path that was timed.
+    pub path: &'static str,
+    /// The number of timings.
+    pub samples: usize,
+    /// The largest `|t|` of Welch's t-test, over all the measurements and
+    /// their cropped copies.
+    pub max_t: f64,
+}
+
+impl TimingReport {
+    /// Returns true iff the timings of the two classes of inputs differ
+    /// beyond `T_THRESHOLD`.
+    pub fn leaks(&self) -> bool {
+        self.max_t > T_THRESHOLD
+    }
+}
+
+impl fmt::Display for TimingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: max |t| = {:.2} over {} samples, {}",
+            self.path,
+            self.max_t,
+            self.samples,
+            if self.leaks() {
+                "timing leak"
+            } else {
+                "no leak detected"
+            }
+        )
+    }
+}
+
+/// Times `path` while running `op` on `samples` inputs built by `input`,
+/// for classes chosen at random with `rng`, and returns the t-test of the
+/// timings. Inputs are built before they are timed. Returns None if `op`
+/// never reaches `path`.
+pub fn measure<R, I, O>(
+    path: &'static str,
+    rng: &mut R,
+    samples: usize,
+    mut input: impl FnMut(Class, &mut R) -> I,
+    mut op: impl FnMut(I) -> O,
+) -> Option<TimingReport>
+where
+    R: RngCore + ?Sized,
+{
+    let classes: Vec<Class> = (0..samples)
+        .map(|_| match rng.next_u32() & 1 {
+            0 => Class::Fixed,
+            _ => Class::Random,
+        })
+        .collect();
+    let inputs: Vec<I> = classes.iter().map(|class| input(*class, rng)).collect();
+
+    ACTIVE.with(|a| a.set(Some(path)));
+    let mut timings = Vec::with_capacity(samples);
+    let mut reached = false;
+    for input in inputs {
+        ELAPSED.with(|e| e.set(0));
+        HITS.with(|h| h.set(0));
+        black_box(op(black_box(input)));
+        reached |= HITS.with(|h| h.get()) > 0;
+        timings.push(ELAPSED.with(|e| e.get()));
+    }
+    ACTIVE.with(|a| a.set(None));
+    if !reached {
+        return None;
+    }
+
+    let mut sorted = timings.clone();
+    sorted.sort_unstable();
+    let mut max_t = welch_t(&classes, &timings, u64::MAX).abs();
+    for k in 0..NUM_CROPS {
+        // The percentiles of dudect: `1 - 2^(-10 (k + 1) / NUM_CROPS)`.
+        let percentile = 1.0 - 0.5f64.powf(10.0 * (k + 1) as f64 / NUM_CROPS as f64);
+        let threshold = sorted[((samples - 1) as f64 * percentile) as usize];
+        max_t = max_t.max(welch_t(&classes, &timings, threshold).abs());
+    }
+    Some(TimingReport {
+        path,
+        samples,
+        max_t,
+    })
+}
+
+/// Returns Welch's t statistic of the timings of the two classes that are
+/// at most `threshold`, or 0 with fewer than two in a class.
+fn welch_t(classes: &[Class], timings: &[u64], threshold: u64) -> f64 {
+    // Welford's running means and sums of squared deviations.
+    let mut n = [0f64; 2];
+    let mut mean = [0f64; 2];
+    let mut m2 = [0f64; 2];
+    for (class, t) in classes.iter().zip(timings) {
+        if *t > threshold {
+            continue;
+        }
+        let c = (*class == Class::Random) as usize;
+        let t = *t as f64;
+        n[c] += 1.0;
+        let delta = t - mean[c];
+        mean[c] += delta / n[c];
+        m2[c] += delta * (t - mean[c]);
+    }
+    if n[0] < 2.0 || n[1] < 2.0 {
+        return 0.0;
+    }
+    let var = [m2[0] / (n[0] - 1.0), m2[1] / (n[1] - 1.0)];
+    let se = (var[0] / n[0] + var[1] / n[1]).sqrt();
+    if se == 0.0 {
+        // Equal constant timings do not leak; different ones do.
+        return if mean[0] == mean[1] {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    (mean[0] - mean[1]) / se
+}
+
+/// Tests `Uint::find_wnaf_with_window` of `U` with window `w`, on one
+/// against random integers. Non-adjacent forms are not constant time, so
+/// this is expected to leak; see `audit_booth_recode`.
+pub fn audit_wnaf<U, R>(rng: &mut R, samples: usize, w: usize) -> Option<TimingReport>
+where
+    U: Uint,
+    R: RngCore + ?Sized,
+{
+    measure(
+        "wnaf",
+        rng,
+        samples,
+        |class, rng| match class {
+            Class::Fixed => U::from(1u64),
+            Class::Random => U::random(rng),
+        },
+        |x| x.find_wnaf_with_window(w),
+    )
+}
+
+/// Tests `Uint::booth_recode` of `U` with window `w`, on one against random
+/// integers.
+pub fn audit_booth_recode<U, R>(rng: &mut R, samples: usize, w: usize) -> Option<TimingReport>
+where
+    U: Uint,
+    R: RngCore + ?Sized,
+{
+    measure(
+        "booth_recode",
+        rng,
+        samples,
+        |class, rng| match class {
+            Class::Fixed => U::from(1u64),
+            Class::Random => U::random(rng),
+        },
+        |x| x.booth_recode(w),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{uint::arithmetic, utils::BitIterator};
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    crate::uint_impl!(U256, 4);
+
+    fn leaky(x: u64) -> u64 {
+        ct_probe!("leaky");
+        if x == 0 {
+            std::thread::sleep(std::time::Duration::from_micros(200));
+        }
+        x
+    }
+
+    #[test]
+    fn ct_audit_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1);
+        let input = |class, rng: &mut XorShiftRng| match class {
+            Class::Fixed => 0,
+            Class::Random => rng.next_u64() | 1,
+        };
+        let report = measure("leaky", &mut rng, 200, input, leaky).unwrap();
+        assert_eq!(report.samples, 200);
+        assert!(report.leaks(), "{}", report);
+
+        // A path that is not reached is not reported.
+        assert_eq!(measure("unknown", &mut rng, 10, input, leaky), None);
+
+        let report = audit_booth_recode::<U256, _>(&mut rng, 1000, 4).unwrap();
+        assert_eq!(report.path, "booth_recode");
+        assert!(audit_wnaf::<U256, _>(&mut rng, 1000, 4).is_some());
+    }
+}
@@ -0,0 +1,414 @@
+//! Discrete logarithms in groups of small order, e.g. to decode values
+//! encoded in the exponent, such as ElGamal-in-the-exponent tallies.
+//!
+//! `baby_step_giant_step` takes `O(sqrt(n))` group operations and memory for
+//! a base of order `n`, and `pohlig_hellman` reduces the problem to the
+//! prime factors of `n`, so that only the largest one matters. Orders are
+//! limited to `MAX_ORDER`.
+//!
+//! `pollard_rho`, for prime orders, and `kangaroo`, for exponents in an
+//! interval, take about as many operations but constant memory, and
+//! `has_order` checks the order of an element from the factors of the
+//! order, so that tests can check the generators and roots of unity that
+//! parameters claim from first principles.
+//!
+//! The solvers work in any `PrimeOrderGroup`, written additively, so in
+//! every `Group` and in subgroups of field elements through
+//! `MultiplicativeSubgroup`. The multiples they take are below `MAX_ORDER`,
+//! hence below the modulus of the scalar field.
+
+use core::hash::{Hash, Hasher};
+
+use crate::{group::PrimeOrderGroup, Vec};
+
+/// The largest supported order of a base.
+pub const MAX_ORDER: u64 = 1 << 40;
+
+/// Returns `exp * g`, for `exp` below the modulus of the scalar field.
+#[inline]
+fn pow<G: PrimeOrderGroup>(g: &G, exp: u64) -> G {
+    g.mul(&G::ScalarField::from(exp))
+}
+
+/// FNV-1a, to index the baby steps without a hash map.
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+fn fingerprint<T: Hash>(value: &T) -> u64 {
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns `floor(sqrt(n))`, by Newton's method.
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Starts above the root and decreases until it reaches it.
+    let mut x = 1u64 << ((64 - n.leading_zeros() + 1) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Returns the `x < order` with `x * base = target`, or None if there is none.
+/// `order` must be a multiple of the order of `base`.
+///
+/// Panics if `order` is zero or larger than `MAX_ORDER`.
+pub fn baby_step_giant_step<G: PrimeOrderGroup + Hash>(
+    base: G,
+    target: G,
+    order: u64,
+) -> Option<u64> {
+    assert!(order > 0 && order <= MAX_ORDER, "unsupported order");
+    let mut m = isqrt(order);
+    while m * m < order {
+        m += 1;
+    }
+
+    // Baby steps `j * base` for `j < m`, sorted by fingerprint.
+    let mut table = Vec::with_capacity(m as usize);
+    let mut step = G::identity();
+    for j in 0..m {
+        table.push((fingerprint(&step), j));
+        step = step.add(&base);
+    }
+    table.sort_unstable();
+
+    // Giant steps `target - i * m * base`, with `-m * base = (order - m) * base`.
+    let giant = pow(&base, order - m);
+    let mut gamma = target;
+    for i in 0..m {
+        let fp = fingerprint(&gamma);
+        let start = table.partition_point(|(f, _)| *f < fp);
+        for &(_, j) in table[start..].iter().take_while(|(f, _)| *f == fp) {
+            let x = i * m + j;
+            if x < order && pow(&base, j) == gamma {
+                return Some(x);
+            }
+        }
+        gamma = gamma.add(&giant);
+    }
+    None
+}
+
+/// Returns the `x < order` with `x * base = target`, or None if there is none,
+/// by solving the problem in the subgroups of prime order dividing `order`
+/// with `baby_step_giant_step`. `order` must be the order of `base`.
+///
+/// Panics if `order` is zero or larger than `MAX_ORDER`.
+pub fn pohlig_hellman<G: PrimeOrderGroup + Hash>(base: G, target: G, order: u64) -> Option<u64> {
+    assert!(order > 0 && order <= MAX_ORDER, "unsupported order");
+    let mut x = 0u64;
+    let mut modulus = 1u64;
+    for (q, e) in factor(order) {
+        let q_e = q.pow(e);
+        let cofactor = order / q_e;
+        let base_i = pow(&base, cofactor);
+        let target_i = pow(&target, cofactor);
+
+        // Solve for x mod q^e one base-q digit at a time, in the subgroup
+        // of order q generated by `gamma`.
+        let gamma = pow(&base_i, q_e / q);
+        let mut x_i = 0u64;
+        let mut q_k = 1u64;
+        for _ in 0..e {
+            let shifted = target_i.add(&pow(&base_i, (q_e - x_i) % q_e));
+            let digit = baby_step_giant_step(gamma, pow(&shifted, q_e / q / q_k), q)?;
+            x_i += digit * q_k;
+            q_k *= q;
+        }
+
+        x = crt(x, modulus, x_i, q_e);
+        modulus *= q_e;
+    }
+    if pow(&base, x) == target {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Returns true iff `base` has order `order` exactly: `order * base` is the
+/// identity and `(order / q) * base` is not, for every prime `q` dividing
+/// `order`.
+///
+/// Panics if `order` is zero.
+pub fn has_order<G: PrimeOrderGroup>(base: G, order: u64) -> bool {
+    assert!(order > 0, "orders are positive");
+    pow(&base, order) == G::identity()
+        && factor(order)
+            .iter()
+            .all(|(q, _)| pow(&base, order / q) != G::identity())
+}
+
+/// The number of walks `pollard_rho` and `kangaroo` try, each with other
+/// pseudorandom steps, before concluding that there is no solution.
+const MAX_WALKS: u64 = 8;
+
+/// Returns the `x < order` with `x * base = target`, or None if there is none,
+/// with Pollard's rho: a pseudorandom walk over the elements
+/// `a * base + b * target` runs into a cycle after about `sqrt(order)` steps,
+/// found with Floyd's algorithm in constant memory, and a collision solves
+/// for `x`. `order` must be the order of `base`, and prime.
+///
+/// Panics if `order` is zero or larger than `MAX_ORDER`.
+pub fn pollard_rho<G: PrimeOrderGroup + Hash>(base: G, target: G, order: u64) -> Option<u64> {
+    assert!(order > 0 && order <= MAX_ORDER, "unsupported order");
+    let n = order as u128;
+    // Steps by `base`, by `target` or doublings, by a third of the
+    // fingerprints, which are shifted from one walk to the next.
+    let step = |walk: u64, (y, a, b): (G, u128, u128)| {
+        let part = fingerprint(&y).rotate_left(7 * walk as u32) % 3;
+        match part {
+            0 => (y.add(&base), (a + 1) % n, b),
+            1 => (y.add(&target), a, (b + 1) % n),
+            _ => (y.add(&y), 2 * a % n, 2 * b % n),
+        }
+    };
+
+    // Cycles are found after about `sqrt(order)` steps, unless `target` is
+    // not a power of `base`.
+    let max_steps = 16 * isqrt(order) + 16;
+    for walk in 0..MAX_WALKS {
+        let (a, b) = ((walk as u128 + 1) % n, 1 % n);
+        let start = (pow(&base, a as u64).add(&target), a, b);
+        let (mut tortoise, mut hare) = (step(walk, start), step(walk, step(walk, start)));
+        for _ in 0..max_steps {
+            if tortoise.0 == hare.0 {
+                break;
+            }
+            tortoise = step(walk, tortoise);
+            hare = step(walk, step(walk, hare));
+        }
+        // `a1 * base + b1 * target = a2 * base + b2 * target`, so
+        // `x (b1 - b2) = a2 - a1 mod order`.
+        let (_, a1, b1) = tortoise;
+        let (_, a2, b2) = hare;
+        let db = (b1 + n - b2) % n;
+        if db == 0 {
+            continue;
+        }
+        let x = (a2 + n - a1) % n * mod_inverse(db as u64, order) as u128 % n;
+        if pow(&base, x as u64) == target {
+            return Some(x as u64);
+        }
+    }
+    None
+}
+
+/// Returns the `x` in `[lower, upper)` with `x * base = target`, or None if
+/// there is none, with Pollard's kangaroo: a tame kangaroo jumps from
+/// `upper * base` and sets a trap where it stops, and a wild one jumps from
+/// `target` with the same pseudorandom jumps until it falls into the trap
+/// or passes it. Both take about `sqrt(upper - lower)` jumps. The interval
+/// must not be wider than the order of `base`.
+///
+/// Panics if the interval is empty.
+pub fn kangaroo<G: PrimeOrderGroup + Hash>(
+    base: G,
+    target: G,
+    lower: u64,
+    upper: u64,
+) -> Option<u64> {
+    assert!(lower < upper, "the interval is empty");
+    let width = upper - lower;
+    if width <= 16 {
+        return (lower..upper).find(|x| pow(&base, *x) == target);
+    }
+
+    // Jumps by `2^i` for `i < k`, with a mean of about `sqrt(width) / 2`.
+    let sqrt = isqrt(width);
+    let mut k = 1;
+    while ((1u64 << k) - 1) / k < sqrt / 2 {
+        k += 1;
+    }
+    let jumps: Vec<(G, u64)> = (0..k).map(|i| (pow(&base, 1 << i), 1u64 << i)).collect();
+    let jump = |walk: u64, y: &G| {
+        let i = fingerprint(&y).rotate_left(7 * walk as u32) % k;
+        jumps[i as usize]
+    };
+
+    for walk in 0..MAX_WALKS {
+        let (mut tame, mut tame_distance) = (pow(&base, upper), 0u64);
+        for _ in 0..2 * sqrt {
+            let (g, d) = jump(walk, &tame);
+            tame = tame.add(&g);
+            tame_distance += d;
+        }
+
+        // The trap is at `upper + tame_distance`, which the wild kangaroo
+        // passes after `width + tame_distance` at most.
+        let (mut wild, mut wild_distance) = (target, 0u64);
+        while wild_distance <= width + tame_distance {
+            if wild == tame {
+                // `x = upper + tame_distance - wild_distance`, unless the
+                // walks met modulo the order of `base`.
+                match (upper + tame_distance).checked_sub(wild_distance) {
+                    Some(x) if (lower..upper).contains(&x) && pow(&base, x) == target => {
+                        return Some(x)
+                    }
+                    _ => break,
+                }
+            }
+            let (g, d) = jump(walk, &wild);
+            wild = wild.add(&g);
+            wild_distance += d;
+        }
+    }
+    None
+}
+
+/// Returns the prime factorization of `n` by trial division.
+fn factor(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        let mut e = 0;
+        while n % p == 0 {
+            n /= p;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((p, e));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Returns the `x < m1 * m2` congruent to `a1` mod `m1` and to `a2` mod
+/// `m2`, for coprime `m1` and `m2`.
+fn crt(a1: u64, m1: u64, a2: u64, m2: u64) -> u64 {
+    // x = a1 + m1 * ((a2 - a1) * m1^(-1) mod m2)
+    let inv = mod_inverse(m1 % m2, m2);
+    let diff = (a2 as u128 + m2 as u128 - (a1 % m2) as u128) % m2 as u128;
+    let t = diff * inv as u128 % m2 as u128;
+    a1 + m1 * t as u64
+}
+
+fn mod_inverse(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(m as i128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ff::FftField, group::MultiplicativeSubgroup};
+    use algebra::bls12_381::fr::Fr;
+
+    /// Subgroups of `Fr^*`, with `Fr` for the multiples, which are all below
+    /// its modulus.
+    fn elem(x: Fr) -> MultiplicativeSubgroup<Fr, Fr> {
+        MultiplicativeSubgroup::new_unchecked(x)
+    }
+
+    #[test]
+    fn factor_test() {
+        assert_eq!(factor(1), vec![]);
+        assert_eq!(factor(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factor(1_000_003), vec![(1_000_003, 1)]);
+        assert_eq!(crt(2, 3, 3, 5), 8);
+        assert_eq!(crt(0, 1, 4, 7), 4);
+        for n in (0..1000).chain(vec![MAX_ORDER - 1, MAX_ORDER, u64::MAX]) {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1).checked_mul(r + 1).map_or(true, |s| s > n));
+        }
+    }
+
+    #[test]
+    fn dlog_test() {
+        // A root of unity of order 2^20.
+        let order = 1u64 << 20;
+        let base = elem(Fr::get_root_of_unity(order as usize).unwrap());
+        for &x in [0, 1, 12345, order - 1].iter() {
+            let target = pow(&base, x);
+            assert_eq!(baby_step_giant_step(base, target, order), Some(x));
+            assert_eq!(pohlig_hellman(base, target, order), Some(x));
+        }
+
+        // A target outside of the subgroup.
+        let target = elem(Fr::from(3u64));
+        assert_eq!(baby_step_giant_step(base, target, order), None);
+        assert_eq!(pohlig_hellman(base, target, order), None);
+
+        // A subgroup of order 3 * 11 * 19 * 2^3, from the order of Fr^*.
+        let base = elem(Fr::multiplicative_generator());
+        let order = 3 * 11 * 19 * 8;
+        let mut cofactor = Fr::characteristic().to_vec();
+        cofactor[0] -= 1;
+        let base = elem(base.element().pow(div_limbs(&cofactor, order)));
+        let target = pow(&base, 4321);
+        assert_eq!(pohlig_hellman(base, target, order), Some(4321));
+        assert_eq!(baby_step_giant_step(base, target, order), Some(4321));
+    }
+
+    #[test]
+    fn rho_kangaroo_test() {
+        use crate::ff::FftParameters;
+
+        // The two-adic root of unity has the order the parameters claim.
+        let two_adicity = <Fr as FftField>::FftParams::TWO_ADICITY;
+        let root = elem(Fr::two_adic_root_of_unity());
+        assert!(has_order(root, 1 << two_adicity));
+        assert!(!has_order(root.add(&root), 1 << two_adicity));
+
+        // A subgroup of prime order 859267, from the order of Fr^*.
+        let order = 859_267;
+        let mut cofactor = Fr::characteristic().to_vec();
+        cofactor[0] -= 1;
+        let base = elem(Fr::multiplicative_generator().pow(div_limbs(&cofactor, order)));
+        assert!(has_order(base, order));
+        for &x in [0, 1, 4321, order - 1].iter() {
+            assert_eq!(pollard_rho(base, pow(&base, x), order), Some(x));
+        }
+        assert_eq!(pollard_rho(base, root, order), None);
+
+        let base = elem(Fr::get_root_of_unity(1 << 24).unwrap());
+        let (lower, upper) = (1000, 1000 + (1 << 20));
+        for &x in [lower, 123_456, upper - 1].iter() {
+            assert_eq!(kangaroo(base, pow(&base, x), lower, upper), Some(x));
+        }
+        assert_eq!(kangaroo(base, pow(&base, 10), lower, upper), None);
+    }
+
+    /// Divides a little-endian number by a small divisor.
+    fn div_limbs(limbs: &[u64], d: u64) -> Vec<u64> {
+        let mut out = vec![0u64; limbs.len()];
+        let mut rem = 0u128;
+        for (o, l) in out.iter_mut().zip(limbs).rev() {
+            let cur = (rem << 64) | *l as u128;
+            *o = (cur / d as u128) as u64;
+            rem = cur % d as u128;
+        }
+        assert_eq!(rem, 0);
+        out
+    }
+}
@@ -0,0 +1,208 @@
+//! Exponentiation by fixed exponents, such as the ones of inversion, square
+//! roots and Legendre symbols, along addition chains computed at compile
+//! time.
+//!
+//! The chains come from a sliding-window recoding of the exponent with
+//! windows of `WINDOW` bits: the base is raised to the odd powers below
+//! `2^WINDOW` once, and each window then costs a single multiplication
+//! instead of one per set bit.
+
+use crate::ff::Field;
+
+/// The width in bits of the windows of the recoding.
+const WINDOW: usize = 4;
+
+/// An addition chain for a fixed exponent of at most `BITS` bits, as a
+/// sequence of steps `(squarings, digit)`: square the accumulator
+/// `squarings` times, then multiply it by `base^digit` if `digit` is not
+/// zero. Digits are odd and smaller than `2^WINDOW`.
+#[derive(Clone, Copy, Debug)]
+pub struct AdditionChain<const BITS: usize> {
+    steps: [(u16, u8); BITS],
+    len: usize,
+}
+
+impl<const BITS: usize> AdditionChain<BITS> {
+    /// Computes the chain of the exponent with the given little-endian
+    /// limbs, which must fit in `BITS` bits.
+    pub const fn new(exp: &[u64]) -> Self {
+        let mut steps = [(0u16, 0u8); BITS];
+        let mut len = 0;
+
+        let mut num_bits = exp.len() * 64;
+        while num_bits > 0 && !bit(exp, num_bits - 1) {
+            num_bits -= 1;
+        }
+        assert!(num_bits <= BITS, "exponent too large for the chain");
+
+        // Scan from the most significant bit, cutting windows that start and
+        // end with a set bit.
+        let mut squarings = 0u16;
+        let mut i = num_bits;
+        while i > 0 {
+            if !bit(exp, i - 1) {
+                squarings += 1;
+                i -= 1;
+                continue;
+            }
+            let mut low = if i > WINDOW { i - WINDOW } else { 0 };
+            while !bit(exp, low) {
+                low += 1;
+            }
+            let mut digit = 0u8;
+            let mut j = i;
+            while j > low {
+                digit = (digit << 1) | bit(exp, j - 1) as u8;
+                j -= 1;
+            }
+            // The accumulator starts at one, so the first squarings are
+            // skipped.
+            if len > 0 {
+                squarings += (i - low) as u16;
+            }
+            steps[len] = (squarings, digit);
+            len += 1;
+            squarings = 0;
+            i = low;
+        }
+        if squarings > 0 {
+            steps[len] = (squarings, 0);
+            len += 1;
+        }
+
+        AdditionChain { steps, len }
+    }
+
+    /// Returns `base` raised to the exponent of the chain.
+    pub fn pow<F: Field>(&self, base: &F) -> F {
+        // base, base^3, ..., base^(2^WINDOW - 1)
+        let mut odd_powers = [*base; 1 << (WINDOW - 1)];
+        let square = base.square();
+        for i in 1..odd_powers.len() {
+            odd_powers[i] = odd_powers[i - 1] * &square;
+        }
+
+        let mut res = F::one();
+        for &(squarings, digit) in &self.steps[..self.len] {
+            for _ in 0..squarings {
+                res = res.square();
+            }
+            if digit != 0 {
+                res *= &odd_powers[digit as usize / 2];
+            }
+        }
+        res
+    }
+}
+
+const fn bit(limbs: &[u64], i: usize) -> bool {
+    (limbs[i / 64] >> (i % 64)) & 1 == 1
+}
+
+/// Returns the little-endian limbs of `limbs + x`, e.g. to derive exponents
+/// from the modulus at compile time. Panics on overflow.
+pub const fn add_u64<const N: usize>(mut limbs: [u64; N], x: u64) -> [u64; N] {
+    let mut carry = x;
+    let mut i = 0;
+    while carry != 0 {
+        let (sum, overflow) = limbs[i].overflowing_add(carry);
+        limbs[i] = sum;
+        carry = overflow as u64;
+        i += 1;
+    }
+    limbs
+}
+
+/// Returns the little-endian limbs of `limbs - x`. Panics on underflow.
+pub const fn sub_u64<const N: usize>(mut limbs: [u64; N], x: u64) -> [u64; N] {
+    let mut borrow = x;
+    let mut i = 0;
+    while borrow != 0 {
+        let (diff, underflow) = limbs[i].overflowing_sub(borrow);
+        limbs[i] = diff;
+        borrow = underflow as u64;
+        i += 1;
+    }
+    limbs
+}
+
+/// Returns the little-endian limbs of `limbs >> k`, for `0 < k < 64`.
+pub const fn shr<const N: usize>(mut limbs: [u64; N], k: u32) -> [u64; N] {
+    let mut i = 0;
+    while i < N {
+        limbs[i] >>= k;
+        if i + 1 < N {
+            limbs[i] |= limbs[i + 1] << (64 - k);
+        }
+        i += 1;
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::{FpParameters, PrimeField};
+    use algebra::bls12_381::{
+        fq::{Fq, FqParameters},
+        fr::{Fr, FrParameters},
+    };
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    #[test]
+    fn addition_chain_test() {
+        let rng = &mut test_rng();
+        let base: Fr = rng.gen();
+        for exp in [
+            [0u64, 0],
+            [1, 0],
+            [2, 0],
+            [0xf0f0, 0],
+            [0, 1],
+            [u64::MAX, u64::MAX],
+        ] {
+            let chain = AdditionChain::<128>::new(&exp);
+            assert_eq!(chain.pow(&base), base.pow(exp));
+        }
+        for _ in 0..10 {
+            let exp: [u64; 4] = rng.gen();
+            assert_eq!(AdditionChain::<256>::new(&exp).pow(&base), base.pow(exp));
+        }
+
+        const CHAIN: AdditionChain<256> =
+            AdditionChain::new(&FrParameters::MODULUS_MINUS_ONE_DIV_TWO.0);
+        assert_eq!(CHAIN.pow(&base), base.pow(Fr::modulus_minus_one_div_two()));
+    }
+
+    #[test]
+    fn modulus_exponents_test() {
+        assert_eq!(add_u64([u64::MAX, 0], 1), [0, 1]);
+        assert_eq!(sub_u64([0, 1], 2), [u64::MAX - 1, 0]);
+        assert_eq!(shr([0, 3], 2), [0xc000_0000_0000_0000, 0]);
+
+        let rng = &mut test_rng();
+
+        // Fermat inversion.
+        const INVERSE: AdditionChain<256> =
+            AdditionChain::new(&sub_u64(FrParameters::MODULUS.0, 2));
+        for _ in 0..10 {
+            let a: Fr = rng.gen();
+            assert_eq!(INVERSE.pow(&a), a.pow(sub_u64(FrParameters::MODULUS.0, 2)));
+            assert_eq!(INVERSE.pow(&a), a.inverse().unwrap());
+            assert_eq!(a.inverse_by_chain(), a.inverse());
+        }
+        assert_eq!(Fr::from(0u64).inverse_by_chain(), None);
+
+        // Square roots modulo p = 3 mod 4.
+        const SQRT: AdditionChain<384> =
+            AdditionChain::new(&shr(add_u64(FqParameters::MODULUS.0, 1), 2));
+        for _ in 0..10 {
+            let a: Fq = rng.gen();
+            let exp = shr(add_u64(FqParameters::MODULUS.0, 1), 2);
+            assert_eq!(SQRT.pow(&a), a.pow(exp));
+            let root = SQRT.pow(&a.square());
+            assert!(root == a || root == -a);
+        }
+    }
+}
@@ -9,6 +9,15 @@ macro_rules! impl_field_mul_assign {
         #[inline]
         #[unroll_for_loops]
         fn mul_assign(&mut self, other: &Self) {
+            record!(field_mul);
+            assert_canonical!(self, other);
+            #[cfg(feature = "fiat-crypto")]
+            {
+                if let Some(backend) = Self::fiat_backend() {
+                    (self.0).0 = (backend.mul)(&(self.0).0, &(other.0).0);
+                    return;
+                }
+            }
             // Checking the modulus at compile time
             let first_bit_set = P::MODULUS.0[$limbs - 1] >> 63 != 0;
             let mut all_bits_set = P::MODULUS.0[$limbs - 1] == !0 - (1 << 63);
@@ -80,7 +89,20 @@ macro_rules! impl_field_into_repr {
         #[inline]
         #[unroll_for_loops]
         fn into_repr(&self) -> $BigIntegerType {
+            assert_canonical!(self);
             let mut tmp = self.0;
+            #[cfg(feature = "fiat-crypto")]
+            {
+                // The reduction below drops a carry for moduli close to `R`,
+                // like those of the backends. Their Montgomery product by the
+                // integer 1 divides by `R` too.
+                if let Some(backend) = Self::fiat_backend() {
+                    let mut one = [0u64; $limbs];
+                    one[0] = 1;
+                    tmp.0 = (backend.mul)(&tmp.0, &one);
+                    return tmp;
+                }
+            }
             let mut r = tmp.0;
             // Montgomery Reduction
             for i in 0..$limbs {
@@ -106,6 +128,15 @@ macro_rules! impl_field_square_in_place {
         #[unroll_for_loops]
         #[allow(unused_braces)]
         fn square_in_place(&mut self) -> &mut Self {
+            record!(field_mul);
+            assert_canonical!(self);
+            #[cfg(feature = "fiat-crypto")]
+            {
+                if let Some(backend) = Self::fiat_backend() {
+                    (self.0).0 = (backend.square)(&(self.0).0);
+                    return self;
+                }
+            }
             // Checking the modulus at compile time
             let first_bit_set = P::MODULUS.0[$limbs - 1] >> 63 != 0;
             let mut all_bits_set = P::MODULUS.0[$limbs - 1] == !0 - (1 << 63);
@@ -242,7 +273,11 @@ macro_rules! impl_prime_field_from_int {
 }
 
 macro_rules! sqrt_impl {
-    ($Self:ident, $P:tt, $self:expr) => {{
+    ($Self:ident, $P:tt, $self:expr) => {
+        sqrt_impl!($Self, $P, $self, $self.pow($P::T_MINUS_ONE_DIV_TWO))
+    };
+    // `$pow_t_minus_one_div_two` computes `self^((t - 1) / 2)`.
+    ($Self:ident, $P:tt, $self:expr, $pow_t_minus_one_div_two:expr) => {{
         use crate::ff::LegendreSymbol::*;
         // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
         // Actually this is just normal Tonelli-Shanks; since `P::Generator`
@@ -253,7 +288,7 @@ macro_rules! sqrt_impl {
             QuadraticNonResidue => None,
             QuadraticResidue => {
                 let mut z = $Self::qnr_to_t();
-                let mut w = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+                let mut w = $pow_t_minus_one_div_two;
                 let mut x = w * $self;
                 let mut b = x * &w;
 
@@ -298,6 +333,43 @@ macro_rules! sqrt_impl {
     }};
 }
 
+// Implements canonical serialization of an extension field as the
+// concatenation of its coefficients, which are read back with `new`.
+macro_rules! impl_extension_field_serialize {
+    ($type: ident, $params: ident, $($c: ident),+) => {
+        impl<P: $params> CanonicalSerialize for $type<P> {
+            #[inline]
+            fn serialize_with_mode(
+                &self,
+                writer: &mut crate::Vec<u8>,
+                compress: Compress,
+            ) -> crate::Result<()> {
+                $(self.$c.serialize_with_mode(writer, compress)?;)+
+                Ok(())
+            }
+
+            #[inline]
+            fn serialized_size(&self, compress: Compress) -> usize {
+                0 $(+ self.$c.serialized_size(compress))+
+            }
+        }
+
+        impl<P: $params> CanonicalDeserialize for $type<P> {
+            #[inline]
+            fn deserialize_with_mode(
+                reader: &mut &[u8],
+                compress: Compress,
+                validate: Validate,
+            ) -> crate::Result<Self> {
+                $(let $c = CanonicalDeserialize::deserialize_with_mode(reader, compress, validate)?;)+
+                Ok($type::new($($c),+))
+            }
+        }
+
+        impl_from_canonical!([P: $params], $type<P>);
+    };
+}
+
 // Implements AddAssign on Self by deferring to an implementation on &Self
 #[macro_export]
 macro_rules! impl_additive_ops_from_ref {
@@ -394,6 +466,61 @@ macro_rules! impl_additive_ops_from_ref {
     };
 }
 
+// Implements the `num_traits` operations beyond `Zero` and `One` when the
+// `num` feature is enabled. `Inv` returns `None` for zero.
+macro_rules! impl_num_traits_ops {
+    ($type: ident, $params: ident) => {
+        #[cfg(feature = "num")]
+        impl<P: $params> num_traits::Inv for $type<P> {
+            type Output = Option<Self>;
+
+            #[inline]
+            fn inv(self) -> Option<Self> {
+                self.inverse()
+            }
+        }
+
+        #[cfg(feature = "num")]
+        impl<P: $params> num_traits::Pow<u64> for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, exp: u64) -> Self {
+                Field::pow(&self, [exp])
+            }
+        }
+
+        #[cfg(feature = "num")]
+        impl<'a, P: $params> num_traits::Pow<&'a [u64]> for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, exp: &'a [u64]) -> Self {
+                Field::pow(&self, exp)
+            }
+        }
+
+        #[cfg(feature = "num")]
+        impl<P: $params> num_traits::MulAdd for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * &a + &b
+            }
+        }
+
+        #[cfg(feature = "num")]
+        impl<P: $params> num_traits::MulAddAssign for $type<P> {
+            #[inline]
+            fn mul_add_assign(&mut self, a: Self, b: Self) {
+                *self *= &a;
+                *self += &b;
+            }
+        }
+    };
+}
+
 // Implements AddAssign on Self by deferring to an implementation on &Self
 #[macro_export]
 macro_rules! impl_multiplicative_ops_from_ref {
@@ -489,3 +616,41 @@ macro_rules! impl_multiplicative_ops_from_ref {
         }
     };
 }
+
+/// Implements `MulAssign` by elements of the prime base field, of any width,
+/// for an extension `$type` with a `mul_assign_by_fp` method. It makes the
+/// extension a `DomainCoeff` of its base field, so that FFTs of coefficients
+/// in the extension over domains of the base field multiply by base field
+/// twiddles, one base field product per coordinate.
+macro_rules! impl_mul_assign_by_base_field {
+    ($type:ident, $params:ident) => {
+        impl_mul_assign_by_base_field!(
+            $type,
+            $params,
+            Fp256,
+            Fp256Parameters;
+            Fp320,
+            Fp320Parameters;
+            Fp384,
+            Fp384Parameters;
+            Fp768,
+            Fp768Parameters;
+            Fp832,
+            Fp832Parameters
+        );
+    };
+    ($type:ident, $params:ident, $($Fp:ident, $FpParameters:ident);*) => {
+        $(
+            impl<P, Q> MulAssign<crate::ff::$Fp<Q>> for $type<P>
+            where
+                P: $params<Fp = crate::ff::$Fp<Q>>,
+                Q: crate::ff::$FpParameters,
+            {
+                #[inline]
+                fn mul_assign(&mut self, other: crate::ff::$Fp<Q>) {
+                    self.mul_assign_by_fp(&other);
+                }
+            }
+        )*
+    };
+}
@@ -0,0 +1,221 @@
+//! The binary field `GF(2^128)` of POLYVAL ([RFC 8452]), of polynomials over
+//! `GF(2)` modulo `x^128 + x^127 + x^126 + x^121 + 1`, for universal hashing
+//! and codes over characteristic 2.
+//!
+//! The arithmetic is portable and runs in constant time: products are
+//! carry-less multiplications and reductions of one bit at a time, with
+//! masks instead of branches.
+//!
+//! [RFC 8452]: https://www.rfc-editor.org/rfc/rfc8452
+
+use core::{
+    fmt::{self, Debug, Display},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::ff::ring::Ring;
+
+/// The low 128 bits of the modulus `x^128 + x^127 + x^126 + x^121 + 1`.
+const MODULUS_LOW: u128 = (1 << 127) | (1 << 126) | (1 << 121) | 1;
+
+/// An element of `GF(2^128)`, whose coefficient of `x^i` is bit `i`: the
+/// little-endian encoding of POLYVAL.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Gf128(pub u128);
+
+impl Gf128 {
+    /// The polynomial `x`.
+    pub const X: Self = Gf128(2);
+
+    /// Returns the element with the little-endian encoding `bytes`.
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Gf128(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns the little-endian encoding of `self`.
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns `self^2`.
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns the inverse of `self`, as `self^(2^128 - 2)`, or None if
+    /// `self` is zero.
+    pub fn inverse(self) -> Option<Self> {
+        if self.0 == 0 {
+            return None;
+        }
+        // `self^(2^128 - 2) = ∏_{i=1}^{127} self^(2^i)`.
+        let mut power = self;
+        let mut result = Gf128(1);
+        for _ in 1..128 {
+            power = power.square();
+            result *= power;
+        }
+        Some(result)
+    }
+}
+
+/// Returns the 256-bit carry-less product of `a` and `b`, as `(low, high)`.
+fn clmul(a: u128, b: u128) -> (u128, u128) {
+    let (mut low, mut high) = (0u128, 0u128);
+    for i in 0..128 {
+        let mask = ((b >> i) & 1).wrapping_neg();
+        low ^= (a << i) & mask;
+        // `a >> (128 - i)`, without shifting by 128 for `i = 0`.
+        high ^= ((a >> 1) >> (127 - i)) & mask;
+    }
+    (low, high)
+}
+
+/// Reduces `low + high x^128` modulo the modulus, clearing the bits of
+/// `high` from the top one down.
+fn reduce(mut low: u128, mut high: u128) -> u128 {
+    for i in (0..128).rev() {
+        let mask = ((high >> i) & 1).wrapping_neg();
+        // Adds `modulus x^i`, whose top term cancels bit `i` of `high`.
+        high ^= (1 << i) & mask;
+        low ^= (MODULUS_LOW << i) & mask;
+        high ^= ((MODULUS_LOW >> 1) >> (127 - i)) & mask;
+    }
+    low
+}
+
+impl Debug for Gf128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Gf128({:#034x})", self.0)
+    }
+}
+
+impl Display for Gf128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#034x}", self.0)
+    }
+}
+
+impl Ring for Gf128 {
+    #[inline]
+    fn zero_element() -> Self {
+        Gf128(0)
+    }
+
+    #[inline]
+    fn one_element() -> Self {
+        Gf128(1)
+    }
+
+    #[inline]
+    fn is_zero_element(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Neg for Gf128 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for Gf128 {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, other: &Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl<'a> SubAssign<&'a Self> for Gf128 {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl<'a> MulAssign<&'a Self> for Gf128 {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let (low, high) = clmul(self.0, other.0);
+        self.0 = reduce(low, high);
+    }
+}
+
+macro_rules! impl_gf128_ops {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident;)*) => {
+        $(
+            impl<'a> $Op<&'a Self> for Gf128 {
+                type Output = Self;
+
+                #[inline]
+                fn $op(mut self, other: &Self) -> Self {
+                    self.$op_assign(other);
+                    self
+                }
+            }
+
+            impl $Op<Self> for Gf128 {
+                type Output = Self;
+
+                #[inline]
+                fn $op(mut self, other: Self) -> Self {
+                    self.$op_assign(&other);
+                    self
+                }
+            }
+
+            impl $OpAssign<Self> for Gf128 {
+                #[inline]
+                fn $op_assign(&mut self, other: Self) {
+                    self.$op_assign(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_gf128_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+);
+
+impl Sum<Self> for Gf128 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero_element(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Gf128 {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero_element(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gf128_test() {
+        let a = Gf128(0x25629347589242761d31f826ba4b757b);
+        let b = Gf128(0x4f4f95668c83dfb6401762bb2d01a262);
+        assert_eq!(a * b, b * a);
+        assert_eq!(a * Gf128::one_element(), a);
+        assert!((a + a).is_zero_element());
+        assert_eq!((a * b) * Gf128::X, a * (b * Gf128::X));
+        assert_eq!(a * (b + Gf128::X), a * b + a * Gf128::X);
+
+        // `x^127 * x = x^128 = x^127 + x^126 + x^121 + 1`.
+        assert_eq!(Gf128(1 << 127) * Gf128::X, Gf128(MODULUS_LOW));
+        assert_eq!(a * a.inverse().unwrap(), Gf128::one_element());
+        assert_eq!(Gf128::zero_element().inverse(), None);
+        assert_eq!(Gf128::from_le_bytes(a.to_le_bytes()), a);
+    }
+}
@@ -0,0 +1,114 @@
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{ff::PrimeField, Vec};
+
+/// A prime field element together with its canonical integer value in
+/// `[0, p)`, by which it is compared and hashed. The value is computed once,
+/// when wrapping, so that sorting and deduplicating multisets, as in lookup
+/// arguments, does not convert elements out of their internal
+/// representation on every comparison, and orders them the same way in
+/// every implementation.
+#[derive(Clone, Copy, Debug)]
+pub struct Canonical<F: PrimeField> {
+    elem: F,
+    repr: F::BigInt,
+}
+
+impl<F: PrimeField> Canonical<F> {
+    pub fn new(elem: F) -> Self {
+        Canonical {
+            elem,
+            repr: elem.into_repr(),
+        }
+    }
+
+    /// Wraps all of `elems`, in parallel if the `parallel` feature is
+    /// enabled.
+    pub fn batch_new(elems: &[F]) -> Vec<Self> {
+        crate::cfg_iter!(elems)
+            .map(|elem| Self::new(*elem))
+            .collect()
+    }
+
+    /// Returns the field element.
+    pub fn get(&self) -> F {
+        self.elem
+    }
+
+    /// Returns the canonical integer value of the field element.
+    pub fn repr(&self) -> &F::BigInt {
+        &self.repr
+    }
+
+    /// Unwraps all of `wrapped`.
+    pub fn batch_into_inner(wrapped: &[Self]) -> Vec<F> {
+        wrapped.iter().map(Self::get).collect()
+    }
+}
+
+impl<F: PrimeField> From<F> for Canonical<F> {
+    fn from(elem: F) -> Self {
+        Self::new(elem)
+    }
+}
+
+impl<F: PrimeField> PartialEq for Canonical<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<F: PrimeField> Eq for Canonical<F> {}
+
+impl<F: PrimeField> Ord for Canonical<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.repr.cmp(&other.repr)
+    }
+}
+
+impl<F: PrimeField> PartialOrd for Canonical<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: PrimeField> Hash for Canonical<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.as_ref().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::{utils::sort_canonical, Field};
+    use algebra::bls12_381::fr::Fr;
+
+    #[test]
+    fn canonical_wrapper_test() {
+        let one = Canonical::new(Fr::one());
+        let minus_one = Canonical::from(-Fr::one());
+        assert!(one < minus_one);
+        assert_eq!(one.get(), Fr::one());
+        assert_eq!(one.repr(), &Fr::one().into_repr());
+
+        let mut elems: Vec<Fr> = (0..50u64)
+            .map(|i| Fr::from_seed(&i.to_le_bytes()))
+            .collect();
+        elems.extend_from_slice(&elems[..10].to_vec());
+        let mut wrapped = Canonical::batch_new(&elems);
+        wrapped.sort();
+        wrapped.dedup();
+        assert_eq!(wrapped.len(), 50);
+
+        sort_canonical(&mut elems);
+        elems.dedup();
+        assert_eq!(Canonical::batch_into_inner(&wrapped), elems);
+    }
+}
@@ -0,0 +1,160 @@
+//! Conversions of integers to residues modulo the characteristics of
+//! several prime fields, and back by the Chinese remainder theorem, for
+//! proof systems that split a computation across fields, e.g. a 64-bit
+//! field and a 256-bit one.
+
+use crate::{ff::PrimeField, uint::Uint, SunziError, Vec};
+
+/// Returns `x` reduced modulo the characteristic of `F`.
+pub fn residue<F: PrimeField, U: Uint>(x: &U) -> F {
+    F::from_le_bytes_mod_order(&words_to_bytes(x.as_ref()))
+}
+
+/// Recombines residues modulo the characteristics of several prime fields,
+/// by Garner's algorithm, into the only integer below their product with
+/// these residues:
+///
+/// ```ignore
+/// let x: U768 = Crt::new(residue::<Fr, _>(&x)).and(residue::<Fq, _>(&x)).to_uint()?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crt {
+    value: Vec<u64>,
+    modulus: Vec<u64>,
+}
+
+impl Crt {
+    /// Starts from a residue modulo the characteristic of `F`.
+    pub fn new<F: PrimeField>(residue: F) -> Self {
+        Crt {
+            value: residue.to_u64_limbs_canonical(),
+            modulus: F::characteristic().to_vec(),
+        }
+    }
+
+    /// Adds a residue modulo the characteristic of `F`.
+    ///
+    /// Panics if this characteristic divides the product of the previous
+    /// ones, e.g. if `F` was already used.
+    pub fn and<F: PrimeField>(self, residue: F) -> Self {
+        let value_mod_p = F::from_le_bytes_mod_order(&words_to_bytes(&self.value));
+        let modulus_inv = F::from_le_bytes_mod_order(&words_to_bytes(&self.modulus))
+            .inverse()
+            .expect("characteristics are not coprime");
+        // `value + modulus * t` is `value` modulo `modulus`, and `residue`
+        // modulo `p`.
+        let t = (residue - value_mod_p) * modulus_inv;
+        let mut value = mul_words(&self.modulus, &t.to_u64_limbs_canonical());
+        add_words(&mut value, &self.value);
+        Crt {
+            value,
+            modulus: mul_words(&self.modulus, F::characteristic()),
+        }
+    }
+
+    /// Returns the little-endian words of the recombined integer.
+    pub fn value(&self) -> &[u64] {
+        &self.value
+    }
+
+    /// Returns the little-endian words of the product of the
+    /// characteristics.
+    pub fn modulus(&self) -> &[u64] {
+        &self.modulus
+    }
+
+    /// Returns the recombined integer, or `SizeMismatch` if it does not fit
+    /// in a `U`.
+    pub fn to_uint<U: Uint>(&self) -> Result<U, SunziError> {
+        let len = self
+            .value
+            .iter()
+            .rposition(|word| *word != 0)
+            .map_or(0, |i| i + 1);
+        if len > U::LIMBS {
+            return Err(SunziError::SizeMismatch {
+                expected: U::LIMBS,
+                found: len,
+            });
+        }
+        let mut x = U::default();
+        x.as_mut()[..len].copy_from_slice(&self.value[..len]);
+        Ok(x)
+    }
+}
+
+// Integers below are little-endian vectors of 64-bit words.
+
+fn words_to_bytes(words: &[u64]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+fn mul_words(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in b.iter().enumerate() {
+            let cur = product[i + j] as u128 + *x as u128 * *y as u128 + carry;
+            product[i + j] = cur as u64;
+            carry = cur >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    product
+}
+
+/// Adds `b` to `a`, which must be long enough to hold the sum.
+fn add_words(a: &mut [u64], b: &[u64]) {
+    let mut carry = 0u128;
+    for (i, x) in a.iter_mut().enumerate() {
+        let cur = *x as u128 + *b.get(i).unwrap_or(&0) as u128 + carry;
+        *x = cur as u64;
+        carry = cur >> 64;
+    }
+    debug_assert_eq!(carry, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ff::Field, uint::arithmetic, utils::BitIterator};
+    use algebra::bls12_381::{fr::Fr, Fq};
+    use algebra_core::test_rng;
+
+    crate::uint_impl!(U256, 4);
+    crate::uint_impl!(U768, 12);
+
+    #[test]
+    fn crt_test() {
+        let rng = &mut test_rng();
+        // Below `2^576`, hence below the product of the characteristics.
+        let mut x = U768::random(rng);
+        x.as_mut()[9..].iter_mut().for_each(|word| *word = 0);
+
+        let (r, q) = (residue::<Fr, _>(&x), residue::<Fq, _>(&x));
+        let crt = Crt::new(r).and(q);
+        assert_eq!(crt.to_uint::<U768>(), Ok(x));
+        assert_eq!(
+            crt.modulus(),
+            &mul_words(Fr::characteristic(), Fq::characteristic())[..]
+        );
+        assert_eq!(
+            crt.to_uint::<U256>(),
+            Err(SunziError::SizeMismatch {
+                expected: 4,
+                found: 9
+            })
+        );
+
+        // The order of the fields does not matter.
+        assert_eq!(Crt::new(q).and(r).to_uint::<U768>(), Ok(x));
+
+        let small = U768::from(12345u64);
+        let crt = Crt::new(residue::<Fq, _>(&small)).and(residue::<Fr, _>(&small));
+        assert_eq!(crt.to_uint::<U768>(), Ok(small));
+        assert_eq!(
+            Crt::new(Fr::from(7u64)).to_uint::<U256>(),
+            Ok(U256::from(7u64))
+        );
+    }
+}
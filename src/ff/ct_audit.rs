@@ -0,0 +1,65 @@
+//! Timing self-tests of the constant-time paths of fields, with
+//! `crate::ct_audit::measure`, enabled by the `ct-audit` feature.
+
+use rand_core::RngCore;
+
+use crate::{
+    ct_audit::{measure, Class, TimingReport},
+    ff::{Field, PrimeField, SquareRootField},
+};
+
+/// Tests `Field::inverse` of `F`, on one against random elements.
+pub fn audit_inverse<F, R>(rng: &mut R, samples: usize) -> Option<TimingReport>
+where
+    F: Field,
+    R: RngCore + ?Sized,
+{
+    measure(
+        "inverse",
+        rng,
+        samples,
+        |class, rng| match class {
+            Class::Fixed => F::one(),
+            Class::Random => F::random(rng),
+        },
+        |x| x.inverse(),
+    )
+}
+
+/// Tests `SquareRootField::sqrt` of `F`, on one against random squares, so
+/// that whether the input is a square does not count as a leak.
+pub fn audit_sqrt<F, R>(rng: &mut R, samples: usize) -> Option<TimingReport>
+where
+    F: SquareRootField,
+    R: RngCore + ?Sized,
+{
+    measure(
+        "sqrt",
+        rng,
+        samples,
+        |class, rng| match class {
+            Class::Fixed => F::one(),
+            Class::Random => F::random(rng).square(),
+        },
+        |x| x.sqrt(),
+    )
+}
+
+/// Tests `PrimeField::from_repr` of `F`, on the representation of zero
+/// against those of random elements.
+pub fn audit_from_repr<F, R>(rng: &mut R, samples: usize) -> Option<TimingReport>
+where
+    F: PrimeField,
+    R: RngCore + ?Sized,
+{
+    measure(
+        "from_repr",
+        rng,
+        samples,
+        |class, rng| match class {
+            Class::Fixed => F::zero().into_repr(),
+            Class::Random => F::random(rng).into_repr(),
+        },
+        F::from_repr,
+    )
+}
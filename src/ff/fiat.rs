@@ -0,0 +1,522 @@
+//! Field arithmetic generated and proven correct by
+//! [fiat-crypto](https://github.com/mit-plv/fiat-crypto), for the moduli it
+//! covers at 256 and 384 bits. Its Montgomery forms are those of `Fp256`
+//! and `Fp384`, so a field only needs to name its backend in its parameters
+//! to have its additions, subtractions, multiplications and squarings done
+//! by the verified code:
+//!
+//! ```ignore
+//! impl Fp256Parameters for P256Parameters {
+//!     const FIAT_BACKEND: Option<FiatBackend<4>> = Some(fiat::P256);
+//! }
+//! ```
+//!
+//! A field whose backend is for another modulus fails to compile.
+
+use fiat_crypto::{
+    curve25519_scalar_64 as curve25519_scalar, p256_64 as p256, p256_scalar_64 as p256_scalar,
+    p384_64 as p384, p384_scalar_64 as p384_scalar, secp256k1_montgomery_64 as secp256k1,
+    secp256k1_montgomery_scalar_64 as secp256k1_scalar,
+};
+
+/// The arithmetic modulo one prime on `N` limbs, on fully reduced values.
+/// Products and squares are of Montgomery forms with `R = 2^(64 N)`.
+#[derive(Clone, Copy)]
+pub struct FiatBackend<const N: usize> {
+    /// The modulus, for the fields to check against their parameters at
+    /// compile time.
+    pub modulus: [u64; N],
+    pub add: fn(&[u64; N], &[u64; N]) -> [u64; N],
+    pub sub: fn(&[u64; N], &[u64; N]) -> [u64; N],
+    pub mul: fn(&[u64; N], &[u64; N]) -> [u64; N],
+    pub square: fn(&[u64; N]) -> [u64; N],
+}
+
+impl<const N: usize> core::fmt::Debug for FiatBackend<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FiatBackend({:x?})", self.modulus)
+    }
+}
+
+macro_rules! fiat_backend {
+    ($(#[$doc:meta])* $name:ident, $limbs:expr, $modulus:expr, $module:ident, $Element:ident,
+     $add:ident, $sub:ident, $mul:ident, $square:ident) => {
+        $(#[$doc])*
+        pub const $name: FiatBackend<$limbs> = FiatBackend {
+            modulus: $modulus,
+            add: |a, b| {
+                let mut out = $module::$Element([0; $limbs]);
+                $module::$add(&mut out, &$module::$Element(*a), &$module::$Element(*b));
+                out.0
+            },
+            sub: |a, b| {
+                let mut out = $module::$Element([0; $limbs]);
+                $module::$sub(&mut out, &$module::$Element(*a), &$module::$Element(*b));
+                out.0
+            },
+            mul: |a, b| {
+                let mut out = $module::$Element([0; $limbs]);
+                $module::$mul(&mut out, &$module::$Element(*a), &$module::$Element(*b));
+                out.0
+            },
+            square: |a| {
+                let mut out = $module::$Element([0; $limbs]);
+                $module::$square(&mut out, &$module::$Element(*a));
+                out.0
+            },
+        };
+    };
+}
+
+fiat_backend!(
+    /// The base field of NIST P-256.
+    P256, 4,
+    [
+        0xffffffffffffffff,
+        0x00000000ffffffff,
+        0x0000000000000000,
+        0xffffffff00000001,
+    ],
+    p256, fiat_p256_montgomery_domain_field_element,
+    fiat_p256_add, fiat_p256_sub, fiat_p256_mul, fiat_p256_square
+);
+fiat_backend!(
+    /// The scalar field of NIST P-256.
+    P256_SCALAR, 4,
+    [
+        0xf3b9cac2fc632551,
+        0xbce6faada7179e84,
+        0xffffffffffffffff,
+        0xffffffff00000000,
+    ],
+    p256_scalar, fiat_p256_scalar_montgomery_domain_field_element,
+    fiat_p256_scalar_add, fiat_p256_scalar_sub, fiat_p256_scalar_mul, fiat_p256_scalar_square
+);
+fiat_backend!(
+    /// The base field of secp256k1.
+    SECP256K1, 4,
+    [
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ],
+    secp256k1, fiat_secp256k1_montgomery_montgomery_domain_field_element,
+    fiat_secp256k1_montgomery_add, fiat_secp256k1_montgomery_sub,
+    fiat_secp256k1_montgomery_mul, fiat_secp256k1_montgomery_square
+);
+fiat_backend!(
+    /// The scalar field of secp256k1.
+    SECP256K1_SCALAR, 4,
+    [
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+    ],
+    secp256k1_scalar,
+    fiat_secp256k1_montgomery_scalar_montgomery_domain_field_element,
+    fiat_secp256k1_montgomery_scalar_add, fiat_secp256k1_montgomery_scalar_sub,
+    fiat_secp256k1_montgomery_scalar_mul, fiat_secp256k1_montgomery_scalar_square
+);
+fiat_backend!(
+    /// The scalar field of Curve25519, of prime order `2^252 + ...`.
+    CURVE25519_SCALAR, 4,
+    [
+        0x5812631a5cf5d3ed,
+        0x14def9dea2f79cd6,
+        0x0000000000000000,
+        0x1000000000000000,
+    ],
+    curve25519_scalar, fiat_25519_scalar_montgomery_domain_field_element,
+    fiat_25519_scalar_add, fiat_25519_scalar_sub, fiat_25519_scalar_mul, fiat_25519_scalar_square
+);
+fiat_backend!(
+    /// The base field of NIST P-384.
+    P384, 6,
+    [
+        0x00000000ffffffff,
+        0xffffffff00000000,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ],
+    p384, fiat_p384_montgomery_domain_field_element,
+    fiat_p384_add, fiat_p384_sub, fiat_p384_mul, fiat_p384_square
+);
+fiat_backend!(
+    /// The scalar field of NIST P-384.
+    P384_SCALAR, 6,
+    [
+        0xecec196accc52973,
+        0x581a0db248b0a77a,
+        0xc7634d81f4372ddf,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ],
+    p384_scalar, fiat_p384_scalar_montgomery_domain_field_element,
+    fiat_p384_scalar_add, fiat_p384_scalar_sub, fiat_p384_scalar_mul, fiat_p384_scalar_square
+);
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryInto;
+
+    use super::*;
+    use crate::{
+        ff::{
+            utils::const_mont_mul, FftParameters, Field, Fp256, Fp256Parameters, Fp384,
+            Fp384Parameters, FpParameters, PrimeField,
+        },
+        uint::{U256, U384},
+    };
+    use algebra_core::test_rng;
+
+    type P256Fq = Fp256<P256Parameters>;
+
+    struct P256Parameters;
+
+    impl Fp256Parameters for P256Parameters {
+        const FIAT_BACKEND: Option<FiatBackend<4>> = Some(P256);
+    }
+
+    impl FftParameters for P256Parameters {
+        type BigInt = U256;
+
+        const TWO_ADICITY: u32 = 1;
+
+        /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+        const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+            0xfffffffffffffffe,
+            0x00000001ffffffff,
+            0x0000000000000000,
+            0xfffffffe00000002,
+        ]);
+    }
+
+    impl FpParameters for P256Parameters {
+        /// MODULUS = 115792089210356248762697446949407573530086143415290314195533631308867097853951
+        const MODULUS: U256 = U256::new([
+            0xffffffffffffffff,
+            0x00000000ffffffff,
+            0x0000000000000000,
+            0xffffffff00000001,
+        ]);
+
+        const MODULUS_BITS: u32 = 256;
+
+        const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+        const REPR_SHAVE_BITS: u32 = 0;
+
+        /// R = 2^256 mod MODULUS
+        const R: U256 = U256::new([
+            0x0000000000000001,
+            0xffffffff00000000,
+            0xffffffffffffffff,
+            0x00000000fffffffe,
+        ]);
+
+        /// R2 = R^2 mod MODULUS
+        const R2: U256 = U256::new([
+            0x0000000000000003,
+            0xfffffffbffffffff,
+            0xfffffffffffffffe,
+            0x00000004fffffffd,
+        ]);
+
+        const INV: u64 = 0x0000000000000001;
+
+        /// GENERATOR = 3, in Montgomery form
+        const GENERATOR: U256 = U256::new([
+            0x0000000000000003,
+            0xfffffffd00000000,
+            0xffffffffffffffff,
+            0x00000002fffffffc,
+        ]);
+
+        const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+            0xffffffffffffffff,
+            0x000000007fffffff,
+            0x8000000000000000,
+            0x7fffffff80000000,
+        ]);
+
+        /// T = (MODULUS - 1) / 2^TWO_ADICITY
+        const T: U256 = U256::new([
+            0xffffffffffffffff,
+            0x000000007fffffff,
+            0x8000000000000000,
+            0x7fffffff80000000,
+        ]);
+
+        const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+            0xffffffffffffffff,
+            0x000000003fffffff,
+            0x4000000000000000,
+            0x3fffffffc0000000,
+        ]);
+    }
+    type Secp256k1Fq = Fp256<Secp256k1Parameters>;
+
+    struct Secp256k1Parameters;
+
+    impl Fp256Parameters for Secp256k1Parameters {
+        const FIAT_BACKEND: Option<FiatBackend<4>> = Some(SECP256K1);
+    }
+
+    impl FftParameters for Secp256k1Parameters {
+        type BigInt = U256;
+
+        const TWO_ADICITY: u32 = 1;
+
+        /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+        const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+            0xfffffffdfffff85e,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ]);
+    }
+
+    impl FpParameters for Secp256k1Parameters {
+        /// MODULUS = 115792089237316195423570985008687907853269984665640564039457584007908834671663
+        const MODULUS: U256 = U256::new([
+            0xfffffffefffffc2f,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ]);
+
+        const MODULUS_BITS: u32 = 256;
+
+        const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+        const REPR_SHAVE_BITS: u32 = 0;
+
+        /// R = 2^256 mod MODULUS
+        const R: U256 = U256::new([
+            0x00000001000003d1,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]);
+
+        /// R2 = R^2 mod MODULUS
+        const R2: U256 = U256::new([
+            0x000007a2000e90a1,
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]);
+
+        const INV: u64 = 0xd838091dd2253531;
+
+        /// GENERATOR = 3, in Montgomery form
+        const GENERATOR: U256 = U256::new([
+            0x0000000300000b73,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]);
+
+        const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+            0xffffffff7ffffe17,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x7fffffffffffffff,
+        ]);
+
+        /// T = (MODULUS - 1) / 2^TWO_ADICITY
+        const T: U256 = U256::new([
+            0xffffffff7ffffe17,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x7fffffffffffffff,
+        ]);
+
+        const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+            0xffffffffbfffff0b,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ]);
+    }
+    type P384Fq = Fp384<P384Parameters>;
+
+    struct P384Parameters;
+
+    impl Fp384Parameters for P384Parameters {
+        const FIAT_BACKEND: Option<FiatBackend<6>> = Some(P384);
+    }
+
+    impl FftParameters for P384Parameters {
+        type BigInt = U384;
+
+        const TWO_ADICITY: u32 = 1;
+
+        /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+        const TWO_ADIC_ROOT_OF_UNITY: U384 = U384::new([
+            0x00000001fffffffe,
+            0xfffffffe00000000,
+            0xfffffffffffffffd,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ]);
+    }
+
+    impl FpParameters for P384Parameters {
+        /// MODULUS = 39402006196394479212279040100143613805079739270465446667948293404245721771496870329047266088258938001861606973112319
+        const MODULUS: U384 = U384::new([
+            0x00000000ffffffff,
+            0xffffffff00000000,
+            0xfffffffffffffffe,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ]);
+
+        const MODULUS_BITS: u32 = 384;
+
+        const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+        const REPR_SHAVE_BITS: u32 = 0;
+
+        /// R = 2^384 mod MODULUS
+        const R: U384 = U384::new([
+            0xffffffff00000001,
+            0x00000000ffffffff,
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]);
+
+        /// R2 = R^2 mod MODULUS
+        const R2: U384 = U384::new([
+            0xfffffffe00000001,
+            0x0000000200000000,
+            0xfffffffe00000000,
+            0x0000000200000000,
+            0x0000000000000001,
+            0x0000000000000000,
+        ]);
+
+        const INV: u64 = 0x0000000100000001;
+
+        /// GENERATOR = 19, in Montgomery form
+        const GENERATOR: U384 = U384::new([
+            0xffffffed00000013,
+            0x00000012ffffffff,
+            0x0000000000000013,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]);
+
+        const MODULUS_MINUS_ONE_DIV_TWO: U384 = U384::new([
+            0x000000007fffffff,
+            0x7fffffff80000000,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x7fffffffffffffff,
+        ]);
+
+        /// T = (MODULUS - 1) / 2^TWO_ADICITY
+        const T: U384 = U384::new([
+            0x000000007fffffff,
+            0x7fffffff80000000,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x7fffffffffffffff,
+        ]);
+
+        const T_MINUS_ONE_DIV_TWO: U384 = U384::new([
+            0x000000003fffffff,
+            0xbfffffffc0000000,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ]);
+    }
+
+    /// Returns `a + b` modulo `modulus`, keeping the carry out of the limbs.
+    fn add_mod<const N: usize>(a: [u64; N], b: [u64; N], modulus: [u64; N]) -> [u64; N] {
+        let mut sum = [0u64; N];
+        let mut carry = 0u128;
+        for i in 0..N {
+            let t = a[i] as u128 + b[i] as u128 + carry;
+            sum[i] = t as u64;
+            carry = t >> 64;
+        }
+        let below = (0..N)
+            .rev()
+            .find(|&i| sum[i] != modulus[i])
+            .map_or(false, |i| sum[i] < modulus[i]);
+        if carry != 0 || !below {
+            let mut borrow = 0u128;
+            for i in 0..N {
+                let t = (sum[i] as u128).wrapping_sub(modulus[i] as u128 + borrow);
+                sum[i] = t as u64;
+                borrow = (t >> 127) & 1;
+            }
+        }
+        sum
+    }
+
+    /// Checks the arithmetic of `F`, whose parameters name a backend,
+    /// against `const_mont_mul` and additions with carries, on random
+    /// elements and on the largest ones, whose doubles carry out of the
+    /// limbs.
+    fn check_field<F: PrimeField, const N: usize>() {
+        let modulus: [u64; N] = F::characteristic().try_into().unwrap();
+        let inv = <F::Params as FpParameters>::INV;
+        let mont = |a: &F| -> [u64; N] { a.to_montgomery_repr().as_ref().try_into().unwrap() };
+        let mul = |a, b| const_mont_mul(a, b, modulus, inv);
+        let mut one = [0u64; N];
+        one[0] = 1;
+
+        let rng = &mut test_rng();
+        let mut values = vec![F::one(), -F::one(), -F::one().double()];
+        values.extend((0..50).map(|_| F::random(rng)));
+        for a in &values {
+            let x = mont(a);
+            assert_eq!(mont(&a.double()), add_mod(x, x, modulus));
+            assert_eq!(mont(&a.square()), mul(x, x));
+            assert_eq!(a.into_repr().as_ref(), &mul(x, one)[..]);
+            assert_eq!(mul(x, mont(&a.inverse().unwrap())), mont(&F::one()));
+            for b in values.iter().take(10) {
+                let y = mont(b);
+                assert_eq!(mont(&(*a + b)), add_mod(x, y, modulus));
+                assert_eq!(add_mod(mont(&(*a - b)), y, modulus), x);
+                assert_eq!(mont(&(*a * b)), mul(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn fiat_fields_test() {
+        check_field::<P256Fq, 4>();
+        check_field::<Secp256k1Fq, 4>();
+        check_field::<P384Fq, 6>();
+
+        // The other backends only have their moduli checked, as `0 - 1`.
+        fn check_modulus<const N: usize>(backend: FiatBackend<N>) {
+            let mut one = [0u64; N];
+            one[0] = 1;
+            let mut p_minus_one = backend.modulus;
+            p_minus_one[0] -= 1;
+            assert_eq!((backend.sub)(&[0; N], &one), p_minus_one);
+        }
+        check_modulus(P256_SCALAR);
+        check_modulus(SECP256K1_SCALAR);
+        check_modulus(CURVE25519_SCALAR);
+        check_modulus(P384_SCALAR);
+    }
+}
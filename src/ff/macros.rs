@@ -1,6 +1,20 @@
 macro_rules! impl_Fp {
     ($Fp:ident, $FpParameters:ident, $BigInteger:ident, $BigIntegerType:ty, $limbs:expr) => {
-        pub trait $FpParameters: FpParameters<BigInt = $BigIntegerType> {}
+        pub trait $FpParameters: FpParameters<BigInt = $BigIntegerType> {
+            /// The fiat-crypto arithmetic for the modulus, if any, which then
+            /// replaces the generic one.
+            #[cfg(feature = "fiat-crypto")]
+            const FIAT_BACKEND: Option<crate::ff::fiat::FiatBackend<$limbs>> = None;
+
+            /// R3 = R^3 % Self::MODULUS, for reducing integers three times as
+            /// wide as the modulus with two Montgomery multiplications.
+            const R3: $BigIntegerType = $BigInteger::new(crate::ff::utils::const_mont_mul(
+                <Self as FpParameters>::R2.0,
+                <Self as FpParameters>::R2.0,
+                <Self as FpParameters>::MODULUS.0,
+                <Self as FpParameters>::INV,
+            ));
+        }
 
         #[derive(Derivative)]
         #[derivative(
@@ -12,7 +26,11 @@ macro_rules! impl_Fp {
             PartialEq(bound = ""),
             Eq(bound = ""),
         )]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(
+            feature = "rkyv",
+            derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+            archive(check_bytes)
+        )]
         pub struct $Fp<P>(
             pub $BigIntegerType,
             #[derivative(Debug = "ignore")]
@@ -28,6 +46,128 @@ macro_rules! impl_Fp {
         }
 
         impl<P: $FpParameters> $Fp<P> {
+            // Chains for the exponents of `inverse_by_chain`, `legendre` and `sqrt`,
+            // built at compile time for each set of parameters.
+            const MODULUS_MINUS_TWO_CHAIN: AdditionChain<{ 64 * $limbs }> =
+                AdditionChain::new(&addition_chain::sub_u64(P::MODULUS.0, 2));
+            const MODULUS_MINUS_ONE_DIV_TWO_CHAIN: AdditionChain<{ 64 * $limbs }> =
+                AdditionChain::new(&P::MODULUS_MINUS_ONE_DIV_TWO.0);
+            const MODULUS_PLUS_ONE_DIV_FOUR_CHAIN: AdditionChain<{ 64 * $limbs }> =
+                AdditionChain::new(&addition_chain::shr(
+                    addition_chain::add_u64(P::MODULUS.0, 1),
+                    2,
+                ));
+            const T_MINUS_ONE_DIV_TWO_CHAIN: AdditionChain<{ 64 * $limbs }> =
+                AdditionChain::new(&P::T_MINUS_ONE_DIV_TWO.0);
+
+            /// Returns the table of `FftParameters::TWO_ADIC_ROOTS` for `P`,
+            /// of `N = P::TWO_ADICITY + 1` roots, in a const context.
+            pub const fn two_adic_roots<const N: usize>() -> [$BigIntegerType; N] {
+                assert!(
+                    N == P::TWO_ADICITY as usize + 1,
+                    "the table has TWO_ADICITY + 1 roots"
+                );
+                let mut roots = [P::TWO_ADIC_ROOT_OF_UNITY; N];
+                let mut k = N - 1;
+                while k > 0 {
+                    roots[k - 1] = $BigInteger::new(crate::ff::utils::const_mont_mul(
+                        roots[k].0,
+                        roots[k].0,
+                        P::MODULUS.0,
+                        P::INV,
+                    ));
+                    k -= 1;
+                }
+                roots
+            }
+
+            /// Returns `self + other`, in a const context, e.g. to derive
+            /// constants from others. Sums of Montgomery forms are Montgomery
+            /// forms, so the result needs no conversion.
+            pub const fn const_add(self, other: Self) -> Self {
+                let (sum, carry) = Self::add_limbs((self.0).0, (other.0).0);
+                if carry || !Self::lt_limbs(sum, P::MODULUS.0) {
+                    Self::new($BigInteger::new(Self::sub_limbs(sum, P::MODULUS.0).0))
+                } else {
+                    Self::new($BigInteger::new(sum))
+                }
+            }
+
+            /// Returns `self - other`, in a const context.
+            pub const fn const_sub(self, other: Self) -> Self {
+                let (diff, borrow) = Self::sub_limbs((self.0).0, (other.0).0);
+                if borrow {
+                    Self::new($BigInteger::new(Self::add_limbs(diff, P::MODULUS.0).0))
+                } else {
+                    Self::new($BigInteger::new(diff))
+                }
+            }
+
+            /// Returns `-self`, in a const context.
+            pub const fn const_neg(self) -> Self {
+                Self::new($BigInteger::new([0; $limbs])).const_sub(self)
+            }
+
+            /// Returns `2 * self`, in a const context.
+            pub const fn const_double(self) -> Self {
+                self.const_add(self)
+            }
+
+            /// Returns the element with the canonical representation `repr`,
+            /// in a const context, e.g. for the constants of curves.
+            ///
+            /// Panics if `repr` is not below the modulus, which fails the
+            /// compilation of constants.
+            pub const fn const_from_repr(repr: $BigIntegerType) -> Self {
+                assert!(
+                    Self::lt_limbs(repr.0, P::MODULUS.0),
+                    "the representation is not below the modulus"
+                );
+                Self::new($BigInteger::new(crate::ff::utils::const_mont_mul(
+                    repr.0,
+                    P::R2.0,
+                    P::MODULUS.0,
+                    P::INV,
+                )))
+            }
+
+            const fn add_limbs(a: [u64; $limbs], b: [u64; $limbs]) -> ([u64; $limbs], bool) {
+                let mut sum = [0u64; $limbs];
+                let mut carry = 0u128;
+                let mut i = 0;
+                while i < $limbs {
+                    let t = a[i] as u128 + b[i] as u128 + carry;
+                    sum[i] = t as u64;
+                    carry = t >> 64;
+                    i += 1;
+                }
+                (sum, carry != 0)
+            }
+
+            const fn sub_limbs(a: [u64; $limbs], b: [u64; $limbs]) -> ([u64; $limbs], bool) {
+                let mut diff = [0u64; $limbs];
+                let mut borrow = 0u128;
+                let mut i = 0;
+                while i < $limbs {
+                    let t = (a[i] as u128).wrapping_sub(b[i] as u128 + borrow);
+                    diff[i] = t as u64;
+                    borrow = t >> 127;
+                    i += 1;
+                }
+                (diff, borrow != 0)
+            }
+
+            const fn lt_limbs(a: [u64; $limbs], b: [u64; $limbs]) -> bool {
+                let mut i = $limbs;
+                while i > 0 {
+                    i -= 1;
+                    if a[i] != b[i] {
+                        return a[i] < b[i];
+                    }
+                }
+                false
+            }
+
             #[inline]
             pub(crate) fn is_valid(&self) -> bool {
                 self.0 < P::MODULUS
@@ -39,6 +179,43 @@ macro_rules! impl_Fp {
                     self.0.sub_noborrow(&P::MODULUS);
                 }
             }
+
+            /// Returns the inverse of `self` as `self^(p - 2)`, by Fermat's
+            /// little theorem, through an addition chain for `p - 2`, or
+            /// `None` if `self` is zero. Its sequence of operations does not
+            /// depend on `self`, unlike `Field::inverse`.
+            pub fn inverse_by_chain(&self) -> Option<Self> {
+                if self.is_zero() {
+                    None
+                } else {
+                    Some(Self::MODULUS_MINUS_TWO_CHAIN.pow(self))
+                }
+            }
+
+            /// Fails to compile for parameters whose fiat-crypto backend is
+            /// for another modulus.
+            #[cfg(feature = "fiat-crypto")]
+            const FIAT_BACKEND_MODULUS_CHECK: () = if let Some(backend) = P::FIAT_BACKEND {
+                let mut i = 0;
+                while i < $limbs {
+                    assert!(
+                        backend.modulus[i] == P::MODULUS.0[i],
+                        "the fiat-crypto backend is for another modulus"
+                    );
+                    i += 1;
+                }
+            };
+
+            /// Returns the fiat-crypto backend of the parameters, after
+            /// checking its modulus at compile time. Every operation goes
+            /// through here rather than `P::FIAT_BACKEND`.
+            #[cfg(feature = "fiat-crypto")]
+            #[inline(always)]
+            fn fiat_backend() -> Option<crate::ff::fiat::FiatBackend<$limbs>> {
+                #[allow(clippy::let_unit_value)]
+                let () = Self::FIAT_BACKEND_MODULUS_CHECK;
+                P::FIAT_BACKEND
+            }
         }
 
         impl<P: $FpParameters> Zero for $Fp<P> {
@@ -75,6 +252,16 @@ macro_rules! impl_Fp {
 
             #[inline]
             fn double_in_place(&mut self) -> &mut Self {
+                assert_canonical!(self);
+                #[cfg(feature = "fiat-crypto")]
+                {
+                    // The moduli of the backends may use every bit of the
+                    // limbs, which `mul2` would lose.
+                    if let Some(backend) = Self::fiat_backend() {
+                        (self.0).0 = (backend.add)(&(self.0).0, &(self.0).0);
+                        return self;
+                    }
+                }
                 // This cannot exceed the backing capacity.
                 self.0.mul2();
                 // However, it may need to be reduced.
@@ -88,33 +275,46 @@ macro_rules! impl_Fp {
             }
 
             #[inline]
-            fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+            fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+                if F::BIT_SIZE > 8 {
+                    return None;
+                }
+                // the flags are stored in the top bits of the byte holding the
+                // highest bit of the flags placed above the modulus
+                let flags_byte_position = (P::MODULUS_BITS as usize + F::BIT_SIZE + 7) / 8 - 1;
+                if flags_byte_position >= $limbs * 8 {
+                    return None;
+                }
+
                 let mut result_bytes = [0u8; $limbs * 8];
                 for (result_byte, in_byte) in result_bytes.iter_mut().zip(bytes.iter()) {
                     *result_byte = *in_byte;
                 }
+                let flags = F::from_u8_remove_flags(&mut result_bytes[flags_byte_position])?;
 
                 let mask: u64 = 0xffffffffffffffff >> P::REPR_SHAVE_BITS;
-                // the flags will be at the same byte with the lowest shaven bits or the one after
-                let flags_byte_position: usize = 7 - P::REPR_SHAVE_BITS as usize / 8;
-                let flags_mask: u8 = ((1 << P::REPR_SHAVE_BITS % 8) - 1) << (8 - P::REPR_SHAVE_BITS % 8);
                 // take the last 8 bytes and pass the mask
                 let last_bytes = &mut result_bytes[($limbs - 1) * 8..];
-                let mut flags: u8 = 0;
-                for (i, (b, m)) in last_bytes.iter_mut().zip(&mask.to_le_bytes()).enumerate() {
-                    if i == flags_byte_position {
-                        flags = *b & flags_mask
-                    }
+                for (b, m) in last_bytes.iter_mut().zip(&mask.to_le_bytes()) {
                     *b &= m;
                 }
 
                 <$BigIntegerType>::from_bytes(&result_bytes[..])
                     .ok()
-                    .map(|f| Self::from_repr(f))
-                    .flatten()
+                    .and_then(Self::from_repr)
                     .map(|f| (f, flags))
             }
 
+            #[inline]
+            fn uniform_bytes_len() -> usize {
+                $limbs * 8 + 16
+            }
+
+            #[inline]
+            fn from_uniform_bytes(bytes: &[u8]) -> Self {
+                Self::from_le_bytes_mod_order(&bytes[..Self::uniform_bytes_len()])
+            }
+
             #[inline]
             fn square(&self) -> Self {
                 let mut temp = self.clone();
@@ -126,6 +326,17 @@ macro_rules! impl_Fp {
 
             #[inline]
             fn inverse(&self) -> Option<Self> {
+                record!(field_inversion);
+                ct_probe!("inverse");
+                assert_canonical!(self);
+                #[cfg(feature = "fiat-crypto")]
+                {
+                    // The moduli of the backends may use every bit of the
+                    // limbs, which `add_nocarry` below would overflow.
+                    if Self::fiat_backend().is_some() {
+                        return self.inverse_by_chain();
+                    }
+                }
                 if self.is_zero() {
                     None
                 } else {
@@ -199,9 +410,11 @@ macro_rules! impl_Fp {
         impl<P: $FpParameters> PrimeField for $Fp<P> {
             type Params = P;
             type BigInt = $BigIntegerType;
+            type Bytes = [u8; $limbs * 8];
 
             #[inline]
             fn from_repr(r: $BigIntegerType) -> Option<Self> {
+                ct_probe!("from_repr");
                 let mut r = $Fp(r, PhantomData);
                 if r.is_zero() {
                     Some(r)
@@ -214,6 +427,34 @@ macro_rules! impl_Fp {
             }
 
             impl_field_into_repr!($limbs, $BigIntegerType);
+
+            #[inline]
+            fn from_montgomery_repr_unchecked(repr: $BigIntegerType) -> Self {
+                debug_assert!(repr < P::MODULUS);
+                let elem = $Fp(repr, PhantomData);
+                assert_canonical!(elem);
+                elem
+            }
+
+            #[inline]
+            fn to_montgomery_repr(&self) -> $BigIntegerType {
+                assert_canonical!(self);
+                self.0
+            }
+
+            #[inline]
+            fn to_bytes_le(&self) -> Self::Bytes {
+                let mut bytes = [0u8; $limbs * 8];
+                bytes.copy_from_slice(&self.into_repr().to_bytes());
+                bytes
+            }
+
+            #[inline]
+            fn from_bytes_le(bytes: &Self::Bytes) -> Option<Self> {
+                <$BigIntegerType>::from_bytes(bytes)
+                    .ok()
+                    .and_then(Self::from_repr)
+            }
         }
 
         impl<P: $FpParameters> FftField for $Fp<P> {
@@ -224,6 +465,20 @@ macro_rules! impl_Fp {
                 $Fp::<P>(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
             }
 
+            fn two_adic_root_of_unity_of_order(log_n: u32) -> Option<Self> {
+                if log_n > P::TWO_ADICITY {
+                    return None;
+                }
+                if let Some(roots) = P::TWO_ADIC_ROOTS {
+                    return Some($Fp::<P>(roots[log_n as usize], PhantomData));
+                }
+                let mut omega = Self::two_adic_root_of_unity();
+                for _ in log_n..P::TWO_ADICITY {
+                    omega.square_in_place();
+                }
+                Some(omega)
+            }
+
             #[inline]
             fn large_subgroup_root_of_unity() -> Option<Self> {
                 Some($Fp::<P>(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
@@ -241,7 +496,7 @@ macro_rules! impl_Fp {
                 use crate::ff::LegendreSymbol::*;
 
                 // s = self^((MODULUS - 1) // 2)
-                let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+                let s = Self::MODULUS_MINUS_ONE_DIV_TWO_CHAIN.pow(self);
                 if s.is_zero() {
                     Zero
                 } else if s.is_one() {
@@ -253,7 +508,17 @@ macro_rules! impl_Fp {
 
             #[inline]
             fn sqrt(&self) -> Option<Self> {
-                sqrt_impl!(Self, P, self)
+                ct_probe!("sqrt");
+                if P::TWO_ADICITY == 1 {
+                    // p = 3 mod 4, so a square a has the root a^((p + 1) / 4).
+                    let root = Self::MODULUS_PLUS_ONE_DIV_FOUR_CHAIN.pow(self);
+                    return if root.square() == *self {
+                        Some(root)
+                    } else {
+                        None
+                    };
+                }
+                sqrt_impl!(Self, P, self, Self::T_MINUS_ONE_DIV_TWO_CHAIN.pow(self))
             }
 
             fn sqrt_in_place(&mut self) -> Option<&mut Self> {
@@ -286,66 +551,118 @@ macro_rules! impl_Fp {
 
         impl_prime_field_standard_sample!($Fp, $FpParameters);
 
+        // Elements are written as the little-endian bytes of their canonical
+        // integer representative, using as few bytes as the modulus needs.
+        impl<P: $FpParameters> CanonicalSerialize for $Fp<P> {
+            #[inline]
+            fn serialize_with_mode(
+                &self,
+                writer: &mut crate::Vec<u8>,
+                _: Compress,
+            ) -> crate::Result<()> {
+                let mut bytes = self.into_repr().to_bytes();
+                bytes.truncate((P::MODULUS_BITS as usize + 7) / 8);
+                writer.extend(bytes);
+                Ok(())
+            }
+
+            #[inline]
+            fn serialized_size(&self, _: Compress) -> usize {
+                (P::MODULUS_BITS as usize + 7) / 8
+            }
+        }
+
+        impl<P: $FpParameters> CanonicalDeserialize for $Fp<P> {
+            #[inline]
+            fn deserialize_with_mode(
+                reader: &mut &[u8],
+                _: Compress,
+                validate: Validate,
+            ) -> crate::Result<Self> {
+                let mut bytes = [0u8; $limbs * 8];
+                let len = (P::MODULUS_BITS as usize + 7) / 8;
+                bytes[..len].copy_from_slice(read_bytes(reader, len)?);
+                let element = Self::from_repr(<$BigIntegerType>::from_bytes(&bytes)?);
+                match validate {
+                    Validate::Yes => element.ok_or(crate::Error("non-canonical field element")),
+                    // Integers from the modulus up are reduced instead of
+                    // rejected. They can exceed it by several multiples,
+                    // which a Montgomery multiplication by `R^2` would not
+                    // bring back below the modulus, so only they take the
+                    // full reduction.
+                    Validate::No => Ok(element
+                        .unwrap_or_else(|| Self::from_le_bytes_mod_order(&bytes[..len]))),
+                }
+            }
+        }
+
+        impl_from_canonical!([P: $FpParameters], $Fp<P>);
+
         impl<P: $FpParameters> FromStr for $Fp<P> {
-            type Err = ();
+            type Err = ParseFieldError;
 
             /// Interpret a string of numbers as a (congruent) prime field element.
-            /// Does not accept unnecessary leading zeroes or a blank string.
+            /// Accepts decimal numbers without unnecessary leading zeroes,
+            /// `0x`-prefixed hexadecimal numbers, and a leading `-` for the
+            /// negation.
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                if s.is_empty() {
-                    return Err(());
+                let (negative, s) = match s.strip_prefix('-') {
+                    Some(s) => (true, s),
+                    None => (false, s),
+                };
+                let (radix, digits) = match s.strip_prefix("0x") {
+                    Some(digits) => (16, digits),
+                    None => (10, s),
+                };
+                if digits.is_empty() {
+                    return Err(ParseFieldError::Empty);
                 }
-
-                if s == "0" {
-                    return Ok(Self::zero());
+                if radix == 10 && digits.len() > 1 && digits.starts_with('0') {
+                    return Err(ParseFieldError::LeadingZero);
                 }
 
+                let base = Self::from(u64::from(radix));
                 let mut res = Self::zero();
-
-                let ten = Self::from(<Self as PrimeField>::BigInt::from(10));
-
-                let mut first_digit = true;
-
-                for c in s.chars() {
-                    match c.to_digit(10) {
-                        Some(c) => {
-                            if first_digit {
-                                if c == 0 {
-                                    return Err(());
-                                }
-
-                                first_digit = false;
-                            }
-
-                            res.mul_assign(&ten);
-                            let digit = Self::from(u64::from(c));
-                            res.add_assign(&digit);
-                        },
-                        None => {
-                            return Err(());
-                        },
-                    }
-                }
-                if !res.is_valid() {
-                    Err(())
-                } else {
-                    Ok(res)
+                for c in digits.chars() {
+                    let digit = c.to_digit(radix).ok_or(ParseFieldError::InvalidDigit)?;
+                    res.mul_assign(&base);
+                    res.add_assign(&Self::from(u64::from(digit)));
                 }
+                Ok(if negative { -res } else { res })
             }
         }
 
+        /// Writes the representation, or in alternate mode the canonical
+        /// integer in hexadecimal, elided down to the precision if one is
+        /// given.
         impl<P: $FpParameters> Display for $Fp<P> {
             #[inline]
             fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                if f.alternate() {
+                    return Display::fmt(&self.into_repr(), f);
+                }
                 write!(f, stringify!($Fp"({})"), self.into_repr())
             }
         }
 
+        impl<P: $FpParameters> core::fmt::LowerHex for $Fp<P> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                core::fmt::LowerHex::fmt(&self.into_repr(), f)
+            }
+        }
+
+        impl<P: $FpParameters> core::fmt::UpperHex for $Fp<P> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                core::fmt::UpperHex::fmt(&self.into_repr(), f)
+            }
+        }
+
         impl<P: $FpParameters> Neg for $Fp<P> {
             type Output = Self;
             #[inline]
             #[must_use]
             fn neg(self) -> Self {
+                assert_canonical!(self);
                 if !self.is_zero() {
                     let mut tmp = P::MODULUS.clone();
                     tmp.sub_noborrow(&self.0);
@@ -402,10 +719,19 @@ macro_rules! impl_Fp {
 
         impl_additive_ops_from_ref!($Fp, $FpParameters);
         impl_multiplicative_ops_from_ref!($Fp, $FpParameters);
+        impl_num_traits_ops!($Fp, $FpParameters);
 
         impl<'a, P: $FpParameters> AddAssign<&'a Self> for $Fp<P> {
             #[inline]
             fn add_assign(&mut self, other: &Self) {
+                assert_canonical!(self, other);
+                #[cfg(feature = "fiat-crypto")]
+                {
+                    if let Some(backend) = Self::fiat_backend() {
+                        (self.0).0 = (backend.add)(&(self.0).0, &(other.0).0);
+                        return;
+                    }
+                }
                 // This cannot exceed the backing capacity.
                 self.0.add_nocarry(&other.0);
                 // However, it may need to be reduced
@@ -416,6 +742,14 @@ macro_rules! impl_Fp {
         impl<'a, P: $FpParameters> SubAssign<&'a Self> for $Fp<P> {
             #[inline]
             fn sub_assign(&mut self, other: &Self) {
+                assert_canonical!(self, other);
+                #[cfg(feature = "fiat-crypto")]
+                {
+                    if let Some(backend) = Self::fiat_backend() {
+                        (self.0).0 = (backend.sub)(&(self.0).0, &(other.0).0);
+                        return;
+                    }
+                }
                 // If `other` is larger than `self`, add the modulus to self first.
                 if other.0 > self.0 {
                     self.0.add_nocarry(&P::MODULUS);
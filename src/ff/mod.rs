@@ -5,7 +5,15 @@ use core::{
     str::FromStr,
 };
 
-use crate::{uint::Uint, utils::BitIterator, Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, EmptyFlags, Flags},
+    uint::Uint,
+    utils::BitIterator,
+    SunziError, Vec,
+};
 
 #[macro_use]
 pub mod macros;
@@ -16,9 +24,38 @@ use utils::k_adicity;
 #[macro_use]
 pub mod arithmetic;
 
+pub mod addition_chain;
+
 pub mod models;
 pub use self::models::*;
 
+pub mod binary;
+pub use self::binary::Gf128;
+
+pub mod canonical;
+pub use self::canonical::Canonical;
+
+#[cfg(feature = "ct-audit")]
+pub mod ct_audit;
+
+pub mod crt;
+
+#[cfg(feature = "fiat-crypto")]
+pub mod fiat;
+
+pub mod nonnative;
+
+pub mod ring;
+
+pub mod sampling;
+pub use self::sampling::FieldSampler;
+
+pub mod small;
+pub use self::small::SmallField;
+
+pub mod solinas;
+pub use self::solinas::{solinas_modulus, SolinasFp, SolinasParameters};
+
 #[macro_export]
 macro_rules! field_new {
     ($name:ident, $c0:expr) => {
@@ -71,6 +108,8 @@ pub trait Field:
     + for<'a> core::iter::Sum<&'a Self>
     + core::iter::Product<Self>
     + for<'a> core::iter::Product<&'a Self>
+    + CanonicalSerialize
+    + CanonicalDeserialize
 {
     /// Returns an element chosen uniformly at random using a user-provided RNG.
     fn random<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self;
@@ -79,14 +118,44 @@ pub trait Field:
     /// otherwise returns None. This function is primarily intended for sampling
     /// random field elements from a hash-function or RNG output.
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
     }
 
-    /// Returns a field element with an extra sign bit used for group parsing if
-    /// the set of bytes forms a valid field element, otherwise returns
-    /// None. This function is primarily intended for sampling
-    /// random field elements from a hash-function or RNG output.
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)>;
+    /// Returns a field element together with flags used for group parsing if
+    /// the set of bytes forms a valid field element and valid flags, otherwise
+    /// returns None. The flags are read from the top bits of the byte above the
+    /// modulus, and cleared before the element is parsed. This function is
+    /// primarily intended for sampling random field elements from a
+    /// hash-function or RNG output.
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)>;
+
+    /// Returns the number of uniformly random bytes `from_uniform_bytes`
+    /// needs.
+    fn uniform_bytes_len() -> usize;
+
+    /// Maps the first `uniform_bytes_len()` bytes of `bytes` to a field
+    /// element, by reducing wide integers modulo the characteristic. Uniform
+    /// bytes give an element within a statistical distance of `2^-128` of
+    /// uniform. Panics if `bytes` is too short.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// Derives a field element deterministically from `seed`, as the first
+    /// element of `FieldSampler::new(seed)`.
+    fn from_seed(seed: &[u8]) -> Self {
+        FieldSampler::new(seed).next().unwrap()
+    }
+
+    /// Returns an element chosen uniformly at random, up to a statistical
+    /// distance of `2^-128`, by reducing `uniform_bytes_len()` random bytes
+    /// with `from_uniform_bytes`. Unlike the `Standard` distribution, which
+    /// masks `REPR_SHAVE_BITS` and rejects samples above the modulus, it
+    /// always draws the same number of bytes and runs the same operations,
+    /// so that its timing does not depend on the samples.
+    fn random_uniform<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = vec![0u8; Self::uniform_bytes_len()];
+        rng.fill_bytes(&mut bytes);
+        Self::from_uniform_bytes(&bytes)
+    }
 
     /// Returns the zero element of the field, the additive identity.
     fn zero() -> Self;
@@ -125,6 +194,14 @@ pub trait Field:
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
 
+    /// Returns `self^(p^k)`, for `p` the characteristic, by applying the
+    /// Frobenius automorphism rather than exponentiating.
+    fn pow_by_characteristic_power(&self, k: usize) -> Self {
+        let mut res = *self;
+        res.frobenius_map(k);
+        res
+    }
+
     /// Exponentiates this element by a number represented with `u64` limbs,
     /// least significant limb first.
     fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
@@ -159,6 +236,13 @@ pub trait FftParameters: 'static + Send + Sync + Sized {
     /// 2^s root of unity computed by GENERATOR^t
     const TWO_ADIC_ROOT_OF_UNITY: Self::BigInt;
 
+    /// The `2^k`-th roots of unity `TWO_ADIC_ROOT_OF_UNITY^(2^(s - k))` for
+    /// `k` from 0 to `s`, in the representation of `TWO_ADIC_ROOT_OF_UNITY`,
+    /// so that roots of unity are looked up instead of computed with up to
+    /// `s` squarings. For prime fields, the table is computed at compile time
+    /// by `two_adic_roots`, e.g. `Fp256::<P>::two_adic_roots::<33>()`.
+    const TWO_ADIC_ROOTS: Option<&'static [Self::BigInt]> = None;
+
     /// An integer `b` such that there exists a multiplicative subgroup
     /// of size `b^k` for some integer `k`.
     const SMALL_SUBGROUP_BASE: Option<u32> = None;
@@ -194,6 +278,9 @@ pub trait FpParameters: FftParameters {
     /// INV = -MODULUS^{-1} mod 2^64
     const INV: u64;
 
+    /// INV32 = -MODULUS^{-1} mod 2^32, for backends with 32-bit limbs.
+    const INV32: u32 = Self::INV as u32;
+
     /// A multiplicative generator of the field.
     /// `Self::GENERATOR` is an element having multiplicative order
     /// `Self::MODULUS - 1`.
@@ -227,6 +314,19 @@ pub trait FftField: Field + From<u128> + From<u64> + From<u32> + From<u16> + Fro
     /// Returns the multiplicative generator of `char()` - 1 order.
     fn multiplicative_generator() -> Self;
 
+    /// Returns the 2^log_n root of unity obtained from the 2^s root of unity,
+    /// or None if `log_n` is larger than the two-adicity.
+    fn two_adic_root_of_unity_of_order(log_n: u32) -> Option<Self> {
+        if log_n > Self::FftParams::TWO_ADICITY {
+            return None;
+        }
+        let mut omega = Self::two_adic_root_of_unity();
+        for _ in log_n..Self::FftParams::TWO_ADICITY {
+            omega.square_in_place();
+        }
+        Some(omega)
+    }
+
     /// Returns the root of unity of order n, if one exists.
     /// If no small multiplicative subgroup is defined, this is the 2-adic root of unity of order n
     /// (for n a power of 2).
@@ -275,10 +375,7 @@ pub trait FftField: Field + From<u128> + From<u64> + From<u32> + From<u16> + Fro
 
             // Compute the generator for the multiplicative subgroup.
             // It should be 2^(log_size_of_group) root of unity.
-            omega = Self::two_adic_root_of_unity();
-            for _ in log_size_of_group..Self::FftParams::TWO_ADICITY {
-                omega.square_in_place();
-            }
+            omega = Self::two_adic_root_of_unity_of_order(log_size_of_group)?;
         }
         Some(omega)
     }
@@ -293,13 +390,138 @@ pub trait PrimeField:
 {
     type Params: FpParameters<BigInt = Self::BigInt>;
     type BigInt: Uint;
+    /// A byte array holding the `8 * BigInt::LIMBS` bytes of the underlying
+    /// representation.
+    type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]>;
 
     /// Returns a prime field element from its underlying representation.
     fn from_repr(repr: Self::BigInt) -> Option<Self>;
 
+    /// Returns a prime field element from its underlying representation, or
+    /// `NotInField` if it is not smaller than the modulus.
+    fn try_from_repr(repr: Self::BigInt) -> Result<Self, SunziError> {
+        Self::from_repr(repr).ok_or(SunziError::NotInField)
+    }
+
     /// Returns the underlying representation of the prime field element.
     fn into_repr(&self) -> Self::BigInt;
 
+    /// Returns a prime field element from its internal representation, the
+    /// Montgomery form `x * R mod p` for `R = 2^(64 * BigInt::LIMBS)`, as
+    /// returned by `to_montgomery_repr`, without converting or checking it.
+    ///
+    /// This is meant for moving elements to and from accelerators, such as
+    /// GPUs, that compute in the same form. `repr` must be below the
+    /// modulus, or the arithmetic on the element is incorrect.
+    fn from_montgomery_repr_unchecked(repr: Self::BigInt) -> Self;
+
+    /// Returns the internal representation of the prime field element, the
+    /// Montgomery form `x * R mod p`, without converting it.
+    fn to_montgomery_repr(&self) -> Self::BigInt;
+
+    /// Compares `self` and `other` as integers in `[0, p)`. The prime fields
+    /// of this crate order their elements this way, but another
+    /// implementation of `Ord` may compare internal representations, such
+    /// as Montgomery forms, instead.
+    fn cmp_canonical(&self, other: &Self) -> core::cmp::Ordering {
+        self.into_repr().cmp(&other.into_repr())
+    }
+
+    /// Returns the little-endian bytes of the underlying representation.
+    fn to_bytes_le(&self) -> Self::Bytes;
+
+    /// Returns the big-endian bytes of the underlying representation.
+    fn to_bytes_be(&self) -> Self::Bytes {
+        let mut bytes = self.to_bytes_le();
+        bytes.as_mut().reverse();
+        bytes
+    }
+
+    /// Returns the field element with the given little-endian representation,
+    /// or None if it is not smaller than the modulus.
+    fn from_bytes_le(bytes: &Self::Bytes) -> Option<Self>;
+
+    /// Returns the field element with the given big-endian representation,
+    /// or None if it is not smaller than the modulus.
+    fn from_bytes_be(bytes: &Self::Bytes) -> Option<Self> {
+        let mut bytes = *bytes;
+        bytes.as_mut().reverse();
+        Self::from_bytes_le(&bytes)
+    }
+
+    /// Returns the integer with the given little-endian bytes, of any length,
+    /// reduced modulo the modulus.
+    fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+        // Chunks of `CAPACITY` bits at most are below the modulus.
+        let chunk_size = (Self::Params::CAPACITY / 8) as usize;
+        let mut base = Self::one();
+        for _ in 0..8 * chunk_size {
+            base = base.double();
+        }
+        bytes
+            .chunks(chunk_size)
+            .rev()
+            .fold(Self::zero(), |acc, chunk| {
+                let mut repr = Self::zero().to_bytes_le();
+                repr.as_mut()[..chunk.len()].copy_from_slice(chunk);
+                acc * base + Self::from_bytes_le(&repr).unwrap()
+            })
+    }
+
+    /// Returns the integer with the given big-endian bytes, of any length,
+    /// reduced modulo the modulus.
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_le_bytes_mod_order(&bytes)
+    }
+
+    /// Returns the `MODULUS_BITS` bits of the canonical integer value of
+    /// `self`, least significant first.
+    fn to_bits_le(&self) -> Vec<bool> {
+        let repr = self.into_repr();
+        (0..Self::Params::MODULUS_BITS as usize)
+            .map(|i| repr.get_bit(i))
+            .collect()
+    }
+
+    /// Returns the `MODULUS_BITS` bits of the canonical integer value of
+    /// `self`, most significant first.
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits = self.to_bits_le();
+        bits.reverse();
+        bits
+    }
+
+    /// Returns the field element with the given bits, least significant
+    /// first, or None if they do not encode an integer smaller than the
+    /// modulus. Any number of bits is accepted.
+    fn from_bits_le(bits: &[bool]) -> Option<Self> {
+        let mut repr = Self::BigInt::default();
+        for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+            if i >= 64 * Self::BigInt::LIMBS {
+                return None;
+            }
+            repr.as_mut()[i / 64] |= 1 << (i % 64);
+        }
+        Self::from_repr(repr)
+    }
+
+    /// Returns the field element with the given bits, most significant
+    /// first, or None if they do not encode an integer smaller than the
+    /// modulus. Any number of bits is accepted.
+    fn from_bits_be(bits: &[bool]) -> Option<Self> {
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        Self::from_bits_le(&bits)
+    }
+
+    /// Returns the little-endian 64-bit limbs of the canonical integer value
+    /// of `self`.
+    fn to_u64_limbs_canonical(&self) -> Vec<u64> {
+        self.into_repr().as_ref().to_vec()
+    }
+
     /// Return the a QNR^T
     fn qnr_to_t() -> Self {
         Self::two_adic_root_of_unity()
@@ -324,6 +546,34 @@ pub trait PrimeField:
     fn modulus_minus_one_div_two() -> Self::BigInt {
         Self::Params::MODULUS_MINUS_ONE_DIV_TWO
     }
+
+    /// Returns `self^((p - 1) / n)`, which is one iff `self` is a nonzero
+    /// `n`-th power, or None if `n` does not divide `p - 1`. The exponents
+    /// for `n = 2` and `n = 2^TWO_ADICITY` are taken from `Self::Params`.
+    fn pow_by_modulus_minus_one_div_n(&self, n: u64) -> Option<Self> {
+        let two_adicity = Self::Params::TWO_ADICITY;
+        if n == 2 {
+            return Some(self.pow(Self::Params::MODULUS_MINUS_ONE_DIV_TWO));
+        } else if two_adicity < 64 && n == 1 << two_adicity {
+            return Some(self.pow(Self::Params::T));
+        } else if n == 0 {
+            return None;
+        }
+
+        let mut exp = Self::Params::MODULUS;
+        exp.sub_noborrow(&Self::BigInt::from(1));
+        let mut rem = 0u128;
+        for limb in exp.as_mut().iter_mut().rev() {
+            let cur = (rem << 64) | *limb as u128;
+            *limb = (cur / n as u128) as u64;
+            rem = cur % n as u128;
+        }
+        if rem == 0 {
+            Some(self.pow(exp))
+        } else {
+            None
+        }
+    }
 }
 
 /// The interface for a field that supports an efficient square-root operation.
@@ -337,6 +587,39 @@ pub trait SquareRootField: Field {
 
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
+
+    /// Returns the Legendre symbols of `elems`.
+    fn batch_legendre(elems: &[Self]) -> Vec<LegendreSymbol> {
+        crate::cfg_iter!(elems).map(|e| e.legendre()).collect()
+    }
+
+    /// Returns the square roots of `elems`, equal to those of `sqrt`.
+    /// Extension fields batch the square roots in the base field and share
+    /// the inversions between elements.
+    fn batch_sqrt(elems: &[Self]) -> Vec<Option<Self>> {
+        crate::cfg_iter!(elems).map(|e| e.sqrt()).collect()
+    }
+}
+
+/// The error returned when parsing a prime field element from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFieldError {
+    /// The string has no digits.
+    Empty,
+    /// The string contains a character that is not a digit in its base.
+    InvalidDigit,
+    /// A decimal string has an unnecessary leading zero.
+    LeadingZero,
+}
+
+impl core::fmt::Display for ParseFieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseFieldError::Empty => write!(f, "no digits to parse"),
+            ParseFieldError::InvalidDigit => write!(f, "invalid digit"),
+            ParseFieldError::LeadingZero => write!(f, "unnecessary leading zero"),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -400,3 +683,332 @@ pub fn batch_inversion<F: Field>(v: &mut [F]) {
         tmp = new_tmp;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::{fr::Fr, Fq, Fq2};
+
+    #[test]
+    fn prime_field_bytes_test() {
+        let a = Fr::from(0x0102030405060708u64);
+        let le = a.to_bytes_le();
+        assert_eq!(le[..8], [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert!(le[8..].iter().all(|b| *b == 0));
+        assert_eq!(Fr::from_bytes_le(&le), Some(a));
+
+        let be = a.to_bytes_be();
+        assert_eq!(be[24..], [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(Fr::from_bytes_be(&be), Some(a));
+
+        // The modulus itself is not a canonical encoding.
+        let mut modulus = [0u8; 32];
+        for (chunk, limb) in modulus.chunks_mut(8).zip(Fr::characteristic().iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        assert_eq!(Fr::from_bytes_be(&modulus), None);
+        assert_eq!(
+            Fr::from_bytes_be(&(-Fr::one()).to_bytes_be()),
+            Some(-Fr::one())
+        );
+
+        let repr = Fr::characteristic().into();
+        assert_eq!(Fr::try_from_repr(repr), Err(SunziError::NotInField));
+        assert_eq!(Fr::try_from_repr(a.into_repr()), Ok(a));
+    }
+
+    #[test]
+    fn random_uniform_test() {
+        use rand_core::{RngCore, SeedableRng};
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(1);
+        let mut replay = XorShiftRng::seed_from_u64(1);
+        let a = Fr::random_uniform(&mut rng);
+        let b = Fq2::random_uniform(&mut rng);
+        assert_ne!(a, Fr::random_uniform(&mut rng));
+
+        // Each sample draws a fixed number of bytes.
+        let mut bytes = vec![0u8; Fr::uniform_bytes_len()];
+        replay.fill_bytes(&mut bytes);
+        assert_eq!(Fr::from_uniform_bytes(&bytes), a);
+        let mut bytes = vec![0u8; Fq2::uniform_bytes_len()];
+        replay.fill_bytes(&mut bytes);
+        assert_eq!(Fq2::from_uniform_bytes(&bytes), b);
+        let mut bytes = vec![0u8; Fr::uniform_bytes_len()];
+        replay.fill_bytes(&mut bytes);
+        assert_eq!(rng.next_u64(), replay.next_u64());
+    }
+
+    #[test]
+    fn deserialize_validation_test() {
+        use crate::serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+        let a = Fr::from_seed(b"a");
+        let mut bytes = Vec::new();
+        a.serialize_compressed(&mut bytes).unwrap();
+        for validate in [Validate::Yes, Validate::No].iter() {
+            assert_eq!(
+                Fr::try_from_bytes_with_mode(&bytes, Compress::Yes, *validate),
+                Ok(a)
+            );
+        }
+
+        // `p + 1` is only accepted without validation, as `1`.
+        let mut modulus_plus_one = Fr::characteristic().to_vec();
+        modulus_plus_one[0] += 1;
+        let bytes: Vec<u8> = modulus_plus_one
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect();
+        assert!(Fr::try_from_bytes(&bytes, Compress::Yes).is_err());
+        assert_eq!(
+            Fr::try_from_bytes_with_mode(&bytes, Compress::Yes, Validate::No),
+            Ok(Fr::one())
+        );
+
+        // The largest encodable integers are several times the modulus.
+        let bytes = vec![0xff; Fr::one().compressed_size()];
+        assert!(Fr::try_from_bytes(&bytes, Compress::Yes).is_err());
+        assert_eq!(
+            Fr::try_from_bytes_with_mode(&bytes, Compress::Yes, Validate::No),
+            Ok(Fr::from(2u64).pow([8 * bytes.len() as u64]) - Fr::one())
+        );
+        let bytes = vec![0xff; Fq::one().compressed_size()];
+        assert!(Fq::try_from_bytes(&bytes, Compress::Yes).is_err());
+        assert_eq!(
+            Fq::try_from_bytes_with_mode(&bytes, Compress::Yes, Validate::No),
+            Ok(Fq::from(2u64).pow([8 * bytes.len() as u64]) - Fq::one())
+        );
+    }
+
+    #[test]
+    fn montgomery_repr_test() {
+        assert_eq!(
+            Fr::one().to_montgomery_repr(),
+            <Fr as PrimeField>::Params::R
+        );
+        assert_eq!(Fr::zero().to_montgomery_repr(), Default::default());
+        for i in 0..100u64 {
+            let a = Fr::from_seed(&i.to_le_bytes());
+            let b = Fr::from_seed(&(i + 100).to_le_bytes());
+            let (ra, rb) = (a.to_montgomery_repr(), b.to_montgomery_repr());
+            assert_eq!(Fr::from_montgomery_repr_unchecked(ra), a);
+            // Arithmetic on the raw forms agrees with that on the elements.
+            let product =
+                Fr::from_montgomery_repr_unchecked(ra) * Fr::from_montgomery_repr_unchecked(rb);
+            assert_eq!(product.to_montgomery_repr(), (a * b).to_montgomery_repr());
+        }
+    }
+
+    #[test]
+    fn prime_field_bits_test() {
+        let a = Fr::from(0b1011u64);
+        let le = a.to_bits_le();
+        assert_eq!(le.len(), 255);
+        assert_eq!(le[..5], [true, true, false, true, false]);
+        assert!(le[4..].iter().all(|b| !b));
+        assert_eq!(Fr::from_bits_le(&le), Some(a));
+        assert_eq!(Fr::from_bits_le(&[true, true, false, true]), Some(a));
+        assert_eq!(Fr::from_bits_be(&[true, false, true, true]), Some(a));
+        assert_eq!(a.to_bits_be()[251..], [true, false, true, true]);
+
+        let b = -Fr::from(5u64);
+        assert_eq!(Fr::from_bits_be(&b.to_bits_be()), Some(b));
+        assert_eq!(b.to_u64_limbs_canonical(), b.into_repr().as_ref().to_vec());
+        assert_eq!(Fr::one().to_u64_limbs_canonical(), [1, 0, 0, 0]);
+
+        // Ones beyond the width of the representation, or encodings of the
+        // modulus, are rejected; leading zeros are not.
+        let mut bits = vec![false; 300];
+        assert_eq!(Fr::from_bits_le(&bits), Some(Fr::zero()));
+        bits[256] = true;
+        assert_eq!(Fr::from_bits_le(&bits), None);
+        let modulus: Vec<bool> = (0..256)
+            .map(|i| Fr::characteristic()[i / 64] & (1 << (i % 64)) != 0)
+            .collect();
+        assert_eq!(Fr::from_bits_le(&modulus), None);
+    }
+
+    #[test]
+    fn prime_field_format_test() {
+        let a = Fr::from(0xabcdefu64);
+        assert_eq!(Fr::from_str("11259375"), Ok(a));
+        assert_eq!(Fr::from_str("0xabcdef"), Ok(a));
+        assert_eq!(Fr::from_str("0x00ABCDEF"), Ok(a));
+        assert_eq!(Fr::from_str("-11259375"), Ok(-a));
+        assert_eq!(Fr::from_str("-0"), Ok(Fr::zero()));
+        assert_eq!(Fr::from_str(""), Err(ParseFieldError::Empty));
+        assert_eq!(Fr::from_str("0x"), Err(ParseFieldError::Empty));
+        assert_eq!(Fr::from_str("012"), Err(ParseFieldError::LeadingZero));
+        assert_eq!(Fr::from_str("12a"), Err(ParseFieldError::InvalidDigit));
+
+        assert_eq!(format!("{:x}", a), "abcdef");
+        assert_eq!(format!("{:#X}", a), "0xABCDEF");
+        assert_eq!(format!("{:#}", a), "0xabcdef");
+        assert_eq!(format!("{:#.8}", -Fr::one()), "0x73ed\u{2026}0000");
+    }
+
+    #[test]
+    fn pow_by_exponent_helpers_test() {
+        let g = Fr::multiplicative_generator();
+        let neg_one = -Fr::one();
+        assert_eq!(g.pow_by_modulus_minus_one_div_n(2), Some(neg_one));
+        assert_eq!(
+            g.square().pow_by_modulus_minus_one_div_n(2),
+            Some(Fr::one())
+        );
+        assert_eq!(
+            g.pow_by_modulus_minus_one_div_n(1 << 32),
+            Some(g.pow(Fr::trace()))
+        );
+
+        // The order of the multiplicative group is divisible by 3 but not 5.
+        let omega = g.pow_by_modulus_minus_one_div_n(3).unwrap();
+        assert_ne!(omega, Fr::one());
+        assert_eq!(omega.pow([3u64]), Fr::one());
+        assert_eq!(
+            g.pow([3u64]).pow_by_modulus_minus_one_div_n(3),
+            Some(Fr::one())
+        );
+        assert_eq!(g.pow_by_modulus_minus_one_div_n(5), None);
+        assert_eq!(g.pow_by_modulus_minus_one_div_n(0), None);
+
+        let a = Fq2::new(Fq::from(3u64), Fq::from(7u64));
+        assert_eq!(
+            a.pow_by_characteristic_power(1),
+            a.pow(Fq::characteristic())
+        );
+        assert_eq!(a.pow_by_characteristic_power(2), a);
+        assert_eq!(g.pow_by_characteristic_power(1), g);
+    }
+
+    // Checks `TWO_ADIC_ROOTS` against repeated squaring of the `2^s`-th root
+    // of unity, and the lookups against the table.
+    #[cfg(any(feature = "bls12_381", feature = "bls12_377", feature = "bn_256"))]
+    fn check_two_adic_roots<P: Fp256Parameters>() {
+        let roots = P::TWO_ADIC_ROOTS.unwrap();
+        assert_eq!(roots.len(), P::TWO_ADICITY as usize + 1);
+        let mut omega = Fp256::<P>::two_adic_root_of_unity();
+        for log_n in (0..=P::TWO_ADICITY).rev() {
+            assert_eq!(Fp256::<P>::new(roots[log_n as usize]), omega);
+            let lookup = Fp256::<P>::two_adic_root_of_unity_of_order(log_n);
+            assert_eq!(lookup, Some(omega));
+            assert_eq!(Fp256::<P>::get_root_of_unity(1 << log_n), lookup);
+            omega.square_in_place();
+        }
+        assert!(Fp256::<P>::new(roots[0]).is_one());
+        assert_eq!(Fp256::<P>::new(roots[1]), -Fp256::<P>::one());
+        assert_eq!(
+            Fp256::<P>::two_adic_root_of_unity_of_order(P::TWO_ADICITY + 1),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "bls12_381", feature = "bls12_377", feature = "bn_256"))]
+    fn two_adic_roots_test() {
+        #[cfg(feature = "bls12_381")]
+        check_two_adic_roots::<crate::pairing::curves::bls12_381::FrParameters>();
+        #[cfg(feature = "bls12_377")]
+        check_two_adic_roots::<crate::pairing::curves::bls12_377::FrParameters>();
+        #[cfg(feature = "bn_256")]
+        check_two_adic_roots::<crate::pairing::curves::bn_256::FrParameters>();
+    }
+
+    #[test]
+    fn batch_sqrt_test() {
+        let elems: Vec<Fr> = (0..20u64).map(Fr::from).collect();
+        let roots = Fr::batch_sqrt(&elems);
+        for (e, root) in elems.iter().zip(&roots) {
+            assert_eq!(*root, e.sqrt());
+        }
+        assert!(roots.iter().any(Option::is_none));
+        assert_eq!(
+            Fr::batch_legendre(&elems[..3]),
+            vec![
+                LegendreSymbol::Zero,
+                LegendreSymbol::QuadraticResidue,
+                Fr::from(2u64).legendre()
+            ]
+        );
+
+        let elems: Vec<Fq2> = (0..20u64)
+            .map(|i| Fq2::new(Fq::from(i / 2), Fq::from(i % 5)))
+            .collect();
+        let roots = Fq2::batch_sqrt(&elems);
+        for (e, root) in elems.iter().zip(&roots) {
+            assert_eq!(*root, e.sqrt());
+            if let Some(root) = root {
+                assert_eq!(root.square(), *e);
+            }
+        }
+        assert!(roots.iter().any(Option::is_none));
+        assert!(Fq2::batch_sqrt(&[]).is_empty());
+    }
+
+    #[test]
+    fn const_arithmetic_test() {
+        use algebra::bls12_381::FrParameters;
+
+        const ONE: Fr = Fr::new(FrParameters::R);
+        const TWO: Fr = ONE.const_double();
+        const MINUS_ONE: Fr = ONE.const_neg();
+        const THREE: Fr = TWO.const_sub(MINUS_ONE);
+        const FIVE: Fr = THREE.const_add(TWO);
+        assert_eq!(ONE, Fr::one());
+        assert_eq!(TWO, Fr::from(2u64));
+        assert_eq!(MINUS_ONE, -Fr::one());
+        assert_eq!(THREE, Fr::from(3u64));
+        assert_eq!(FIVE, Fr::from(5u64));
+        assert_eq!(MINUS_ONE.const_add(ONE), Fr::zero());
+        assert_eq!(Fr::zero().const_neg(), Fr::zero());
+        assert_eq!(ONE.const_sub(TWO), MINUS_ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "the representation is not below the modulus")]
+    fn const_from_repr_test() {
+        use algebra::bls12_381::FrParameters;
+
+        let _ = Fr::const_from_repr(FrParameters::MODULUS);
+    }
+
+    #[test]
+    fn montgomery_constants_test() {
+        use algebra::bls12_381::FrParameters;
+
+        // `R3` holds `R^2` in Montgomery form, as `R2` holds `R`.
+        let r = Fr::new(FrParameters::R2);
+        assert_eq!(Fr::new(FrParameters::R3), r.square());
+        assert_eq!(
+            (FrParameters::MODULUS.0[0] as u32).wrapping_mul(FrParameters::INV32),
+            u32::MAX
+        );
+    }
+
+    #[cfg(feature = "debug-assert-canonical")]
+    #[test]
+    #[should_panic(expected = "non-canonical field element")]
+    fn assert_canonical_test() {
+        use algebra::bls12_381::FrParameters;
+
+        // The modulus is not reduced, so no operation accepts it.
+        let unreduced = Fr::new(FrParameters::MODULUS);
+        let _ = unreduced * Fr::one();
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn num_traits_test() {
+        use num_traits::{Inv, MulAdd, Pow};
+
+        let a = Fr::from(3u64);
+        let b = Fr::from(5u64);
+        assert_eq!(a.inv(), a.inverse());
+        assert_eq!(Fr::zero().inv(), None);
+        assert_eq!(Pow::pow(a, 4u64), Fr::from(81u64));
+        assert_eq!(Pow::pow(a, &[4u64][..]), Fr::from(81u64));
+        assert_eq!(a.mul_add(b, a), Fr::from(18u64));
+    }
+}
@@ -10,7 +10,10 @@ use rand::{
     Rng,
 };
 
-use crate::{BitIterator, UniformRand};
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, EmptyFlags, Flags, Validate},
+    BitIterator, UniformRand,
+};
 
 use super::{fp6_3over2::*, Field, Fp2, Fp2Parameters};
 
@@ -32,7 +35,11 @@ pub trait Fp12Parameters: 'static + Send + Sync + Copy {
     PartialEq(bound = "P: Fp12Parameters"),
     Eq(bound = "P: Fp12Parameters")
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Fp12<P: Fp12Parameters> {
     pub c0: Fp6<P::Fp6Params>,
     pub c1: Fp6<P::Fp6Params>,
@@ -203,11 +210,133 @@ impl<P: Fp12Parameters> Fp12<P> {
         }
         res
     }
+
+    /// Returns whether `self` lies in the cyclotomic subgroup of order
+    /// `p^4 - p^2 + 1`, which contains the image of the final exponentiation.
+    pub fn is_in_cyclotomic_subgroup(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        // f^(p^4 - p^2 + 1) == 1  <==>  f^(p^4) * f == f^(p^2)
+        let mut f_p2 = *self;
+        f_p2.frobenius_map(2);
+        let mut f_p4 = f_p2;
+        f_p4.frobenius_map(2);
+        f_p4 * self == f_p2
+    }
+
+    /// Compresses `self` to half its size, or returns `None` if `self` is not
+    /// in the cyclotomic subgroup.
+    pub fn compress(&self) -> Option<CompressedFp12<P>> {
+        if self.is_in_cyclotomic_subgroup() {
+            Some(self.compress_unchecked())
+        } else {
+            None
+        }
+    }
+
+    fn compress_unchecked(&self) -> CompressedFp12<P> {
+        // Elements of norm one are f = c0 + c1 * w = (g + w) / (g - w) with
+        // g = (1 + c0) / c1, except for f = 1 which has c1 = 0.
+        let g = if self.c1.is_zero() {
+            if self.c0.is_one() {
+                None
+            } else {
+                Some(Fp6::zero())
+            }
+        } else {
+            Some((self.c0 + &Fp6::one()) * &self.c1.inverse().unwrap())
+        };
+        CompressedFp12 { g }
+    }
+}
+
+/// An element of the cyclotomic subgroup of Fp12 in the compressed form of
+/// the algebraic torus T2 over Fp6: the element `(g + w) / (g - w)` is
+/// stored as the single Fp6 element `g`, and the identity as `None`.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "P: Fp12Parameters"),
+    Hash(bound = "P: Fp12Parameters"),
+    Clone(bound = "P: Fp12Parameters"),
+    Copy(bound = "P: Fp12Parameters"),
+    Debug(bound = "P: Fp12Parameters"),
+    PartialEq(bound = "P: Fp12Parameters"),
+    Eq(bound = "P: Fp12Parameters")
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate", bound = "")
+)]
+pub struct CompressedFp12<P: Fp12Parameters> {
+    g: Option<Fp6<P::Fp6Params>>,
+}
+
+impl<P: Fp12Parameters> CompressedFp12<P> {
+    /// Returns the compressed element `g`, or `None` for the identity.
+    pub fn to_fp6(&self) -> Option<Fp6<P::Fp6Params>> {
+        self.g
+    }
+
+    /// Constructs the element `(g + w) / (g - w)`, or the identity for `None`.
+    pub fn from_fp6(g: Option<Fp6<P::Fp6Params>>) -> Self {
+        Self { g }
+    }
+
+    /// Recovers the full Fp12 representation.
+    pub fn decompress(&self) -> Fp12<P> {
+        match self.g {
+            None => Fp12::one(),
+            Some(g) => {
+                // (g + w) / (g - w) = (g^2 + v + 2 * g * w) / (g^2 - v), where
+                // g^2 - v is nonzero because v is not a square in Fp6.
+                let v = Fp12::<P>::mul_fp6_by_nonresidue(&Fp6::one());
+                let g2 = g.square();
+                let denom_inv = (g2 - &v).inverse().unwrap();
+                Fp12::new((g2 + &v) * &denom_inv, g.double() * &denom_inv)
+            }
+        }
+    }
+
+    /// Returns `self * other` without decompressing.
+    pub fn mul(&self, other: &Self) -> Self {
+        match (self.g, other.g) {
+            (None, _) => *other,
+            (_, None) => *self,
+            (Some(g1), Some(g2)) => {
+                // The product is (g1 * g2 + v) / (g1 + g2), or the identity
+                // if other is the inverse of self.
+                let v = Fp12::<P>::mul_fp6_by_nonresidue(&Fp6::one());
+                let g = (g1 + &g2)
+                    .inverse()
+                    .map(|sum_inv| (g1 * &g2 + &v) * &sum_inv);
+                Self { g }
+            }
+        }
+    }
+
+    /// Returns the inverse of `self`, which maps `g` to `-g`.
+    pub fn inverse(&self) -> Self {
+        Self {
+            g: self.g.map(|g| -g),
+        }
+    }
+
+    /// Returns `self^exp`, using cyclotomic squarings on the decompressed
+    /// element.
+    pub fn exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        self.decompress().cyclotomic_exp(exp).compress_unchecked()
+    }
 }
 
 impl<P: Fp12Parameters> fmt::Display for Fp12<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Fp12({} + {} * w)", self.c0, self.c1)
+        write!(f, "Fp12(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, " + ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, " * w)")
     }
 }
 
@@ -250,11 +379,11 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
     }
 
     #[inline]
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 2;
         if let Some(c0) = Fp6::<P::Fp6Params>::from_random_bytes(&bytes[..split_at]) {
             if let Some((c1, flags)) =
-                Fp6::<P::Fp6Params>::from_random_bytes_with_flags(&bytes[split_at..])
+                Fp6::<P::Fp6Params>::from_random_bytes_with_flags::<F>(&bytes[split_at..])
             {
                 return Some((Fp12::new(c0, c1), flags));
             }
@@ -264,7 +393,21 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
 
     #[inline]
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    #[inline]
+    fn uniform_bytes_len() -> usize {
+        2 * Fp6::<P::Fp6Params>::uniform_bytes_len()
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let n = Fp6::<P::Fp6Params>::uniform_bytes_len();
+        Fp12::new(
+            Fp6::<P::Fp6Params>::from_uniform_bytes(&bytes[..n]),
+            Fp6::<P::Fp6Params>::from_uniform_bytes(&bytes[n..2 * n]),
+        )
     }
 
     fn double_in_place(&mut self) -> &mut Self {
@@ -399,6 +542,8 @@ impl<'a, P: Fp12Parameters> Div<&'a Self> for Fp12<P> {
 
 impl_additive_ops_from_ref!(Fp12, Fp12Parameters);
 impl_multiplicative_ops_from_ref!(Fp12, Fp12Parameters);
+impl_num_traits_ops!(Fp12, Fp12Parameters);
+impl_extension_field_serialize!(Fp12, Fp12Parameters, c0, c1);
 
 impl<'a, P: Fp12Parameters> AddAssign<&'a Self> for Fp12<P> {
     #[inline]
@@ -481,3 +626,52 @@ impl<P: Fp12Parameters> From<u8> for Fp12<P> {
         Self::new(other.into(), Fp6::zero())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use algebra::bls12_381::Fq12;
+    use algebra_core::{test_rng, Field, One, UniformRand, Zero};
+
+    /// Maps `f` into the cyclotomic subgroup by raising it to the power
+    /// `(p^6 - 1) * (p^2 + 1)`, the easy part of the final exponentiation.
+    fn cyclotomic(f: Fq12) -> Fq12 {
+        let mut g = f;
+        g.conjugate();
+        g *= &f.inverse().unwrap();
+        let mut h = g;
+        h.frobenius_map(2);
+        h * &g
+    }
+
+    #[test]
+    fn compress_round_trip() {
+        let rng = &mut test_rng();
+        let one = Fq12::one().compress().unwrap();
+        assert_eq!(one.to_fp6(), None);
+        assert!(one.decompress().is_one());
+        for _ in 0..10 {
+            let a = cyclotomic(Fq12::rand(rng));
+            let b = cyclotomic(Fq12::rand(rng));
+            assert!(a.is_in_cyclotomic_subgroup());
+            let a_c = a.compress().unwrap();
+            let b_c = b.compress().unwrap();
+            assert_eq!(a_c.decompress(), a);
+            assert_eq!(a_c.mul(&b_c).decompress(), a * &b);
+            assert!(a_c.mul(&a_c.inverse()).decompress().is_one());
+            assert_eq!(a_c.exp([5u64]).decompress(), a.pow([5u64]));
+        }
+    }
+
+    #[test]
+    fn compress_rejects_non_cyclotomic_elements() {
+        let rng = &mut test_rng();
+        assert!(Fq12::zero().compress().is_none());
+        // -1 has order 2, which does not divide p^4 - p^2 + 1.
+        assert!((-Fq12::one()).compress().is_none());
+        for _ in 0..10 {
+            let a = Fq12::rand(rng);
+            assert!(!a.is_in_cyclotomic_subgroup());
+            assert!(a.compress().is_none());
+        }
+    }
+}
@@ -9,7 +9,11 @@ use rand::{
     Rng,
 };
 
-use crate::UniformRand;
+use crate::{
+    ff::{batch_inversion, utils::mul_by_small_int},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, EmptyFlags, Flags, Validate},
+    UniformRand, Vec,
+};
 
 use super::{Field, LegendreSymbol, PrimeField, SquareRootField};
 
@@ -23,9 +27,16 @@ pub trait Fp2Parameters: 'static + Send + Sync {
     /// Coefficients for the Frobenius automorphism.
     const FROBENIUS_COEFF_FP2_C1: [Self::Fp; 2];
 
+    /// `NONRESIDUE` as a small integer, e.g. `Some(-1)`, if it is one. It is
+    /// then multiplied by with additions instead of a field multiplication.
+    const SMALL_NONRESIDUE: Option<i64> = None;
+
     #[inline(always)]
     fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
-        Self::NONRESIDUE * fe
+        match Self::SMALL_NONRESIDUE {
+            Some(k) => mul_by_small_int(fe, k),
+            None => Self::NONRESIDUE * fe,
+        }
     }
 }
 
@@ -39,7 +50,11 @@ pub trait Fp2Parameters: 'static + Send + Sync {
     PartialEq(bound = "P: Fp2Parameters"),
     Eq(bound = "P: Fp2Parameters")
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Fp2<P: Fp2Parameters> {
     pub c0: P::Fp,
     pub c1: P::Fp,
@@ -110,10 +125,11 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
     }
 
     #[inline]
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 2;
         if let Some(c0) = P::Fp::from_random_bytes(&bytes[..split_at]) {
-            if let Some((c1, flags)) = P::Fp::from_random_bytes_with_flags(&bytes[split_at..]) {
+            if let Some((c1, flags)) = P::Fp::from_random_bytes_with_flags::<F>(&bytes[split_at..])
+            {
                 return Some((Fp2::new(c0, c1), flags));
             }
         }
@@ -122,7 +138,21 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
 
     #[inline]
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    #[inline]
+    fn uniform_bytes_len() -> usize {
+        2 * P::Fp::uniform_bytes_len()
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let n = P::Fp::uniform_bytes_len();
+        Fp2::new(
+            P::Fp::from_uniform_bytes(&bytes[..n]),
+            P::Fp::from_uniform_bytes(&bytes[n..2 * n]),
+        )
     }
 
     fn square_in_place(&mut self) -> &mut Self {
@@ -220,6 +250,67 @@ where
             self
         })
     }
+
+    fn batch_legendre(elems: &[Self]) -> Vec<LegendreSymbol> {
+        let norms: Vec<_> = elems.iter().map(|e| e.norm()).collect();
+        P::Fp::batch_legendre(&norms)
+    }
+
+    // Runs the complex method of `sqrt` on all elements at once.
+    fn batch_sqrt(elems: &[Self]) -> Vec<Option<Self>> {
+        let two_inv = P::Fp::one()
+            .double()
+            .inverse()
+            .expect("Two should always have an inverse");
+
+        // `alpha` is the square root of the norm, or of `c0` if `c1` is zero,
+        // and is None iff the element is not a square.
+        let norms: Vec<_> = elems
+            .iter()
+            .map(|e| if e.c1.is_zero() { e.c0 } else { e.norm() })
+            .collect();
+        let alphas = P::Fp::batch_sqrt(&norms);
+
+        // Take the square root of `(alpha + c0) / 2`, or of `(c0 - alpha) / 2`
+        // if the first one is not a square.
+        let mut deltas: Vec<_> = elems
+            .iter()
+            .zip(&alphas)
+            .map(|(e, alpha)| match alpha {
+                Some(alpha) if !e.c1.is_zero() => (*alpha + &e.c0) * &two_inv,
+                _ => P::Fp::zero(),
+            })
+            .collect();
+        let mut c0s = P::Fp::batch_sqrt(&deltas);
+        let retry: Vec<usize> = (0..elems.len()).filter(|&i| c0s[i].is_none()).collect();
+        for &i in &retry {
+            deltas[i] -= alphas[i]
+                .as_ref()
+                .expect("Delta is only a non-square for squares");
+        }
+        let retried = P::Fp::batch_sqrt(&retry.iter().map(|&i| deltas[i]).collect::<Vec<_>>());
+        for (&i, c0) in retry.iter().zip(retried) {
+            c0s[i] = Some(c0.expect("Delta must have a square root"));
+        }
+
+        let c0s: Vec<_> = c0s.into_iter().map(Option::unwrap).collect();
+        let mut c0_invs = c0s.clone();
+        batch_inversion(&mut c0_invs);
+
+        elems
+            .iter()
+            .zip(alphas)
+            .zip(c0s.into_iter().zip(c0_invs))
+            .map(|((e, alpha), (c0, c0_inv))| {
+                let alpha = alpha?;
+                if e.c1.is_zero() {
+                    Some(Self::new(alpha, P::Fp::zero()))
+                } else {
+                    Some(Self::new(c0, e.c1 * &two_inv * &c0_inv))
+                }
+            })
+            .collect()
+    }
 }
 
 /// `Fp2` elements are ordered lexicographically.
@@ -352,6 +443,9 @@ impl<'a, P: Fp2Parameters> SubAssign<&'a Self> for Fp2<P> {
 
 impl_additive_ops_from_ref!(Fp2, Fp2Parameters);
 impl_multiplicative_ops_from_ref!(Fp2, Fp2Parameters);
+impl_mul_assign_by_base_field!(Fp2, Fp2Parameters);
+impl_num_traits_ops!(Fp2, Fp2Parameters);
+impl_extension_field_serialize!(Fp2, Fp2Parameters, c0, c1);
 
 impl<'a, P: Fp2Parameters> MulAssign<&'a Self> for Fp2<P> {
     #[inline]
@@ -378,6 +472,12 @@ impl<'a, P: Fp2Parameters> DivAssign<&'a Self> for Fp2<P> {
 
 impl<P: Fp2Parameters> fmt::Display for Fp2<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Fp2({} + {} * u)", self.c0, self.c1)
+        // The coefficients are written with the flags of `f`, such as the
+        // alternate mode of prime field elements.
+        write!(f, "Fp2(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, " + ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, " * u)")
     }
 }
@@ -10,7 +10,11 @@ use rand::{
     Rng,
 };
 
-use crate::{UniformRand, Vec};
+use crate::{
+    ff::utils::mul_by_small_int,
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, EmptyFlags, Flags, Validate},
+    UniformRand, Vec,
+};
 
 use super::{Field, LegendreSymbol, PrimeField, SquareRootField};
 
@@ -26,9 +30,16 @@ pub trait Fp3Parameters: 'static + Send + Sync {
     /// t-th power of a quadratic nonresidue in Fp3.
     const QUADRATIC_NONRESIDUE_TO_T: (Self::Fp, Self::Fp, Self::Fp);
 
+    /// `NONRESIDUE` as a small integer, e.g. `Some(-1)`, if it is one. It is
+    /// then multiplied by with additions instead of a field multiplication.
+    const SMALL_NONRESIDUE: Option<i64> = None;
+
     #[inline(always)]
     fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
-        Self::NONRESIDUE * fe
+        match Self::SMALL_NONRESIDUE {
+            Some(k) => mul_by_small_int(fe, k),
+            None => Self::NONRESIDUE * fe,
+        }
     }
 }
 
@@ -42,7 +53,11 @@ pub trait Fp3Parameters: 'static + Send + Sync {
     PartialEq(bound = "P: Fp3Parameters"),
     Eq(bound = "P: Fp3Parameters")
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Fp3<P: Fp3Parameters> {
     pub c0: P::Fp,
     pub c1: P::Fp,
@@ -130,12 +145,12 @@ impl<P: Fp3Parameters> Field for Fp3<P> {
     }
 
     #[inline]
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 3;
         if let Some(c0) = P::Fp::from_random_bytes(&bytes[..split_at]) {
             if let Some(c1) = P::Fp::from_random_bytes(&bytes[split_at..2 * split_at]) {
                 if let Some((c2, flags)) =
-                    P::Fp::from_random_bytes_with_flags(&bytes[2 * split_at..])
+                    P::Fp::from_random_bytes_with_flags::<F>(&bytes[2 * split_at..])
                 {
                     return Some((Fp3::new(c0, c1, c2), flags));
                 }
@@ -146,7 +161,22 @@ impl<P: Fp3Parameters> Field for Fp3<P> {
 
     #[inline]
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    #[inline]
+    fn uniform_bytes_len() -> usize {
+        3 * P::Fp::uniform_bytes_len()
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let n = P::Fp::uniform_bytes_len();
+        Fp3::new(
+            P::Fp::from_uniform_bytes(&bytes[..n]),
+            P::Fp::from_uniform_bytes(&bytes[n..2 * n]),
+            P::Fp::from_uniform_bytes(&bytes[2 * n..3 * n]),
+        )
     }
 
     fn square(&self) -> Self {
@@ -390,6 +420,9 @@ impl<'a, P: Fp3Parameters> Div<&'a Fp3<P>> for Fp3<P> {
 
 impl_additive_ops_from_ref!(Fp3, Fp3Parameters);
 impl_multiplicative_ops_from_ref!(Fp3, Fp3Parameters);
+impl_mul_assign_by_base_field!(Fp3, Fp3Parameters);
+impl_num_traits_ops!(Fp3, Fp3Parameters);
+impl_extension_field_serialize!(Fp3, Fp3Parameters, c0, c1, c2);
 impl<'a, P: Fp3Parameters> AddAssign<&'a Self> for Fp3<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
@@ -475,6 +508,12 @@ impl<P: Fp3Parameters> FromStr for Fp3<P> {
 
 impl<P: Fp3Parameters> fmt::Display for Fp3<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Fp3({}, {}, {})", self.c0, self.c1, self.c2)
+        write!(f, "Fp3(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.c2, f)?;
+        write!(f, ")")
     }
 }
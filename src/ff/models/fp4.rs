@@ -9,7 +9,10 @@ use rand::{
     Rng,
 };
 
-use crate::{Uint, UniformRand};
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, EmptyFlags, Flags, Validate},
+    Uint, UniformRand,
+};
 
 use super::{Field, Fp2, Fp2Parameters};
 
@@ -38,7 +41,11 @@ pub trait Fp4Parameters: 'static + Send + Sync {
     PartialEq(bound = "P: Fp4Parameters"),
     Eq(bound = "P: Fp4Parameters")
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Fp4<P: Fp4Parameters> {
     pub c0: Fp2<P::Fp2Params>,
     pub c1: Fp2<P::Fp2Params>,
@@ -135,11 +142,11 @@ impl<P: Fp4Parameters> Field for Fp4<P> {
     }
 
     #[inline]
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 2;
         if let Some(c0) = Fp2::<P::Fp2Params>::from_random_bytes(&bytes[..split_at]) {
             if let Some((c1, flags)) =
-                Fp2::<P::Fp2Params>::from_random_bytes_with_flags(&bytes[split_at..])
+                Fp2::<P::Fp2Params>::from_random_bytes_with_flags::<F>(&bytes[split_at..])
             {
                 return Some((Fp4::new(c0, c1), flags));
             }
@@ -149,7 +156,21 @@ impl<P: Fp4Parameters> Field for Fp4<P> {
 
     #[inline]
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    #[inline]
+    fn uniform_bytes_len() -> usize {
+        2 * Fp2::<P::Fp2Params>::uniform_bytes_len()
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let n = Fp2::<P::Fp2Params>::uniform_bytes_len();
+        Fp4::new(
+            Fp2::<P::Fp2Params>::from_uniform_bytes(&bytes[..n]),
+            Fp2::<P::Fp2Params>::from_uniform_bytes(&bytes[n..2 * n]),
+        )
     }
 
     fn square_in_place(&mut self) -> &mut Self {
@@ -341,6 +362,8 @@ impl<'a, P: Fp4Parameters> SubAssign<&'a Self> for Fp4<P> {
 
 impl_additive_ops_from_ref!(Fp4, Fp4Parameters);
 impl_multiplicative_ops_from_ref!(Fp4, Fp4Parameters);
+impl_num_traits_ops!(Fp4, Fp4Parameters);
+impl_extension_field_serialize!(Fp4, Fp4Parameters, c0, c1);
 
 impl<'a, P: Fp4Parameters> MulAssign<&'a Self> for Fp4<P> {
     #[inline]
@@ -369,6 +392,10 @@ impl<'a, P: Fp4Parameters> DivAssign<&'a Self> for Fp4<P> {
 
 impl<P: Fp4Parameters> fmt::Display for Fp4<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Fp4({} + {} * u)", self.c0, self.c1)
+        write!(f, "Fp4(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, " + ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, " * u)")
     }
 }
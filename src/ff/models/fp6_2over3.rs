@@ -9,7 +9,11 @@ use rand::{
     Rng,
 };
 
-use crate::{uint::Uint, UniformRand};
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, EmptyFlags, Flags, Validate},
+    uint::Uint,
+    UniformRand,
+};
 
 use super::{Field, Fp3, Fp3Parameters};
 
@@ -37,7 +41,11 @@ pub trait Fp6Parameters: 'static + Send + Sync {
     PartialEq(bound = "P: Fp6Parameters"),
     Eq(bound = "P: Fp6Parameters")
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Fp6<P: Fp6Parameters> {
     pub c0: Fp3<P::Fp3Params>,
     pub c1: Fp3<P::Fp3Params>,
@@ -205,11 +213,11 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 
     #[inline]
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 2;
         if let Some(c0) = Fp3::<P::Fp3Params>::from_random_bytes(&bytes[..split_at]) {
             if let Some((c1, flags)) =
-                Fp3::<P::Fp3Params>::from_random_bytes_with_flags(&bytes[split_at..])
+                Fp3::<P::Fp3Params>::from_random_bytes_with_flags::<F>(&bytes[split_at..])
             {
                 return Some((Fp6::new(c0, c1), flags));
             }
@@ -219,7 +227,21 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
 
     #[inline]
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    #[inline]
+    fn uniform_bytes_len() -> usize {
+        2 * Fp3::<P::Fp3Params>::uniform_bytes_len()
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let n = Fp3::<P::Fp3Params>::uniform_bytes_len();
+        Fp6::new(
+            Fp3::<P::Fp3Params>::from_uniform_bytes(&bytes[..n]),
+            Fp3::<P::Fp3Params>::from_uniform_bytes(&bytes[n..2 * n]),
+        )
     }
 
     fn square_in_place(&mut self) -> &mut Self {
@@ -392,6 +414,8 @@ impl<'a, P: Fp6Parameters> Div<&'a Fp6<P>> for Fp6<P> {
 
 impl_additive_ops_from_ref!(Fp6, Fp6Parameters);
 impl_multiplicative_ops_from_ref!(Fp6, Fp6Parameters);
+impl_num_traits_ops!(Fp6, Fp6Parameters);
+impl_extension_field_serialize!(Fp6, Fp6Parameters, c0, c1);
 
 impl<'a, P: Fp6Parameters> AddAssign<&'a Self> for Fp6<P> {
     #[inline]
@@ -447,6 +471,10 @@ impl<'a, P: Fp6Parameters> From<&'a [bool]> for Fp6<P> {
 
 impl<P: Fp6Parameters> fmt::Display for Fp6<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Fp6_2over3({}, {})", self.c0, self.c1)
+        write!(f, "Fp6_2over3(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, ")")
     }
 }
@@ -9,7 +9,10 @@ use rand::{
     Rng,
 };
 
-use crate::UniformRand;
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, EmptyFlags, Flags, Validate},
+    UniformRand,
+};
 
 use super::{Field, Fp2, Fp2Parameters};
 
@@ -39,7 +42,11 @@ pub trait Fp6Parameters: 'static + Send + Sync + Copy {
     PartialEq(bound = "P: Fp6Parameters"),
     Eq(bound = "P: Fp6Parameters")
 )]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Fp6<P: Fp6Parameters> {
     pub c0: Fp2<P::Fp2Params>,
     pub c1: Fp2<P::Fp2Params>,
@@ -175,13 +182,13 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 
     #[inline]
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 3;
         if let Some(c0) = Fp2::<P::Fp2Params>::from_random_bytes(&bytes[..split_at]) {
             if let Some(c1) = Fp2::<P::Fp2Params>::from_random_bytes(&bytes[split_at..2 * split_at])
             {
                 if let Some((c2, flags)) =
-                    Fp2::<P::Fp2Params>::from_random_bytes_with_flags(&bytes[2 * split_at..])
+                    Fp2::<P::Fp2Params>::from_random_bytes_with_flags::<F>(&bytes[2 * split_at..])
                 {
                     return Some((Fp6::new(c0, c1, c2), flags));
                 }
@@ -192,7 +199,22 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
 
     #[inline]
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    #[inline]
+    fn uniform_bytes_len() -> usize {
+        3 * Fp2::<P::Fp2Params>::uniform_bytes_len()
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let n = Fp2::<P::Fp2Params>::uniform_bytes_len();
+        Fp6::new(
+            Fp2::<P::Fp2Params>::from_uniform_bytes(&bytes[..n]),
+            Fp2::<P::Fp2Params>::from_uniform_bytes(&bytes[n..2 * n]),
+            Fp2::<P::Fp2Params>::from_uniform_bytes(&bytes[2 * n..3 * n]),
+        )
     }
 
     fn square(&self) -> Self {
@@ -279,11 +301,13 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
 
 impl<P: Fp6Parameters> fmt::Display for Fp6<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Fq6_3over2({} + {} * v, {} * v^2)",
-            self.c0, self.c1, self.c2
-        )
+        write!(f, "Fq6_3over2(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, " + ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, " * v, ")?;
+        fmt::Display::fmt(&self.c2, f)?;
+        write!(f, " * v^2)")
     }
 }
 
@@ -357,6 +381,8 @@ impl<'a, P: Fp6Parameters> Div<&'a Self> for Fp6<P> {
 
 impl_additive_ops_from_ref!(Fp6, Fp6Parameters);
 impl_multiplicative_ops_from_ref!(Fp6, Fp6Parameters);
+impl_num_traits_ops!(Fp6, Fp6Parameters);
+impl_extension_field_serialize!(Fp6, Fp6Parameters, c0, c1, c2);
 
 impl<'a, P: Fp6Parameters> AddAssign<&'a Self> for Fp6<P> {
     #[inline]
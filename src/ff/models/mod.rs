@@ -8,9 +8,15 @@ use core::{
 use num_traits::{One, Zero};
 use unroll::unroll_for_loops;
 
-use crate::uint::{arithmetic as fa, Uint as _U, U256, U320, U384, U768, U832};
+use crate::{
+    serialize::{read_bytes, CanonicalDeserialize, CanonicalSerialize, Compress, Flags, Validate},
+    uint::{arithmetic as fa, Uint as _U, U256, U320, U384, U768, U832},
+};
 
-use super::{FftField, Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField};
+use super::{
+    addition_chain::{self, AdditionChain},
+    FftField, Field, FpParameters, LegendreSymbol, ParseFieldError, PrimeField, SquareRootField,
+};
 
 #[cfg(use_asm)]
 use std::mem::MaybeUninit;
@@ -0,0 +1,340 @@
+//! Emulation of a "foreign" prime field in a native one, as needed to
+//! verify proofs over one field inside a circuit over another.
+//!
+//! An element of the target field is stored as limbs of the base field,
+//! each holding `bits_per_limb` bits of its canonical integer value. Sums and
+//! products are computed limb by limb without carries, and the elements
+//! track a bound on the size of their limbs, so that the caller knows when
+//! a reduction modulo the target modulus is due before the limbs overflow
+//! the base field. A reduction returns the quotient and the remainder as a
+//! hint, which a circuit checks instead of dividing.
+
+use core::marker::PhantomData;
+
+use crate::{
+    ff::{FpParameters, PrimeField},
+    Vec,
+};
+
+/// The shape of the limbs used to emulate `Target` in `Base`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonNativeParams {
+    pub num_limbs: usize,
+    pub bits_per_limb: u32,
+}
+
+impl NonNativeParams {
+    /// Returns the fewest limbs such that the product of two reduced
+    /// elements does not overflow `Base`, each with as few bits as possible.
+    ///
+    /// Panics if `Base` is too small to multiply even single bits.
+    pub fn new<Target: PrimeField, Base: PrimeField>() -> Self {
+        let target_bits = Target::Params::MODULUS_BITS;
+        let capacity = Base::Params::CAPACITY;
+        (1..=target_bits as usize)
+            .map(|num_limbs| NonNativeParams {
+                num_limbs,
+                bits_per_limb: (target_bits + num_limbs as u32 - 1) / num_limbs as u32,
+            })
+            .find(|params| 2 * params.bits_per_limb + ceil_log2(params.num_limbs) <= capacity)
+            .expect("base field too small to emulate the target field")
+    }
+}
+
+/// An element of `Target` stored as limbs of `Base`, least significant
+/// first, whose canonical integer value is `∑ limbs[i] 2^(i bits_per_limb)`.
+/// Limbs are never negative, and are smaller than `2^limb_bits`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct NonNativeElement<Target: PrimeField, Base: PrimeField> {
+    limbs: Vec<Base>,
+    limb_bits: u32,
+    params: NonNativeParams,
+    #[derivative(Debug = "ignore")]
+    _target: PhantomData<Target>,
+}
+
+/// The result of `NonNativeElement::reduce`: the integer value of the
+/// reduced element is `quotient * p + remainder`, for `p` the modulus of
+/// `Target`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct ReductionHint<Target: PrimeField, Base: PrimeField> {
+    /// The limbs of the quotient, of `bits_per_limb` bits each.
+    pub quotient: Vec<Base>,
+    pub remainder: NonNativeElement<Target, Base>,
+}
+
+impl<Target: PrimeField, Base: PrimeField> NonNativeElement<Target, Base> {
+    /// Returns the limbs of the canonical integer value of `elem`.
+    pub fn new(elem: &Target, params: NonNativeParams) -> Self {
+        let value = elem.to_u64_limbs_canonical();
+        Self::from_integer(&value, params, params.num_limbs)
+    }
+
+    /// Returns the limbs of `0`.
+    pub fn zero(params: NonNativeParams) -> Self {
+        Self::from_integer(&[], params, params.num_limbs)
+    }
+
+    /// Returns the limbs, least significant first.
+    pub fn limbs(&self) -> &[Base] {
+        &self.limbs
+    }
+
+    /// Returns the bound on the number of bits of each limb.
+    pub fn limb_bits(&self) -> u32 {
+        self.limb_bits
+    }
+
+    pub fn params(&self) -> NonNativeParams {
+        self.params
+    }
+
+    /// Returns the element of `Target` represented by the limbs.
+    pub fn value(&self) -> Target {
+        let bytes: Vec<u8> = self
+            .to_integer()
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        Target::from_le_bytes_mod_order(&bytes)
+    }
+
+    /// Returns the limbwise sum of `self` and `other`, or None if a limb
+    /// might overflow `Base`, in which case the operands must be reduced
+    /// first.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let limb_bits = self.limb_bits.max(other.limb_bits) + 1;
+        if limb_bits > Base::Params::CAPACITY {
+            return None;
+        }
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut limbs = long.limbs.clone();
+        for (a, b) in limbs.iter_mut().zip(&short.limbs) {
+            *a += b;
+        }
+        Some(self.with_limbs(limbs, limb_bits))
+    }
+
+    /// Returns the product of `self` and `other` as a polynomial product of
+    /// their limbs, or None if a limb might overflow `Base`, in which case
+    /// the operands must be reduced first.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let terms = self.limbs.len().min(other.limbs.len());
+        let limb_bits = self.limb_bits + other.limb_bits + ceil_log2(terms);
+        if limb_bits > Base::Params::CAPACITY {
+            return None;
+        }
+        let mut limbs = vec![Base::zero(); self.limbs.len() + other.limbs.len() - 1];
+        for (i, a) in self.limbs.iter().enumerate() {
+            for (j, b) in other.limbs.iter().enumerate() {
+                limbs[i + j] += &(*a * b);
+            }
+        }
+        Some(self.with_limbs(limbs, limb_bits))
+    }
+
+    /// Returns the quotient and the remainder of the integer value of
+    /// `self` by the modulus of `Target`. The remainder has
+    /// `params().num_limbs` limbs of `bits_per_limb` bits.
+    pub fn reduce(&self) -> ReductionHint<Target, Base> {
+        let modulus = Target::characteristic();
+        let (quotient, remainder) = div_rem(&self.to_integer(), modulus);
+        let bits_per_limb = self.params.bits_per_limb;
+        let quotient_limbs = ((bit_len(&quotient) + bits_per_limb - 1) / bits_per_limb).max(1);
+        ReductionHint {
+            quotient: to_limbs(&quotient, bits_per_limb, quotient_limbs as usize),
+            remainder: Self::from_integer(&remainder, self.params, self.params.num_limbs),
+        }
+    }
+
+    fn with_limbs(&self, limbs: Vec<Base>, limb_bits: u32) -> Self {
+        NonNativeElement {
+            limbs,
+            limb_bits,
+            params: self.params,
+            _target: PhantomData,
+        }
+    }
+
+    fn from_integer(value: &[u64], params: NonNativeParams, num_limbs: usize) -> Self {
+        NonNativeElement {
+            limbs: to_limbs(value, params.bits_per_limb, num_limbs),
+            limb_bits: params.bits_per_limb,
+            params,
+            _target: PhantomData,
+        }
+    }
+
+    fn to_integer(&self) -> Vec<u64> {
+        let mut value = Vec::new();
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let shift = i * self.params.bits_per_limb as usize;
+            add_shifted(&mut value, &limb.to_u64_limbs_canonical(), shift);
+        }
+        value
+    }
+}
+
+fn ceil_log2(n: usize) -> u32 {
+    n.next_power_of_two().trailing_zeros()
+}
+
+// Integers below are little-endian vectors of 64-bit words.
+
+fn bit(value: &[u64], i: usize) -> bool {
+    value
+        .get(i / 64)
+        .map_or(false, |word| (word >> (i % 64)) & 1 == 1)
+}
+
+fn bit_len(value: &[u64]) -> u32 {
+    value
+        .iter()
+        .rposition(|word| *word != 0)
+        .map_or(0, |i| 64 * i as u32 + 64 - value[i].leading_zeros())
+}
+
+/// Splits `value` into `num_limbs` limbs of `bits_per_limb` bits. The bits
+/// beyond are dropped.
+fn to_limbs<Base: PrimeField>(value: &[u64], bits_per_limb: u32, num_limbs: usize) -> Vec<Base> {
+    let bits_per_limb = bits_per_limb as usize;
+    (0..num_limbs)
+        .map(|j| {
+            let bits: Vec<bool> = (0..bits_per_limb)
+                .map(|k| bit(value, j * bits_per_limb + k))
+                .collect();
+            Base::from_bits_le(&bits).unwrap()
+        })
+        .collect()
+}
+
+/// Adds `value * 2^shift` to `acc`.
+fn add_shifted(acc: &mut Vec<u64>, value: &[u64], shift: usize) {
+    let (words, bits) = (shift / 64, shift % 64);
+    let mut shifted = vec![0u64; words + value.len() + 1];
+    for (i, word) in value.iter().enumerate() {
+        shifted[words + i] |= word << bits;
+        if bits > 0 {
+            shifted[words + i + 1] |= word >> (64 - bits);
+        }
+    }
+    if acc.len() < shifted.len() {
+        acc.resize(shifted.len(), 0);
+    }
+    let mut carry = 0u128;
+    for (i, a) in acc.iter_mut().enumerate() {
+        let sum = *a as u128 + *shifted.get(i).unwrap_or(&0) as u128 + carry;
+        *a = sum as u64;
+        carry = sum >> 64;
+    }
+    if carry > 0 {
+        acc.push(carry as u64);
+    }
+}
+
+/// Returns the quotient and the remainder of `n` by `d`, bit by bit.
+fn div_rem(n: &[u64], d: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    // One more word holds the remainder shifted left before subtracting.
+    let mut d = d.to_vec();
+    d.push(0);
+    let mut quotient = vec![0u64; n.len()];
+    let mut remainder = vec![0u64; d.len()];
+    for i in (0..64 * n.len()).rev() {
+        let mut carry = bit(n, i) as u64;
+        for word in remainder.iter_mut() {
+            let next = *word >> 63;
+            *word = (*word << 1) | carry;
+            carry = next;
+        }
+        let at_least_d = remainder
+            .iter()
+            .zip(&d)
+            .rev()
+            .find(|(r, d)| r != d)
+            .map_or(true, |(r, d)| r > d);
+        if at_least_d {
+            let mut borrow = 0u128;
+            for (r, d) in remainder.iter_mut().zip(&d) {
+                let diff = (*r as u128).wrapping_sub(*d as u128 + borrow);
+                *r = diff as u64;
+                borrow = (diff >> 127) & 1;
+            }
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+    remainder.pop();
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::Field;
+    use algebra::bls12_381::{fr::Fr, Fq};
+
+    /// Evaluates the limbs at `2^bits_per_limb` in `Base`, i.e. returns the
+    /// integer value of the limbs modulo the modulus of `Base`.
+    fn in_base(limbs: &[Fr], bits_per_limb: u32) -> Fr {
+        let shift = Fr::from(2u64).pow([bits_per_limb as u64]);
+        limbs
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, limb| acc * shift + limb)
+    }
+
+    #[test]
+    fn nonnative_arithmetic_test() {
+        let params = NonNativeParams::new::<Fq, Fr>();
+        assert_eq!(
+            params,
+            NonNativeParams {
+                num_limbs: 4,
+                bits_per_limb: 96
+            }
+        );
+
+        let (a, b) = (Fq::from_seed(b"a"), Fq::from_seed(b"b"));
+        let x = NonNativeElement::<Fq, Fr>::new(&a, params);
+        let y = NonNativeElement::new(&b, params);
+        assert_eq!(x.value(), a);
+        assert_eq!(x.limbs().len(), 4);
+        assert_eq!(NonNativeElement::<Fq, Fr>::zero(params).value(), Fq::zero());
+
+        let sum = x.checked_add(&y).unwrap();
+        assert_eq!(sum.limb_bits(), 97);
+        assert_eq!(sum.value(), a + b);
+        let product = sum.checked_mul(&x).unwrap();
+        assert_eq!(product.limbs().len(), 7);
+        assert_eq!(product.value(), (a + b) * a);
+        assert!(product.checked_mul(&x).is_none());
+
+        // The hint satisfies the integer equation, hence the equation
+        // modulo the modulus of the base field that a circuit checks.
+        let hint = product.reduce();
+        assert_eq!(hint.remainder.value(), (a + b) * a);
+        assert_eq!(hint.remainder.limb_bits(), 96);
+        let p = Fr::from_le_bytes_mod_order(
+            &Fq::characteristic()
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            in_base(product.limbs(), 96),
+            in_base(&hint.quotient, 96) * p + in_base(hint.remainder.limbs(), 96)
+        );
+        let next = hint.remainder.checked_mul(&x).unwrap();
+        assert_eq!(next.value(), (a + b) * a * a);
+    }
+}
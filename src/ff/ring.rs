@@ -0,0 +1,278 @@
+//! Commutative rings with identity, for arithmetic that does not need
+//! inverses, such as polynomials over `Z_q` for a composite `q` in
+//! RLWE-style schemes.
+
+use core::{
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::ff::{Field, SmallField};
+
+/// The interface for a commutative ring with identity. Every `Field` is a
+/// `Ring`, so code written against this trait accepts fields too.
+///
+/// The methods are named apart from `Field::zero`, `Field::one` and
+/// `Field::is_zero`, so that calls on a field type resolve with both traits
+/// in scope.
+pub trait Ring:
+    'static
+    + Copy
+    + Clone
+    + Debug
+    + Display
+    + Default
+    + Send
+    + Sync
+    + Eq
+    + Hash
+    + Neg<Output = Self>
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + AddAssign<Self>
+    + SubAssign<Self>
+    + MulAssign<Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self>
+    + for<'a> MulAssign<&'a Self>
+    + Sum<Self>
+    + for<'a> Sum<&'a Self>
+{
+    /// Returns the additive identity.
+    fn zero_element() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one_element() -> Self;
+
+    /// Returns true iff this element is the additive identity.
+    fn is_zero_element(&self) -> bool;
+}
+
+impl<F: Field> Ring for F {
+    #[inline]
+    fn zero_element() -> Self {
+        F::zero()
+    }
+
+    #[inline]
+    fn one_element() -> Self {
+        F::one()
+    }
+
+    #[inline]
+    fn is_zero_element(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+/// The integers modulo `Q`, for any `Q` from 2 to `2^63`, stored as their
+/// representatives in `[0, Q)`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Zq<const Q: u64>(u64);
+
+impl<const Q: u64> Zq<Q> {
+    /// Returns `value` reduced modulo `Q`.
+    pub const fn new(value: u64) -> Self {
+        Zq(value % Q)
+    }
+
+    /// Returns the representative in `[0, Q)`.
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<const Q: u64> From<u64> for Zq<Q> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const Q: u64> Debug for Zq<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} mod {}", self.0, Q)
+    }
+}
+
+impl<const Q: u64> Display for Zq<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const Q: u64> Ring for Zq<Q> {
+    #[inline]
+    fn zero_element() -> Self {
+        Zq(0)
+    }
+
+    #[inline]
+    fn one_element() -> Self {
+        Zq(1 % Q)
+    }
+
+    #[inline]
+    fn is_zero_element(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const Q: u64> SmallField for Zq<Q> {
+    const MODULUS: u64 = Q;
+
+    #[inline]
+    fn as_canonical_u64(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn from_canonical_u64_unchecked(value: u64) -> Self {
+        debug_assert!(value < Q);
+        Zq(value)
+    }
+}
+
+impl<const Q: u64> Neg for Zq<Q> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Zq(Q - self.0)
+        }
+    }
+}
+
+impl<'a, const Q: u64> AddAssign<&'a Self> for Zq<Q> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // Both are below `2^63`, so the sum does not overflow.
+        self.0 += other.0;
+        if self.0 >= Q {
+            self.0 -= Q;
+        }
+    }
+}
+
+impl<'a, const Q: u64> SubAssign<&'a Self> for Zq<Q> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        *self += &-*other;
+    }
+}
+
+impl<'a, const Q: u64> MulAssign<&'a Self> for Zq<Q> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        self.0 = (u128::from(self.0) * u128::from(other.0) % u128::from(Q)) as u64;
+    }
+}
+
+macro_rules! impl_zq_ops {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident;)*) => {
+        $(
+            impl<'a, const Q: u64> $Op<&'a Self> for Zq<Q> {
+                type Output = Self;
+
+                #[inline]
+                fn $op(mut self, other: &Self) -> Self {
+                    self.$op_assign(other);
+                    self
+                }
+            }
+
+            impl<const Q: u64> $Op<Self> for Zq<Q> {
+                type Output = Self;
+
+                #[inline]
+                fn $op(mut self, other: Self) -> Self {
+                    self.$op_assign(&other);
+                    self
+                }
+            }
+
+            impl<const Q: u64> $OpAssign<Self> for Zq<Q> {
+                #[inline]
+                fn $op_assign(&mut self, other: Self) {
+                    self.$op_assign(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_zq_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+);
+
+impl<const Q: u64> Sum<Self> for Zq<Q> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero_element(), Add::add)
+    }
+}
+
+impl<'a, const Q: u64> Sum<&'a Self> for Zq<Q> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero_element(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::fr::Fr;
+
+    fn ring_identities<R: Ring>(a: R, b: R, c: R) {
+        assert_eq!(a + R::zero_element(), a);
+        assert_eq!(a * R::one_element(), a);
+        assert!((a - a).is_zero_element());
+        assert!((a + -a).is_zero_element());
+        assert_eq!(a * (b + c), a * b + a * c);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!([a, b, c].iter().sum::<R>(), a + b + c);
+    }
+
+    #[test]
+    fn ring_test() {
+        type R = Zq<{ 1 << 32 }>;
+        let (a, b) = (R::new(u64::MAX), R::new(3));
+        assert_eq!(a.value(), (1 << 32) - 1);
+        assert!((a + R::one_element()).is_zero_element());
+        // `2^16` is a zero divisor.
+        assert!((R::new(1 << 16) * R::new(1 << 16)).is_zero_element());
+        ring_identities(a, b, R::new(12345));
+
+        type S = Zq<{ (1 << 63) - 25 }>;
+        let c = S::new((1 << 63) - 26);
+        assert_eq!(c * c, S::one_element());
+        assert_eq!(S::zero_element() - S::one_element(), c);
+        ring_identities(c, S::new(1 << 62), S::new(7));
+
+        let x = Fr::from(5u64);
+        ring_identities(x, -x, Fr::one());
+        assert_eq!(Fr::zero_element(), Fr::zero());
+        assert!(Fr::zero().is_zero_element());
+    }
+
+    #[test]
+    fn small_field_test() {
+        // The BabyBear prime `15 * 2^27 + 1`.
+        type F = Zq<{ 15 * (1 << 27) + 1 }>;
+        let a = F::from_canonical_u64(12345).unwrap();
+        assert_eq!(a.as_canonical_u64(), 12345);
+        assert_eq!(a, F::from_canonical_u64_unchecked(12345));
+        assert_eq!(F::from_canonical_u64(F::MODULUS), None);
+        assert_eq!(F::from_noncanonical_u64(F::MODULUS + 12345), a);
+        assert_eq!((-a).as_canonical_u64(), F::MODULUS - 12345);
+    }
+}
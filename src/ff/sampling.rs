@@ -0,0 +1,110 @@
+use core::marker::PhantomData;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+use sha2::{Digest, Sha256};
+
+use crate::{ff::Field, Vec};
+
+/// An unbounded stream of field elements derived deterministically from a
+/// seed, for Fiat–Shamir challenges and reproducible test fixtures.
+///
+/// The ChaCha20 key is the SHA-256 hash of the seed prefixed with its length
+/// as a little-endian `u64`, and the key stream is cut into chunks of
+/// `F::uniform_bytes_len()` bytes, each reduced to a field element with
+/// `F::from_uniform_bytes`. Distinct seeds thus give unrelated elements,
+/// including seeds only differing by trailing zeros.
+pub struct FieldSampler<F: Field> {
+    rng: ChaCha20Rng,
+    bytes: Vec<u8>,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> FieldSampler<F> {
+    pub fn new(seed: &[u8]) -> Self {
+        let key = Sha256::new()
+            .chain_update((seed.len() as u64).to_le_bytes())
+            .chain_update(seed)
+            .finalize();
+
+        FieldSampler {
+            rng: ChaCha20Rng::from_seed(key.into()),
+            bytes: vec![0u8; F::uniform_bytes_len()],
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: Field> Iterator for FieldSampler<F> {
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<F> {
+        self.rng.fill_bytes(&mut self.bytes);
+        Some(F::from_uniform_bytes(&self.bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::PrimeField;
+    use algebra::bls12_381::{fr::Fr, Fq2};
+
+    #[test]
+    fn field_sampler_test() {
+        assert_eq!(Fr::from_seed(b"seed"), Fr::from_seed(b"seed"));
+        assert_ne!(Fr::from_seed(b"seed"), Fr::from_seed(b"seee"));
+        assert_ne!(Fr::from_seed(b"seed"), Fr::from_seed(b"seed\0"));
+        assert_ne!(Fr::from_seed(&[]), Fr::from_seed(&[0]));
+
+        let elems: Vec<Fr> = FieldSampler::new(b"seed").take(4).collect();
+        assert_eq!(elems[0], Fr::from_seed(b"seed"));
+        assert!(elems.windows(2).all(|w| w[0] != w[1]));
+
+        let elems: Vec<Fq2> = FieldSampler::new(&[7u8; 100]).take(2).collect();
+        assert_ne!(elems[0], elems[1]);
+        assert_ne!(elems[0].c0, elems[0].c1);
+    }
+
+    #[test]
+    fn seeds_of_the_same_length_give_different_elements() {
+        let elems: Vec<Fr> = (0..64u8).map(|i| Fr::from_seed(&[i; 64])).collect();
+        for (i, a) in elems.iter().enumerate() {
+            assert!(elems[i + 1..].iter().all(|b| a != b));
+        }
+
+        // Two 64-byte seeds that collide when each 32-byte chunk is XORed into
+        // a key which is then replaced by its ChaCha20 key stream.
+        let stream = |key: [u8; 32]| {
+            let mut out = [0u8; 32];
+            ChaCha20Rng::from_seed(key).fill_bytes(&mut out);
+            out
+        };
+        let mut a = [0u8; 64];
+        let mut b = [1u8; 64];
+        a[32..].copy_from_slice(&stream([0; 32]));
+        b[32..].copy_from_slice(&stream([1; 32]));
+        assert_ne!(Fr::from_seed(&a), Fr::from_seed(&b));
+    }
+
+    #[test]
+    fn from_bytes_mod_order_test() {
+        let mut modulus = Vec::new();
+        for limb in Fr::characteristic() {
+            modulus.extend_from_slice(&limb.to_le_bytes());
+        }
+        assert_eq!(Fr::from_le_bytes_mod_order(&modulus), Fr::zero());
+        modulus[0] += 5;
+        assert_eq!(Fr::from_le_bytes_mod_order(&modulus), Fr::from(5u64));
+
+        let bytes: Vec<u8> = (1..=80).collect();
+        let mut expected = Fr::zero();
+        for byte in bytes.iter().rev() {
+            expected = expected * Fr::from(256u64) + Fr::from(*byte as u64);
+        }
+        assert_eq!(Fr::from_le_bytes_mod_order(&bytes), expected);
+        let be: Vec<u8> = bytes.iter().rev().cloned().collect();
+        assert_eq!(Fr::from_be_bytes_mod_order(&be), expected);
+        assert_eq!(Fr::from_le_bytes_mod_order(&[]), Fr::zero());
+    }
+}
@@ -0,0 +1,39 @@
+//! Conversions between machine words and the elements of rings and fields
+//! with a modulus of at most 64 bits, such as the 31-bit and 64-bit primes
+//! of hash permutations and vectorized kernels, without going through big
+//! integers.
+
+use crate::ff::ring::Ring;
+
+/// A ring, usually a field, of integers modulo `MODULUS`, stored as their
+/// canonical representatives in `[0, MODULUS)`, so that the conversions to
+/// and from `u64` cost nothing.
+pub trait SmallField: Ring {
+    /// The modulus.
+    const MODULUS: u64;
+
+    /// Returns the representative of `self` in `[0, MODULUS)`.
+    fn as_canonical_u64(&self) -> u64;
+
+    /// Returns the element whose representative is `value`, without
+    /// checking it. `value` must be below `MODULUS`, or the arithmetic on
+    /// the element is incorrect.
+    fn from_canonical_u64_unchecked(value: u64) -> Self;
+
+    /// Returns the element whose representative is `value`, or None if it
+    /// is not below `MODULUS`.
+    #[inline]
+    fn from_canonical_u64(value: u64) -> Option<Self> {
+        if value < Self::MODULUS {
+            Some(Self::from_canonical_u64_unchecked(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `value` reduced modulo `MODULUS`.
+    #[inline]
+    fn from_noncanonical_u64(value: u64) -> Self {
+        Self::from_canonical_u64_unchecked(value % Self::MODULUS)
+    }
+}
@@ -0,0 +1,515 @@
+//! Reduction modulo primes that are a short signed sum of powers of two,
+//! such as `2^64 - 2^32 + 1` (Goldilocks), `2^255 - 19` and the modulus of
+//! the P-256 base field, `2^256 - 2^224 + 2^192 + 2^96 - 1`.
+//!
+//! For `p = 2^n + ∑ ±2^(e_i)`, the congruence `2^n = ∑ ∓2^(e_i)` modulo `p`
+//! folds the bits above `n` onto the lower ones with shifts and additions
+//! only, instead of a division or a Montgomery reduction. `SolinasFp` is the
+//! field of such a modulus with this reduction after every product.
+
+use core::{
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{ff::ring::Ring, uint::Uint};
+
+/// Describes a modulus `p = 2^TOP + ∑ ±2^e` for the terms `(negative, e)` of
+/// `TERMS`, with `e + 1 < TOP`, e.g. `2^255 - 19` as
+///
+/// ```ignore
+/// const TOP: u32 = 255;
+/// const TERMS: &'static [(bool, u32)] = &[(true, 4), (true, 1), (true, 0)];
+/// const MODULUS: U256 = U256::new(solinas_modulus(Self::TOP, Self::TERMS));
+/// ```
+///
+/// The reduction folds `TOP - e - 1` bits at a time for the largest `e`, so
+/// it is fastest when the terms are much smaller than `2^TOP`.
+pub trait SolinasParameters: 'static + Send + Sync {
+    /// An integer type holding the modulus.
+    type BigInt: Uint;
+
+    /// The exponent of the leading power of two.
+    const TOP: u32;
+
+    /// The signs, `true` for negative, and exponents of the other terms.
+    const TERMS: &'static [(bool, u32)];
+
+    /// The modulus described by the terms, given by `solinas_modulus`.
+    const MODULUS: Self::BigInt;
+}
+
+/// Returns the limbs of the modulus `2^top + ∑ ±2^e` for the terms
+/// `(negative, e)`, in a const context, for `SolinasParameters::MODULUS`.
+pub const fn solinas_modulus<const N: usize>(top: u32, terms: &[(bool, u32)]) -> [u64; N] {
+    // The modulus is below `2^(64 N)`, so it is computed modulo `2^(64 N)`.
+    let mut modulus = [0u64; N];
+    if (top as usize) < 64 * N {
+        modulus = add_power_of_two(modulus, top, false);
+    }
+    let mut i = 0;
+    while i < terms.len() {
+        modulus = add_power_of_two(modulus, terms[i].1, terms[i].0);
+        i += 1;
+    }
+    modulus
+}
+
+/// Returns `value + 2^e`, or `value - 2^e` if `negative`, modulo `2^(64 N)`.
+const fn add_power_of_two<const N: usize>(mut value: [u64; N], e: u32, negative: bool) -> [u64; N] {
+    let mut i = (e / 64) as usize;
+    let mut word = 1u64 << (e % 64);
+    while i < N && word != 0 {
+        let (res, overflow) = if negative {
+            value[i].overflowing_sub(word)
+        } else {
+            value[i].overflowing_add(word)
+        };
+        value[i] = res;
+        word = overflow as u64;
+        i += 1;
+    }
+    value
+}
+
+/// The capacity, in words, of the integers of `solinas_reduce`: products of
+/// integers of up to 16 limbs, and a word for the carries of the folding.
+const MAX_WORDS: usize = 33;
+
+/// Returns the little-endian limbs `wide`, of up to 32 limbs, reduced modulo
+/// the modulus of `P`.
+pub fn solinas_reduce<P: SolinasParameters>(wide: &[u64]) -> P::BigInt {
+    debug_assert!(P::TERMS.iter().all(|&(_, e)| e + 1 < P::TOP));
+    let len = wide.len().max(P::BigInt::LIMBS) + 1;
+    assert!(len <= MAX_WORDS, "too many limbs to reduce");
+    // The value is `pos - neg`, both below `2^TOP` at the end. Folding never
+    // makes them longer than `len` words.
+    let (mut pos, mut neg) = ([0u64; MAX_WORDS], [0u64; MAX_WORDS]);
+    pos[..wide.len()].copy_from_slice(wide);
+    let (pos, neg) = (&mut pos[..len], &mut neg[..len]);
+    loop {
+        let (mut pos_high, mut neg_high) = ([0u64; MAX_WORDS], [0u64; MAX_WORDS]);
+        let (pos_high, neg_high) = (&mut pos_high[..len], &mut neg_high[..len]);
+        split_off_high(pos, pos_high, P::TOP);
+        split_off_high(neg, neg_high, P::TOP);
+        if is_zero(pos_high) && is_zero(neg_high) {
+            break;
+        }
+        // `high 2^TOP = high ∑ ∓2^e`.
+        for &(negative, e) in P::TERMS {
+            let (to_pos, to_neg) = if negative {
+                (&*pos_high, &*neg_high)
+            } else {
+                (&*neg_high, &*pos_high)
+            };
+            add_shifted(pos, to_pos, e);
+            add_shifted(neg, to_neg, e);
+        }
+    }
+
+    // `p > 2^(TOP - 1)`, so `pos + 2p - neg` is positive and below `4p`.
+    let modulus = P::MODULUS;
+    let modulus = modulus.as_ref();
+    add_shifted(pos, modulus, 1);
+    sub(pos, neg);
+    while !less_than(pos, modulus) {
+        sub(pos, modulus);
+    }
+    to_uint(pos)
+}
+
+/// An element of the prime field modulo the modulus of `P`, stored as its
+/// canonical representative. Products are reduced with `solinas_reduce`
+/// rather than in Montgomery form, so no conversion is needed either.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: SolinasParameters"),
+    Clone(bound = "P: SolinasParameters"),
+    Default(bound = "P: SolinasParameters"),
+    PartialEq(bound = "P: SolinasParameters"),
+    Eq(bound = "P: SolinasParameters")
+)]
+pub struct SolinasFp<P: SolinasParameters>(P::BigInt, PhantomData<P>);
+
+impl<P: SolinasParameters> SolinasFp<P> {
+    /// Returns `value` reduced modulo the modulus.
+    pub fn new(value: P::BigInt) -> Self {
+        SolinasFp(solinas_reduce::<P>(value.as_ref()), PhantomData)
+    }
+
+    /// Returns the representative in `[0, p)`.
+    pub fn into_repr(&self) -> P::BigInt {
+        self.0
+    }
+}
+
+impl<P: SolinasParameters> From<u64> for SolinasFp<P> {
+    fn from(value: u64) -> Self {
+        Self::new(P::BigInt::from(value))
+    }
+}
+
+impl<P: SolinasParameters> Hash for SolinasFp<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state);
+    }
+}
+
+impl<P: SolinasParameters> Debug for SolinasFp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SolinasFp({})", self.0)
+    }
+}
+
+impl<P: SolinasParameters> Display for SolinasFp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<P: SolinasParameters> Ring for SolinasFp<P> {
+    #[inline]
+    fn zero_element() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn one_element() -> Self {
+        Self::from(1)
+    }
+
+    #[inline]
+    fn is_zero_element(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: SolinasParameters> Neg for SolinasFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.0.is_zero() {
+            return self;
+        }
+        let mut res = P::MODULUS;
+        res.sub_noborrow(&self.0);
+        SolinasFp(res, PhantomData)
+    }
+}
+
+impl<'a, P: SolinasParameters> AddAssign<&'a Self> for SolinasFp<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // The sum is below `2p`, so one subtraction reduces it, even when it
+        // carries out of the limbs.
+        let carry = self.0.add_nocarry(&other.0);
+        if carry || self.0 >= P::MODULUS {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+}
+
+impl<'a, P: SolinasParameters> SubAssign<&'a Self> for SolinasFp<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        *self += &-*other;
+    }
+}
+
+impl<'a, P: SolinasParameters> MulAssign<&'a Self> for SolinasFp<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let (a, b) = (self.0.as_ref(), other.0.as_ref());
+        let mut wide = [0u64; MAX_WORDS];
+        let wide = &mut wide[..2 * P::BigInt::LIMBS];
+        for (i, x) in a.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, y) in b.iter().enumerate() {
+                let t = wide[i + j] as u128 + *x as u128 * *y as u128 + carry;
+                wide[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            wide[i + b.len()] = carry as u64;
+        }
+        self.0 = solinas_reduce::<P>(wide);
+    }
+}
+
+macro_rules! impl_solinas_ops {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident;)*) => {
+        $(
+            impl<'a, P: SolinasParameters> $Op<&'a Self> for SolinasFp<P> {
+                type Output = Self;
+
+                #[inline]
+                fn $op(mut self, other: &Self) -> Self {
+                    self.$op_assign(other);
+                    self
+                }
+            }
+
+            impl<P: SolinasParameters> $Op<Self> for SolinasFp<P> {
+                type Output = Self;
+
+                #[inline]
+                fn $op(mut self, other: Self) -> Self {
+                    self.$op_assign(&other);
+                    self
+                }
+            }
+
+            impl<P: SolinasParameters> $OpAssign<Self> for SolinasFp<P> {
+                #[inline]
+                fn $op_assign(&mut self, other: Self) {
+                    self.$op_assign(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_solinas_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+);
+
+impl<P: SolinasParameters> Sum<Self> for SolinasFp<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero_element(), Add::add)
+    }
+}
+
+impl<'a, P: SolinasParameters> Sum<&'a Self> for SolinasFp<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero_element(), Add::add)
+    }
+}
+
+// Integers below are little-endian vectors of 64-bit words.
+
+fn is_zero(value: &[u64]) -> bool {
+    value.iter().all(|word| *word == 0)
+}
+
+/// Keeps the `bits` low bits of `value`, and writes the others shifted down
+/// to `high`, which is zero and as long as `value`.
+fn split_off_high(value: &mut [u64], high: &mut [u64], bits: u32) {
+    let (words, bits) = ((bits / 64) as usize, bits % 64);
+    if value.len() <= words {
+        return;
+    }
+    let n = value.len() - words;
+    high[..n].copy_from_slice(&value[words..]);
+    if bits > 0 {
+        for i in 0..n {
+            let next = if i + 1 < n { high[i + 1] } else { 0 };
+            high[i] = (high[i] >> bits) | (next << (64 - bits));
+        }
+        value[words] &= (1 << bits) - 1;
+        value[words + 1..].iter_mut().for_each(|word| *word = 0);
+    } else {
+        value[words..].iter_mut().for_each(|word| *word = 0);
+    }
+}
+
+/// Adds `addend * 2^shift` to `acc`, which must hold the sum.
+fn add_shifted(acc: &mut [u64], addend: &[u64], shift: u32) {
+    let (words, bits) = ((shift / 64) as usize, shift % 64);
+    let mut carry = 0u128;
+    for (j, limb) in acc[words..].iter_mut().enumerate() {
+        if j > addend.len() && carry == 0 {
+            break;
+        }
+        let mut word = addend.get(j).copied().unwrap_or(0) << bits;
+        if bits > 0 && j > 0 {
+            word |= addend.get(j - 1).copied().unwrap_or(0) >> (64 - bits);
+        }
+        let sum = *limb as u128 + word as u128 + carry;
+        *limb = sum as u64;
+        carry = sum >> 64;
+    }
+    debug_assert_eq!(carry, 0);
+}
+
+/// Subtracts `b` from `a`, which must not be smaller.
+fn sub(a: &mut [u64], b: &[u64]) {
+    let mut borrow = 0u128;
+    for (i, x) in a.iter_mut().enumerate() {
+        let diff = (*x as u128).wrapping_sub(b.get(i).copied().unwrap_or(0) as u128 + borrow);
+        *x = diff as u64;
+        borrow = (diff >> 127) & 1;
+    }
+    debug_assert_eq!(borrow, 0);
+}
+
+fn less_than(a: &[u64], b: &[u64]) -> bool {
+    let len = a.len().max(b.len());
+    for i in (0..len).rev() {
+        let (x, y) = (
+            a.get(i).copied().unwrap_or(0),
+            b.get(i).copied().unwrap_or(0),
+        );
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+fn to_uint<B: Uint>(value: &[u64]) -> B {
+    let mut res = B::default();
+    for (limb, word) in res.as_mut().iter_mut().zip(value) {
+        *limb = *word;
+    }
+    debug_assert!(value[B::LIMBS.min(value.len())..].iter().all(|w| *w == 0));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        uint::{arithmetic, Uint},
+        utils::BitIterator,
+    };
+
+    crate::uint_impl!(U64, 1);
+    crate::uint_impl!(U256, 4);
+
+    struct Goldilocks;
+
+    impl SolinasParameters for Goldilocks {
+        type BigInt = U64;
+        const TOP: u32 = 64;
+        const TERMS: &'static [(bool, u32)] = &[(true, 32), (false, 0)];
+        const MODULUS: U64 = U64::new(solinas_modulus(Self::TOP, Self::TERMS));
+    }
+
+    struct Curve25519;
+
+    impl SolinasParameters for Curve25519 {
+        type BigInt = U256;
+        const TOP: u32 = 255;
+        const TERMS: &'static [(bool, u32)] = &[(true, 4), (true, 1), (true, 0)];
+        const MODULUS: U256 = U256::new(solinas_modulus(Self::TOP, Self::TERMS));
+    }
+
+    struct P256;
+
+    impl SolinasParameters for P256 {
+        type BigInt = U256;
+        const TOP: u32 = 256;
+        const TERMS: &'static [(bool, u32)] = &[(true, 224), (false, 192), (false, 96), (true, 0)];
+        const MODULUS: U256 = U256::new(solinas_modulus(Self::TOP, Self::TERMS));
+    }
+
+    #[test]
+    fn solinas_reduce_test() {
+        const GOLDILOCKS: u64 = 0xffff_ffff_0000_0001;
+        assert_eq!(Goldilocks::MODULUS.0, [GOLDILOCKS]);
+        let mut seed = 0x1234_5678_9abc_def0u64;
+        for _ in 0..1000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let (a, b) = (seed, seed.rotate_left(29));
+            let product = a as u128 * b as u128;
+            let reduced = solinas_reduce::<Goldilocks>(&[product as u64, (product >> 64) as u64]);
+            assert_eq!(reduced.0[0] as u128, product % GOLDILOCKS as u128);
+        }
+        assert_eq!(solinas_reduce::<Goldilocks>(&[GOLDILOCKS]).0, [0]);
+        assert_eq!(
+            solinas_reduce::<Goldilocks>(&[u64::MAX, u64::MAX, u64::MAX]).0[0],
+            {
+                // 2^192 - 1 = (2^64 - 1)(2^128 + 2^64 + 1).
+                let m = GOLDILOCKS as u128;
+                let a = u64::MAX as u128 % m;
+                let b = ((1u128 << 64) % m * ((1u128 << 64) % m) % m + (1u128 << 64) % m + 1) % m;
+                (a * b % m) as u64
+            }
+        );
+
+        let p = Curve25519::MODULUS;
+        assert_eq!(p.0, [u64::MAX - 18, u64::MAX, u64::MAX, u64::MAX >> 1]);
+        assert_eq!(solinas_reduce::<Curve25519>(&p.0).0, [0; 4]);
+        // 2^255 = 19 and 2^510 = 361.
+        assert_eq!(
+            solinas_reduce::<Curve25519>(&[0, 0, 0, 1 << 63]).0,
+            [19, 0, 0, 0]
+        );
+        assert_eq!(
+            solinas_reduce::<Curve25519>(&[0, 0, 0, 0, 0, 0, 0, 1 << 62]).0,
+            [361, 0, 0, 0]
+        );
+        let mut p_minus_one = p.0;
+        p_minus_one[0] -= 1;
+        assert_eq!(solinas_reduce::<Curve25519>(&p_minus_one).0, p_minus_one);
+
+        let p = P256::MODULUS;
+        assert_eq!(
+            p.0,
+            [u64::MAX, 0x0000_0000_ffff_ffff, 0, 0xffff_ffff_0000_0001]
+        );
+        // (p - 1)^2 = 1.
+        let mut p_minus_one = p.0;
+        p_minus_one[0] -= 1;
+        let mut square = [0u64; 8];
+        for (i, x) in p_minus_one.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, y) in p_minus_one.iter().enumerate() {
+                let t = square[i + j] as u128 + *x as u128 * *y as u128 + carry;
+                square[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            square[i + 4] = carry as u64;
+        }
+        assert_eq!(solinas_reduce::<P256>(&square).0, [1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn solinas_field_test() {
+        type F = SolinasFp<Goldilocks>;
+        let minus_one = F::zero_element() - F::one_element();
+        assert_eq!(minus_one.into_repr().0, [0xffff_ffff_0000_0000]);
+        assert_eq!(minus_one * minus_one, F::one_element());
+        assert_eq!(minus_one + F::from(2), F::one_element());
+        // Sums of elements above `2^63` carry out of the limb.
+        assert_eq!(
+            (minus_one + minus_one).into_repr().0,
+            [0xffff_ffff_0000_0000 - 1]
+        );
+        assert_eq!(F::from(u64::MAX).into_repr().0, [0xffff_fffe]);
+        assert!((F::from(5) + -F::from(5)).is_zero_element());
+    }
+
+    #[cfg(feature = "curve25519")]
+    #[test]
+    fn solinas_field_matches_montgomery_test() {
+        use crate::{
+            ff::{Field, PrimeField},
+            pairing::curves::curve25519::Fq,
+        };
+        use algebra_core::test_rng;
+        use rand::Rng;
+
+        type F = SolinasFp<Curve25519>;
+        let to_solinas = |a: Fq| F::new(U256(a.into_repr().0));
+        let rng = &mut test_rng();
+        let mut values = vec![Fq::zero(), Fq::one(), -Fq::one()];
+        values.extend((0..50).map(|_| rng.gen::<Fq>()));
+        for a in &values {
+            for b in &values {
+                let (x, y) = (to_solinas(*a), to_solinas(*b));
+                assert_eq!(x * y, to_solinas(*a * b));
+                assert_eq!(x + y, to_solinas(*a + b));
+                assert_eq!(x - y, to_solinas(*a - b));
+            }
+            assert_eq!(-to_solinas(*a), to_solinas(-*a));
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    ff::{FftField, FftParameters, Field, FpParameters, PrimeField, SquareRootField},
+    Vec,
+};
+
 /// Calculates the k-adicity of n, i.e., the number of trailing 0s in a base-k
 /// representation.
 pub fn k_adicity(k: usize, mut n: usize) -> u32 {
@@ -12,3 +17,350 @@ pub fn k_adicity(k: usize, mut n: usize) -> u32 {
     }
     r
 }
+
+/// Returns the Montgomery product `a b R^(-1) mod modulus` of the limbs `a`
+/// and `b`, below the modulus, for `R = 2^(64 N)` and `inv = -modulus^(-1)
+/// mod 2^64`, in a const context, e.g. to derive Montgomery constants from
+/// others.
+pub const fn const_mont_mul<const N: usize>(
+    a: [u64; N],
+    b: [u64; N],
+    modulus: [u64; N],
+    inv: u64,
+) -> [u64; N] {
+    // `t + (t_n + t_n1 2^64) 2^(64 N)`, below `2 modulus R` between rounds.
+    let mut t = [0u64; N];
+    let mut t_n = 0u64;
+    let mut i = 0;
+    while i < N {
+        // `t += a b_i`.
+        let mut carry = 0u128;
+        let mut j = 0;
+        while j < N {
+            let s = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry;
+            t[j] = s as u64;
+            carry = s >> 64;
+            j += 1;
+        }
+        let s = t_n as u128 + carry;
+        t_n = s as u64;
+        let t_n1 = (s >> 64) as u64;
+
+        // `t = (t + k modulus) / 2^64`, for the `k` that makes it exact.
+        let k = t[0].wrapping_mul(inv);
+        let mut carry = (t[0] as u128 + k as u128 * modulus[0] as u128) >> 64;
+        let mut j = 1;
+        while j < N {
+            let s = t[j] as u128 + k as u128 * modulus[j] as u128 + carry;
+            t[j - 1] = s as u64;
+            carry = s >> 64;
+            j += 1;
+        }
+        let s = t_n as u128 + carry;
+        t[N - 1] = s as u64;
+        t_n = t_n1 + (s >> 64) as u64;
+        i += 1;
+    }
+
+    // `t < 2 modulus`, so one subtraction reduces it.
+    let mut geq = t_n != 0;
+    if !geq {
+        geq = true;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            if t[i] != modulus[i] {
+                geq = t[i] > modulus[i];
+                break;
+            }
+        }
+    }
+    if geq {
+        let mut borrow = 0u128;
+        let mut i = 0;
+        while i < N {
+            let d = (t[i] as u128).wrapping_sub(modulus[i] as u128 + borrow);
+            t[i] = d as u64;
+            borrow = d >> 127;
+            i += 1;
+        }
+    }
+    t
+}
+
+/// Returns `(i, j)` such that `n = 2^i * q^j`, for `q` the small subgroup
+/// base of `F`, if `F` has a subgroup of order `n`. `j` is zero for fields
+/// without a small subgroup.
+pub fn domain_size_factors<F: FftField>(n: usize) -> Option<(u32, u32)> {
+    if n == 0 {
+        return None;
+    }
+    let two_adicity = k_adicity(2, n);
+    let mut rest = n >> two_adicity;
+    let mut q_adicity = 0;
+    if let Some(q) = F::FftParams::SMALL_SUBGROUP_BASE {
+        q_adicity = k_adicity(q as usize, rest);
+        rest /= (q as usize).pow(q_adicity);
+    }
+    let max_q_adicity = F::FftParams::SMALL_SUBGROUP_BASE_ADICITY.unwrap_or(0);
+    if rest == 1 && two_adicity <= F::FftParams::TWO_ADICITY && q_adicity <= max_q_adicity {
+        Some((two_adicity, q_adicity))
+    } else {
+        None
+    }
+}
+
+/// Returns the order of the largest subgroup of `F` usable by an FFT, that
+/// is `2^TWO_ADICITY * q^SMALL_SUBGROUP_BASE_ADICITY`, or the largest such
+/// divisor that fits in a `usize`.
+pub fn max_fft_size<F: FftField>() -> usize {
+    let two_adicity = F::FftParams::TWO_ADICITY.min(usize::BITS - 1);
+    let mut size = 1usize << two_adicity;
+    if let (Some(q), Some(q_adicity)) = (
+        F::FftParams::SMALL_SUBGROUP_BASE,
+        F::FftParams::SMALL_SUBGROUP_BASE_ADICITY,
+    ) {
+        for _ in 0..q_adicity {
+            match size.checked_mul(q as usize) {
+                Some(s) => size = s,
+                None => break,
+            }
+        }
+    }
+    size
+}
+
+/// Returns the smallest order of a subgroup of `F` usable by an FFT that is
+/// at least `num_coeffs`, using the small subgroup if `F` defines one, or
+/// None if every such subgroup is too small.
+///
+/// Unlike `GeneralEvaluationDomain::compute_size_of_domain`, which prefers
+/// radix-2 sizes, this may return a mixed-radix size when it is smaller.
+pub fn best_domain_size<F: FftField>(num_coeffs: usize) -> Option<usize> {
+    let num_coeffs = num_coeffs.max(1);
+    let max_q_adicity = F::FftParams::SMALL_SUBGROUP_BASE_ADICITY.unwrap_or(0);
+    let q = F::FftParams::SMALL_SUBGROUP_BASE.unwrap_or(1) as usize;
+
+    let mut best: Option<usize> = None;
+    let mut q_part = 1usize;
+    for _ in 0..=max_q_adicity {
+        // The smallest `q_part * 2^i` that is at least `num_coeffs`.
+        let mut size = q_part;
+        let mut two_adicity = 0;
+        while size < num_coeffs {
+            size = match size.checked_mul(2) {
+                Some(s) => s,
+                None => break,
+            };
+            two_adicity += 1;
+        }
+        if size >= num_coeffs && two_adicity <= F::FftParams::TWO_ADICITY {
+            best = Some(best.map_or(size, |b| b.min(size)));
+        }
+        q_part = match q_part.checked_mul(q) {
+            Some(p) if q > 1 => p,
+            _ => break,
+        };
+    }
+    best
+}
+
+/// Returns `k * fe` for a small integer `k`, with additions and doublings
+/// only, e.g. to multiply by a small nonresidue of a tower field.
+#[inline(always)]
+pub fn mul_by_small_int<F: Field>(fe: &F, k: i64) -> F {
+    let mut res = match k.unsigned_abs() {
+        0 => F::zero(),
+        1 => *fe,
+        2 => fe.double(),
+        3 => fe.double() + fe,
+        n => {
+            let mut res = F::zero();
+            for i in (0..64 - n.leading_zeros()).rev() {
+                res = res.double();
+                if (n >> i) & 1 == 1 {
+                    res += fe;
+                }
+            }
+            res
+        }
+    };
+    if k < 0 {
+        res = -res;
+    }
+    res
+}
+
+/// The largest integer tried by `find_generator` and `find_nonresidue`.
+pub const MAX_CANDIDATE: u64 = 1 << 16;
+
+/// Returns the smallest integer `k >= 2` that is a quadratic nonresidue in
+/// `F`, as needed for quadratic extensions and square roots, or None if
+/// there is none up to `MAX_CANDIDATE`.
+pub fn find_nonresidue<F: SquareRootField + From<u64>>() -> Option<u64> {
+    (2..=MAX_CANDIDATE).find(|&k| F::from(k).legendre().is_qnr())
+}
+
+/// Returns the smallest integer `g >= 2` that generates the multiplicative
+/// group of `F`, or None if there is none up to `MAX_CANDIDATE`, e.g. to
+/// derive the `GENERATOR` of new parameters.
+///
+/// `prime_factors` are the distinct prime factors of `p - 1` that fit in 64
+/// bits; `p - 1` divided by their powers must be one or a prime, which is
+/// not checked. Returns None if one of them does not divide `p - 1`.
+pub fn find_generator<F: PrimeField>(prime_factors: &[u64]) -> Option<u64> {
+    // Split `p - 1` into the powers of the given primes and the cofactor.
+    let mut cofactor = F::Params::MODULUS;
+    cofactor.sub_noborrow(&F::BigInt::from(1));
+    let mut multiplicities = Vec::with_capacity(prime_factors.len());
+    for &q in prime_factors {
+        let mut e = 0;
+        loop {
+            let mut quotient = cofactor;
+            if q < 2 || div_rem(quotient.as_mut(), q) != 0 {
+                break;
+            }
+            cofactor = quotient;
+            e += 1;
+        }
+        if e == 0 {
+            return None;
+        }
+        multiplicities.push((q, e));
+    }
+    let has_cofactor = cofactor != F::BigInt::from(1);
+
+    (2..=MAX_CANDIDATE).find(|&k| {
+        let g = F::from(k);
+        // `g` generates iff `g^((p - 1) / q)` is not one for every prime `q`.
+        let small_orders = prime_factors
+            .iter()
+            .all(|&q| !g.pow_by_modulus_minus_one_div_n(q).unwrap().is_one());
+        let large_order = || {
+            // `(p - 1) / cofactor` is the product of the `q^e`.
+            let mut h = g;
+            for &(q, e) in &multiplicities {
+                for _ in 0..e {
+                    h = h.pow([q]);
+                }
+            }
+            !h.is_one()
+        };
+        small_orders && (!has_cofactor || large_order())
+    })
+}
+
+/// Divides the little-endian number `limbs` by `d` in place, and returns
+/// the remainder.
+fn div_rem(limbs: &mut [u64], d: u64) -> u64 {
+    let mut rem = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let cur = (rem << 64) | *limb as u128;
+        *limb = (cur / d as u128) as u64;
+        rem = cur % d as u128;
+    }
+    rem as u64
+}
+
+/// Sorts `elems` by their canonical integer values, converting each element
+/// out of its internal representation only once.
+pub fn sort_canonical<F: PrimeField>(elems: &mut [F]) {
+    elems.sort_by_cached_key(|elem| elem.into_repr());
+}
+
+/// Checks if `elems` is sorted by canonical integer values.
+pub fn is_sorted_canonical<F: PrimeField>(elems: &[F]) -> bool {
+    let mut reprs = elems.iter().map(|elem| elem.into_repr());
+    let mut prev = match reprs.next() {
+        Some(repr) => repr,
+        None => return true,
+    };
+    reprs.all(|repr| {
+        let sorted = prev <= repr;
+        prev = repr;
+        sorted
+    })
+}
+
+/// Searches `elems`, sorted by canonical integer values, for `elem`, like
+/// `slice::binary_search`.
+pub fn binary_search_canonical<F: PrimeField>(elems: &[F], elem: &F) -> Result<usize, usize> {
+    let repr = elem.into_repr();
+    elems.binary_search_by(|probe| probe.into_repr().cmp(&repr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::fr::Fr;
+
+    #[test]
+    fn domain_size_test() {
+        assert_eq!(k_adicity(3, 3 * 3 * 4), 2);
+        assert_eq!(k_adicity(2, 1), 0);
+
+        // The scalar field of BLS12-381 has two-adicity 32 and no small
+        // subgroup.
+        assert_eq!(max_fft_size::<Fr>(), 1 << 32);
+        assert_eq!(best_domain_size::<Fr>(0), Some(1));
+        assert_eq!(best_domain_size::<Fr>(1000), Some(1024));
+        assert_eq!(best_domain_size::<Fr>(1 << 32), Some(1 << 32));
+        assert_eq!(best_domain_size::<Fr>((1 << 32) + 1), None);
+
+        assert_eq!(domain_size_factors::<Fr>(1024), Some((10, 0)));
+        assert_eq!(domain_size_factors::<Fr>(1 << 33), None);
+        assert_eq!(domain_size_factors::<Fr>(12), None);
+        assert_eq!(domain_size_factors::<Fr>(0), None);
+    }
+
+    #[test]
+    fn mul_by_small_int_test() {
+        let fe = Fr::from(7u64);
+        for k in [0i64, 1, 2, 3, 5, 11, 1 << 40] {
+            assert_eq!(mul_by_small_int(&fe, k), Fr::from(k as u64) * fe);
+            assert_eq!(mul_by_small_int(&fe, -k), -(Fr::from(k as u64) * fe));
+        }
+        let min = mul_by_small_int(&Fr::from(1u64), i64::MIN);
+        assert_eq!(min, -Fr::from(1u64 << 63));
+    }
+
+    #[test]
+    fn find_parameters_test() {
+        // The factorization of `r - 1` for the scalar field of BLS12-381.
+        let factors = [
+            2, 3, 11, 19, 10177, 125527, 859267, 906349, 2508409, 2529403, 52437899, 254760293,
+        ];
+        assert_eq!(find_generator::<Fr>(&factors), Some(7));
+        // Leaving out a prime factor of multiplicity one makes it the
+        // cofactor.
+        let mut partial = factors.to_vec();
+        partial.retain(|&q| q != 52437899);
+        assert_eq!(find_generator::<Fr>(&partial), Some(7));
+        assert_eq!(find_generator::<Fr>(&[2, 5]), None);
+        assert_eq!(find_nonresidue::<Fr>(), Some(5));
+    }
+
+    #[test]
+    fn canonical_order_test() {
+        let small = Fr::from(3u64);
+        let large = -Fr::from(1u64);
+        assert_eq!(small.cmp_canonical(&large), core::cmp::Ordering::Less);
+        assert_eq!(large.cmp_canonical(&large), core::cmp::Ordering::Equal);
+
+        let mut elems: Vec<Fr> = (0..50u64)
+            .map(|i| Fr::from_seed(&i.to_le_bytes()))
+            .collect();
+        elems.push(small);
+        assert!(!is_sorted_canonical(&elems));
+        sort_canonical(&mut elems);
+        assert!(is_sorted_canonical(&elems));
+        assert!(elems
+            .windows(2)
+            .all(|w| w[0].into_repr() <= w[1].into_repr()));
+        assert_eq!(binary_search_canonical(&elems, &elems[17]), Ok(17));
+        let index = binary_search_canonical(&elems, &small).unwrap();
+        assert_eq!(elems[index], small);
+        assert!(binary_search_canonical(&elems, &large).is_err());
+        assert!(is_sorted_canonical::<Fr>(&[]));
+    }
+}
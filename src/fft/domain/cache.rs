@@ -0,0 +1,152 @@
+//! A thread-safe cache of radix-2 domains and their roots of unity, for
+//! provers that build the same domains over and over across subprotocols.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use crate::{FftField, Vec};
+
+use super::{utils::powers, EvaluationDomain, Radix2EvaluationDomain};
+
+/// A radix-2 domain together with the powers of its generator and of its
+/// inverse, i.e. the twiddle factors of its FFTs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedDomain<F: FftField> {
+    domain: Radix2EvaluationDomain<F>,
+    roots: Vec<F>,
+    inverse_roots: Vec<F>,
+}
+
+impl<F: FftField> CachedDomain<F> {
+    fn new(domain: Radix2EvaluationDomain<F>) -> Self {
+        let half_size = (domain.size() / 2).max(1);
+        CachedDomain {
+            roots: powers(domain.group_gen, half_size),
+            inverse_roots: powers(domain.group_gen_inv, half_size),
+            domain,
+        }
+    }
+
+    /// Returns the domain.
+    pub fn domain(&self) -> &Radix2EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Returns `ω^i` for `i` less than half the size of the domain, for `ω`
+    /// its generator.
+    pub fn roots(&self) -> &[F] {
+        &self.roots
+    }
+
+    /// Returns `ω^(-i)` for `i` less than half the size of the domain.
+    pub fn inverse_roots(&self) -> &[F] {
+        &self.inverse_roots
+    }
+}
+
+/// Memoizes `CachedDomain`s by size. It can be shared between threads, and
+/// each domain is computed at most once unless two threads first ask for it
+/// at the same time.
+#[derive(Debug)]
+pub struct DomainCache<F: FftField> {
+    domains: RwLock<DomainMap<F>>,
+}
+
+type DomainMap<F> = BTreeMap<usize, Arc<CachedDomain<F>>>;
+
+impl<F: FftField> Default for DomainCache<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: FftField> DomainCache<F> {
+    pub fn new() -> Self {
+        DomainCache {
+            domains: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the domain that `Radix2EvaluationDomain::new(num_coeffs)`
+    /// builds, computing it on the first request for its size. Returns None
+    /// if there is no such domain.
+    pub fn get(&self, num_coeffs: usize) -> Option<Arc<CachedDomain<F>>> {
+        let size = Radix2EvaluationDomain::<F>::compute_size_of_domain(num_coeffs)?;
+        if let Some(cached) = self.read().get(&size) {
+            return Some(cached.clone());
+        }
+
+        // Build the domain without holding the lock.
+        let cached = Arc::new(CachedDomain::new(Radix2EvaluationDomain::new(size)?));
+        Some(self.write().entry(size).or_insert(cached).clone())
+    }
+
+    /// Returns the number of cached domains.
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Returns true iff no domain is cached.
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Drops all cached domains. Domains still held by callers stay valid.
+    pub fn clear(&self) {
+        self.write().clear()
+    }
+
+    // A panic while holding the lock cannot leave the map inconsistent, so
+    // poisoning is ignored.
+    fn read(&self) -> RwLockReadGuard<'_, DomainMap<F>> {
+        self.domains.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, DomainMap<F>> {
+        self.domains.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Field;
+    use algebra::bls12_381::fr::Fr;
+    use std::thread;
+
+    #[test]
+    fn domain_cache_test() {
+        let cache = DomainCache::<Fr>::new();
+        assert!(cache.is_empty());
+
+        let a = cache.get(1000).unwrap();
+        assert_eq!(*a.domain(), Radix2EvaluationDomain::new(1000).unwrap());
+        assert_eq!(a.roots().len(), 512);
+        assert_eq!(a.roots()[3], a.domain().group_gen.pow([3u64]));
+        assert_eq!(a.roots()[5] * a.inverse_roots()[5], Fr::one());
+
+        // Sizes rounding to the same domain share an entry.
+        assert!(Arc::ptr_eq(&a, &cache.get(1024).unwrap()));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(1 << 33).is_none());
+
+        let cache = Arc::new(cache);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let cache = cache.clone();
+                thread::spawn(move || cache.get(1 << (i % 2)).unwrap())
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(1).unwrap().roots(), &[Fr::one()][..]);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(a.domain().size(), 1024);
+    }
+}
@@ -0,0 +1,154 @@
+//! The round trip through a coset of a domain that provers make to compute
+//! quotient polynomials: evaluate several polynomials on the coset, combine
+//! their evaluations pointwise, e.g. dividing by the vanishing polynomial
+//! of a smaller domain, and interpolate the result.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{ff::batch_inversion, fft::linear_chunk_size, DensePolynomial, FftField, Vec};
+
+use super::{utils::powers, EvaluationDomain, GeneralEvaluationDomain};
+
+/// Evaluates polynomials over the coset `g H` of a domain `H` that
+/// `coset_fft` uses, with the powers of `g` and of its inverse computed once
+/// for all of them:
+///
+/// ```ignore
+/// let evaluator = CosetEvaluator::new(GeneralEvaluationDomain::new(4 * n)?);
+/// let z_inv = evaluator.vanishing_inverses(&domain);
+/// let t = evaluator.evaluate(&[&a, &b, &c], |i, v| (v[0] * v[1] - v[2]) * z_inv[i]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CosetEvaluator<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
+    domain: D,
+    /// `g^i` for `i < n`.
+    offsets: Vec<F>,
+    /// `g^(-i)` for `i < n`.
+    inverse_offsets: Vec<F>,
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> CosetEvaluator<F, D> {
+    pub fn new(domain: D) -> Self {
+        let g = F::multiplicative_generator();
+        CosetEvaluator {
+            domain,
+            offsets: powers(g, domain.size()),
+            inverse_offsets: powers(g.inverse().unwrap(), domain.size()),
+        }
+    }
+
+    /// Returns the domain whose coset this evaluates on.
+    pub fn domain(&self) -> D {
+        self.domain
+    }
+
+    /// Returns the evaluations of `poly` over the coset, as `coset_fft`.
+    ///
+    /// Panics if `poly` has more coefficients than the domain has elements.
+    pub fn coset_fft(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        let n = self.domain.size();
+        assert!(
+            poly.coeffs.len() <= n,
+            "the polynomial has too many coefficients for the domain"
+        );
+        let mut evals = poly.coeffs.clone();
+        evals.resize(n, F::zero());
+        cfg_iter_mut!(evals)
+            .zip(&self.offsets)
+            .for_each(|(c, g_i)| *c *= g_i);
+        self.domain.fft_in_place(&mut evals);
+        evals
+    }
+
+    /// Returns the polynomial with evaluations `evals` over the coset, as
+    /// `coset_ifft`.
+    ///
+    /// Panics if `evals` does not have the size of the domain.
+    pub fn coset_ifft(&self, mut evals: Vec<F>) -> DensePolynomial<F> {
+        assert_eq!(
+            evals.len(),
+            self.domain.size(),
+            "evaluations must span the domain"
+        );
+        self.domain.ifft_in_place(&mut evals);
+        cfg_iter_mut!(evals)
+            .zip(&self.inverse_offsets)
+            .for_each(|(c, g_inv_i)| *c *= g_inv_i);
+        DensePolynomial::from_coefficients_vec(evals)
+    }
+
+    /// Returns the evaluations of the vanishing polynomial of `subdomain`
+    /// over the coset, inverted, for dividing by it pointwise.
+    pub fn vanishing_inverses<S: EvaluationDomain<F>>(&self, subdomain: &S) -> Vec<F> {
+        let mut evals = subdomain.evaluate_vanishing_polynomial_on_coset(&self.domain);
+        batch_inversion(&mut evals);
+        evals
+    }
+
+    /// Evaluates `polys` over the coset, calls `op(i, values)` with the
+    /// values of all of them at the `i`-th element of the coset, in the
+    /// order of `polys`, and returns the polynomial with the results as its
+    /// evaluations. The calls run in parallel if the `parallel` feature is
+    /// enabled.
+    ///
+    /// Panics if a polynomial has more coefficients than the domain has
+    /// elements.
+    pub fn evaluate<Op>(&self, polys: &[&DensePolynomial<F>], op: Op) -> DensePolynomial<F>
+    where
+        Op: Fn(usize, &[F]) -> F + Send + Sync,
+    {
+        let columns: Vec<Vec<F>> = polys.iter().map(|p| self.coset_fft(p)).collect();
+        let chunk_size = linear_chunk_size();
+        let mut evals = vec![F::zero(); self.domain.size()];
+        cfg_chunks_mut!(evals, chunk_size)
+            .enumerate()
+            .for_each(|(chunk, evals)| {
+                let mut values = vec![F::zero(); columns.len()];
+                for (j, eval) in evals.iter_mut().enumerate() {
+                    let i = chunk * chunk_size + j;
+                    for (value, column) in values.iter_mut().zip(&columns) {
+                        *value = column[i];
+                    }
+                    *eval = op(i, &values);
+                }
+            });
+        self.coset_ifft(evals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CosetEvaluator;
+    use crate::{
+        fft::{EvaluationDomain, GeneralEvaluationDomain},
+        DensePolynomial, Field,
+    };
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::test_rng;
+
+    #[test]
+    fn coset_evaluator_test() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        let evaluator = CosetEvaluator::new(GeneralEvaluationDomain::<Fr>::new(64).unwrap());
+
+        let a = DensePolynomial::<Fr>::rand(15, rng);
+        let b = DensePolynomial::<Fr>::rand(15, rng);
+        assert_eq!(
+            evaluator.coset_fft(&a),
+            evaluator.domain().coset_fft(&a.coeffs)
+        );
+        assert_eq!(evaluator.coset_ifft(evaluator.coset_fft(&a)), a);
+
+        // `a b - c` vanishes on the domain when `c` is the remainder of
+        // `a b` by its vanishing polynomial.
+        let (q, c) = (&a * &b).divide_by_vanishing_poly(domain).unwrap();
+        let z_inv = evaluator.vanishing_inverses(&domain);
+        let quotient = evaluator.evaluate(&[&a, &b, &c], |i, values| {
+            (values[0] * values[1] - values[2]) * z_inv[i]
+        });
+        assert_eq!(quotient, q);
+        assert!(z_inv.iter().all(|z| !z.is_zero()));
+    }
+}
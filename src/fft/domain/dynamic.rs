@@ -0,0 +1,264 @@
+//! A type-erased evaluation domain, for protocols that choose their domains
+//! at runtime and keep domains of different types side by side.
+
+use core::{any::Any, fmt};
+
+use crate::{Box, FftField, SparsePolynomial, Vec};
+
+use super::EvaluationDomain;
+
+/// Any `EvaluationDomain` over `F`, behind a pointer. `EvaluationDomain` has
+/// generic methods and cannot be made into a trait object, so this wrapper
+/// exposes the operations on vectors of field elements instead:
+///
+/// ```ignore
+/// let domains: Vec<DynDomain<F>> = vec![
+///     DynDomain::new(Radix2EvaluationDomain::<F>::new(n)?),
+///     DynDomain::new(MixedRadixEvaluationDomain::<F>::new(m)?),
+/// ];
+/// ```
+pub struct DynDomain<F: FftField> {
+    inner: Box<dyn ErasedDomain<F>>,
+}
+
+impl<F: FftField> DynDomain<F> {
+    pub fn new<D: EvaluationDomain<F> + Send + Sync + 'static>(domain: D) -> Self {
+        DynDomain {
+            inner: Box::new(domain),
+        }
+    }
+
+    /// Returns the wrapped domain, if it is a `D`.
+    pub fn downcast_ref<D: EvaluationDomain<F> + 'static>(&self) -> Option<&D> {
+        self.inner.as_any().downcast_ref()
+    }
+
+    /// Return the size of the domain.
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// Return the size of the domain as a field element.
+    pub fn size_as_field_element(&self) -> F {
+        self.inner.size_as_field_element()
+    }
+
+    /// Compute a FFT.
+    pub fn fft(&self, coeffs: &[F]) -> Vec<F> {
+        let mut coeffs = coeffs.to_vec();
+        self.inner.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute a FFT, modifying the vector in place.
+    pub fn fft_in_place(&self, coeffs: &mut Vec<F>) {
+        self.inner.fft_in_place(coeffs)
+    }
+
+    /// Compute a IFFT.
+    pub fn ifft(&self, evals: &[F]) -> Vec<F> {
+        let mut evals = evals.to_vec();
+        self.inner.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute a IFFT, modifying the vector in place.
+    pub fn ifft_in_place(&self, evals: &mut Vec<F>) {
+        self.inner.ifft_in_place(evals)
+    }
+
+    /// Compute a FFT over a coset of the domain.
+    pub fn coset_fft(&self, coeffs: &[F]) -> Vec<F> {
+        let mut coeffs = coeffs.to_vec();
+        self.inner.coset_fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute a FFT over a coset of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place(&self, coeffs: &mut Vec<F>) {
+        self.inner.coset_fft_in_place(coeffs)
+    }
+
+    /// Compute a IFFT over a coset of the domain.
+    pub fn coset_ifft(&self, evals: &[F]) -> Vec<F> {
+        let mut evals = evals.to_vec();
+        self.inner.coset_ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute a IFFT over a coset of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_ifft_in_place(&self, evals: &mut Vec<F>) {
+        self.inner.coset_ifft_in_place(evals)
+    }
+
+    /// Evaluate all the lagrange polynomials defined by the domain at the
+    /// point `tau`.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        self.inner.evaluate_all_lagrange_coefficients(tau)
+    }
+
+    /// Evaluates the `i`-th Lagrange polynomial of the domain at `tau`.
+    ///
+    /// Panics if `i` is not smaller than the size of the domain.
+    pub fn evaluate_lagrange_at(&self, i: usize, tau: F) -> F {
+        self.inner.evaluate_lagrange_at(i, tau)
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        self.inner.vanishing_polynomial()
+    }
+
+    /// This evaluates the vanishing polynomial for the domain at tau.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        self.inner.evaluate_vanishing_polynomial(tau)
+    }
+
+    /// Returns the `i`-th element `ω^i` of the domain.
+    pub fn element(&self, i: usize) -> F {
+        self.inner.element(i)
+    }
+
+    /// Returns the elements of the domain.
+    pub fn elements(&self) -> Vec<F> {
+        self.inner.elements()
+    }
+}
+
+impl<F: FftField> Clone for DynDomain<F> {
+    fn clone(&self) -> Self {
+        DynDomain {
+            inner: self.inner.box_clone(),
+        }
+    }
+}
+
+impl<F: FftField> fmt::Debug for DynDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+/// The object-safe part of `EvaluationDomain`. It is private so that its
+/// methods never shadow those of `EvaluationDomain` on concrete domains.
+trait ErasedDomain<F: FftField>: fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn box_clone(&self) -> Box<dyn ErasedDomain<F>>;
+    fn size(&self) -> usize;
+    fn size_as_field_element(&self) -> F;
+    fn fft_in_place(&self, coeffs: &mut Vec<F>);
+    fn ifft_in_place(&self, evals: &mut Vec<F>);
+    fn coset_fft_in_place(&self, coeffs: &mut Vec<F>);
+    fn coset_ifft_in_place(&self, evals: &mut Vec<F>);
+    fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F>;
+    fn evaluate_lagrange_at(&self, i: usize, tau: F) -> F;
+    fn vanishing_polynomial(&self) -> SparsePolynomial<F>;
+    fn evaluate_vanishing_polynomial(&self, tau: F) -> F;
+    fn element(&self, i: usize) -> F;
+    fn elements(&self) -> Vec<F>;
+}
+
+impl<F: FftField, D: EvaluationDomain<F> + Send + Sync + 'static> ErasedDomain<F> for D {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn box_clone(&self) -> Box<dyn ErasedDomain<F>> {
+        Box::new(*self)
+    }
+
+    fn size(&self) -> usize {
+        EvaluationDomain::size(self)
+    }
+
+    fn size_as_field_element(&self) -> F {
+        EvaluationDomain::size_as_field_element(self)
+    }
+
+    fn fft_in_place(&self, coeffs: &mut Vec<F>) {
+        EvaluationDomain::fft_in_place(self, coeffs)
+    }
+
+    fn ifft_in_place(&self, evals: &mut Vec<F>) {
+        EvaluationDomain::ifft_in_place(self, evals)
+    }
+
+    fn coset_fft_in_place(&self, coeffs: &mut Vec<F>) {
+        EvaluationDomain::coset_fft_in_place(self, coeffs)
+    }
+
+    fn coset_ifft_in_place(&self, evals: &mut Vec<F>) {
+        EvaluationDomain::coset_ifft_in_place(self, evals)
+    }
+
+    fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        EvaluationDomain::evaluate_all_lagrange_coefficients(self, tau)
+    }
+
+    fn evaluate_lagrange_at(&self, i: usize, tau: F) -> F {
+        EvaluationDomain::evaluate_lagrange_at(self, i, tau)
+    }
+
+    fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        EvaluationDomain::vanishing_polynomial(self)
+    }
+
+    fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        EvaluationDomain::evaluate_vanishing_polynomial(self, tau)
+    }
+
+    fn element(&self, i: usize) -> F {
+        EvaluationDomain::element(self, i)
+    }
+
+    fn elements(&self) -> Vec<F> {
+        EvaluationDomain::elements(self).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynDomain;
+    use crate::{
+        fft::{EvaluationDomain, GeneralEvaluationDomain, Radix2EvaluationDomain},
+        Field, Vec,
+    };
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    #[test]
+    fn dyn_domain_test() {
+        let rng = &mut test_rng();
+        let radix2 = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+        let general = GeneralEvaluationDomain::<Fr>::new(30).unwrap();
+        let domains = vec![
+            DynDomain::new(radix2),
+            DynDomain::new(general),
+            DynDomain::new(Radix2EvaluationDomain::<Fr>::new(4).unwrap()),
+        ];
+
+        for domain in domains.iter().cloned() {
+            let coeffs: Vec<Fr> = (0..domain.size()).map(|_| rng.gen()).collect();
+            let evals = domain.fft(&coeffs);
+            assert_eq!(domain.ifft(&evals), coeffs);
+            assert_eq!(domain.coset_ifft(&domain.coset_fft(&coeffs)), coeffs);
+            assert_eq!(domain.elements().len(), domain.size());
+            assert!(domain
+                .elements()
+                .iter()
+                .all(|x| domain.evaluate_vanishing_polynomial(*x).is_zero()));
+        }
+
+        let coeffs: Vec<Fr> = (0..16).map(|_| rng.gen()).collect();
+        assert_eq!(domains[0].fft(&coeffs), radix2.fft(&coeffs));
+        assert_eq!(domains[1].element(3), general.element(3));
+        assert_eq!(domains[0].downcast_ref(), Some(&radix2));
+        assert_eq!(
+            domains[1].downcast_ref::<Radix2EvaluationDomain<Fr>>(),
+            None
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Radix-2 FFTs over arrays whose size is known at compile time. They do
+//! not allocate: the twiddle factors are computed on the fly and the
+//! transform runs serially in place, so they suit small transforms, say of
+//! at most 4096 elements, on targets without a heap.
+//!
+//! `FixedRadix2Domain` fixes the size of a domain in its type instead, for
+//! protocols whose parameters never change, e.g. with always `2^12` rows.
+
+use crate::{FftField, Field, SunziError};
+
+use super::{radix2::serial_radix2_fft, DomainCoeff, EvaluationDomain, Radix2EvaluationDomain};
+
+struct FixedSize<const N: usize>;
+
+impl<const N: usize> FixedSize<N> {
+    const LOG_N: u32 = {
+        assert!(N.is_power_of_two(), "fixed FFTs need a power-of-two size");
+        N.trailing_zeros()
+    };
+}
+
+/// Checks at compile time that arrays of `N` elements span a domain of size
+/// `2^LOG_N`.
+struct SizeOfDomain<const LOG_N: usize, const N: usize>;
+
+impl<const LOG_N: usize, const N: usize> SizeOfDomain<LOG_N, N> {
+    const CHECK: () = assert!(N == 1 << LOG_N, "arrays must span the domain");
+}
+
+/// Replaces `coeffs` with the evaluations of the polynomial with these
+/// coefficients over the subgroup of order `N` of `F^*`, in the order of the
+/// powers of its generator, like `Radix2EvaluationDomain::fft`.
+///
+/// Returns `NoRootOfUnity` if `F` has no root of unity of order `N`. Does
+/// not compile if `N` is not a power of two.
+pub fn fft_fixed<T: DomainCoeff<F>, F: FftField, const N: usize>(
+    coeffs: &mut [T; N],
+) -> Result<(), SunziError> {
+    let log_n = FixedSize::<N>::LOG_N;
+    let omega = F::two_adic_root_of_unity_of_order(log_n).ok_or(SunziError::NoRootOfUnity)?;
+    record!(fft, N);
+    serial_radix2_fft(coeffs, omega, log_n);
+    Ok(())
+}
+
+/// The inverse of `fft_fixed`: replaces `evals` with the coefficients of the
+/// polynomial with these evaluations.
+pub fn ifft_fixed<T: DomainCoeff<F>, F: FftField, const N: usize>(
+    evals: &mut [T; N],
+) -> Result<(), SunziError> {
+    let log_n = FixedSize::<N>::LOG_N;
+    let omega = F::two_adic_root_of_unity_of_order(log_n).ok_or(SunziError::NoRootOfUnity)?;
+    record!(fft, N);
+    serial_radix2_fft(evals, omega.inverse().unwrap(), log_n);
+    let size_inv = F::from(N as u64).inverse().unwrap();
+    for val in evals.iter_mut() {
+        *val *= size_inv;
+    }
+    Ok(())
+}
+
+/// A radix-2 domain of size `2^LOG_N`, known at compile time. Its (I)FFTs
+/// run serially in place over arrays of that size, and do not compile for
+/// arrays of any other size.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct FixedRadix2Domain<F: FftField, const LOG_N: usize> {
+    domain: Radix2EvaluationDomain<F>,
+}
+
+impl<F: FftField, const LOG_N: usize> FixedRadix2Domain<F, LOG_N> {
+    /// The size of the domain.
+    pub const SIZE: usize = 1 << LOG_N;
+
+    /// Constructs the domain, or returns why `F` has no subgroup of order
+    /// `2^LOG_N`, like `EvaluationDomain::try_new`.
+    pub fn new() -> Result<Self, SunziError> {
+        Ok(FixedRadix2Domain {
+            domain: Radix2EvaluationDomain::try_new(Self::SIZE)?,
+        })
+    }
+
+    /// Returns the domain, with the other methods of `EvaluationDomain`.
+    pub fn domain(&self) -> &Radix2EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Replaces `coeffs` with the evaluations of the polynomial with these
+    /// coefficients over the domain, like `EvaluationDomain::fft_in_place`.
+    pub fn fft_in_place<T: DomainCoeff<F>, const N: usize>(&self, coeffs: &mut [T; N]) {
+        let () = SizeOfDomain::<LOG_N, N>::CHECK;
+        record!(fft, N);
+        serial_radix2_fft(coeffs, self.domain.group_gen, LOG_N as u32);
+    }
+
+    /// The inverse of `fft_in_place`.
+    pub fn ifft_in_place<T: DomainCoeff<F>, const N: usize>(&self, evals: &mut [T; N]) {
+        let () = SizeOfDomain::<LOG_N, N>::CHECK;
+        record!(fft, N);
+        serial_radix2_fft(evals, self.domain.group_gen_inv, LOG_N as u32);
+        for val in evals.iter_mut() {
+            *val *= self.domain.size_inv;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::{EvaluationDomain, Radix2EvaluationDomain};
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    fn check<const N: usize>() {
+        let rng = &mut test_rng();
+        let coeffs: [Fr; N] = core::array::from_fn(|_| rng.gen());
+        let domain = Radix2EvaluationDomain::<Fr>::new(N).unwrap();
+
+        let mut evals = coeffs;
+        fft_fixed(&mut evals).unwrap();
+        assert_eq!(&evals[..], &domain.fft(&coeffs)[..]);
+        ifft_fixed(&mut evals).unwrap();
+        assert_eq!(evals, coeffs);
+    }
+
+    #[test]
+    fn fft_fixed_test() {
+        check::<1>();
+        check::<2>();
+        check::<16>();
+        check::<1024>();
+    }
+
+    #[test]
+    fn fixed_domain_test() {
+        let rng = &mut test_rng();
+        let domain = FixedRadix2Domain::<Fr, 4>::new().unwrap();
+        assert_eq!(FixedRadix2Domain::<Fr, 4>::SIZE, 16);
+        assert_eq!(domain.domain(), &Radix2EvaluationDomain::new(16).unwrap());
+
+        let coeffs: [Fr; 16] = core::array::from_fn(|_| rng.gen());
+        let mut evals = coeffs;
+        domain.fft_in_place(&mut evals);
+        assert_eq!(&evals[..], &domain.domain().fft(&coeffs)[..]);
+        domain.ifft_in_place(&mut evals);
+        assert_eq!(evals, coeffs);
+
+        assert_eq!(
+            FixedRadix2Domain::<Fr, 40>::new(),
+            Err(SunziError::DomainTooLarge)
+        );
+    }
+}
@@ -17,6 +17,11 @@ pub use super::{
 /// Generally tries to build a radix-2 domain and falls back to a mixed-radix
 /// domain if the radix-2 multiplicative subgroup is too small.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub enum GeneralEvaluationDomain<F: FftField> {
     /// Radix-2 domain
     Radix2(Radix2EvaluationDomain<F>),
@@ -134,6 +139,14 @@ impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
     }
 
     /// Return an iterator over the elements of the domain.
+    #[inline]
+    fn element(&self, i: usize) -> F {
+        match self {
+            GeneralEvaluationDomain::Radix2(domain) => domain.element(i),
+            GeneralEvaluationDomain::MixedRadix(domain) => domain.element(i),
+        }
+    }
+
     fn elements(&self) -> GeneralElements<F> {
         match self {
             GeneralEvaluationDomain::Radix2(domain) => {
@@ -165,9 +178,16 @@ impl<F: FftField> Iterator for GeneralElements<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{EvaluationDomain, GeneralEvaluationDomain};
-    use algebra::{bls12_381::Fr, mnt6_753::Fr as MNT6Fr};
-    use algebra_core::{test_rng, Zero};
+    use crate::{
+        fft::{coset_offsets, domain::oracle::naive_coset_dft, sample_coset_offset},
+        EvaluationDomain, GeneralEvaluationDomain, SunziError,
+    };
+    use algebra::{
+        bls12_381::Fr,
+        mnt4_298::{Fq as MNT4Fq, Fq2 as MNT4Fq2},
+        mnt6_753::Fr as MNT6Fr,
+    };
+    use algebra_core::{test_rng, FftField, Field, One, Zero};
     use rand::Rng;
 
     #[test]
@@ -218,4 +238,259 @@ mod tests {
             assert_eq!(domain_size, domain.elements().count());
         }
     }
+
+    #[test]
+    fn try_new() {
+        let domain = GeneralEvaluationDomain::<Fr>::try_new(10).unwrap();
+        assert_eq!(domain.size(), 16);
+        assert_eq!(
+            GeneralEvaluationDomain::<Fr>::try_new(1 << 33),
+            Err(SunziError::DomainTooLarge)
+        );
+    }
+
+    #[test]
+    fn ifft_group_elements() {
+        let rng = &mut test_rng();
+        let tau: Fr = rng.gen();
+        let domain = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let powers: Vec<Fr> = (0..10u64).map(|i| tau.pow([i])).collect();
+        assert_eq!(
+            domain.ifft_group_elements(&powers).unwrap(),
+            domain.evaluate_all_lagrange_coefficients(tau)
+        );
+        assert_eq!(
+            domain.ifft_group_elements(&powers[..7]),
+            Err(SunziError::SizeMismatch {
+                expected: 8,
+                found: 7
+            })
+        );
+    }
+
+    #[test]
+    fn distribute_powers() {
+        let rng = &mut test_rng();
+        let g: Fr = rng.gen();
+        let expected: Vec<Fr> = crate::fft::Powers::new(g).take(5000).collect();
+        assert_eq!(expected[3], g * g * g);
+        assert_eq!(crate::fft::powers(g, 5000), expected);
+
+        let coeffs: Vec<Fr> = (0..5000).map(|_| rng.gen()).collect();
+        let mut scaled = coeffs.clone();
+        crate::fft::distribute_powers(&mut scaled, g);
+        for ((s, c), pow) in scaled.iter().zip(&coeffs).zip(&expected) {
+            assert_eq!(*s, *c * pow);
+        }
+    }
+
+    #[test]
+    fn barycentric_weights() {
+        let rng = &mut test_rng();
+        for &size in [1, 8, 64].iter() {
+            let domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+            let tau: Fr = rng.gen();
+            let n = Fr::from(size as u64);
+            assert_eq!(
+                domain.vanishing_poly_derivative_at(tau),
+                n * tau.pow([size as u64 - 1])
+            );
+
+            let weights = domain.barycentric_weights();
+            assert_eq!(weights.len(), size);
+            let z = domain.evaluate_vanishing_polynomial(tau);
+            for (i, (w, x)) in weights.iter().zip(domain.elements()).enumerate() {
+                assert_eq!(*w * domain.vanishing_poly_derivative_at(x), Fr::one());
+                let lagrange = z * w * (tau - x).inverse().unwrap();
+                assert_eq!(lagrange, domain.evaluate_lagrange_at(i, tau));
+            }
+        }
+    }
+
+    #[test]
+    fn subdomains() {
+        let domain = GeneralEvaluationDomain::<Fr>::new(64).unwrap();
+        let subdomains = domain.subdomains();
+        assert_eq!(
+            subdomains.iter().map(|d| d.size()).collect::<Vec<_>>(),
+            vec![1, 2, 4, 8, 16, 32, 64]
+        );
+        assert_eq!(subdomains[6], domain);
+        for sub in &subdomains {
+            assert!(sub.is_subdomain_of(&domain));
+            for (i, x) in sub.elements().enumerate() {
+                let j = sub.index_in_superdomain(&domain, i);
+                assert_eq!(domain.element(j), x);
+                assert_eq!(domain.index_in_subdomain(sub, j), Some(i));
+            }
+        }
+        assert!(!domain.is_subdomain_of(&subdomains[3]));
+        assert_eq!(domain.index_in_subdomain(&subdomains[3], 4), None);
+
+        // Squaring the elements of a domain maps index `i` to `i mod n/2`.
+        let half = subdomains[5];
+        for (i, x) in domain.elements().enumerate() {
+            let j = half.index_in_superdomain(&domain, i % 32);
+            assert_eq!(domain.element(j), x.square());
+        }
+    }
+
+    #[test]
+    fn disjoint_cosets() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(64).unwrap();
+        for _ in 0..10 {
+            let g = sample_coset_offset(&domain, rng);
+            assert!(!g.is_zero());
+            assert!(!domain.evaluate_vanishing_polynomial(g).is_zero());
+        }
+
+        // The cosets are distinct iff the offsets to the power of the size
+        // of the domain are.
+        let offsets = coset_offsets(&domain, 5);
+        assert_eq!(offsets[0], Fr::multiplicative_generator());
+        let shifted: Vec<Fr> = offsets.iter().map(|g| g.pow([64])).collect();
+        for (i, a) in shifted.iter().enumerate() {
+            assert!(!a.is_one());
+            assert!(shifted[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn extension_coefficients_fft() {
+        // The FFT of coefficients in a quadratic extension over a domain of
+        // the base field is the FFT of each coordinate.
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<MNT4Fq>::new(64).unwrap();
+        let coeffs: Vec<MNT4Fq2> = (0..64).map(|_| rng.gen()).collect();
+        let evals = domain.fft(&coeffs);
+        let c0: Vec<MNT4Fq> = coeffs.iter().map(|c| c.c0).collect();
+        let c1: Vec<MNT4Fq> = coeffs.iter().map(|c| c.c1).collect();
+        for ((e, e0), e1) in evals.iter().zip(domain.fft(&c0)).zip(domain.fft(&c1)) {
+            assert_eq!((e.c0, e.c1), (e0, e1));
+        }
+        assert_eq!(domain.ifft(&evals), coeffs);
+    }
+
+    #[test]
+    fn extension_coefficients_domain_ops() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<MNT4Fq>::new(32).unwrap();
+        let coeffs: Vec<MNT4Fq2> = (0..32).map(|_| rng.gen()).collect();
+        let evals = domain.coset_fft(&coeffs);
+        assert_eq!(evals, naive_coset_dft(&domain, &coeffs));
+        assert_eq!(domain.coset_ifft(&evals), coeffs);
+
+        let base: Vec<MNT4Fq> = (0..32).map(|_| rng.gen()).collect();
+        let product = domain.mul_polynomials_in_evaluation_domain(&evals, &base);
+        for ((p, e), b) in product.iter().zip(&evals).zip(&base) {
+            assert_eq!(*p, *e * MNT4Fq2::new(*b, MNT4Fq::zero()));
+        }
+
+        // Each coordinate is divided by the vanishing polynomial.
+        let mut quotient = evals.clone();
+        domain.divide_by_vanishing_poly_on_coset_in_place(&mut quotient);
+        let mut c0: Vec<MNT4Fq> = evals.iter().map(|e| e.c0).collect();
+        domain.divide_by_vanishing_poly_on_coset_in_place(&mut c0);
+        assert!(quotient.iter().zip(&c0).all(|(q, c)| q.c0 == *c));
+    }
+
+    #[test]
+    fn batch_vanishing_polynomial_evaluation() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let points: Vec<Fr> = (0..10).map(|_| rng.gen()).collect();
+        let evals = domain.batch_evaluate_vanishing_polynomial(&points);
+        for (tau, eval) in points.iter().zip(evals) {
+            assert_eq!(eval, domain.evaluate_vanishing_polynomial(*tau));
+        }
+
+        let g = Fr::multiplicative_generator();
+        for &size in [32, 8, 4].iter() {
+            let coset_domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+            let evals = domain.evaluate_vanishing_polynomial_on_coset(&coset_domain);
+            assert_eq!(evals.len(), size);
+            for (w, eval) in coset_domain.elements().zip(evals) {
+                assert_eq!(eval, domain.evaluate_vanishing_polynomial(g * w));
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_lagrange_at() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        let tau: Fr = rng.gen();
+        let all = domain.evaluate_all_lagrange_coefficients(tau);
+        for (i, l_i) in all.iter().enumerate() {
+            assert_eq!(domain.evaluate_lagrange_at(i, tau), *l_i);
+        }
+        assert_eq!(domain.evaluate_first_lagrange_at(tau), all[0]);
+        assert_eq!(domain.evaluate_last_lagrange_at(tau), all[15]);
+
+        // On the domain, L_i is the indicator of the i-th element.
+        let omega_3 = domain.element(3);
+        assert_eq!(omega_3, domain.elements().nth(3).unwrap());
+        assert_eq!(domain.evaluate_lagrange_at(3, omega_3), Fr::one());
+        assert_eq!(domain.evaluate_lagrange_at(4, omega_3), Fr::zero());
+        assert_eq!(domain.evaluate_first_lagrange_at(Fr::one()), Fr::one());
+        assert_eq!(domain.evaluate_last_lagrange_at(Fr::one()), Fr::zero());
+    }
+
+    #[test]
+    fn rotate_evaluations() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let coeffs: Vec<Fr> = (0..8).map(|_| rng.gen()).collect();
+        let evals = domain.fft(&coeffs);
+
+        for &k in [0, 1, 3, -1, 9].iter() {
+            let rotated = domain.rotate_evaluations(&evals, k);
+            let i = (2 + k).rem_euclid(8) as usize;
+            assert_eq!(rotated[2], evals[i]);
+            assert_eq!(domain.fft(&domain.scale_by_omega_k(&coeffs, k)), rotated);
+        }
+
+        let evals = crate::fft::Evaluations::from_vec_and_domain(evals, domain);
+        assert_eq!(evals.rotate(1)[7], evals[0]);
+        assert_eq!(evals.rotate(1).rotate(-1), evals);
+    }
+
+    #[test]
+    fn padding_and_subdomain_chunks() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let coeffs: Vec<Fr> = (0..4).map(|_| rng.gen()).collect();
+
+        let (padded, mask) = crate::fft::pad_to_domain(&coeffs[..3], &domain).unwrap();
+        assert_eq!(&padded[..3], &coeffs[..3]);
+        assert_eq!(padded[3..], [Fr::zero(); 5]);
+        assert_eq!(mask, [false, false, false, true, true, true, true, true]);
+        assert_eq!(
+            crate::fft::pad_to_domain(&[Fr::zero(); 9], &domain),
+            Err(SunziError::SizeMismatch {
+                expected: 8,
+                found: 9
+            })
+        );
+
+        // The chunks are the evaluations over the cosets of the subdomain.
+        let evals = domain.fft(&coeffs);
+        let subdomain = GeneralEvaluationDomain::<Fr>::new(4).unwrap();
+        let chunks = crate::fft::split_into_subdomain_chunks(&evals, 2).unwrap();
+        assert_eq!(chunks[0], subdomain.fft(&coeffs));
+        assert_eq!(
+            chunks[1],
+            subdomain.fft(&domain.scale_by_omega_k(&coeffs, 1))
+        );
+        let mask_chunks = crate::fft::split_into_subdomain_chunks(&mask, 4).unwrap();
+        assert_eq!(mask_chunks[3], [true, true]);
+        assert_eq!(
+            crate::fft::split_into_subdomain_chunks(&evals, 3),
+            Err(SunziError::SizeMismatch {
+                expected: 9,
+                found: 8
+            })
+        );
+    }
 }
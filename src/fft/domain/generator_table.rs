@@ -0,0 +1,133 @@
+//! A radix-2 domain with a fixed-base table of the powers of its generator,
+//! for protocols that ask for many single elements of a large domain, e.g.
+//! to evaluate Lagrange polynomials at opening points.
+
+use crate::{FftField, Field, Vec};
+
+use super::{
+    lagrange_at_element, utils::powers, DomainCoeff, EvaluationDomain, Radix2EvaluationDomain,
+};
+
+/// The width in bits of the windows of the table.
+const WINDOW: u32 = 8;
+
+/// A `Radix2EvaluationDomain` with the powers `ω^(d 2^(8j))` of its
+/// generator `ω` for all digits `d < 2^8`, so that `ω^i` costs one
+/// multiplication per byte of `i` instead of a square-and-multiply.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Radix2DomainWithTable<F: FftField> {
+    domain: Radix2EvaluationDomain<F>,
+    /// `table[j][d] = ω^(d 2^(WINDOW j))`.
+    table: Vec<Vec<F>>,
+}
+
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    /// Builds the table of the powers of the generator of `self`, which
+    /// holds at most `2^8` elements per byte of the indices of the domain.
+    pub fn with_precomputed_generator_table(&self) -> Radix2DomainWithTable<F> {
+        let mut table = Vec::new();
+        let mut base = self.group_gen;
+        let mut bits = self.log_size_of_group;
+        while bits > 0 {
+            let window = bits.min(WINDOW);
+            table.push(powers(base, 1 << window));
+            for _ in 0..window {
+                base = base.square();
+            }
+            bits -= window;
+        }
+        Radix2DomainWithTable {
+            domain: *self,
+            table,
+        }
+    }
+}
+
+impl<F: FftField> Radix2DomainWithTable<F> {
+    /// Returns the domain.
+    pub fn domain(&self) -> &Radix2EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Returns the `i`-th element `ω^i` of the domain, like
+    /// `EvaluationDomain::element`.
+    pub fn element(&self, i: usize) -> F {
+        let mut i = i % self.domain.size();
+        let mut res = F::one();
+        for window in &self.table {
+            let digit = i & ((1 << WINDOW) - 1);
+            if digit != 0 {
+                res *= &window[digit];
+            }
+            i >>= WINDOW;
+        }
+        res
+    }
+
+    /// Evaluates the `i`-th Lagrange polynomial of the domain at `tau`, like
+    /// `EvaluationDomain::evaluate_lagrange_at`.
+    ///
+    /// Panics if `i` is not smaller than the size of the domain.
+    pub fn evaluate_lagrange_at(&self, i: usize, tau: F) -> F {
+        assert!(i < self.domain.size(), "index out of the domain");
+        lagrange_at_element(&self.domain, self.element(i), tau)
+    }
+
+    /// Evaluates the first Lagrange polynomial of the domain at `tau`.
+    pub fn evaluate_first_lagrange_at(&self, tau: F) -> F {
+        self.evaluate_lagrange_at(0, tau)
+    }
+
+    /// Evaluates the last Lagrange polynomial of the domain at `tau`.
+    pub fn evaluate_last_lagrange_at(&self, tau: F) -> F {
+        self.evaluate_lagrange_at(self.domain.size() - 1, tau)
+    }
+
+    /// Given the coefficients of `p(X)`, returns those of `p(ω^k X)`, like
+    /// `EvaluationDomain::scale_by_omega_k`.
+    pub fn scale_by_omega_k<T: DomainCoeff<F>>(&self, coeffs: &[T], k: isize) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        let omega_k = self.element(k.rem_euclid(self.domain.size() as isize) as usize);
+        Radix2EvaluationDomain::distribute_powers(&mut coeffs, omega_k);
+        coeffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    #[test]
+    fn generator_table_test() {
+        let rng = &mut test_rng();
+        for &log_size in [0, 1, 8, 11].iter() {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let with_table = domain.with_precomputed_generator_table();
+            assert_eq!(with_table.domain(), &domain);
+            for i in 0..domain.size().min(300) {
+                assert_eq!(with_table.element(i), domain.element(i));
+            }
+            let i = domain.size() - 1;
+            assert_eq!(with_table.element(i), domain.element(i));
+
+            let tau: Fr = rng.gen();
+            assert_eq!(
+                with_table.evaluate_lagrange_at(i / 2, tau),
+                domain.evaluate_lagrange_at(i / 2, tau)
+            );
+            assert_eq!(
+                with_table.evaluate_last_lagrange_at(tau),
+                domain.evaluate_last_lagrange_at(tau)
+            );
+
+            let coeffs: Vec<Fr> = (0..4).map(|_| rng.gen()).collect();
+            assert_eq!(
+                with_table.scale_by_omega_k(&coeffs, -3),
+                domain.scale_by_omega_k(&coeffs, -3)
+            );
+        }
+    }
+}
@@ -25,6 +25,11 @@ use super::{
 /// only for fields that have a multiplicative subgroup of size that is
 /// a power-of-2 and another small subgroup over a different base defined.
 #[derive(Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct MixedRadixEvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
@@ -203,6 +208,11 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
         tau.pow(&[self.size]) - &F::one()
     }
 
+    #[inline]
+    fn element(&self, i: usize) -> F {
+        self.group_gen.pow([i as u64])
+    }
+
     /// Return an iterator over the elements of the domain.
     fn elements(&self) -> Elements<F> {
         Elements {
@@ -12,23 +12,48 @@ use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use crate::{FftField, Vec};
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    Error, FftField, SunziError, Vec,
+};
 
+#[cfg(feature = "std")]
+pub mod cache;
+pub mod coset;
+pub mod dynamic;
+pub mod fixed;
 pub mod general;
+pub mod generator_table;
 pub mod mixed_radix;
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod oracle;
 pub mod radix2;
-pub(crate) mod utils;
+pub mod streaming;
+pub mod utils;
+pub mod workspace;
 
+#[cfg(feature = "std")]
+pub use cache::{CachedDomain, DomainCache};
+pub use coset::CosetEvaluator;
+pub use dynamic::DynDomain;
+pub use fixed::{fft_fixed, ifft_fixed, FixedRadix2Domain};
 pub use general::GeneralEvaluationDomain;
+pub use generator_table::Radix2DomainWithTable;
 pub use mixed_radix::MixedRadixEvaluationDomain;
 pub use radix2::Radix2EvaluationDomain;
+pub use streaming::IfftBuilder;
+pub use utils::{
+    bitrev_permutation_indices, bitreverse, bitreverse_u64, coset_offsets, distribute_powers,
+    pad_to_domain, powers, sample_coset_offset, split_into_subdomain_chunks, Powers,
+};
+pub use workspace::FftWorkspace;
 
 /// Defines a domain over which finite field (I)FFTs can be performed. The
 /// size of the supported FFT depends on the size of the multiplicative
 /// subgroup. For efficiency, we recommend that the field has at least one large
 /// subgroup generated by a root of unity.
 pub trait EvaluationDomain<F: FftField>:
-    Copy + Clone + hash::Hash + Eq + PartialEq + fmt::Debug
+    Copy + Clone + hash::Hash + Eq + PartialEq + fmt::Debug + CanonicalSerialize + CanonicalDeserialize
 {
     /// The type of the elements iterator.
     type Elements: Iterator<Item = F> + Sized;
@@ -46,6 +71,14 @@ pub trait EvaluationDomain<F: FftField>:
     /// having `num_coeffs` coefficients.
     fn new(num_coeffs: usize) -> Option<Self>;
 
+    /// Construct a domain like `new`, reporting why it cannot be built.
+    fn try_new(num_coeffs: usize) -> Result<Self, SunziError> {
+        if Self::compute_size_of_domain(num_coeffs).is_none() {
+            return Err(SunziError::DomainTooLarge);
+        }
+        Self::new(num_coeffs).ok_or(SunziError::NoRootOfUnity)
+    }
+
     /// Return the size of a domain that is large enough for evaluations of a
     /// polynomial having `num_coeffs` coefficients.
     fn compute_size_of_domain(num_coeffs: usize) -> Option<usize>;
@@ -53,7 +86,9 @@ pub trait EvaluationDomain<F: FftField>:
     /// Return the size of `self`.
     fn size(&self) -> usize;
 
-    /// Return the size of `self` as a field element.
+    /// Return the size of `self` as a field element. It is in `F` even when
+    /// the coefficients of (I)FFTs are in an extension, which it scales
+    /// through `MulAssign<F>`.
     fn size_as_field_element(&self) -> F {
         F::from(self.size() as u64)
     }
@@ -80,13 +115,27 @@ pub trait EvaluationDomain<F: FftField>:
     /// Compute a IFFT, modifying the vector in place.
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>);
 
+    /// Converts a structured reference string in the monomial basis,
+    /// `[g^{τ^i}]` for `i < size`, into the Lagrange basis over `self`,
+    /// `[g^{L_i(τ)}]`, where `L_i` is the Lagrange polynomial of the `i`-th
+    /// element of the domain. Elements beyond the size of the domain are
+    /// ignored. The SRS can hold projective points, or field elements for
+    /// the powers of a known `τ`.
+    fn ifft_group_elements<G: DomainCoeff<F>>(&self, srs: &[G]) -> Result<Vec<G>, SunziError> {
+        if srs.len() < self.size() {
+            return Err(SunziError::SizeMismatch {
+                expected: self.size(),
+                found: srs.len(),
+            });
+        }
+        // L_i(τ) = (1/n) Σ_j ω^{-ij} τ^j, so the Lagrange basis is the
+        // inverse FFT of the powers of τ.
+        Ok(self.ifft(&srs[..self.size()]))
+    }
+
     /// Multiply the `i`-th element of `coeffs` with the `i`-th power of `g`.
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
-        let mut pow = F::one();
-        coeffs.iter_mut().for_each(|c| {
-            *c *= pow;
-            pow *= &g
-        })
+        utils::distribute_powers(coeffs, g)
     }
 
     /// Compute a FFT over a coset of the domain.
@@ -125,25 +174,127 @@ pub trait EvaluationDomain<F: FftField>:
     /// point `tau`.
     fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F>;
 
+    /// Evaluates the sparse polynomial `poly` over the domain, in the order
+    /// of `fft`. With few terms each is evaluated at all elements, by one
+    /// multiplication per element, instead of transforming the dense
+    /// coefficients; with more, the latter is done.
+    fn sparse_fft(&self, poly: &crate::SparsePolynomial<F>) -> Vec<F> {
+        utils::sparse_fft(self, poly)
+    }
+
     /// Return the sparse vanishing polynomial.
     fn vanishing_polynomial(&self) -> crate::SparsePolynomial<F>;
 
     /// This evaluates the vanishing polynomial for this domain at tau.
     fn evaluate_vanishing_polynomial(&self, tau: F) -> F;
 
+    /// Evaluates the vanishing polynomial for this domain at each of `points`.
+    fn batch_evaluate_vanishing_polynomial(&self, points: &[F]) -> Vec<F> {
+        cfg_iter!(points)
+            .map(|tau| self.evaluate_vanishing_polynomial(*tau))
+            .collect()
+    }
+
+    /// Returns the evaluations of the vanishing polynomial for this domain
+    /// over the coset of `domain` that `coset_fft` evaluates on, e.g. to
+    /// divide a quotient by it. The vanishing polynomial is `X^n - 1`, so the
+    /// evaluations repeat with period `domain.size() / n` when `n` divides
+    /// `domain.size()`, and only one period is computed.
+    fn evaluate_vanishing_polynomial_on_coset<D: EvaluationDomain<F>>(&self, domain: &D) -> Vec<F> {
+        let n = self.size() as u64;
+        let m = domain.size();
+        let period = if m as u64 % n == 0 { m / n as usize } else { m };
+
+        // (g * w^i)^n - 1 = g^n * (w^n)^i - 1
+        let g_n = F::multiplicative_generator().pow([n]);
+        let w_n = domain.elements().nth(1).unwrap_or_else(F::one).pow([n]);
+        let mut evals = utils::powers(w_n, period);
+        cfg_iter_mut!(evals).for_each(|e| *e = g_n * *e - F::one());
+        evals.iter().cycle().take(m).cloned().collect()
+    }
+
     /// Return an iterator over the elements of the domain.
     fn elements(&self) -> Self::Elements;
 
+    /// Returns the `i`-th element `ω^i` of the domain.
+    fn element(&self, i: usize) -> F {
+        self.elements().nth(i % self.size()).unwrap()
+    }
+
+    /// Evaluates the `i`-th Lagrange polynomial of the domain at `tau`, as
+    /// `L_i(τ) = Z(τ) ω^i / (n (τ - ω^i))`, without computing the others.
+    ///
+    /// Panics if `i` is not smaller than the size of the domain.
+    fn evaluate_lagrange_at(&self, i: usize, tau: F) -> F {
+        assert!(i < self.size(), "index out of the domain");
+        lagrange_at_element(self, self.element(i), tau)
+    }
+
+    /// Evaluates the derivative `Z'` of the vanishing polynomial for this
+    /// domain at `tau`: `n τ^(n-1)` for `Z = X^n - 1`.
+    fn vanishing_poly_derivative_at(&self, tau: F) -> F {
+        self.vanishing_polynomial()
+            .iter()
+            .filter(|(d, _)| *d > 0)
+            .fold(F::zero(), |sum, (d, c)| {
+                sum + F::from(*d as u64) * c * tau.pow([*d as u64 - 1])
+            })
+    }
+
+    /// Returns the barycentric weights `w_i = 1 / Z'(ω^i) = ω^i / n` of the
+    /// domain, with which `L_i(τ) = Z(τ) w_i / (τ - ω^i)` as in
+    /// `evaluate_lagrange_at`.
+    fn barycentric_weights(&self) -> Vec<F> {
+        let mut weights = utils::powers(self.element(1), self.size());
+        let size_inv = self.size_as_field_element().inverse().unwrap();
+        cfg_iter_mut!(weights).for_each(|w| *w *= &size_inv);
+        weights
+    }
+
+    /// Given the evaluations of `p(X)` over the domain, returns those of
+    /// `p(ω^k X)`, whose `i`-th element is `p(ω^(i + k))`: with `k = 1`, the
+    /// value of the next row. Indices wrap around the domain, and negative
+    /// `k` shifts the other way.
+    ///
+    /// Panics if `evals` does not have the size of the domain.
+    fn rotate_evaluations<T: Clone>(&self, evals: &[T], k: isize) -> Vec<T> {
+        assert_eq!(evals.len(), self.size(), "evaluations must span the domain");
+        let mut rotated = evals.to_vec();
+        rotated.rotate_left(k.rem_euclid(self.size() as isize) as usize);
+        rotated
+    }
+
+    /// Given the coefficients of `p(X)`, returns those of `p(ω^k X)`, the
+    /// polynomial whose evaluations are `rotate_evaluations(evals, k)`.
+    fn scale_by_omega_k<T: DomainCoeff<F>>(&self, coeffs: &[T], k: isize) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        let omega_k = self.element(k.rem_euclid(self.size() as isize) as usize);
+        Self::distribute_powers(&mut coeffs, omega_k);
+        coeffs
+    }
+
+    /// Evaluates the first Lagrange polynomial `L_0`, which is one at one and
+    /// zero elsewhere on the domain, at `tau`.
+    fn evaluate_first_lagrange_at(&self, tau: F) -> F {
+        self.evaluate_lagrange_at(0, tau)
+    }
+
+    /// Evaluates the last Lagrange polynomial `L_{n-1}`, which is one at
+    /// `ω^(n-1)` and zero elsewhere on the domain, at `tau`.
+    fn evaluate_last_lagrange_at(&self, tau: F) -> F {
+        self.evaluate_lagrange_at(self.size() - 1, tau)
+    }
+
     /// The target polynomial is the zero polynomial in our
     /// evaluation domain, so we must perform division over
-    /// a coset.
-    fn divide_by_vanishing_poly_on_coset_in_place(&self, evals: &mut [F]) {
+    /// a coset. The evaluations can be in an extension of `F`.
+    fn divide_by_vanishing_poly_on_coset_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
         let i = self
             .evaluate_vanishing_polynomial(F::multiplicative_generator())
             .inverse()
             .unwrap();
 
-        cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
+        cfg_iter_mut!(evals).for_each(|eval| *eval *= i);
     }
 
     /// Given an index which assumes the first elements of this domain are the
@@ -173,26 +324,96 @@ pub trait EvaluationDomain<F: FftField>:
         }
     }
 
+    /// Returns whether the elements of `self` are those of `other` at the
+    /// multiples of `other.size() / self.size()`, in order: whether `self` is
+    /// a subgroup of `other` with the generator `other.element(period)`, so
+    /// that the indices of the two map into each other.
+    fn is_subdomain_of<D: EvaluationDomain<F>>(&self, other: &D) -> bool {
+        other.size() % self.size() == 0
+            && self.element(1) == other.element(other.size() / self.size())
+    }
+
+    /// Returns the subdomains of `self` of power-of-two sizes, from the
+    /// trivial one of size 1 up to the largest, which is `self` if its size
+    /// is a power of two. Halving a domain steps down this list.
+    fn subdomains(&self) -> Vec<Self> {
+        let two_adicity = self.size().trailing_zeros();
+        (0..=two_adicity)
+            .filter_map(|k| Self::new(1 << k))
+            .filter(|d| d.size().is_power_of_two() && d.is_subdomain_of(self))
+            .collect()
+    }
+
+    /// Returns the index in `superdomain` of the `i`-th element of `self`.
+    ///
+    /// Panics if `self` is not a subdomain of `superdomain`, or if `i` is not
+    /// smaller than the size of `self`.
+    fn index_in_superdomain<D: EvaluationDomain<F>>(&self, superdomain: &D, i: usize) -> usize {
+        assert!(self.is_subdomain_of(superdomain), "not a subdomain");
+        assert!(i < self.size(), "index out of the domain");
+        i * (superdomain.size() / self.size())
+    }
+
+    /// Returns the index in `subdomain` of the `i`-th element of `self`, or
+    /// None if that element is not in `subdomain`.
+    ///
+    /// Panics if `subdomain` is not a subdomain of `self`, or if `i` is not
+    /// smaller than the size of `self`.
+    fn index_in_subdomain<D: EvaluationDomain<F>>(&self, subdomain: &D, i: usize) -> Option<usize> {
+        assert!(subdomain.is_subdomain_of(self), "not a subdomain");
+        assert!(i < self.size(), "index out of the domain");
+        let period = self.size() / subdomain.size();
+        if i % period == 0 {
+            Some(i / period)
+        } else {
+            None
+        }
+    }
+
     /// Perform O(n) multiplication of two polynomials that are presented by
     /// their evaluations in the domain.
     /// Returns the evaluations of the product over the domain.
     ///
     /// Assumes that the domain is large enough to allow for successful
-    /// interpolation after multiplication.
+    /// interpolation after multiplication. The first polynomial can have
+    /// coefficients in an extension of `F`, e.g. to multiply a column of the
+    /// base field by one of the extension.
     #[must_use]
-    fn mul_polynomials_in_evaluation_domain(&self, self_evals: &[F], other_evals: &[F]) -> Vec<F> {
+    fn mul_polynomials_in_evaluation_domain<T: DomainCoeff<F>>(
+        &self,
+        self_evals: &[T],
+        other_evals: &[F],
+    ) -> Vec<T> {
         assert_eq!(self_evals.len(), other_evals.len());
         let mut result = self_evals.to_vec();
 
         cfg_iter_mut!(result)
             .zip(other_evals)
-            .for_each(|(a, b)| *a *= b);
+            .for_each(|(a, b)| *a *= *b);
 
         result
     }
 }
 
-/// Types that can be FFT-ed must implement this trait.
+/// Evaluates at `tau` the Lagrange polynomial of the element `omega_i` of
+/// `domain`.
+pub(crate) fn lagrange_at_element<F: FftField, D: EvaluationDomain<F>>(
+    domain: &D,
+    omega_i: F,
+    tau: F,
+) -> F {
+    let z = domain.evaluate_vanishing_polynomial(tau);
+    if z.is_zero() {
+        // `tau` is in the domain.
+        return if tau == omega_i { F::one() } else { F::zero() };
+    }
+    let denominator = domain.size_as_field_element() * (tau - omega_i);
+    z * omega_i * denominator.inverse().unwrap()
+}
+
+/// Types that can be FFT-ed must implement this trait. Quadratic and cubic
+/// extensions of a prime field `F` do, multiplying by the twiddles in `F` one
+/// coordinate at a time.
 pub trait DomainCoeff<F: FftField>:
     Copy
     + Send
@@ -216,3 +437,57 @@ where
         + core::ops::MulAssign<F>,
 {
 }
+
+// A domain is written as its size and its generator. It is rebuilt from the
+// size, and from the generator if that is not the one `new` picks; a domain
+// that can't have that generator is an error.
+macro_rules! impl_domain_serialize {
+    ($domain: ident, $with_generator: expr) => {
+        impl<F: FftField> CanonicalSerialize for $domain<F> {
+            fn serialize_with_mode(
+                &self,
+                writer: &mut Vec<u8>,
+                compress: Compress,
+            ) -> Result<(), Error> {
+                self.size().serialize_with_mode(writer, compress)?;
+                self.element(1).serialize_with_mode(writer, compress)
+            }
+
+            fn serialized_size(&self, compress: Compress) -> usize {
+                self.size().serialized_size(compress) + self.element(1).serialized_size(compress)
+            }
+        }
+
+        impl<F: FftField> CanonicalDeserialize for $domain<F> {
+            fn deserialize_with_mode(
+                reader: &mut &[u8],
+                compress: Compress,
+                validate: Validate,
+            ) -> Result<Self, Error> {
+                let size = usize::deserialize_with_mode(reader, compress, validate)?;
+                let group_gen = F::deserialize_with_mode(reader, compress, validate)?;
+                let domain = Self::new(size)
+                    .filter(|domain| domain.size() == size)
+                    .ok_or(Error("invalid size of an evaluation domain"))?;
+                if domain.element(1) == group_gen {
+                    Ok(domain)
+                } else {
+                    let with_generator: fn(usize, F) -> Option<Self> = $with_generator;
+                    with_generator(size, group_gen)
+                        .ok_or(Error("invalid generator of an evaluation domain"))
+                }
+            }
+        }
+
+        impl_from_canonical!([F: FftField], $domain<F>);
+    };
+}
+
+impl_domain_serialize!(
+    Radix2EvaluationDomain,
+    Radix2EvaluationDomain::new_with_generator
+);
+impl_domain_serialize!(MixedRadixEvaluationDomain, |_, _| None);
+impl_domain_serialize!(GeneralEvaluationDomain, |size, group_gen| {
+    Radix2EvaluationDomain::new_with_generator(size, group_gen).map(GeneralEvaluationDomain::Radix2)
+});
@@ -0,0 +1,184 @@
+//! Quadratic-time discrete Fourier transforms straight from their
+//! definition, to check FFTs against, e.g. for new `DomainCoeff` types or
+//! fields. They are only built for tests and with the `test-helpers`
+//! feature.
+
+use core::fmt;
+
+use crate::{FftField, Vec};
+
+use super::{DomainCoeff, EvaluationDomain};
+
+/// Returns the evaluations of the polynomial with coefficients `coeffs` at
+/// the elements of `domain`, as `∑_j coeffs[j] ω^(ij)` for the `i`-th one.
+///
+/// Panics if there are more coefficients than elements of the domain.
+pub fn naive_dft<F, T, D>(domain: &D, coeffs: &[T]) -> Vec<T>
+where
+    F: FftField,
+    T: DomainCoeff<F>,
+    D: EvaluationDomain<F>,
+{
+    evaluate_at(coeffs, domain.elements(), domain.size())
+}
+
+/// Returns the evaluations of the polynomial with coefficients `coeffs` at
+/// the elements of the coset of `domain` that `coset_fft` evaluates on.
+///
+/// Panics if there are more coefficients than elements of the domain.
+pub fn naive_coset_dft<F, T, D>(domain: &D, coeffs: &[T]) -> Vec<T>
+where
+    F: FftField,
+    T: DomainCoeff<F>,
+    D: EvaluationDomain<F>,
+{
+    let g = F::multiplicative_generator();
+    evaluate_at(coeffs, domain.elements().map(|x| g * x), domain.size())
+}
+
+/// Returns the coefficients of the polynomial with evaluations `evals` over
+/// `domain`, as `n^(-1) ∑_i evals[i] ω^(-ij)` for the `j`-th one.
+///
+/// Panics if `evals` does not have the size of the domain.
+pub fn naive_idft<F, T, D>(domain: &D, evals: &[T]) -> Vec<T>
+where
+    F: FftField,
+    T: DomainCoeff<F>,
+    D: EvaluationDomain<F>,
+{
+    assert_eq!(
+        evals.len(),
+        domain.size(),
+        "evaluations must span the domain"
+    );
+    let inverses = domain.elements().map(|x| x.inverse().unwrap());
+    let mut coeffs = evaluate_at(evals, inverses, domain.size());
+    let size_inv = domain.size_as_field_element().inverse().unwrap();
+    coeffs.iter_mut().for_each(|c| *c *= size_inv);
+    coeffs
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at each of `points`.
+fn evaluate_at<F, T>(coeffs: &[T], points: impl Iterator<Item = F>, size: usize) -> Vec<T>
+where
+    F: FftField,
+    T: DomainCoeff<F>,
+{
+    assert!(coeffs.len() <= size, "too many coefficients for the domain");
+    points
+        .map(|x| {
+            let mut power = F::one();
+            let mut eval = T::zero();
+            for c in coeffs {
+                let mut term = *c;
+                term *= power;
+                eval += term;
+                power *= x;
+            }
+            eval
+        })
+        .collect()
+}
+
+/// The first disagreement found by `verify_fft_roundtrip`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FftMismatch {
+    /// The transform that disagrees, e.g. `"coset_ifft"`.
+    pub transform: &'static str,
+    /// The first index at which it does.
+    pub index: usize,
+}
+
+impl fmt::Display for FftMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} disagrees with its definition at index {}",
+            self.transform, self.index
+        )
+    }
+}
+
+/// Checks `fft`, `ifft`, `coset_fft` and `coset_ifft` of `domain` against
+/// the quadratic-time transforms of this module, and that each inverse
+/// transform undoes the direct one, on pseudorandom field elements.
+pub fn verify_fft_roundtrip<F, D>(domain: &D) -> Result<(), FftMismatch>
+where
+    F: FftField,
+    D: EvaluationDomain<F>,
+{
+    let coeffs: Vec<F> = (0..domain.size() as u64)
+        .map(|i| F::from_seed(&i.to_le_bytes()))
+        .collect();
+    verify_fft_roundtrip_with(domain, &coeffs)
+}
+
+/// Checks the transforms of `domain` as `verify_fft_roundtrip` does, on
+/// `coeffs`, e.g. values of a new `DomainCoeff` type. There may be fewer
+/// coefficients than elements of the domain.
+pub fn verify_fft_roundtrip_with<F, T, D>(domain: &D, coeffs: &[T]) -> Result<(), FftMismatch>
+where
+    F: FftField,
+    T: DomainCoeff<F> + PartialEq,
+    D: EvaluationDomain<F>,
+{
+    let mut padded = coeffs.to_vec();
+    padded.resize(domain.size(), T::zero());
+
+    let evals = domain.fft(coeffs);
+    compare("fft", &evals, &naive_dft(domain, coeffs))?;
+    compare("ifft", &domain.ifft(&evals), &naive_idft(domain, &evals))?;
+    compare("ifft", &domain.ifft(&evals), &padded)?;
+
+    let coset_evals = domain.coset_fft(coeffs);
+    compare("coset_fft", &coset_evals, &naive_coset_dft(domain, coeffs))?;
+    compare("coset_ifft", &domain.coset_ifft(&coset_evals), &padded)
+}
+
+fn compare<T: PartialEq>(
+    transform: &'static str,
+    got: &[T],
+    expected: &[T],
+) -> Result<(), FftMismatch> {
+    match got.iter().zip(expected).position(|(a, b)| a != b) {
+        Some(index) => Err(FftMismatch { transform, index }),
+        None if got.len() != expected.len() => Err(FftMismatch {
+            transform,
+            index: got.len().min(expected.len()),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::{GeneralEvaluationDomain, Radix2EvaluationDomain};
+    use algebra::{bls12_381::fr::Fr, mnt6_753::Fr as MNT6Fr};
+
+    #[test]
+    fn fft_oracle_test() {
+        for log_n in 0..6 {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_n).unwrap();
+            assert_eq!(verify_fft_roundtrip(&domain), Ok(()));
+        }
+        let domain = GeneralEvaluationDomain::<MNT6Fr>::new(16).unwrap();
+        assert_eq!(verify_fft_roundtrip(&domain), Ok(()));
+
+        // Shorter inputs are padded with zeros.
+        let domain = Radix2EvaluationDomain::<Fr>::new(8).unwrap();
+        let coeffs = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+        assert_eq!(verify_fft_roundtrip_with(&domain, &coeffs), Ok(()));
+
+        // A transform that is off by one index is caught.
+        let mut evals = domain.fft(&coeffs);
+        evals.swap(1, 2);
+        assert_eq!(
+            compare("fft", &evals, &naive_dft(&domain, &coeffs)),
+            Err(FftMismatch {
+                transform: "fft",
+                index: 1
+            })
+        );
+    }
+}
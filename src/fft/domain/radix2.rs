@@ -18,6 +18,11 @@ use super::{
 /// only for fields that have a large multiplicative subgroup of size that is
 /// a power-of-2.
 #[derive(Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Radix2EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
@@ -41,6 +46,51 @@ impl<F: FftField> fmt::Debug for Radix2EvaluationDomain<F> {
     }
 }
 
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    /// Construct the domain of size `size` generated by `group_gen` rather
+    /// than by the root of unity of `F::get_root_of_unity`, for protocols
+    /// which fix another one. Returns `None` if `size` is not a power of two,
+    /// or if `group_gen` is not a primitive `size`-th root of unity.
+    pub fn new_with_generator(size: usize, group_gen: F) -> Option<Self> {
+        if !size.is_power_of_two() {
+            return None;
+        }
+        let size = size as u64;
+        // The order of `group_gen` divides `size` but not `size / 2`, so it
+        // is `size` since both are powers of two.
+        let one = F::one();
+        if group_gen.pow([size]) != one || (size > 1 && group_gen.pow([size / 2]) == one) {
+            return None;
+        }
+        Self::from_generator(size, group_gen)
+    }
+
+    /// Returns the domain of half the size, whose elements are the squares
+    /// of the elements of `self`. Returns `None` if `self` has size one.
+    pub fn halve(&self) -> Option<Self> {
+        if self.size == 1 {
+            None
+        } else {
+            Self::from_generator(self.size / 2, self.group_gen.square())
+        }
+    }
+
+    fn from_generator(size: u64, group_gen: F) -> Option<Self> {
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(Radix2EvaluationDomain {
+            size,
+            log_size_of_group: size.trailing_zeros(),
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            generator_inv: F::multiplicative_generator().inverse()?,
+        })
+    }
+}
+
 impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
     type Elements = Elements<F>;
 
@@ -61,18 +111,7 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
         let group_gen = F::get_root_of_unity(size as usize)?;
         // Check that it is indeed the 2^(log_size_of_group) root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
-        let size_as_field_element = F::from(size);
-        let size_inv = size_as_field_element.inverse()?;
-
-        Some(Radix2EvaluationDomain {
-            size,
-            log_size_of_group,
-            size_as_field_element,
-            size_inv,
-            group_gen,
-            group_gen_inv: group_gen.inverse()?,
-            generator_inv: F::multiplicative_generator().inverse()?,
-        })
+        Self::from_generator(size, group_gen)
     }
 
     fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
@@ -170,6 +209,11 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
         tau.pow(&[self.size]) - &F::one()
     }
 
+    #[inline]
+    fn element(&self, i: usize) -> F {
+        self.group_gen.pow([i as u64])
+    }
+
     /// Return an iterator over the elements of the domain.
     fn elements(&self) -> Elements<F> {
         Elements {
@@ -215,3 +259,72 @@ pub(crate) fn serial_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], ome
         m *= 2;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Radix2EvaluationDomain;
+    use crate::{
+        fft::GeneralEvaluationDomain,
+        serialize::{CanonicalDeserialize, CanonicalSerialize},
+        EvaluationDomain, Field,
+    };
+    use algebra::bls12_381::Fr;
+    use algebra_core::{test_rng, One, UniformRand, Zero};
+
+    #[test]
+    fn new_with_generator_test() {
+        let canonical = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+        // Another primitive 16-th root of unity.
+        let group_gen = canonical.group_gen.pow([3]);
+        let domain = Radix2EvaluationDomain::new_with_generator(16, group_gen).unwrap();
+        assert_eq!(domain.element(1), group_gen);
+        assert_eq!(domain.group_gen_inv * group_gen, Fr::one());
+
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..16).map(|_| Fr::rand(rng)).collect();
+        let evals = domain.fft(&coeffs);
+        for (i, eval) in evals.iter().enumerate() {
+            let x = domain.element(i);
+            let value = coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c);
+            assert_eq!(*eval, value);
+        }
+        assert_eq!(domain.ifft(&evals), coeffs);
+
+        let half = domain.halve().unwrap();
+        assert_eq!(half.element(1), group_gen.square());
+        assert_eq!(canonical.halve(), Radix2EvaluationDomain::new(8));
+
+        // Not a power of two, not a root of unity of the size, and not a
+        // primitive one.
+        assert!(Radix2EvaluationDomain::new_with_generator(12, group_gen).is_none());
+        assert!(Radix2EvaluationDomain::new_with_generator(8, group_gen).is_none());
+        assert!(Radix2EvaluationDomain::new_with_generator(16, group_gen.square()).is_none());
+        assert!(Radix2EvaluationDomain::new_with_generator(1, Fr::one()).is_some());
+    }
+
+    #[test]
+    fn serialize_with_generator_test() {
+        let canonical = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+        let group_gen = canonical.group_gen.pow([3]);
+        let domain = Radix2EvaluationDomain::new_with_generator(16, group_gen).unwrap();
+        for domain in [canonical, domain] {
+            let mut bytes = Vec::new();
+            domain.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), domain.compressed_size());
+            let read = Radix2EvaluationDomain::deserialize_compressed(&mut &bytes[..]).unwrap();
+            assert_eq!(read, domain);
+
+            let general = GeneralEvaluationDomain::Radix2(domain);
+            let mut bytes = Vec::new();
+            general.serialize_compressed(&mut bytes).unwrap();
+            let read = GeneralEvaluationDomain::deserialize_compressed(&mut &bytes[..]).unwrap();
+            assert_eq!(read, general);
+        }
+
+        // A generator which is not a primitive root of unity of the size.
+        let mut bytes = Vec::new();
+        16usize.serialize_compressed(&mut bytes).unwrap();
+        group_gen.square().serialize_compressed(&mut bytes).unwrap();
+        assert!(Radix2EvaluationDomain::<Fr>::deserialize_compressed(&mut &bytes[..]).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+//! Inverse FFTs of evaluations that arrive in chunks, e.g. from the network
+//! or from disk, which start working on the first chunks instead of waiting
+//! for all of them.
+
+use crate::{FftField, Field, SunziError, Vec};
+
+use super::{
+    radix2::serial_radix2_fft, utils::bitreverse, DomainCoeff, EvaluationDomain,
+    Radix2EvaluationDomain,
+};
+
+/// A staged inverse FFT over a radix-2 domain of size `n`, of evaluations
+/// absorbed in order, in chunks of any length.
+///
+/// The evaluations are split into `k` blocks of `m = n / k`. The first
+/// `log(k)` stages of a decimation-in-frequency IFFT combine pairs of blocks,
+/// and run as soon as both blocks of a pair are complete; the last `log(m)`
+/// stages are an IFFT of each block on its own, which runs as soon as it has
+/// gone through the first ones. `finalize` only permutes and scales.
+#[derive(Clone, Debug)]
+pub struct IfftBuilder<T, F: FftField> {
+    domain: Radix2EvaluationDomain<F>,
+    evals: Vec<T>,
+    /// The number of evaluations absorbed so far.
+    len: usize,
+    block_size: usize,
+    /// The number of stages each complete block has gone through, where
+    /// the last one is its own IFFT.
+    stages: Vec<Option<u32>>,
+}
+
+impl<T: DomainCoeff<F>, F: FftField> IfftBuilder<T, F> {
+    /// Starts the IFFT over `domain`, with blocks of `block_size`
+    /// evaluations. Smaller blocks start the work earlier, but spend more of
+    /// it on stages between blocks.
+    ///
+    /// Panics if `block_size` is not a power of two at most the size of
+    /// `domain`.
+    pub fn new(domain: Radix2EvaluationDomain<F>, block_size: usize) -> Self {
+        assert!(
+            block_size.is_power_of_two() && block_size <= domain.size(),
+            "blocks must be powers of two which divide the domain"
+        );
+        IfftBuilder {
+            domain,
+            evals: vec![T::zero(); domain.size()],
+            len: 0,
+            block_size,
+            stages: vec![None; domain.size() / block_size],
+        }
+    }
+
+    /// Returns the number of evaluations absorbed so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no evaluations have been absorbed yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Absorbs the next evaluations, and runs every stage of the IFFT whose
+    /// blocks are complete.
+    ///
+    /// Returns `SizeMismatch` if there would be more evaluations than
+    /// elements of the domain, in which case none of `chunk` is absorbed.
+    pub fn absorb_chunk(&mut self, chunk: &[T]) -> Result<(), SunziError> {
+        let end = self.len + chunk.len();
+        if end > self.evals.len() {
+            return Err(SunziError::SizeMismatch {
+                expected: self.evals.len(),
+                found: end,
+            });
+        }
+        self.evals[self.len..end].copy_from_slice(chunk);
+        let first_block = self.len / self.block_size;
+        self.len = end;
+        for block in first_block..end / self.block_size {
+            self.stages[block] = Some(0);
+            self.advance(block);
+        }
+        Ok(())
+    }
+
+    /// Returns the coefficients of the polynomial with the absorbed
+    /// evaluations, like `EvaluationDomain::ifft`.
+    ///
+    /// Returns `SizeMismatch` if the evaluations do not span the domain.
+    pub fn finalize(self) -> Result<Vec<T>, SunziError> {
+        if self.len != self.evals.len() {
+            return Err(SunziError::SizeMismatch {
+                expected: self.evals.len(),
+                found: self.len,
+            });
+        }
+        record!(fft, self.len);
+        // The `r`-th value of block `b` is the coefficient of index
+        // `r k + bitreverse(b)`.
+        let num_blocks = self.stages.len();
+        let log_blocks = num_blocks.trailing_zeros();
+        let mut coeffs = vec![T::zero(); self.len];
+        for (b, block) in self.evals.chunks(self.block_size).enumerate() {
+            let offset = bitreverse(b as u32, log_blocks) as usize;
+            for (r, val) in block.iter().enumerate() {
+                let mut coeff = *val;
+                coeff *= self.domain.size_inv;
+                coeffs[r * num_blocks + offset] = coeff;
+            }
+        }
+        Ok(coeffs)
+    }
+
+    /// Runs the stages that `block`, now complete, unlocks, along with
+    /// those that these unlock in turn.
+    fn advance(&mut self, block: usize) {
+        let num_blocks = self.stages.len();
+        let log_blocks = num_blocks.trailing_zeros();
+        let mut pending = vec![block];
+        while let Some(b) = pending.pop() {
+            let stage = self.stages[b].unwrap();
+            if stage == log_blocks {
+                let omega = self.domain.group_gen_inv.pow([num_blocks as u64]);
+                let log_size = self.block_size.trailing_zeros();
+                let start = b * self.block_size;
+                serial_radix2_fft(
+                    &mut self.evals[start..start + self.block_size],
+                    omega,
+                    log_size,
+                );
+                self.stages[b] = Some(stage + 1);
+                continue;
+            }
+            // At this stage, blocks `distance` apart are paired.
+            let distance = num_blocks >> (stage + 1);
+            let partner = b ^ distance;
+            if self.stages[partner] == Some(stage) {
+                let (low, high) = (b.min(partner), b.max(partner));
+                self.butterflies(low, high, stage);
+                self.stages[low] = Some(stage + 1);
+                self.stages[high] = Some(stage + 1);
+                pending.push(low);
+                pending.push(high);
+            }
+        }
+    }
+
+    /// Runs the butterflies of `stage` between the blocks `low < high`.
+    fn butterflies(&mut self, low: usize, high: usize, stage: u32) {
+        let distance = high - low;
+        let step = self.domain.group_gen_inv.pow([1 << stage]);
+        let mut twiddle = step.pow([((low & (distance - 1)) * self.block_size) as u64]);
+        let (head, tail) = self.evals.split_at_mut(high * self.block_size);
+        let low_block = &mut head[low * self.block_size..][..self.block_size];
+        for (a, b) in low_block.iter_mut().zip(&mut tail[..self.block_size]) {
+            let mut diff = *a;
+            diff -= *b;
+            diff *= twiddle;
+            *a += *b;
+            *b = diff;
+            twiddle *= &step;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IfftBuilder;
+    use crate::{
+        fft::{EvaluationDomain, Radix2EvaluationDomain},
+        SunziError,
+    };
+    use algebra::bls12_381::Fr;
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    #[test]
+    fn streaming_ifft_test() {
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::<Fr>::new(64).unwrap();
+        let evals: Vec<Fr> = (0..64).map(|_| rng.gen()).collect();
+        let coeffs = domain.ifft(&evals);
+        for block_size in [1, 4, 16, 64] {
+            let mut builder = IfftBuilder::new(domain, block_size);
+            for chunk in evals.chunks(7) {
+                builder.absorb_chunk(chunk).unwrap();
+            }
+            assert_eq!(builder.finalize().unwrap(), coeffs);
+        }
+
+        let mut builder = IfftBuilder::new(domain, 8);
+        builder.absorb_chunk(&evals[..60]).unwrap();
+        assert_eq!(
+            builder.absorb_chunk(&evals[..8]),
+            Err(SunziError::SizeMismatch {
+                expected: 64,
+                found: 68
+            })
+        );
+        assert_eq!(builder.len(), 60);
+        assert_eq!(
+            builder.finalize(),
+            Err(SunziError::SizeMismatch {
+                expected: 64,
+                found: 60
+            })
+        );
+    }
+}
@@ -1,18 +1,38 @@
+use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use crate::FftField;
+use crate::{FftField, Field, SparsePolynomial, SunziError, Vec, Zero};
 
-use super::DomainCoeff;
+use super::{DomainCoeff, EvaluationDomain};
 
+/// Returns the `l` low bits of `n` in reverse order, ignoring its higher
+/// bits: the index to which `n` moves in the bit-reversal permutation of
+/// `2^l` elements, which radix-2 FFTs apply and FRI folds in.
+///
+/// Panics if `l` is larger than 32.
 #[inline]
-pub(crate) fn bitreverse(mut n: u32, l: u32) -> u32 {
-    let mut r = 0;
-    for _ in 0..l {
-        r = (r << 1) | (n & 1);
-        n >>= 1;
-    }
-    r
+pub fn bitreverse(n: u32, l: u32) -> u32 {
+    assert!(l <= 32, "too many bits to reverse");
+    bitreverse_u64(n as u64, l) as u32
+}
+
+/// Like `bitreverse`, for indices of up to 64 bits.
+///
+/// Panics if `l` is larger than 64.
+#[inline]
+pub fn bitreverse_u64(n: u64, l: u32) -> u64 {
+    assert!(l <= 64, "too many bits to reverse");
+    n.reverse_bits().checked_shr(64 - l).unwrap_or(0)
+}
+
+/// Returns the bit-reversal permutation of `2^log_n` elements, whose `i`-th
+/// entry is `bitreverse(i, log_n)`: the index of the `i`-th element of a
+/// domain in outputs in bit-reversed order.
+pub fn bitrev_permutation_indices(log_n: u32) -> Vec<usize> {
+    (0..1u64 << log_n)
+        .map(|i| bitreverse_u64(i, log_n) as usize)
+        .collect()
 }
 
 #[cfg(feature = "parallel")]
@@ -31,6 +51,7 @@ pub(crate) fn best_fft<T: DomainCoeff<F>, F: FftField>(
         pow
     }
 
+    record!(fft, a.len());
     let num_cpus = rayon::current_num_threads();
     let log_cpus = log2_floor(num_cpus);
     if log_n <= log_cpus {
@@ -48,6 +69,7 @@ pub(crate) fn best_fft<T: DomainCoeff<F>, F: FftField>(
     log_n: u32,
     serial_fft: fn(&mut [T], F, u32),
 ) {
+    record!(fft, a.len());
     serial_fft(a, omega, log_n)
 }
 
@@ -117,3 +139,243 @@ impl<F: FftField> Iterator for Elements<F> {
         }
     }
 }
+
+/// The smallest number of powers computed by a single thread.
+const MIN_POWERS_PER_THREAD: usize = 1 << 10;
+
+/// Returns the number of powers computed per chunk when computing `n`
+/// powers, so that each thread gets about one chunk.
+fn powers_chunk_size(n: usize) -> usize {
+    #[cfg(feature = "parallel")]
+    let num_threads = rayon::current_num_threads();
+    #[cfg(not(feature = "parallel"))]
+    let num_threads = 1;
+
+    (n / num_threads + 1).max(MIN_POWERS_PER_THREAD)
+}
+
+/// An unbounded iterator over the powers `1, g, g^2, ...` of an element.
+#[derive(Clone, Debug)]
+pub struct Powers<F: Field> {
+    cur: F,
+    g: F,
+}
+
+impl<F: Field> Powers<F> {
+    pub fn new(g: F) -> Self {
+        Powers { cur: F::one(), g }
+    }
+}
+
+impl<F: Field> Iterator for Powers<F> {
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<F> {
+        let cur = self.cur;
+        self.cur *= &self.g;
+        Some(cur)
+    }
+}
+
+/// Returns the first `n` powers `1, g, ..., g^(n - 1)` of `g`, computed in
+/// parallel chunks if the `parallel` feature is enabled.
+pub fn powers<F: Field>(g: F, n: usize) -> Vec<F> {
+    let mut powers = vec![F::one(); n];
+    distribute_powers(&mut powers, g);
+    powers
+}
+
+/// Multiplies the `i`-th element of `coeffs` with the `i`-th power of `g`,
+/// in parallel chunks if the `parallel` feature is enabled. Each chunk starts
+/// from its own power of `g`.
+pub fn distribute_powers<T, F>(coeffs: &mut [T], g: F)
+where
+    T: Send + core::ops::MulAssign<F>,
+    F: Field,
+{
+    let chunk_size = powers_chunk_size(coeffs.len());
+    cfg_chunks_mut!(coeffs, chunk_size)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            let start = g.pow([(i * chunk_size) as u64]);
+            for (c, pow) in chunk.iter_mut().zip(Powers { cur: start, g }) {
+                *c *= pow;
+            }
+        });
+}
+
+/// Evaluates `poly` over `domain` for `EvaluationDomain::sparse_fft`. The
+/// exponents are reduced modulo the size `n` of the domain, since `ω^n = 1`.
+/// A term `c X^d` then takes the values `c ω^(d i)`, each the previous one
+/// times `ω^d`, so `k` terms cost about `2 k n` multiplications, against
+/// about `n log(n) / 2` for an FFT, which is used from `k = log(n) / 4`.
+pub(crate) fn sparse_fft<F, D>(domain: &D, poly: &SparsePolynomial<F>) -> Vec<F>
+where
+    F: FftField,
+    D: EvaluationDomain<F>,
+{
+    let n = domain.size();
+    let mut terms: Vec<(usize, F)> = poly.iter().map(|(d, c)| (d % n, *c)).collect();
+    terms.sort_unstable_by_key(|(d, _)| *d);
+    terms.dedup_by(|(d, c), (prev_d, prev_c)| {
+        let same = d == prev_d;
+        if same {
+            *prev_c += &*c;
+        }
+        same
+    });
+    terms.retain(|(_, c)| !c.is_zero());
+
+    let log_n = (usize::BITS - n.leading_zeros()) as usize;
+    if 4 * terms.len() >= log_n {
+        let mut coeffs = vec![F::zero(); n];
+        for (d, c) in terms {
+            coeffs[d] = c;
+        }
+        domain.fft_in_place(&mut coeffs);
+        return coeffs;
+    }
+
+    let omega = domain.elements().nth(1).unwrap_or_else(F::one);
+    let steps: Vec<F> = terms.iter().map(|(d, _)| omega.pow([*d as u64])).collect();
+    let chunk_size = powers_chunk_size(n);
+    let mut evals = vec![F::zero(); n];
+    cfg_chunks_mut!(evals, chunk_size)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            // The values of the terms at the first element of the chunk.
+            let start = (i * chunk_size) as u64;
+            let mut values: Vec<F> = terms
+                .iter()
+                .zip(&steps)
+                .map(|((_, c), step)| *c * step.pow([start]))
+                .collect();
+            for eval in chunk {
+                for (value, step) in values.iter_mut().zip(&steps) {
+                    *eval += &*value;
+                    *value *= step;
+                }
+            }
+        });
+    evals
+}
+
+/// Pads `values` with zeros to the size of `domain`, and returns them with
+/// a mask whose `i`-th element is true iff the `i`-th value is padding.
+/// Returns `SizeMismatch` if `values` is larger than the domain.
+pub fn pad_to_domain<T, F, D>(values: &[T], domain: &D) -> Result<(Vec<T>, Vec<bool>), SunziError>
+where
+    T: Copy + Zero,
+    F: FftField,
+    D: EvaluationDomain<F>,
+{
+    let size = domain.size();
+    if values.len() > size {
+        return Err(SunziError::SizeMismatch {
+            expected: size,
+            found: values.len(),
+        });
+    }
+    let mut padded = values.to_vec();
+    padded.resize(size, T::zero());
+    let mut mask = vec![false; values.len()];
+    mask.resize(size, true);
+    Ok((padded, mask))
+}
+
+/// Splits evaluations over a domain of size `n` into `k` vectors of size
+/// `n / k`, the `j`-th of which holds the values at `ω^(j + k i)` for all
+/// `i`: the evaluations over the coset `ω^j H` of the subdomain `H` of size
+/// `n / k`. Works on masks from `pad_to_domain` too.
+///
+/// Returns `SizeMismatch` if `k` does not divide `n`, and panics if `k` is
+/// zero.
+pub fn split_into_subdomain_chunks<T: Clone>(
+    evals: &[T],
+    k: usize,
+) -> Result<Vec<Vec<T>>, SunziError> {
+    assert!(k > 0, "cannot split into zero chunks");
+    let remainder = evals.len() % k;
+    if remainder != 0 {
+        return Err(SunziError::SizeMismatch {
+            expected: evals.len() + k - remainder,
+            found: evals.len(),
+        });
+    }
+    Ok((0..k)
+        .map(|j| evals.iter().skip(j).step_by(k).cloned().collect())
+        .collect())
+}
+
+/// Returns a random nonzero `g` with `g^n != 1` for the size `n` of
+/// `domain`, so that the coset `g H` of the domain `H` is disjoint from it
+/// and from all of its subdomains, as dividing by their vanishing
+/// polynomials over the coset requires.
+pub fn sample_coset_offset<F, D, R>(domain: &D, rng: &mut R) -> F
+where
+    F: FftField,
+    D: EvaluationDomain<F>,
+    R: Rng + ?Sized,
+{
+    let n = [domain.size() as u64];
+    loop {
+        let g = F::rand(rng);
+        if !g.is_zero() && !g.pow(n).is_one() {
+            return g;
+        }
+    }
+}
+
+/// Returns the `k` offsets `g, g^2, ..., g^k` for the multiplicative
+/// generator `g` of `F`, whose cosets of `domain` are pairwise disjoint and
+/// disjoint from the domain, for provers and verifiers to agree on them
+/// without sampling.
+///
+/// Panics if the field has fewer than `k + 1` cosets of the domain.
+pub fn coset_offsets<F, D>(domain: &D, k: usize) -> Vec<F>
+where
+    F: FftField,
+    D: EvaluationDomain<F>,
+{
+    let g = F::multiplicative_generator();
+    // `g^i H = g^j H` iff `(g^n)^i = (g^n)^j`, so the cosets are distinct
+    // while the powers of `g^n` are not one.
+    let g_n = g.pow([domain.size() as u64]);
+    let mut g_n_i = F::one();
+    let mut offsets = Vec::with_capacity(k);
+    for offset in Powers::new(g).skip(1).take(k) {
+        g_n_i *= &g_n;
+        assert!(
+            !g_n_i.is_one(),
+            "the field has too few cosets of the domain"
+        );
+        offsets.push(offset);
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bitrev_permutation_indices, bitreverse, bitreverse_u64};
+
+    #[test]
+    fn bitreverse_test() {
+        assert_eq!(bitreverse(0b0011, 4), 0b1100);
+        assert_eq!(bitreverse(0b1_0110, 4), 0b0110);
+        assert_eq!(bitreverse(5, 0), 0);
+        assert_eq!(bitreverse(1, 32), 1 << 31);
+        assert_eq!(bitreverse_u64(1, 64), 1 << 63);
+        assert_eq!(bitreverse_u64(0b1101 << 36, 40), 0b1011);
+        for l in 0..12 {
+            for n in 0..1 << l {
+                assert_eq!(bitreverse(bitreverse(n, l), l), n);
+            }
+        }
+
+        assert_eq!(bitrev_permutation_indices(0), [0]);
+        assert_eq!(bitrev_permutation_indices(3), [0, 4, 2, 6, 1, 5, 3, 7]);
+        let indices = bitrev_permutation_indices(10);
+        assert!(indices.iter().enumerate().all(|(i, j)| indices[*j] == i));
+    }
+}
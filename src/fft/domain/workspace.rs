@@ -0,0 +1,206 @@
+//! Reusable buffers for radix-2 FFTs, so that repeated transforms do not
+//! allocate, and the roots of unity of each stage are looked up instead of
+//! recomputed.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{FftField, Vec};
+
+use super::{
+    utils::{bitreverse, distribute_powers},
+    DomainCoeff, EvaluationDomain, Radix2EvaluationDomain,
+};
+
+/// The scratch buffer and twiddle factors of radix-2 FFTs over one domain at
+/// a time. Passing a workspace to `Radix2EvaluationDomain::fft_in_place_with`
+/// and the like reuses its memory between calls; switching to a domain of
+/// another size recomputes the twiddle factors into the same memory.
+#[derive(Clone, Debug)]
+pub struct FftWorkspace<T, F: FftField> {
+    buffer: Vec<T>,
+    /// `ω^j` for `j < n / 2`.
+    twiddles: Vec<F>,
+    /// `ω^(-j)` for `j < n / 2`.
+    inverse_twiddles: Vec<F>,
+    /// The generator of the domain of the twiddle factors, if any.
+    group_gen: Option<F>,
+}
+
+impl<T: DomainCoeff<F>, F: FftField> Default for FftWorkspace<T, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DomainCoeff<F>, F: FftField> FftWorkspace<T, F> {
+    pub fn new() -> Self {
+        FftWorkspace {
+            buffer: Vec::new(),
+            twiddles: Vec::new(),
+            inverse_twiddles: Vec::new(),
+            group_gen: None,
+        }
+    }
+
+    /// Computes the twiddle factors of `domain`, unless they are already
+    /// there.
+    fn prepare(&mut self, domain: &Radix2EvaluationDomain<F>) {
+        if self.group_gen == Some(domain.group_gen) {
+            return;
+        }
+        let half_size = domain.size() / 2;
+        for (twiddles, g) in [
+            (&mut self.twiddles, domain.group_gen),
+            (&mut self.inverse_twiddles, domain.group_gen_inv),
+        ] {
+            twiddles.clear();
+            twiddles.resize(half_size, F::one());
+            distribute_powers(twiddles, g);
+        }
+        self.group_gen = Some(domain.group_gen);
+    }
+}
+
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    /// Computes the FFT of `coeffs`, which must have the size of the domain,
+    /// in place, using the twiddle factors of `workspace`.
+    pub fn fft_in_place_with<T: DomainCoeff<F>>(
+        &self,
+        workspace: &mut FftWorkspace<T, F>,
+        coeffs: &mut [T],
+    ) {
+        assert_eq!(coeffs.len(), self.size(), "input must span the domain");
+        workspace.prepare(self);
+        radix2_fft_with_twiddles(coeffs, &workspace.twiddles, self.log_size_of_group);
+    }
+
+    /// Computes the inverse FFT of `evals`, which must have the size of the
+    /// domain, in place, using the twiddle factors of `workspace`.
+    pub fn ifft_in_place_with<T: DomainCoeff<F>>(
+        &self,
+        workspace: &mut FftWorkspace<T, F>,
+        evals: &mut [T],
+    ) {
+        assert_eq!(evals.len(), self.size(), "input must span the domain");
+        workspace.prepare(self);
+        radix2_fft_with_twiddles(evals, &workspace.inverse_twiddles, self.log_size_of_group);
+        cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
+    }
+
+    /// Computes the FFT of `coeffs`, padded with zeros to the size of the
+    /// domain, in the buffer of `workspace`, and returns it.
+    ///
+    /// Panics if `coeffs` is larger than the domain.
+    pub fn fft_with<'a, T: DomainCoeff<F>>(
+        &self,
+        workspace: &'a mut FftWorkspace<T, F>,
+        coeffs: &[T],
+    ) -> &'a mut [T] {
+        let mut buffer = self.fill_buffer(workspace, coeffs);
+        self.fft_in_place_with(workspace, &mut buffer);
+        workspace.buffer = buffer;
+        &mut workspace.buffer
+    }
+
+    /// Computes the inverse FFT of `evals`, padded with zeros to the size of
+    /// the domain, in the buffer of `workspace`, and returns it.
+    ///
+    /// Panics if `evals` is larger than the domain.
+    pub fn ifft_with<'a, T: DomainCoeff<F>>(
+        &self,
+        workspace: &'a mut FftWorkspace<T, F>,
+        evals: &[T],
+    ) -> &'a mut [T] {
+        let mut buffer = self.fill_buffer(workspace, evals);
+        self.ifft_in_place_with(workspace, &mut buffer);
+        workspace.buffer = buffer;
+        &mut workspace.buffer
+    }
+
+    // Takes the buffer out of `workspace` while the twiddle factors are in
+    // use, keeping its memory.
+    fn fill_buffer<T: DomainCoeff<F>>(
+        &self,
+        workspace: &mut FftWorkspace<T, F>,
+        input: &[T],
+    ) -> Vec<T> {
+        assert!(
+            input.len() <= self.size(),
+            "input is larger than the domain"
+        );
+        let mut buffer = core::mem::take(&mut workspace.buffer);
+        buffer.clear();
+        buffer.extend_from_slice(input);
+        buffer.resize(self.size(), T::zero());
+        buffer
+    }
+}
+
+/// An iterative radix-2 FFT, whose stage with butterflies of span `2m` uses
+/// `twiddles[j * n / (2m)] = ω^(j * n / (2m))` for `j < m`. The butterflies
+/// of a stage run in parallel if the `parallel` feature is enabled.
+fn radix2_fft_with_twiddles<T: DomainCoeff<F>, F: FftField>(
+    a: &mut [T],
+    twiddles: &[F],
+    log_n: u32,
+) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+    record!(fft, n);
+
+    for k in 0..n as u32 {
+        let rk = bitreverse(k, log_n);
+        if k < rk {
+            a.swap(rk as usize, k as usize);
+        }
+    }
+
+    let mut m = 1;
+    for _ in 0..log_n {
+        let stride = n / (2 * m);
+        cfg_chunks_mut!(a, 2 * m).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(m);
+            for (j, (x, y)) in lo.iter_mut().zip(hi).enumerate() {
+                let mut t = *y;
+                t *= twiddles[j * stride];
+                *y = *x;
+                *y -= t;
+                *x += t;
+            }
+        });
+        m *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    #[test]
+    fn fft_workspace_test() {
+        let rng = &mut test_rng();
+        let mut workspace = FftWorkspace::new();
+        for &log_size in [0, 1, 5, 3].iter() {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let coeffs: Vec<Fr> = (0..domain.size() - domain.size() / 2)
+                .map(|_| rng.gen())
+                .collect();
+
+            let evals = domain.fft(&coeffs);
+            assert_eq!(domain.fft_with(&mut workspace, &coeffs), &evals[..]);
+            assert_eq!(
+                domain.ifft_with(&mut workspace, &evals),
+                &domain.ifft(&evals)[..]
+            );
+
+            let mut in_place = evals.clone();
+            domain.ifft_in_place_with(&mut workspace, &mut in_place);
+            domain.fft_in_place_with(&mut workspace, &mut in_place);
+            assert_eq!(in_place, evals);
+        }
+    }
+}
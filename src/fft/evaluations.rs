@@ -2,12 +2,30 @@
 
 use core::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Sub, SubAssign};
 
-use crate::{FftField, Vec};
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    Error, FftField, SunziError, Vec,
+};
 
-use super::{DensePolynomial, EvaluationDomain, GeneralEvaluationDomain};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{
+    polynomial::{linear_chunk_size, map_in_place, zip_with},
+    DensePolynomial, EvaluationDomain, GeneralEvaluationDomain,
+};
 
 /// Stores a polynomial in evaluation form.
+///
+/// With the `rkyv` feature, the archived form of the evaluations can be read
+/// in place, for example from a memory-mapped file, without a
+/// deserialization pass.
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Evaluations<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
     /// The evaluations of a polynomial over the domain `D`
     pub evals: Vec<F>,
@@ -21,6 +39,73 @@ impl<F: FftField, D: EvaluationDomain<F>> Evaluations<F, D> {
         Self { evals, domain }
     }
 
+    /// Construct `Self` from evaluations and a domain, failing if there is
+    /// not exactly one evaluation per element of the domain.
+    pub fn try_from_vec_and_domain(evals: Vec<F>, domain: D) -> Result<Self, SunziError> {
+        if evals.len() != domain.size() {
+            return Err(SunziError::SizeMismatch {
+                expected: domain.size(),
+                found: evals.len(),
+            });
+        }
+        Ok(Self { evals, domain })
+    }
+
+    /// Return the domain `self` is defined over.
+    pub fn domain(&self) -> D {
+        self.domain
+    }
+
+    /// Returns the evaluations of `p(ω^k X)` over the domain, for `p` the
+    /// polynomial of `self` and `ω` the generator of the domain. See
+    /// `EvaluationDomain::rotate_evaluations`.
+    pub fn rotate(&self, k: isize) -> Self {
+        Self::from_vec_and_domain(self.domain.rotate_evaluations(&self.evals, k), self.domain)
+    }
+
+    /// Returns `∑ c_i e_i` for the pairs `(c_i, e_i)` of `terms`, in a
+    /// single pass over the evaluations, chunk by chunk and in parallel if
+    /// the `parallel` feature is enabled, rather than one pass per term as
+    /// with `+=`.
+    ///
+    /// Panics if `terms` is empty or if their domains are unequal.
+    pub fn linear_combination(terms: &[(F, &Self)]) -> Self {
+        let domain = terms.first().expect("no terms to combine").1.domain;
+        let len = terms[0].1.evals.len();
+        for (_, e) in terms {
+            assert_eq!(e.domain, domain, "domains are unequal");
+            assert_eq!(e.evals.len(), len, "evaluations have unequal lengths");
+        }
+
+        let chunk_size = linear_chunk_size();
+        let mut evals = vec![F::zero(); len];
+        cfg_chunks_mut!(evals, chunk_size)
+            .enumerate()
+            .for_each(|(i, chunk)| {
+                let start = i * chunk_size;
+                for (c, e) in terms {
+                    let e = &e.evals[start..start + chunk.len()];
+                    chunk.iter_mut().zip(e).for_each(|(acc, e)| *acc += *c * e);
+                }
+            });
+        Self::from_vec_and_domain(evals, domain)
+    }
+
+    /// Returns the evaluations in consecutive chunks of `chunk_size`, like
+    /// `DensePolynomial::coeffs_chunked`.
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn evals_chunked(&self, chunk_size: usize) -> core::slice::Chunks<'_, F> {
+        self.evals.chunks(chunk_size)
+    }
+
+    /// Returns the chunks of `evals_chunked` as a parallel iterator.
+    #[cfg(feature = "parallel")]
+    pub fn par_evals_chunked(&self, chunk_size: usize) -> rayon::slice::Chunks<'_, F> {
+        use rayon::prelude::*;
+        self.evals.par_chunks(chunk_size)
+    }
+
     /// Interpolate a polynomial from a list of evaluations
     pub fn interpolate_by_ref(&self) -> DensePolynomial<F> {
         DensePolynomial::from_coefficients_vec(self.domain.ifft(&self.evals))
@@ -61,10 +146,25 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> MulAssign<&'a Evaluations<F, D>>
     #[inline]
     fn mul_assign(&mut self, other: &'a Evaluations<F, D>) {
         assert_eq!(self.domain, other.domain, "domains are unequal");
-        self.evals
-            .iter_mut()
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a *= b);
+        zip_with(&mut self.evals, &other.evals, |a, b| *a *= b);
+    }
+}
+
+impl<'a, F: FftField, D: EvaluationDomain<F>> Mul<F> for &'a Evaluations<F, D> {
+    type Output = Evaluations<F, D>;
+
+    #[inline]
+    fn mul(self, scalar: F) -> Evaluations<F, D> {
+        let mut result = self.clone();
+        result *= scalar;
+        result
+    }
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> MulAssign<F> for Evaluations<F, D> {
+    #[inline]
+    fn mul_assign(&mut self, scalar: F) {
+        map_in_place(&mut self.evals, |e| *e *= scalar);
     }
 }
 
@@ -87,10 +187,17 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> AddAssign<&'a Evaluations<F, D>>
     #[inline]
     fn add_assign(&mut self, other: &'a Evaluations<F, D>) {
         assert_eq!(self.domain, other.domain, "domains are unequal");
-        self.evals
-            .iter_mut()
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a += b);
+        zip_with(&mut self.evals, &other.evals, |a, b| *a += b);
+    }
+}
+
+impl<'a, F: FftField, D: EvaluationDomain<F>> AddAssign<(F, &'a Evaluations<F, D>)>
+    for Evaluations<F, D>
+{
+    #[inline]
+    fn add_assign(&mut self, (c, other): (F, &'a Evaluations<F, D>)) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        zip_with(&mut self.evals, &other.evals, |a, b| *a += c * b);
     }
 }
 
@@ -113,10 +220,7 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> SubAssign<&'a Evaluations<F, D>>
     #[inline]
     fn sub_assign(&mut self, other: &'a Evaluations<F, D>) {
         assert_eq!(self.domain, other.domain, "domains are unequal");
-        self.evals
-            .iter_mut()
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a -= b);
+        zip_with(&mut self.evals, &other.evals, |a, b| *a -= b);
     }
 }
 
@@ -139,9 +243,66 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> DivAssign<&'a Evaluations<F, D>>
     #[inline]
     fn div_assign(&mut self, other: &'a Evaluations<F, D>) {
         assert_eq!(self.domain, other.domain, "domains are unequal");
-        self.evals
-            .iter_mut()
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a /= b);
+        zip_with(&mut self.evals, &other.evals, |a, b| *a /= b);
+    }
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> CanonicalSerialize for Evaluations<F, D> {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.domain.serialize_with_mode(writer, compress)?;
+        self.evals.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.domain.serialized_size(compress) + self.evals.serialized_size(compress)
+    }
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> CanonicalDeserialize for Evaluations<F, D> {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let domain = D::deserialize_with_mode(reader, compress, validate)?;
+        let evals: Vec<F> = Vec::deserialize_with_mode(reader, compress, validate)?;
+        if evals.len() != domain.size() {
+            return Err(Error("number of evaluations does not match the domain"));
+        }
+        Ok(Self::from_vec_and_domain(evals, domain))
+    }
+}
+
+impl_from_canonical!([F: FftField, D: EvaluationDomain<F>], Evaluations<F, D>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::Radix2EvaluationDomain, Field};
+    use algebra::bls12_381::fr::Fr;
+
+    #[test]
+    fn linear_combination_test() {
+        let domain = Radix2EvaluationDomain::<Fr>::new(64).unwrap();
+        let columns: Vec<Evaluations<Fr, _>> = (0..5u64)
+            .map(|j| {
+                let evals = (0..64u64)
+                    .map(|i| Fr::from_seed(&[i.to_le_bytes(), j.to_le_bytes()].concat()))
+                    .collect();
+                Evaluations::from_vec_and_domain(evals, domain)
+            })
+            .collect();
+        let coeffs: Vec<Fr> = (0..5u64).map(|j| Fr::from(j + 2)).collect();
+        let terms: Vec<_> = coeffs.iter().cloned().zip(&columns).collect();
+
+        let mut expected = &columns[0] * coeffs[0];
+        for (c, e) in &terms[1..] {
+            expected += (*c, *e);
+        }
+        assert_eq!(Evaluations::linear_combination(&terms), expected);
+
+        let mut scaled = columns[1].clone();
+        scaled *= Fr::from(3u64);
+        assert_eq!(scaled, &(&columns[1] + &columns[1]) + &columns[1]);
     }
 }
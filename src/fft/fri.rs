@@ -0,0 +1,130 @@
+//! Index arithmetic and folding for FRI over cosets of radix-2 subgroups.
+//!
+//! Evaluations over a coset `offset * H` of the subgroup `H` of order `n` are
+//! stored in bit-reversed order: position `i` holds the evaluation at
+//! `offset * g^bitreverse(i)`. In this layout the points `x` and `-x` are
+//! adjacent at positions `2i` and `2i + 1`, and folding maps them to position
+//! `i` of the next layer, which is again in bit-reversed order over the coset
+//! `offset^2 * H^2`.
+
+use core::ops::Range;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    fft::{domain::utils::bitreverse, EvaluationDomain, Radix2EvaluationDomain},
+    FftField, Vec,
+};
+
+/// Folds the bit-reversed evaluations `evals` of `f` over the coset
+/// `offset * H` into the bit-reversed evaluations of
+/// `f_even + beta * f_odd` over `offset^2 * H^2`, where
+/// `f(x) = f_even(x^2) + x * f_odd(x^2)`.
+///
+/// # Panics
+/// This method panics if `evals.len()` is not a power of two greater than
+/// one, or if `offset` is zero.
+pub fn fold_evaluations<F: FftField>(evals: &[F], beta: F, offset: F) -> Vec<F> {
+    let n = evals.len();
+    assert!(
+        n > 1 && n.is_power_of_two(),
+        "number of evaluations must be a power of two greater than one"
+    );
+    let domain = Radix2EvaluationDomain::<F>::new(n).expect("domain is too large");
+    let log_half = domain.log_size_of_group - 1;
+
+    let two_inv = F::from(2u64).inverse().unwrap();
+    let offset_inv = offset.inverse().expect("offset must be nonzero");
+    // `(2x)^{-1}` for the points `x = offset * g^j` with `j < n / 2`.
+    let mut half_point_inverses = Vec::with_capacity(n / 2);
+    let mut cur = offset_inv * &two_inv;
+    for _ in 0..n / 2 {
+        half_point_inverses.push(cur);
+        cur *= &domain.group_gen_inv;
+    }
+
+    cfg_chunks!(evals, 2)
+        .enumerate()
+        .map(|(i, pair)| {
+            let (a, b) = (pair[0], pair[1]);
+            let x_inv = half_point_inverses[bitreverse(i as u32, log_half) as usize];
+            (a + &b) * &two_inv + &(beta * &(a - &b) * &x_inv)
+        })
+        .collect()
+}
+
+/// Returns the point `offset * g^bitreverse(index)` of the coset
+/// `offset * H` at which position `index` of a bit-reversed layer is
+/// evaluated.
+pub fn query_point<F: FftField>(domain: &Radix2EvaluationDomain<F>, offset: F, index: usize) -> F {
+    let exponent = bitreverse(index as u32, domain.log_size_of_group);
+    offset * &domain.group_gen.pow([u64::from(exponent)])
+}
+
+/// Returns the position of the evaluation at `-x` for the evaluation of `x`
+/// at position `index`.
+#[inline]
+pub fn sibling_index(index: usize) -> usize {
+    index ^ 1
+}
+
+/// Returns the position in the next layer that the evaluation at position
+/// `index` folds into.
+#[inline]
+pub fn folded_index(index: usize) -> usize {
+    index >> 1
+}
+
+/// Returns the positions of the coset of size `2^log_arity` containing
+/// `index`, i.e. the evaluations that are folded together when folding
+/// `log_arity` times in a row.
+#[inline]
+pub fn coset_indices(index: usize, log_arity: u32) -> Range<usize> {
+    let start = (index >> log_arity) << log_arity;
+    start..start + (1 << log_arity)
+}
+
+/// Returns the position queried in each of `num_layers` layers for a query
+/// at position `index` of the first layer.
+pub fn query_indices(index: usize, num_layers: usize) -> Vec<usize> {
+    (0..num_layers).map(|layer| index >> layer).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fft::{
+        domain::utils::bitreverse,
+        fri::{fold_evaluations, query_point},
+        DensePolynomial, EvaluationDomain, Radix2EvaluationDomain,
+    };
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn fold_matches_even_odd_decomposition() {
+        let rng = &mut test_rng();
+        let n = 32;
+        let poly = DensePolynomial::<Fr>::rand(n - 1, rng);
+        let (beta, offset) = (Fr::rand(rng), Fr::rand(rng));
+        let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+        let evals: Vec<_> = (0..n)
+            .map(|i| poly.evaluate(query_point(&domain, offset, i)))
+            .collect();
+
+        let folded = fold_evaluations(&evals, beta, offset);
+
+        let even: Vec<_> = poly.coeffs.iter().step_by(2).copied().collect();
+        let odd: Vec<_> = poly.coeffs.iter().skip(1).step_by(2).copied().collect();
+        let expected = &DensePolynomial::from_coefficients_vec(even)
+            + &DensePolynomial::from_coefficients_vec(odd.iter().map(|c| beta * c).collect());
+        let half = domain.halve().unwrap();
+        for (i, eval) in folded.iter().enumerate() {
+            let point = query_point(&half, offset * &offset, i);
+            assert_eq!(*eval, expected.evaluate(point));
+            let x = query_point(&domain, offset, 2 * i);
+            assert_eq!(x * &x, point);
+            assert_eq!(bitreverse(2 * i as u32, 5), bitreverse(i as u32, 4));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Encoding of the rows of evaluation matrices as fixed-size byte leaves,
+//! for committing to them with Merkle trees from other crates.
+//!
+//! A leaf is the length of the domain separator as one byte, the separator,
+//! the canonical integer values of the elements of one row, each on the
+//! byte length of `F::Bytes` in the chosen byte order, and zeros up to the
+//! size of the leaf. The layout depends only on the encoding, the field and
+//! the number of columns, so that verifiers recompute the same leaves.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{PrimeField, Vec};
+
+/// The byte order of the elements in a leaf.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+/// How `encode_leaves` lays out the leaves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeafEncoding<'a> {
+    /// The domain separator at the start of every leaf, of at most 255
+    /// bytes.
+    pub domain: &'a [u8],
+    /// The byte order of the elements.
+    pub endianness: Endianness,
+}
+
+impl<'a> LeafEncoding<'a> {
+    /// Returns the number of bytes of a leaf in use for rows of `cols`
+    /// elements of `F`, to choose the size of the leaves.
+    pub fn encoded_len<F: PrimeField>(&self, cols: usize) -> usize {
+        1 + self.domain.len() + cols * F::zero().to_bytes_le().as_ref().len()
+    }
+}
+
+/// Returns the leaves of `N` bytes of the rows of the row-major matrix
+/// `elems` of `cols` columns, in parallel if the `parallel` feature is
+/// enabled.
+///
+/// Panics if `cols` is zero or does not divide the length of `elems`, if
+/// the domain separator is longer than 255 bytes, or if the rows do not fit
+/// in `N` bytes.
+pub fn encode_leaves<F: PrimeField, const N: usize>(
+    elems: &[F],
+    cols: usize,
+    encoding: &LeafEncoding,
+) -> Vec<[u8; N]> {
+    assert!(cols > 0, "rows must not be empty");
+    assert_eq!(elems.len() % cols, 0, "the matrix has a partial row");
+    assert!(
+        encoding.domain.len() <= u8::MAX as usize,
+        "the domain separator is too long"
+    );
+    assert!(
+        encoding.encoded_len::<F>(cols) <= N,
+        "the rows do not fit in the leaves"
+    );
+
+    let start = 1 + encoding.domain.len();
+    cfg_chunks!(elems, cols)
+        .map(|row| {
+            let mut leaf = [0u8; N];
+            leaf[0] = encoding.domain.len() as u8;
+            leaf[1..start].copy_from_slice(encoding.domain);
+            let mut offset = start;
+            for elem in row {
+                let bytes = match encoding.endianness {
+                    Endianness::Little => elem.to_bytes_le(),
+                    Endianness::Big => elem.to_bytes_be(),
+                };
+                let bytes = bytes.as_ref();
+                leaf[offset..offset + bytes.len()].copy_from_slice(bytes);
+                offset += bytes.len();
+            }
+            leaf
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_leaves, Endianness, LeafEncoding};
+    use crate::PrimeField;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn encode_leaves_test() {
+        let rng = &mut test_rng();
+        let elems: Vec<Fr> = (0..12).map(|_| Fr::rand(rng)).collect();
+        let encoding = LeafEncoding {
+            domain: b"rows",
+            endianness: Endianness::Little,
+        };
+        assert_eq!(encoding.encoded_len::<Fr>(3), 1 + 4 + 3 * 32);
+
+        let leaves = encode_leaves::<Fr, 128>(&elems, 3, &encoding);
+        assert_eq!(leaves.len(), 4);
+        for (leaf, row) in leaves.iter().zip(elems.chunks(3)) {
+            assert_eq!(&leaf[..5], b"\x04rows");
+            for (j, elem) in row.iter().enumerate() {
+                let bytes = &leaf[5 + 32 * j..5 + 32 * (j + 1)];
+                assert_eq!(bytes, elem.to_bytes_le().as_ref());
+            }
+            assert!(leaf[5 + 3 * 32..].iter().all(|b| *b == 0));
+        }
+
+        let big = encode_leaves::<Fr, 101>(
+            &elems,
+            3,
+            &LeafEncoding {
+                endianness: Endianness::Big,
+                ..encoding
+            },
+        );
+        assert_eq!(&big[0][5..37], elems[0].to_bytes_be().as_ref());
+
+        // Another domain separator changes every leaf.
+        let other = encode_leaves::<Fr, 128>(&elems, 3, &LeafEncoding::default());
+        assert!(other.iter().zip(&leaves).all(|(a, b)| a != b));
+        assert_eq!(other[0][0], 0);
+    }
+}
@@ -73,13 +73,29 @@ macro_rules! cfg_chunks_mut {
 pub mod domain;
 
 pub mod evaluations;
+pub mod fri;
+pub mod leaves;
 pub mod polynomial;
+pub mod utils;
 
 pub use domain::{
-    EvaluationDomain, GeneralEvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain,
+    bitrev_permutation_indices, bitreverse, bitreverse_u64, coset_offsets, distribute_powers, fft_fixed, ifft_fixed, pad_to_domain, powers, sample_coset_offset, split_into_subdomain_chunks,
+    CosetEvaluator, DynDomain, EvaluationDomain, FftWorkspace, FixedRadix2Domain, GeneralEvaluationDomain, IfftBuilder, MixedRadixEvaluationDomain, Powers,
+    Radix2DomainWithTable, Radix2EvaluationDomain,
 };
+#[cfg(feature = "std")]
+pub use domain::{CachedDomain, DomainCache};
 pub use evaluations::Evaluations;
-pub use polynomial::{DenseOrSparsePolynomial, DensePolynomial, SparsePolynomial};
+pub use leaves::{encode_leaves, Endianness, LeafEncoding};
+#[cfg(feature = "std")]
+pub use polynomial::tune_mul_thresholds;
+pub use polynomial::{
+    batch_evaluate, fft_mul_threshold, karatsuba_threshold, linear_chunk_size,
+    set_fft_mul_threshold, set_karatsuba_threshold, set_linear_chunk_size, set_toom3_threshold,
+    toom3_threshold, DenseOrSparsePolynomial, DensePolynomial, RationalFunction, SmallPolynomial,
+    SparsePolynomial, VanishingPoly,
+};
+pub use utils::{transpose, transpose_columns, transpose_in_place};
 
 #[cfg(test)]
 mod test;
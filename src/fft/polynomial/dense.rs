@@ -2,7 +2,7 @@
 
 use core::{
     fmt,
-    ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use rand::Rng;
 
@@ -10,20 +10,33 @@ use rand::Rng;
 use rayon::prelude::*;
 
 use crate::{
+    ff::ring::Ring,
     fft::{DenseOrSparsePolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain},
-    FftField, Field, Vec,
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    Error, FftField, Field, SunziError, Vec,
+};
+
+use super::{
+    map_in_place,
+    mul::{fft_mul_threshold, karatsuba_mul, toom_mul},
+    zip_with,
 };
 
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default)]
-pub struct DensePolynomial<F: Field> {
+pub struct DensePolynomial<F: Ring> {
     /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
     pub coeffs: Vec<F>,
 }
 
-impl<F: Field> fmt::Debug for DensePolynomial<F> {
+impl<F: Ring> fmt::Debug for DensePolynomial<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        for (i, coeff) in self.coeffs.iter().enumerate().filter(|(_, c)| !c.is_zero()) {
+        for (i, coeff) in self
+            .coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero_element())
+        {
             if i == 0 {
                 write!(f, "\n{:?}", coeff)?;
             } else if i == 1 {
@@ -36,7 +49,7 @@ impl<F: Field> fmt::Debug for DensePolynomial<F> {
     }
 }
 
-impl<F: Field> Deref for DensePolynomial<F> {
+impl<F: Ring> Deref for DensePolynomial<F> {
     type Target = [F];
 
     fn deref(&self) -> &[F] {
@@ -44,13 +57,13 @@ impl<F: Field> Deref for DensePolynomial<F> {
     }
 }
 
-impl<F: Field> DerefMut for DensePolynomial<F> {
+impl<F: Ring> DerefMut for DensePolynomial<F> {
     fn deref_mut(&mut self) -> &mut [F] {
         &mut self.coeffs
     }
 }
 
-impl<F: Field> DensePolynomial<F> {
+impl<F: Ring> DensePolynomial<F> {
     /// Returns the zero polynomial.
     pub fn zero() -> Self {
         Self { coeffs: Vec::new() }
@@ -58,7 +71,7 @@ impl<F: Field> DensePolynomial<F> {
 
     /// Checks if the given polynomial is zero.
     pub fn is_zero(&self) -> bool {
-        self.coeffs.is_empty() || self.coeffs.iter().all(|coeff| coeff.is_zero())
+        self.coeffs.is_empty() || self.coeffs.iter().all(|coeff| coeff.is_zero_element())
     }
 
     /// Constructs a new polynomial from a list of coefficients.
@@ -73,7 +86,10 @@ impl<F: Field> DensePolynomial<F> {
         result.truncate_leading_zeros();
         // Check that either the coefficients vec is empty or that the last coeff is
         // non-zero.
-        assert!(result.coeffs.last().map_or(true, |coeff| !coeff.is_zero()));
+        assert!(result
+            .coeffs
+            .last()
+            .map_or(true, |coeff| !coeff.is_zero_element()));
 
         result
     }
@@ -83,23 +99,26 @@ impl<F: Field> DensePolynomial<F> {
         if self.is_zero() {
             0
         } else {
-            assert!(self.coeffs.last().map_or(false, |coeff| !coeff.is_zero()));
+            assert!(self
+                .coeffs
+                .last()
+                .map_or(false, |coeff| !coeff.is_zero_element()));
             self.coeffs.len() - 1
         }
     }
 
     fn truncate_leading_zeros(&mut self) {
-        while self.coeffs.last().map_or(false, |c| c.is_zero()) {
+        while self.coeffs.last().map_or(false, |c| c.is_zero_element()) {
             self.coeffs.pop();
         }
     }
 
-    /// Evaluates `self` at the given `point` in the field.
+    /// Evaluates `self` at the given `point` in the ring.
     pub fn evaluate(&self, point: F) -> F {
         if self.is_zero() {
-            return F::zero();
+            return F::zero_element();
         }
-        let mut powers_of_point = vec![F::one()];
+        let mut powers_of_point = vec![F::one_element()];
         let mut cur = point;
         for _ in 0..self.degree() {
             powers_of_point.push(cur);
@@ -112,12 +131,29 @@ impl<F: Field> DensePolynomial<F> {
             .sum()
     }
 
+    /// Returns the coefficients in consecutive chunks of `chunk_size`, the
+    /// last one possibly shorter, lowest degree first. Consumers such as
+    /// `StreamingEvaluator::absorb_chunks` and
+    /// `VariableBaseMSM::multi_scalar_mul_chunked` take chunks from any
+    /// source, so coefficients produced chunk by chunk need not be stored.
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn coeffs_chunked(&self, chunk_size: usize) -> core::slice::Chunks<'_, F> {
+        self.coeffs.chunks(chunk_size)
+    }
+
+    /// Returns the chunks of `coeffs_chunked` as a parallel iterator.
+    #[cfg(feature = "parallel")]
+    pub fn par_coeffs_chunked(&self, chunk_size: usize) -> rayon::slice::Chunks<'_, F> {
+        self.coeffs.par_chunks(chunk_size)
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
     pub fn naive_mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
         } else {
-            let mut result = vec![F::zero(); self.degree() + other.degree() + 1];
+            let mut result = vec![F::zero_element(); self.degree() + other.degree() + 1];
             for (i, self_coeff) in self.coeffs.iter().enumerate() {
                 for (j, other_coeff) in other.coeffs.iter().enumerate() {
                     result[i + j] += &(*self_coeff * other_coeff);
@@ -127,15 +163,149 @@ impl<F: Field> DensePolynomial<F> {
         }
     }
 
-    /// Outputs a polynomial of degree `d` where each coefficient is sampled
-    /// uniformly at random from the field `F`.
+    /// Multiplies `self` by `other` with Karatsuba's method, splitting
+    /// factors with at least `karatsuba_threshold()` coefficients in two.
+    pub fn karatsuba_mul(&self, other: &Self) -> Self {
+        Self::from_coefficients_vec(karatsuba_mul(&self.coeffs, &other.coeffs))
+    }
+
+    /// Returns `self * other` modulo `X^n + 1`, the product in the
+    /// negacyclic ring of RLWE-style schemes, without reducing the inputs
+    /// first.
+    ///
+    /// Panics if `n` is zero.
+    pub fn negacyclic_mul(&self, other: &Self, n: usize) -> Self {
+        assert!(n > 0, "the ring degree must be positive");
+        let mut result = vec![F::zero_element(); n];
+        for (i, self_coeff) in self.coeffs.iter().enumerate() {
+            for (j, other_coeff) in other.coeffs.iter().enumerate() {
+                // `X^(i + j) = (-1)^k X^((i + j) mod n)` for `k = (i + j) / n`.
+                let product = *self_coeff * other_coeff;
+                let k = i + j;
+                if (k / n) % 2 == 0 {
+                    result[k % n] += &product;
+                } else {
+                    result[k % n] -= &product;
+                }
+            }
+        }
+        DensePolynomial::from_coefficients_vec(result)
+    }
+
+    /// Checks if the degree of `self` is at most `d`. The zero polynomial
+    /// has degree at most `d` for every `d`.
+    pub fn is_degree_at_most(&self, d: usize) -> bool {
+        self.is_zero() || self.degree() <= d
+    }
+
+    /// Drops all coefficients of degree larger than `d`, i.e. reduces `self`
+    /// modulo `X^{d + 1}`.
+    pub fn truncate_to_degree(&mut self, d: usize) {
+        self.coeffs.truncate(d + 1);
+        self.truncate_leading_zeros();
+    }
+
+    /// Returns `self` reduced modulo `X^{d + 1}`.
+    pub fn truncated(&self, d: usize) -> Self {
+        let len = core::cmp::min(self.coeffs.len(), d + 1);
+        Self::from_coefficients_slice(&self.coeffs[..len])
+    }
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Evaluates `self` at the given `point` with a block-wise Horner's
+    /// method. The coefficients are split into one block per thread, each
+    /// block is evaluated with Horner's method, and the block results are
+    /// combined with the appropriate power of `point`. Unlike `evaluate`,
+    /// this does not allocate a table of powers of `point`.
+    pub fn evaluate_parallel(&self, point: F) -> F {
+        if self.is_zero() {
+            return F::zero();
+        }
+        #[cfg(feature = "parallel")]
+        let num_threads = rayon::current_num_threads();
+        #[cfg(not(feature = "parallel"))]
+        let num_threads = 1;
+
+        let chunk_size = (self.coeffs.len() + num_threads - 1) / num_threads;
+        cfg_chunks!(self.coeffs, chunk_size)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let chunk_eval = horner_evaluate(chunk, &point);
+                chunk_eval * &point.pow(&[(i * chunk_size) as u64])
+            })
+            .sum()
+    }
+
+    /// Multiplies `self` by `other` with Toom-3 for factors with at least
+    /// `toom3_threshold()` coefficients, and with Karatsuba's method below,
+    /// in `O(n^1.47)` field operations. Unlike `*`, this needs no roots of
+    /// unity.
+    pub fn toom_mul(&self, other: &Self) -> Self {
+        Self::from_coefficients_vec(toom_mul(&self.coeffs, &other.coeffs))
+    }
+
+    /// Outputs a polynomial of degree exactly `d` where each coefficient is
+    /// sampled uniformly at random from the field `F`, except that the
+    /// leading coefficient is resampled until it is nonzero. The result is
+    /// thus uniform among polynomials of degree `d`, not of degree at most
+    /// `d`.
     pub fn rand<R: Rng>(d: usize, rng: &mut R) -> Self {
         let mut random_coeffs = Vec::new();
         for _ in 0..=d {
             random_coeffs.push(F::rand(rng));
         }
+        // Resample the leading coefficient so that the degree is exactly `d`.
+        while random_coeffs[d].is_zero() {
+            random_coeffs[d] = F::rand(rng);
+        }
         Self::from_coefficients_vec(random_coeffs)
     }
+
+    /// Outputs a polynomial of degree `d` with uniformly random coefficients,
+    /// conditioned on the constant coefficient being nonzero.
+    pub fn rand_with_nonzero_constant<R: Rng>(d: usize, rng: &mut R) -> Self {
+        let mut result = Self::rand(d, rng);
+        while result.coeffs[0].is_zero() {
+            result.coeffs[0] = F::rand(rng);
+        }
+        result
+    }
+
+    /// Returns the monic greatest common divisor of `self` and `other`,
+    /// computed with the Euclidean algorithm. The gcd of two zero polynomials
+    /// is the zero polynomial.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let a_poly: DenseOrSparsePolynomial<F> = (&a).into();
+            let b_poly: DenseOrSparsePolynomial<F> = (&b).into();
+            let (_, r) = a_poly
+                .divide_with_q_and_r(&b_poly)
+                .expect("division failed");
+            a = core::mem::replace(&mut b, r);
+        }
+        a.into_monic()
+    }
+
+    /// Scales `self` so that its leading coefficient is one. The zero
+    /// polynomial is returned unchanged.
+    pub fn into_monic(mut self) -> Self {
+        if let Some(lc_inv) = self.coeffs.last().and_then(|c| c.inverse()) {
+            self.coeffs.iter_mut().for_each(|c| *c *= &lc_inv);
+        }
+        self
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `point` using
+/// Horner's method.
+#[inline]
+pub(crate) fn horner_evaluate<F: Ring>(coeffs: &[F], point: &F) -> F {
+    coeffs
+        .iter()
+        .rfold(F::zero_element(), |acc, coeff| acc * point + coeff)
 }
 
 impl<F: FftField> DensePolynomial<F> {
@@ -160,9 +330,52 @@ impl<F: FftField> DensePolynomial<F> {
         let vanishing_poly: DenseOrSparsePolynomial<F> = domain.vanishing_polynomial().into();
         self_poly.divide_with_q_and_r(&vanishing_poly)
     }
+
+    /// Runs the extended Euclidean algorithm on `self` and `other` and
+    /// returns `(g, s, t)` such that `s * self + t * other = g`, where `g` is
+    /// the monic greatest common divisor of `self` and `other`.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (g, s, t) = self.partial_xgcd(other, 0);
+        match g.coeffs.last().and_then(|c| c.inverse()) {
+            Some(lc_inv) => {
+                let scale = |mut p: Self| {
+                    p.coeffs.iter_mut().for_each(|c| *c *= &lc_inv);
+                    p
+                };
+                (scale(g), scale(s), scale(t))
+            }
+            None => (g, s, t),
+        }
+    }
+
+    /// Runs the extended Euclidean algorithm on `self` and `other`, stopping
+    /// at the first remainder of degree less than `degree_bound` (or at the
+    /// last nonzero remainder, if none is that small). Returns `(r, s, t)`
+    /// such that `s * self + t * other = r`.
+    ///
+    /// This is the partial gcd computation used by Gao's decoding algorithm
+    /// for Reed–Solomon codes.
+    pub fn partial_xgcd(&self, other: &Self, degree_bound: usize) -> (Self, Self, Self) {
+        let one = Self::from_coefficients_vec(vec![F::one()]);
+        let (mut r0, mut s0, mut t0) = (self.clone(), one.clone(), Self::zero());
+        let (mut r1, mut s1, mut t1) = (other.clone(), Self::zero(), one);
+        while r0.degree() >= degree_bound && !r1.is_zero() {
+            let r0_poly: DenseOrSparsePolynomial<F> = (&r0).into();
+            let r1_poly: DenseOrSparsePolynomial<F> = (&r1).into();
+            let (q, r) = r0_poly
+                .divide_with_q_and_r(&r1_poly)
+                .expect("division failed");
+            let s = &s0 - &(&q * &s1);
+            let t = &t0 - &(&q * &t1);
+            r0 = core::mem::replace(&mut r1, r);
+            s0 = core::mem::replace(&mut s1, s);
+            t0 = core::mem::replace(&mut t1, t);
+        }
+        (r0, s0, t0)
+    }
 }
 
-impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
+impl<'a, 'b, F: Ring> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
     fn add(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
@@ -172,15 +385,11 @@ impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
             self.clone()
         } else if self.degree() >= other.degree() {
             let mut result = self.clone();
-            for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
-                *a += b
-            }
+            zip_with(&mut result.coeffs, &other.coeffs, |a, b| *a += b);
             result
         } else {
             let mut result = other.clone();
-            for (a, b) in result.coeffs.iter_mut().zip(&self.coeffs) {
-                *a += b
-            }
+            zip_with(&mut result.coeffs, &self.coeffs, |a, b| *a += b);
             result
         };
         result.truncate_leading_zeros();
@@ -188,44 +397,36 @@ impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     }
 }
 
-impl<'a, 'b, F: Field> AddAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
+impl<'a, 'b, F: Ring> AddAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
     fn add_assign(&mut self, other: &'a DensePolynomial<F>) {
         if self.is_zero() {
             self.coeffs.truncate(0);
             self.coeffs.extend_from_slice(&other.coeffs);
         } else if other.is_zero() {
         } else if self.degree() >= other.degree() {
-            for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
-                *a += b
-            }
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a += b);
         } else {
             // Add the necessary number of zero coefficients.
-            self.coeffs.resize(other.coeffs.len(), F::zero());
-            for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
-                *a += b
-            }
+            self.coeffs.resize(other.coeffs.len(), F::zero_element());
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a += b);
             self.truncate_leading_zeros();
         }
     }
 }
 
-impl<'a, 'b, F: Field> AddAssign<(F, &'a DensePolynomial<F>)> for DensePolynomial<F> {
+impl<'a, 'b, F: Ring> AddAssign<(F, &'a DensePolynomial<F>)> for DensePolynomial<F> {
     fn add_assign(&mut self, (f, other): (F, &'a DensePolynomial<F>)) {
         if self.is_zero() {
             self.coeffs.truncate(0);
             self.coeffs.extend_from_slice(&other.coeffs);
-            self.coeffs.iter_mut().for_each(|c| *c *= &f);
+            map_in_place(&mut self.coeffs, |c| *c *= &f);
         } else if other.is_zero() {
         } else if self.degree() >= other.degree() {
-            for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
-                *a += &(f * b);
-            }
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a += &(f * b));
         } else {
             // Add the necessary number of zero coefficients.
-            self.coeffs.resize(other.coeffs.len(), F::zero());
-            for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
-                *a += &(f * b);
-            }
+            self.coeffs.resize(other.coeffs.len(), F::zero_element());
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a += &(f * b));
             self.truncate_leading_zeros();
         }
     }
@@ -246,45 +447,96 @@ impl<F: FftField> DensePolynomial<F> {
         let poly: DenseOrSparsePolynomial<'_, F> = self.into();
         DenseOrSparsePolynomial::<F>::evaluate_over_domain(poly, domain)
     }
+
+    /// Evaluate `self` over `domain`, failing if `self` has more coefficients
+    /// than `domain` has elements, in which case the evaluations would not
+    /// determine `self`.
+    pub fn try_evaluate_over_domain_by_ref<D: EvaluationDomain<F>>(
+        &self,
+        domain: D,
+    ) -> Result<Evaluations<F, D>, SunziError> {
+        self.check_fits(domain)?;
+        Ok(self.evaluate_over_domain_by_ref(domain))
+    }
+
+    /// Evaluate `self` over `domain`, failing if `self` has more coefficients
+    /// than `domain` has elements.
+    pub fn try_evaluate_over_domain<D: EvaluationDomain<F>>(
+        self,
+        domain: D,
+    ) -> Result<Evaluations<F, D>, SunziError> {
+        self.check_fits(domain)?;
+        Ok(self.evaluate_over_domain(domain))
+    }
+
+    fn check_fits<D: EvaluationDomain<F>>(&self, domain: D) -> Result<(), SunziError> {
+        if self.coeffs.len() > domain.size() {
+            return Err(SunziError::DegreeTooLarge {
+                degree: self.degree(),
+                domain_size: domain.size(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> From<Evaluations<F, D>> for DensePolynomial<F> {
+    fn from(evals: Evaluations<F, D>) -> Self {
+        evals.interpolate()
+    }
 }
 
-impl<F: Field> Neg for DensePolynomial<F> {
+impl<F: Ring> Neg for DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
     #[inline]
     fn neg(mut self) -> DensePolynomial<F> {
-        for coeff in &mut self.coeffs {
-            *coeff = -*coeff;
-        }
+        map_in_place(&mut self.coeffs, |coeff| *coeff = -*coeff);
         self
     }
 }
 
-impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
+impl<'a, F: Ring> Mul<F> for &'a DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    #[inline]
+    fn mul(self, scalar: F) -> DensePolynomial<F> {
+        let mut result = self.clone();
+        result *= scalar;
+        result
+    }
+}
+
+impl<F: Ring> MulAssign<F> for DensePolynomial<F> {
+    #[inline]
+    fn mul_assign(&mut self, scalar: F) {
+        if scalar.is_zero_element() {
+            self.coeffs.clear();
+        } else {
+            map_in_place(&mut self.coeffs, |coeff| *coeff *= scalar);
+        }
+    }
+}
+
+impl<'a, 'b, F: Ring> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
     #[inline]
     fn sub(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
         let mut result = if self.is_zero() {
             let mut result = other.clone();
-            for coeff in &mut result.coeffs {
-                *coeff = -(*coeff);
-            }
+            map_in_place(&mut result.coeffs, |coeff| *coeff = -*coeff);
             result
         } else if other.is_zero() {
             self.clone()
         } else if self.degree() >= other.degree() {
             let mut result = self.clone();
-            for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
-                *a -= b
-            }
+            zip_with(&mut result.coeffs, &other.coeffs, |a, b| *a -= b);
             result
         } else {
             let mut result = self.clone();
-            result.coeffs.resize(other.coeffs.len(), F::zero());
-            for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
-                *a -= b;
-            }
+            result.coeffs.resize(other.coeffs.len(), F::zero_element());
+            zip_with(&mut result.coeffs, &other.coeffs, |a, b| *a -= b);
             result
         };
         result.truncate_leading_zeros();
@@ -292,25 +544,19 @@ impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     }
 }
 
-impl<'a, 'b, F: Field> SubAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
+impl<'a, 'b, F: Ring> SubAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
     #[inline]
     fn sub_assign(&mut self, other: &'a DensePolynomial<F>) {
         if self.is_zero() {
-            self.coeffs.resize(other.coeffs.len(), F::zero());
-            for (i, coeff) in other.coeffs.iter().enumerate() {
-                self.coeffs[i] -= coeff;
-            }
+            self.coeffs.resize(other.coeffs.len(), F::zero_element());
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a -= b);
         } else if other.is_zero() {
         } else if self.degree() >= other.degree() {
-            for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
-                *a -= b
-            }
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a -= b);
         } else {
             // Add the necessary number of zero coefficients.
-            self.coeffs.resize(other.coeffs.len(), F::zero());
-            for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
-                *a -= b
-            }
+            self.coeffs.resize(other.coeffs.len(), F::zero_element());
+            zip_with(&mut self.coeffs, &other.coeffs, |a, b| *a -= b);
             // If the leading coefficient ends up being zero, pop it off.
             self.truncate_leading_zeros();
         }
@@ -328,7 +574,8 @@ impl<'a, 'b, F: Field> Div<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     }
 }
 
-/// Performs O(nlogn) multiplication of polynomials if F is smooth.
+/// Performs O(nlogn) multiplication of polynomials if F is smooth, or
+/// `toom_mul` if a factor has fewer than `fft_mul_threshold()` coefficients.
 impl<'a, 'b, F: FftField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
@@ -336,6 +583,8 @@ impl<'a, 'b, F: FftField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F>
     fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
+        } else if self.coeffs.len().min(other.coeffs.len()) < fft_mul_threshold() {
+            self.toom_mul(other)
         } else {
             let domain = GeneralEvaluationDomain::new(self.coeffs.len() + other.coeffs.len())
                 .expect("field is not smooth enough to construct domain");
@@ -347,10 +596,33 @@ impl<'a, 'b, F: FftField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F>
     }
 }
 
+impl<F: Field> CanonicalSerialize for DensePolynomial<F> {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.coeffs.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coeffs.serialized_size(compress)
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for DensePolynomial<F> {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let coeffs = Vec::deserialize_with_mode(reader, compress, validate)?;
+        Ok(Self::from_coefficients_vec(coeffs))
+    }
+}
+
+impl_from_canonical!([F: Field], DensePolynomial<F>);
+
 #[cfg(test)]
 mod tests {
     use crate::polynomial::*;
-    use crate::{EvaluationDomain, GeneralEvaluationDomain};
+    use crate::{EvaluationDomain, Evaluations, GeneralEvaluationDomain, SunziError};
     use algebra::bls12_381::fr::Fr;
     use algebra_core::{test_rng, Field, One, UniformRand, Zero};
 
@@ -413,6 +685,33 @@ mod tests {
         assert_eq!(res1, -res2, "p2 - p1 = -(p1 - p2)");
     }
 
+    #[test]
+    fn linear_passes_in_chunks() {
+        let rng = &mut test_rng();
+        let p1 = DensePolynomial::<Fr>::rand(100, rng);
+        let p2 = DensePolynomial::<Fr>::rand(70, rng);
+        let f = Fr::rand(rng);
+        let expected = (&p1 + &p2, &p1 - &p2, &p2 - &p1, &p1 * f, &p1 * &p2);
+
+        // The results do not depend on the chunk size.
+        crate::fft::set_linear_chunk_size(7);
+        assert_eq!(crate::fft::linear_chunk_size(), 7);
+        let mut sum = p2.clone();
+        sum += &p1;
+        let mut scaled_sum = p1.clone();
+        scaled_sum += (f, &p2);
+        assert_eq!(&p1 + &p2, expected.0);
+        assert_eq!(sum, expected.0);
+        assert_eq!(&p1 - &p2, expected.1);
+        assert_eq!(&p2 - &p1, expected.2);
+        assert_eq!(-(&p1 - &p2), expected.2);
+        assert_eq!(&p1 * f, expected.3);
+        assert_eq!(scaled_sum, &p1 + &(&p2 * f));
+        assert_eq!(&p1 * &p2, expected.4);
+        assert!((&p1 * Fr::zero()).is_zero());
+        crate::fft::set_linear_chunk_size(crate::fft::polynomial::DEFAULT_LINEAR_CHUNK_SIZE);
+    }
+
     #[test]
     fn divide_polynomials_fixed() {
         let dividend = DensePolynomial::from_coefficients_slice(&[
@@ -463,6 +762,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn evaluate_parallel_and_streaming() {
+        let rng = &mut test_rng();
+        for a_degree in 0..70 {
+            let p = DensePolynomial::rand(a_degree, rng);
+            let point = Fr::rand(rng);
+            let eval = p.evaluate(point);
+            assert_eq!(p.evaluate_parallel(point), eval);
+            assert_eq!(
+                StreamingEvaluator::evaluate(p.coeffs.iter().cloned(), point),
+                eval
+            );
+        }
+    }
+
+    #[test]
+    fn coefficients_in_chunks() {
+        let rng = &mut test_rng();
+        let p = DensePolynomial::<Fr>::rand(99, rng);
+        let point = Fr::rand(rng);
+        for &chunk_size in [1, 7, 100, 128].iter() {
+            let chunks: Vec<_> = p.coeffs_chunked(chunk_size).collect();
+            assert_eq!(chunks.len(), (100 + chunk_size - 1) / chunk_size);
+            assert_eq!(chunks.concat(), p.coeffs);
+
+            let mut evaluator = StreamingEvaluator::new(point);
+            evaluator.absorb_chunks(p.coeffs_chunked(chunk_size));
+            assert_eq!(evaluator.finalize(), p.evaluate(point));
+        }
+
+        let domain = GeneralEvaluationDomain::new(64).unwrap();
+        let evals = p.evaluate_over_domain_by_ref(domain);
+        let chunks: Vec<_> = evals.evals_chunked(10).collect();
+        assert_eq!(chunks.len(), 7);
+        assert_eq!(chunks.concat(), evals.evals);
+    }
+
+    #[test]
+    fn polynomials_over_composite_ring() {
+        use crate::ff::ring::{Ring, Zq};
+
+        type R = Zq<{ 1 << 32 }>;
+        let rng = &mut test_rng();
+        let n = 16;
+        let mut random = || -> DensePolynomial<R> {
+            let coeffs = (0..n).map(|_| R::new(rng.gen())).collect();
+            DensePolynomial::from_coefficients_vec(coeffs)
+        };
+        let (a, b) = (random(), random());
+        assert_eq!(&(&a + &b) - &b, a);
+        assert_eq!((-a.clone()).evaluate(R::new(3)), -a.evaluate(R::new(3)));
+
+        // Reducing the full product modulo `X^n + 1` by hand.
+        let full = a.naive_mul(&b);
+        let mut reduced = vec![R::zero_element(); n];
+        for (i, c) in full.coeffs.iter().enumerate() {
+            if i < n {
+                reduced[i] += c;
+            } else {
+                reduced[i - n] -= c;
+            }
+        }
+        assert_eq!(a.karatsuba_mul(&b), full);
+        let product = a.negacyclic_mul(&b, n);
+        assert_eq!(product, DensePolynomial::from_coefficients_vec(reduced));
+
+        // `X^(n - 1) * X = -1`.
+        let mut x_n_minus_one = vec![R::zero_element(); n];
+        x_n_minus_one[n - 1] = R::one_element();
+        let x_n_minus_one = DensePolynomial::from_coefficients_vec(x_n_minus_one);
+        let x = DensePolynomial::from_coefficients_vec(vec![R::zero_element(), R::one_element()]);
+        assert_eq!(
+            x_n_minus_one.negacyclic_mul(&x, n).coeffs,
+            vec![-R::one_element()]
+        );
+    }
+
     #[test]
     fn mul_polynomials_random() {
         let rng = &mut test_rng();
@@ -475,6 +851,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_polynomials_subquadratic() {
+        let rng = &mut test_rng();
+        for &(a_degree, b_degree) in [(0, 5), (31, 31), (40, 100), (299, 257), (700, 40)].iter() {
+            let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let product = a.naive_mul(&b);
+            assert_eq!(a.karatsuba_mul(&b), product);
+            assert_eq!(a.toom_mul(&b), product);
+        }
+
+        // Above `fft_mul_threshold()`, `*` uses FFTs.
+        let a = DensePolynomial::<Fr>::rand(1000, rng);
+        let b = DensePolynomial::<Fr>::rand(600, rng);
+        assert_eq!(&a * &b, a.toom_mul(&b));
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut test_rng();
@@ -516,4 +909,96 @@ mod tests {
         assert_eq!(y.degree(), n - 1);
         assert!(!y.coeffs.last().unwrap().is_zero());
     }
+
+    #[test]
+    fn checked_evaluate_over_domain() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let p = DensePolynomial::<Fr>::rand(7, rng);
+        let evals = p.try_evaluate_over_domain_by_ref(domain).unwrap();
+        assert_eq!(evals, p.clone().evaluate_over_domain(domain));
+        assert_eq!(DensePolynomial::from(evals), p);
+
+        let q = DensePolynomial::<Fr>::rand(8, rng);
+        assert_eq!(
+            q.try_evaluate_over_domain(domain),
+            Err(SunziError::DegreeTooLarge {
+                degree: 8,
+                domain_size: 8
+            })
+        );
+
+        let evals = vec![Fr::one(); 7];
+        assert_eq!(
+            Evaluations::try_from_vec_and_domain(evals, domain),
+            Err(SunziError::SizeMismatch {
+                expected: 8,
+                found: 7
+            })
+        );
+    }
+
+    #[test]
+    fn batch_evaluate_matches_evaluate() {
+        let rng = &mut test_rng();
+        let polys: Vec<_> = [0, 3, 17, 64, 5]
+            .iter()
+            .map(|&d| DensePolynomial::<Fr>::rand(d, rng))
+            .chain(core::iter::once(DensePolynomial::zero()))
+            .collect();
+        let point = Fr::rand(rng);
+        let evals = batch_evaluate(&polys, point);
+        assert_eq!(evals.len(), polys.len());
+        for (p, eval) in polys.iter().zip(evals) {
+            assert_eq!(p.evaluate(point), eval);
+        }
+        assert!(batch_evaluate::<Fr>(&[], point).is_empty());
+    }
+
+    #[test]
+    fn rand_has_exact_degree() {
+        let rng = &mut test_rng();
+        for degree in 0..20 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            assert_eq!(p.degree(), degree);
+            assert_eq!(p.coeffs.len(), degree + 1);
+            let q = DensePolynomial::<Fr>::rand_with_nonzero_constant(degree, rng);
+            assert_eq!(q.degree(), degree);
+            assert!(!q.coeffs[0].is_zero());
+        }
+    }
+
+    #[test]
+    fn degree_bounds_and_truncation() {
+        let rng = &mut test_rng();
+        let zero = DensePolynomial::<Fr>::zero();
+        assert!(zero.is_degree_at_most(0));
+        assert!(zero.truncated(3).is_zero());
+
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+        assert!(p.is_degree_at_most(10));
+        assert!(p.is_degree_at_most(11));
+        assert!(!p.is_degree_at_most(9));
+
+        for d in [0, 4, 10, 15] {
+            let truncated = p.truncated(d);
+            let len = core::cmp::min(d + 1, p.coeffs.len());
+            assert_eq!(
+                truncated,
+                DensePolynomial::from_coefficients_slice(&p.coeffs[..len])
+            );
+            assert!(truncated.is_degree_at_most(d));
+            let mut q = p.clone();
+            q.truncate_to_degree(d);
+            assert_eq!(q, truncated);
+        }
+
+        // Truncation drops the leading zeros it uncovers.
+        let mut coeffs = vec![Fr::one(), Fr::zero(), Fr::zero()];
+        coeffs.extend_from_slice(&p.coeffs);
+        let mut q = DensePolynomial::from_coefficients_vec(coeffs);
+        q.truncate_to_degree(2);
+        assert_eq!(q.coeffs, vec![Fr::one()]);
+        assert_eq!(q.degree(), 0);
+    }
 }
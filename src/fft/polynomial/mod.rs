@@ -1,16 +1,109 @@
 //! Work with sparse and dense polynomials.
 
-use core::convert::TryInto;
+use core::{
+    convert::TryInto,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use crate::{Cow, EvaluationDomain, Evaluations, FftField, Field, Vec};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use DenseOrSparsePolynomial::*;
 
 mod dense;
+mod mul;
+pub mod multilinear;
+mod rational;
+mod small;
 mod sparse;
+mod streaming;
+mod vanishing;
 
 pub use dense::DensePolynomial;
+#[cfg(feature = "std")]
+pub use mul::tune_mul_thresholds;
+pub use mul::{
+    fft_mul_threshold, karatsuba_threshold, set_fft_mul_threshold, set_karatsuba_threshold,
+    set_toom3_threshold, toom3_threshold, DEFAULT_FFT_MUL_THRESHOLD, DEFAULT_KARATSUBA_THRESHOLD,
+    DEFAULT_TOOM3_THRESHOLD,
+};
+pub use multilinear::{DenseMultilinearPolynomial, SparseMultilinearPolynomial};
+pub use rational::RationalFunction;
+pub use small::SmallPolynomial;
 pub use sparse::SparsePolynomial;
+pub use streaming::StreamingEvaluator;
+pub use vanishing::VanishingPoly;
+
+/// Evaluates each of `polys` at the same `point`. The powers of `point` are
+/// computed once, up to the largest degree, and shared by all polynomials;
+/// the polynomials are evaluated in parallel if the `parallel` feature is
+/// enabled.
+pub fn batch_evaluate<F: Field>(polys: &[DensePolynomial<F>], point: F) -> Vec<F> {
+    let max_len = polys.iter().map(|p| p.coeffs.len()).max().unwrap_or(0);
+    let mut powers_of_point = Vec::with_capacity(max_len);
+    let mut cur = F::one();
+    for _ in 0..max_len {
+        powers_of_point.push(cur);
+        cur *= &point;
+    }
+
+    cfg_iter!(polys)
+        .map(|p| {
+            p.coeffs
+                .iter()
+                .zip(&powers_of_point)
+                .map(|(coeff, power)| *coeff * power)
+                .sum()
+        })
+        .collect()
+}
+
+/// The default of `linear_chunk_size`.
+pub const DEFAULT_LINEAR_CHUNK_SIZE: usize = 1 << 12;
+
+static LINEAR_CHUNK_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_LINEAR_CHUNK_SIZE);
+
+/// Returns the number of coefficients or evaluations that each task handles
+/// in the linear passes of polynomial arithmetic, such as additions, scalar
+/// multiplications and the pointwise products of FFT-based multiplication.
+/// The tasks run in parallel if the `parallel` feature is enabled.
+pub fn linear_chunk_size() -> usize {
+    LINEAR_CHUNK_SIZE.load(Ordering::Relaxed)
+}
+
+/// Sets `linear_chunk_size` for all threads. Smaller chunks spread small
+/// polynomials over more threads, at the cost of more scheduling.
+///
+/// Panics if `chunk_size` is zero.
+pub fn set_linear_chunk_size(chunk_size: usize) {
+    assert!(chunk_size > 0, "chunk size must be positive");
+    LINEAR_CHUNK_SIZE.store(chunk_size, Ordering::Relaxed);
+}
+
+/// Applies `op` to the pairs of elements of `a` and `b` at the same index,
+/// up to the length of the shorter one, in chunks of `linear_chunk_size()`.
+pub(crate) fn zip_with<T, U, Op>(a: &mut [T], b: &[U], op: Op)
+where
+    T: Send,
+    U: Sync,
+    Op: Fn(&mut T, &U) + Send + Sync,
+{
+    let chunk_size = linear_chunk_size();
+    cfg_chunks_mut!(a, chunk_size)
+        .zip(cfg_chunks!(b, chunk_size))
+        .for_each(|(a, b)| a.iter_mut().zip(b).for_each(|(a, b)| op(a, b)));
+}
+
+/// Applies `op` to the elements of `a`, in chunks of `linear_chunk_size()`.
+pub(crate) fn map_in_place<T, Op>(a: &mut [T], op: Op)
+where
+    T: Send,
+    Op: Fn(&mut T) + Send + Sync,
+{
+    cfg_chunks_mut!(a, linear_chunk_size()).for_each(|a| a.iter_mut().for_each(&op));
+}
 
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone)]
@@ -145,14 +238,7 @@ impl<'a, F: 'a + FftField> DenseOrSparsePolynomial<'a, F> {
 
     fn eval_over_domain_helper<D: EvaluationDomain<F>>(self, domain: D) -> Evaluations<F, D> {
         match self {
-            SPolynomial(Cow::Borrowed(s)) => {
-                let evals = domain.elements().map(|elem| s.evaluate(elem)).collect();
-                Evaluations::from_vec_and_domain(evals, domain)
-            }
-            SPolynomial(Cow::Owned(s)) => {
-                let evals = domain.elements().map(|elem| s.evaluate(elem)).collect();
-                Evaluations::from_vec_and_domain(evals, domain)
-            }
+            SPolynomial(s) => Evaluations::from_vec_and_domain(domain.sparse_fft(&s), domain),
             DPolynomial(Cow::Borrowed(d)) => {
                 Evaluations::from_vec_and_domain(domain.fft(&d.coeffs), domain)
             }
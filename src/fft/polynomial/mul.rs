@@ -0,0 +1,278 @@
+//! Subquadratic multiplication of coefficient vectors, by Karatsuba's method
+//! over any ring and by Toom-3 over fields, for polynomials too small for
+//! FFTs to pay off, or over fields without large subgroups of roots of
+//! unity.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{ff::ring::Ring, Field, Vec};
+
+/// The default of `karatsuba_threshold`.
+pub const DEFAULT_KARATSUBA_THRESHOLD: usize = 32;
+
+/// The default of `toom3_threshold`.
+pub const DEFAULT_TOOM3_THRESHOLD: usize = 256;
+
+/// The default of `fft_mul_threshold`.
+pub const DEFAULT_FFT_MUL_THRESHOLD: usize = 512;
+
+static KARATSUBA_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_KARATSUBA_THRESHOLD);
+static TOOM3_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_TOOM3_THRESHOLD);
+static FFT_MUL_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_FFT_MUL_THRESHOLD);
+
+/// Returns the number of coefficients below which factors are multiplied
+/// by the schoolbook method rather than split by Karatsuba's.
+pub fn karatsuba_threshold() -> usize {
+    KARATSUBA_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Sets `karatsuba_threshold` for all threads.
+///
+/// Panics if `threshold` is smaller than 2.
+pub fn set_karatsuba_threshold(threshold: usize) {
+    assert!(threshold >= 2, "threshold must be at least 2");
+    KARATSUBA_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Returns the number of coefficients from which factors over fields are
+/// split in three by Toom-3 rather than in two by Karatsuba's method.
+pub fn toom3_threshold() -> usize {
+    TOOM3_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Sets `toom3_threshold` for all threads.
+///
+/// Panics if `threshold` is smaller than 3.
+pub fn set_toom3_threshold(threshold: usize) {
+    assert!(threshold >= 3, "threshold must be at least 3");
+    TOOM3_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Returns the number of coefficients of the smaller factor from which the
+/// product of `DensePolynomial`s over FFT-friendly fields is computed with
+/// FFTs rather than with `toom_mul`.
+pub fn fft_mul_threshold() -> usize {
+    FFT_MUL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Sets `fft_mul_threshold` for all threads.
+pub fn set_fft_mul_threshold(threshold: usize) {
+    FFT_MUL_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Sets `karatsuba_threshold` and `toom3_threshold` to the sizes from which
+/// splitting the factors once is faster than not splitting them, timed on
+/// this machine for elements of `F`, and returns them. The thresholds are
+/// global, so multiplications on other threads meanwhile may be slower.
+#[cfg(feature = "std")]
+pub fn tune_mul_thresholds<F: Field>() -> (usize, usize) {
+    use std::time::{Duration, Instant};
+
+    fn time<F: Field>(a: &[F], b: &[F]) -> Duration {
+        (0..5)
+            .map(|_| {
+                let start = Instant::now();
+                core::hint::black_box(toom_mul(a, b));
+                start.elapsed()
+            })
+            .min()
+            .unwrap()
+    }
+    let factor = |n: usize, seed: u64| -> Vec<F> {
+        (0..n as u64)
+            .map(|i| F::from_seed(&(seed * 1_000_000 + i).to_le_bytes()))
+            .collect()
+    };
+
+    // Both thresholds are compared at the top level only: the splits below
+    // stay under the threshold being tuned.
+    set_toom3_threshold(usize::MAX);
+    let mut karatsuba = 1 << 10;
+    for n in (3..10).map(|log_n| 1 << log_n) {
+        let (a, b) = (factor(n, 0), factor(n, 1));
+        set_karatsuba_threshold(n + 1);
+        let schoolbook = time(&a, &b);
+        set_karatsuba_threshold(n);
+        if time(&a, &b) <= schoolbook {
+            karatsuba = n;
+            break;
+        }
+    }
+    set_karatsuba_threshold(karatsuba);
+
+    let mut toom3 = 1 << 13;
+    let mut n = 3 * karatsuba;
+    while n < toom3 {
+        let (a, b) = (factor(n, 0), factor(n, 1));
+        set_toom3_threshold(n + 1);
+        let split_in_two = time(&a, &b);
+        set_toom3_threshold(n);
+        if time(&a, &b) <= split_in_two {
+            toom3 = n;
+        }
+        n *= 2;
+    }
+    set_toom3_threshold(toom3);
+    (karatsuba, toom3)
+}
+
+/// Returns the coefficients of the product of the polynomials with
+/// coefficients `a` and `b`, by Karatsuba's method.
+pub(crate) fn karatsuba_mul<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    mul_rec(a, b, None)
+}
+
+/// Returns the coefficients of the product of the polynomials with
+/// coefficients `a` and `b`, by Toom-3 and Karatsuba's method, or only the
+/// latter if the characteristic of `F` is 2 or 3.
+pub(crate) fn toom_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let two = F::one().double();
+    let inverses = two
+        .inverse()
+        .and_then(|half| Some((half, (two + F::one()).inverse()?)));
+    mul_rec(a, b, inverses)
+}
+
+/// Multiplies `a` and `b`, with Toom-3 if the inverses of 2 and 3 are given.
+fn mul_rec<R: Ring>(a: &[R], b: &[R], inverses: Option<(R, R)>) -> Vec<R> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        return Vec::new();
+    }
+    if b.len() < karatsuba_threshold().max(2) {
+        return schoolbook_mul(a, b);
+    }
+    if a.len() > b.len() {
+        // Splits the longer factor into pieces as long as the shorter one.
+        let mut result = vec![R::zero_element(); a.len() + b.len() - 1];
+        for (i, piece) in a.chunks(b.len()).enumerate() {
+            add_at(&mut result, &mul_rec(piece, b, inverses), i * b.len());
+        }
+        return result;
+    }
+    match inverses {
+        Some(inverses) if a.len() >= toom3_threshold().max(3) => toom3(a, b, inverses),
+        _ => karatsuba(a, b, inverses),
+    }
+}
+
+fn schoolbook_mul<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut result = vec![R::zero_element(); a.len() + b.len() - 1];
+    for (i, a_i) in a.iter().enumerate() {
+        for (j, b_j) in b.iter().enumerate() {
+            result[i + j] += &(*a_i * b_j);
+        }
+    }
+    result
+}
+
+/// Multiplies factors of the same length `n`, at least 2, with three
+/// products of half the length: `(a0 + a1 x^m)(b0 + b1 x^m) = z0 +
+/// ((a0 + a1)(b0 + b1) - z0 - z2) x^m + z2 x^2m` for `z0 = a0 b0` and
+/// `z2 = a1 b1`.
+fn karatsuba<R: Ring>(a: &[R], b: &[R], inverses: Option<(R, R)>) -> Vec<R> {
+    let n = a.len();
+    let m = n / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = mul_rec(a0, b0, inverses);
+    let z2 = mul_rec(a1, b1, inverses);
+    let mut z1 = mul_rec(&sum(a1, a0), &sum(b1, b0), inverses);
+    sub_at(&mut z1, &z0, 0);
+    sub_at(&mut z1, &z2, 0);
+
+    let mut result = vec![R::zero_element(); 2 * n - 1];
+    add_at(&mut result, &z0, 0);
+    add_at(&mut result, &z1, m);
+    add_at(&mut result, &z2, 2 * m);
+    result
+}
+
+/// Multiplies factors of the same length `n`, at least 3, split in three
+/// pieces of length `m`, with five products of the length of the pieces:
+/// those of the evaluations at 0, 1, -1, -2 and infinity, interpolated with
+/// Bodrato's sequence.
+fn toom3<R: Ring>(a: &[R], b: &[R], (half, third): (R, R)) -> Vec<R> {
+    let n = a.len();
+    let m = (n + 2) / 3;
+    let evaluate = |x: &[R]| -> [Vec<R>; 5] {
+        let piece = |i: usize| {
+            let mut piece = x[(i * m).min(n)..((i + 1) * m).min(n)].to_vec();
+            piece.resize(m, R::zero_element());
+            piece
+        };
+        let (x0, x1, x2) = (piece(0), piece(1), piece(2));
+        let x0_plus_x2 = sum(&x0, &x2);
+        let at_one = sum(&x0_plus_x2, &x1);
+        let at_minus_one = difference(&x0_plus_x2, &x1);
+        // `x0 - 2 x1 + 4 x2 = 2 (x(-1) + x2) - x0`.
+        let mut at_minus_two = sum(&at_minus_one, &x2);
+        at_minus_two.iter_mut().for_each(|c| *c = *c + *c);
+        sub_at(&mut at_minus_two, &x0, 0);
+        [x0, at_one, at_minus_one, at_minus_two, x2]
+    };
+    let (a_evals, b_evals) = (evaluate(a), evaluate(b));
+    let mut products = a_evals
+        .iter()
+        .zip(&b_evals)
+        .map(|(a, b)| mul_rec(a, b, Some((half, third))));
+    let mut next = || products.next().unwrap();
+    let (r0, r1, r_minus_one, r_minus_two, r_inf) = (next(), next(), next(), next(), next());
+
+    let scale = |x: &mut [R], c: R| x.iter_mut().for_each(|x| *x *= c);
+    let mut r3 = difference(&r_minus_two, &r1);
+    scale(&mut r3, third);
+    let mut r1 = difference(&r1, &r_minus_one);
+    scale(&mut r1, half);
+    let mut r2 = difference(&r_minus_one, &r0);
+    r3 = difference(&r2, &r3);
+    scale(&mut r3, half);
+    add_at(&mut r3, &r_inf, 0);
+    add_at(&mut r3, &r_inf, 0);
+    add_at(&mut r2, &r1, 0);
+    sub_at(&mut r2, &r_inf, 0);
+    sub_at(&mut r1, &r3, 0);
+
+    // The padding of the pieces only adds zero coefficients at the top.
+    let mut result = vec![R::zero_element(); 6 * m - 1];
+    for (i, r) in [r0, r1, r2, r3, r_inf].iter().enumerate() {
+        add_at(&mut result, r, i * m);
+    }
+    debug_assert!(result[2 * n - 1..].iter().all(|c| c.is_zero_element()));
+    result.truncate(2 * n - 1);
+    result
+}
+
+/// Returns `a + b`, as long as the longer of the two.
+fn sum<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let (mut result, other) = if a.len() >= b.len() {
+        (a.to_vec(), b)
+    } else {
+        (b.to_vec(), a)
+    };
+    add_at(&mut result, other, 0);
+    result
+}
+
+/// Returns `a - b`, for `b` no longer than `a`.
+fn difference<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut result = a.to_vec();
+    sub_at(&mut result, b, 0);
+    result
+}
+
+/// Adds `x * X^shift` to `acc`, which must be long enough.
+fn add_at<R: Ring>(acc: &mut [R], x: &[R], shift: usize) {
+    acc[shift..shift + x.len()]
+        .iter_mut()
+        .zip(x)
+        .for_each(|(a, x)| *a += x);
+}
+
+/// Subtracts `x * X^shift` from `acc`, which must be long enough.
+fn sub_at<R: Ring>(acc: &mut [R], x: &[R], shift: usize) {
+    acc[shift..shift + x.len()]
+        .iter_mut()
+        .zip(x)
+        .for_each(|(a, x)| *a -= x);
+}
@@ -0,0 +1,127 @@
+//! Conversions between univariate polynomials over radix-2 domains and
+//! multilinear polynomials.
+//!
+//! A univariate polynomial `f(X) = sum_i c_i X^i` with `2^n` coefficients is
+//! identified with the multilinear polynomial whose coefficient on the
+//! monomial `prod_{j in bits(i)} x_j` is `c_i`. The two agree in the sense
+//! that `f(z) = f_ml(z, z^2, z^4, ..., z^{2^{n - 1}})`.
+
+use crate::{
+    fft::{DensePolynomial, Evaluations, Radix2EvaluationDomain},
+    FftField, Field, Vec,
+};
+
+use super::DenseMultilinearPolynomial;
+
+/// Returns the tensor product expansion
+/// `(1, r_0, r_1, r_0 r_1, r_2, r_0 r_2, ...)` of `r`, i.e. the vector whose
+/// `i`-th entry is the product of the `r_j` for the bits `j` set in `i`, as
+/// `expand`.
+pub fn tensor<F: Field>(r: &[F]) -> Vec<F> {
+    super::expand(r)
+}
+
+/// Returns the point `(z, z^2, z^4, ..., z^{2^{num_vars - 1}})` at which the
+/// multilinear counterpart of a univariate polynomial must be evaluated to
+/// agree with the univariate polynomial at `z`.
+pub fn univariate_point_to_multilinear<F: Field>(z: F, num_vars: usize) -> Vec<F> {
+    let mut point = Vec::with_capacity(num_vars);
+    let mut cur = z;
+    for _ in 0..num_vars {
+        point.push(cur);
+        cur = cur.square();
+    }
+    point
+}
+
+/// Converts a univariate polynomial with at most `2^num_vars` coefficients
+/// into its multilinear counterpart, represented by evaluations over the
+/// boolean hypercube.
+///
+/// # Panics
+/// This method panics if `poly` has more than `2^num_vars` coefficients.
+pub fn univariate_to_multilinear<F: Field>(
+    poly: &DensePolynomial<F>,
+    num_vars: usize,
+) -> DenseMultilinearPolynomial<F> {
+    let size = 1 << num_vars;
+    assert!(poly.coeffs.len() <= size, "too many coefficients");
+    let mut evals = poly.coeffs.clone();
+    evals.resize(size, F::zero());
+    // The evaluation at x is the sum of the coefficients of all monomials
+    // supported on the bits of x (the subset-sum transform).
+    for j in 0..num_vars {
+        let bit = 1 << j;
+        for i in 0..size {
+            if i & bit != 0 {
+                let low = evals[i ^ bit];
+                evals[i] += &low;
+            }
+        }
+    }
+    DenseMultilinearPolynomial::from_evaluations_vec(num_vars, evals)
+}
+
+/// Converts a multilinear polynomial back into its univariate counterpart.
+/// This is the inverse of `univariate_to_multilinear`.
+pub fn multilinear_to_univariate<F: Field>(
+    poly: &DenseMultilinearPolynomial<F>,
+) -> DensePolynomial<F> {
+    let size = poly.evaluations.len();
+    let mut coeffs = poly.evaluations.clone();
+    // Invert the subset-sum transform (the Moebius transform).
+    for j in 0..poly.num_vars {
+        let bit = 1 << j;
+        for i in 0..size {
+            if i & bit != 0 {
+                let low = coeffs[i ^ bit];
+                coeffs[i] -= &low;
+            }
+        }
+    }
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Interpolates `evals` over its radix-2 domain and converts the result into
+/// its multilinear counterpart in `log_2(domain.size)` variables.
+pub fn evaluations_to_multilinear<F: FftField>(
+    evals: &Evaluations<F, Radix2EvaluationDomain<F>>,
+) -> DenseMultilinearPolynomial<F> {
+    univariate_to_multilinear(
+        &evals.interpolate_by_ref(),
+        evals.domain().log_size_of_group as usize,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::multilinear::*;
+    use crate::polynomial::DensePolynomial;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn univariate_multilinear_roundtrip() {
+        let rng = &mut test_rng();
+        for nv in 0..10 {
+            let poly = DensePolynomial::<Fr>::rand((1 << nv) - 1, rng);
+            let ml = univariate_to_multilinear(&poly, nv);
+            let z = Fr::rand(rng);
+            assert_eq!(
+                ml.evaluate(&univariate_point_to_multilinear(z, nv)),
+                poly.evaluate(z)
+            );
+            assert_eq!(multilinear_to_univariate(&ml), poly);
+        }
+    }
+
+    #[test]
+    fn tensor_inner_product() {
+        let rng = &mut test_rng();
+        let poly = DensePolynomial::<Fr>::rand(63, rng);
+        let z = Fr::rand(rng);
+        let t = tensor(&univariate_point_to_multilinear(z, 6));
+        let eval: Fr = poly.coeffs.iter().zip(&t).map(|(c, t)| *c * t).sum();
+        assert_eq!(eval, poly.evaluate(z));
+    }
+}
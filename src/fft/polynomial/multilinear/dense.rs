@@ -0,0 +1,355 @@
+//! A multilinear polynomial represented by its evaluations over the boolean
+//! hypercube.
+
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Index, Neg, Sub, SubAssign},
+};
+use rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    Error, Field, Vec,
+};
+
+use super::swap_bits;
+
+/// Stores a multilinear polynomial in `num_vars` variables by its evaluations
+/// over `{0, 1}^num_vars`.
+///
+/// The evaluation at the point `(x_0, ..., x_{n - 1})` is stored at index
+/// `x_0 + 2 * x_1 + ... + 2^{n - 1} * x_{n - 1}`, i.e. the first variable
+/// is the least significant bit of the index.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct DenseMultilinearPolynomial<F: Field> {
+    /// The evaluations over the boolean hypercube.
+    pub evaluations: Vec<F>,
+    /// The number of variables.
+    pub num_vars: usize,
+}
+
+impl<F: Field> fmt::Debug for DenseMultilinearPolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "DenseML(nv = {}, evaluations = [", self.num_vars)?;
+        for (i, eval) in self.evaluations.iter().enumerate().take(4) {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", eval)?;
+        }
+        if self.evaluations.len() > 4 {
+            write!(f, ", ...")?;
+        }
+        write!(f, "])")
+    }
+}
+
+impl<F: Field> DenseMultilinearPolynomial<F> {
+    /// Returns the zero polynomial in `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        Self {
+            evaluations: vec![F::zero(); 1 << num_vars],
+            num_vars,
+        }
+    }
+
+    /// Checks if the given polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.evaluations.iter().all(|e| e.is_zero())
+    }
+
+    /// Constructs a new polynomial from a list of evaluations over the
+    /// boolean hypercube.
+    pub fn from_evaluations_slice(num_vars: usize, evaluations: &[F]) -> Self {
+        Self::from_evaluations_vec(num_vars, evaluations.to_vec())
+    }
+
+    /// Constructs a new polynomial from a list of evaluations over the
+    /// boolean hypercube.
+    ///
+    /// # Panics
+    /// This method panics if `evaluations.len() != 2^num_vars`.
+    pub fn from_evaluations_vec(num_vars: usize, evaluations: Vec<F>) -> Self {
+        assert_eq!(
+            evaluations.len(),
+            1 << num_vars,
+            "The size of evaluations should be 2^num_vars."
+        );
+        Self {
+            evaluations,
+            num_vars,
+        }
+    }
+
+    /// Evaluates `self` at the given `point`.
+    ///
+    /// # Panics
+    /// This method panics if `point.len() != self.num_vars`.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "invalid size of point");
+        self.fix_variables(point).evaluations[0]
+    }
+
+    /// Fixes the first `partial_point.len()` variables of `self` to the
+    /// values in `partial_point`, and returns the polynomial in the
+    /// remaining variables.
+    ///
+    /// # Panics
+    /// This method panics if `partial_point.len() > self.num_vars`.
+    pub fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        let mut poly = self.evaluations.to_vec();
+        let nv = self.num_vars;
+        let dim = partial_point.len();
+        // Evaluate single variable of partial point from left to right.
+        for (i, r) in partial_point.iter().enumerate() {
+            let half = 1 << (nv - i - 1);
+            for b in 0..half {
+                let left = poly[b << 1];
+                let right = poly[(b << 1) + 1];
+                poly[b] = left + &(*r * &(right - &left));
+            }
+        }
+        poly.truncate(1 << (nv - dim));
+        Self::from_evaluations_vec(nv - dim, poly)
+    }
+
+    /// Returns a polynomial whose variables are relabeled: the `k` variables
+    /// starting at index `a` are swapped with the `k` variables starting at
+    /// index `b`. The two ranges must not overlap.
+    ///
+    /// # Panics
+    /// This method panics if the ranges overlap or exceed `self.num_vars`.
+    pub fn relabel(&self, mut a: usize, mut b: usize, k: usize) -> Self {
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        if a == b || k == 0 {
+            return self.clone();
+        }
+        assert!(a + k <= b, "overlapped swap window is not allowed");
+        assert!(b + k <= self.num_vars, "invalid relabel argument");
+
+        let evaluations = (0..self.evaluations.len())
+            .map(|i| self.evaluations[swap_bits(i, a, b, k)])
+            .collect();
+        Self::from_evaluations_vec(self.num_vars, evaluations)
+    }
+
+    /// Outputs a multilinear polynomial in `num_vars` variables whose
+    /// evaluations are sampled uniformly at random from the field `F`.
+    pub fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self {
+        let evaluations = (0..(1 << num_vars)).map(|_| F::rand(rng)).collect();
+        Self::from_evaluations_vec(num_vars, evaluations)
+    }
+
+    /// Returns an iterator over the evaluations over the boolean hypercube.
+    pub fn iter(&self) -> core::slice::Iter<'_, F> {
+        self.evaluations.iter()
+    }
+}
+
+impl<F: Field> Index<usize> for DenseMultilinearPolynomial<F> {
+    type Output = F;
+
+    /// Returns the evaluation of `self` at the `index`-th point of the
+    /// boolean hypercube.
+    fn index(&self, index: usize) -> &F {
+        &self.evaluations[index]
+    }
+}
+
+impl<F: Field> Neg for DenseMultilinearPolynomial<F> {
+    type Output = DenseMultilinearPolynomial<F>;
+
+    #[inline]
+    fn neg(mut self) -> DenseMultilinearPolynomial<F> {
+        cfg_iter_mut!(self.evaluations).for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<'a, 'b, F: Field> Add<&'a DenseMultilinearPolynomial<F>>
+    for &'b DenseMultilinearPolynomial<F>
+{
+    type Output = DenseMultilinearPolynomial<F>;
+
+    fn add(self, other: &'a DenseMultilinearPolynomial<F>) -> DenseMultilinearPolynomial<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a DenseMultilinearPolynomial<F>> for DenseMultilinearPolynomial<F> {
+    fn add_assign(&mut self, other: &'a DenseMultilinearPolynomial<F>) {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "trying to add two multilinear polynomials with different number of variables"
+        );
+        cfg_iter_mut!(self.evaluations)
+            .zip(&other.evaluations)
+            .for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a DenseMultilinearPolynomial<F>)>
+    for DenseMultilinearPolynomial<F>
+{
+    fn add_assign(&mut self, (f, other): (F, &'a DenseMultilinearPolynomial<F>)) {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "trying to add two multilinear polynomials with different number of variables"
+        );
+        cfg_iter_mut!(self.evaluations)
+            .zip(&other.evaluations)
+            .for_each(|(a, b)| *a += &(f * b));
+    }
+}
+
+impl<'a, 'b, F: Field> Sub<&'a DenseMultilinearPolynomial<F>>
+    for &'b DenseMultilinearPolynomial<F>
+{
+    type Output = DenseMultilinearPolynomial<F>;
+
+    fn sub(self, other: &'a DenseMultilinearPolynomial<F>) -> DenseMultilinearPolynomial<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a DenseMultilinearPolynomial<F>> for DenseMultilinearPolynomial<F> {
+    fn sub_assign(&mut self, other: &'a DenseMultilinearPolynomial<F>) {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "trying to subtract two multilinear polynomials with different number of variables"
+        );
+        cfg_iter_mut!(self.evaluations)
+            .zip(&other.evaluations)
+            .for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<F: Field> CanonicalSerialize for DenseMultilinearPolynomial<F> {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.num_vars.serialize_with_mode(writer, compress)?;
+        self.evaluations.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.num_vars.serialized_size(compress) + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for DenseMultilinearPolynomial<F> {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let num_vars = usize::deserialize_with_mode(reader, compress, validate)?;
+        let evaluations: Vec<F> = Vec::deserialize_with_mode(reader, compress, validate)?;
+        if num_vars >= 64 || evaluations.len() as u64 != 1 << num_vars {
+            return Err(Error("the number of evaluations should be 2^num_vars"));
+        }
+        Ok(Self::from_evaluations_vec(num_vars, evaluations))
+    }
+}
+
+impl_from_canonical!([F: Field], DenseMultilinearPolynomial<F>);
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::DenseMultilinearPolynomial;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, One, UniformRand, Zero};
+
+    /// Evaluates the multilinear extension directly from its definition.
+    fn naive_evaluate(poly: &DenseMultilinearPolynomial<Fr>, point: &[Fr]) -> Fr {
+        let mut total = Fr::zero();
+        for (i, eval) in poly.iter().enumerate() {
+            let mut weight = Fr::one();
+            for (j, x) in point.iter().enumerate() {
+                if (i >> j) & 1 == 1 {
+                    weight *= x;
+                } else {
+                    weight *= &(Fr::one() - x);
+                }
+            }
+            total += &(weight * eval);
+        }
+        total
+    }
+
+    #[test]
+    fn evaluate_matches_definition() {
+        let rng = &mut test_rng();
+        for nv in 0..10 {
+            let poly = DenseMultilinearPolynomial::<Fr>::rand(nv, rng);
+            let point: Vec<_> = (0..nv).map(|_| Fr::rand(rng)).collect();
+            assert_eq!(poly.evaluate(&point), naive_evaluate(&poly, &point));
+        }
+    }
+
+    #[test]
+    fn evaluate_on_hypercube() {
+        let rng = &mut test_rng();
+        let poly = DenseMultilinearPolynomial::<Fr>::rand(5, rng);
+        for i in 0..32 {
+            let point: Vec<_> = (0..5)
+                .map(|j| {
+                    if (i >> j) & 1 == 1 {
+                        Fr::one()
+                    } else {
+                        Fr::zero()
+                    }
+                })
+                .collect();
+            assert_eq!(poly.evaluate(&point), poly[i]);
+        }
+    }
+
+    #[test]
+    fn fix_variables_then_evaluate() {
+        let rng = &mut test_rng();
+        let poly = DenseMultilinearPolynomial::<Fr>::rand(8, rng);
+        let point: Vec<_> = (0..8).map(|_| Fr::rand(rng)).collect();
+        let partial = poly.fix_variables(&point[..3]);
+        assert_eq!(partial.num_vars, 5);
+        assert_eq!(partial.evaluate(&point[3..]), poly.evaluate(&point));
+    }
+
+    #[test]
+    fn relabel_swaps_variables() {
+        let rng = &mut test_rng();
+        let poly = DenseMultilinearPolynomial::<Fr>::rand(6, rng);
+        let point: Vec<_> = (0..6).map(|_| Fr::rand(rng)).collect();
+        let mut permuted = point.clone();
+        permuted[0..2].copy_from_slice(&point[3..5]);
+        permuted[3..5].copy_from_slice(&point[0..2]);
+        let relabeled = poly.relabel(0, 3, 2);
+        assert_eq!(relabeled.evaluate(&point), poly.evaluate(&permuted));
+        assert_eq!(relabeled.relabel(3, 0, 2), poly);
+    }
+
+    #[test]
+    fn add_and_sub() {
+        let rng = &mut test_rng();
+        let a = DenseMultilinearPolynomial::<Fr>::rand(7, rng);
+        let b = DenseMultilinearPolynomial::<Fr>::rand(7, rng);
+        let point: Vec<_> = (0..7).map(|_| Fr::rand(rng)).collect();
+        assert_eq!(
+            (&a + &b).evaluate(&point),
+            a.evaluate(&point) + &b.evaluate(&point)
+        );
+        assert_eq!(&(&a - &b) + &b, a);
+        assert!((&a - &a).is_zero());
+    }
+}
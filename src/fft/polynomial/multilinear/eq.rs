@@ -0,0 +1,82 @@
+//! Helpers for the multilinear equality polynomial
+//! `eq(x, r) = prod_i (x_i * r_i + (1 - x_i) * (1 - r_i))`.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Field, Vec};
+
+use super::DenseMultilinearPolynomial;
+
+/// Returns the evaluations of `eq(x, r)` over all `x` in the boolean
+/// hypercube, as a multilinear polynomial in `r.len()` variables.
+///
+/// The table is built in `O(2^n)` field multiplications by extending it one
+/// variable at a time.
+pub fn build_eq_x_r<F: Field>(r: &[F]) -> DenseMultilinearPolynomial<F> {
+    DenseMultilinearPolynomial::from_evaluations_vec(r.len(), build_eq_x_r_vec(r))
+}
+
+/// Returns the evaluations of `eq(x, r)` over all `x` in the boolean
+/// hypercube, indexed with the first variable as the least significant bit.
+pub fn build_eq_x_r_vec<F: Field>(r: &[F]) -> Vec<F> {
+    let mut evals = Vec::with_capacity(1 << r.len());
+    evals.push(F::one());
+    for r_i in r {
+        // Entries with x_i = 1 are appended after the entries with x_i = 0,
+        // since x_i is the next most significant bit.
+        let high: Vec<F> = cfg_iter_mut!(evals)
+            .map(|e| {
+                let hi = *e * r_i;
+                *e -= &hi;
+                hi
+            })
+            .collect();
+        evals.extend(high);
+    }
+    evals
+}
+
+/// Evaluates `eq(x, y)` at two points of the same dimension.
+///
+/// # Panics
+/// This method panics if `x.len() != y.len()`.
+pub fn eq_eval<F: Field>(x: &[F], y: &[F]) -> F {
+    assert_eq!(x.len(), y.len(), "x and y have different length");
+    x.iter().zip(y).fold(F::one(), |acc, (x_i, y_i)| {
+        let xy = *x_i * y_i;
+        // x_i * y_i + (1 - x_i) * (1 - y_i) = 1 + 2 * x_i * y_i - x_i - y_i
+        acc * &(F::one() + &xy.double() - x_i - y_i)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::multilinear::{build_eq_x_r, eq_eval};
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, One, UniformRand, Zero};
+
+    #[test]
+    fn eq_table_matches_eq_eval() {
+        let rng = &mut test_rng();
+        for nv in 0..8 {
+            let r: Vec<_> = (0..nv).map(|_| Fr::rand(rng)).collect();
+            let table = build_eq_x_r(&r);
+            for i in 0..(1 << nv) {
+                let x: Vec<_> = (0..nv)
+                    .map(|j| {
+                        if (i >> j) & 1 == 1 {
+                            Fr::one()
+                        } else {
+                            Fr::zero()
+                        }
+                    })
+                    .collect();
+                assert_eq!(table[i], eq_eval(&x, &r));
+            }
+            // The multilinear extension of eq(., r) evaluated at y is eq(y, r).
+            let y: Vec<_> = (0..nv).map(|_| Fr::rand(rng)).collect();
+            assert_eq!(table.evaluate(&y), eq_eval(&y, &r));
+        }
+    }
+}
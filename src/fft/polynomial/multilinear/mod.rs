@@ -0,0 +1,28 @@
+//! Work with multilinear polynomials represented by their evaluations over
+//! the boolean hypercube.
+
+mod convert;
+mod dense;
+mod eq;
+mod sparse;
+mod tensor;
+
+pub use convert::{
+    evaluations_to_multilinear, multilinear_to_univariate, tensor, univariate_point_to_multilinear,
+    univariate_to_multilinear,
+};
+pub use dense::DenseMultilinearPolynomial;
+pub use eq::{build_eq_x_r, build_eq_x_r_vec, eq_eval};
+pub use sparse::SparseMultilinearPolynomial;
+pub use tensor::{expand, tensor_product};
+
+/// Swaps the `k` bits of `x` starting at position `a` with the `k` bits
+/// starting at position `b`.
+#[inline]
+pub(crate) fn swap_bits(x: usize, a: usize, b: usize, k: usize) -> usize {
+    let mask = (1 << k) - 1;
+    let a_bits = (x >> a) & mask;
+    let b_bits = (x >> b) & mask;
+    let diff = a_bits ^ b_bits;
+    x ^ (diff << a) ^ (diff << b)
+}
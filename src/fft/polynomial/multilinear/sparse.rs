@@ -0,0 +1,287 @@
+//! A multilinear polynomial represented by its nonzero evaluations over the
+//! boolean hypercube.
+
+use core::{
+    fmt,
+    ops::{Add, Neg, Sub},
+};
+use rand::Rng;
+
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    BTreeMap, Error, Field, Vec,
+};
+
+use super::DenseMultilinearPolynomial;
+
+/// Stores a multilinear polynomial in `num_vars` variables by its nonzero
+/// evaluations over `{0, 1}^num_vars`. Indices follow the same convention as
+/// `DenseMultilinearPolynomial`: the first variable is the least significant
+/// bit of the index.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct SparseMultilinearPolynomial<F: Field> {
+    /// The nonzero evaluations, keyed by their index in the hypercube.
+    evaluations: BTreeMap<usize, F>,
+    /// The number of variables.
+    pub num_vars: usize,
+}
+
+impl<F: Field> fmt::Debug for SparseMultilinearPolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "SparseML(nv = {}, evaluations = [", self.num_vars)?;
+        for (i, (index, eval)) in self.evaluations.iter().enumerate().take(4) {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "({}, {:?})", index, eval)?;
+        }
+        if self.evaluations.len() > 4 {
+            write!(f, ", ...")?;
+        }
+        write!(f, "])")
+    }
+}
+
+impl<F: Field> SparseMultilinearPolynomial<F> {
+    /// Returns the zero polynomial in `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        Self {
+            evaluations: BTreeMap::new(),
+            num_vars,
+        }
+    }
+
+    /// Checks if the given polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.evaluations.is_empty()
+    }
+
+    /// Constructs a new polynomial from a list of `(index, evaluation)`
+    /// pairs. Zero evaluations are dropped and repeated indices are summed.
+    ///
+    /// # Panics
+    /// This method panics if an index is not smaller than `2^num_vars`.
+    pub fn from_evaluations(num_vars: usize, evaluations: &[(usize, F)]) -> Self {
+        let bound = 1 << num_vars;
+        let mut map = BTreeMap::new();
+        for (index, eval) in evaluations {
+            assert!(*index < bound, "index out of range");
+            *map.entry(*index).or_insert_with(F::zero) += eval;
+        }
+        Self::from_map(num_vars, map)
+    }
+
+    fn from_map(num_vars: usize, mut evaluations: BTreeMap<usize, F>) -> Self {
+        evaluations.retain(|_, e| !e.is_zero());
+        Self {
+            evaluations,
+            num_vars,
+        }
+    }
+
+    /// Returns the number of nonzero evaluations.
+    pub fn num_nonzero(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    /// Returns an iterator over the nonzero `(index, evaluation)` pairs in
+    /// increasing order of index.
+    pub fn iter(&self) -> impl Iterator<Item = (&usize, &F)> {
+        self.evaluations.iter()
+    }
+
+    /// Evaluates `self` at the given `point`.
+    ///
+    /// # Panics
+    /// This method panics if `point.len() != self.num_vars`.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "invalid size of point");
+        self.fix_variables(point)
+            .evaluations
+            .get(&0)
+            .copied()
+            .unwrap_or_else(F::zero)
+    }
+
+    /// Fixes the first `partial_point.len()` variables of `self` to the
+    /// values in `partial_point`, and returns the polynomial in the
+    /// remaining variables. Runs in time linear in the number of nonzero
+    /// evaluations per fixed variable.
+    ///
+    /// # Panics
+    /// This method panics if `partial_point.len() > self.num_vars`.
+    pub fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        let mut evaluations = self.evaluations.clone();
+        for r in partial_point {
+            let one_minus_r = F::one() - r;
+            let mut folded = BTreeMap::new();
+            for (index, eval) in evaluations {
+                let weight = if index & 1 == 1 { *r } else { one_minus_r };
+                *folded.entry(index >> 1).or_insert_with(F::zero) += &(eval * &weight);
+            }
+            evaluations = folded;
+        }
+        Self::from_map(self.num_vars - partial_point.len(), evaluations)
+    }
+
+    /// Converts `self` into a dense multilinear polynomial.
+    pub fn to_dense(&self) -> DenseMultilinearPolynomial<F> {
+        let mut evaluations = vec![F::zero(); 1 << self.num_vars];
+        for (index, eval) in &self.evaluations {
+            evaluations[*index] = *eval;
+        }
+        DenseMultilinearPolynomial::from_evaluations_vec(self.num_vars, evaluations)
+    }
+
+    /// Outputs a multilinear polynomial in `num_vars` variables with (at
+    /// most) `num_nonzero` evaluations sampled uniformly at random.
+    pub fn rand<R: Rng>(num_vars: usize, num_nonzero: usize, rng: &mut R) -> Self {
+        let evaluations: Vec<_> = (0..num_nonzero)
+            .map(|_| (rng.gen_range(0, 1 << num_vars), F::rand(rng)))
+            .collect();
+        Self::from_evaluations(num_vars, &evaluations)
+    }
+}
+
+impl<F: Field> From<&DenseMultilinearPolynomial<F>> for SparseMultilinearPolynomial<F> {
+    fn from(other: &DenseMultilinearPolynomial<F>) -> Self {
+        let evaluations = other
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !e.is_zero())
+            .map(|(i, e)| (i, *e))
+            .collect();
+        Self {
+            evaluations,
+            num_vars: other.num_vars,
+        }
+    }
+}
+
+impl<F: Field> Neg for SparseMultilinearPolynomial<F> {
+    type Output = SparseMultilinearPolynomial<F>;
+
+    #[inline]
+    fn neg(mut self) -> SparseMultilinearPolynomial<F> {
+        self.evaluations.values_mut().for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<'a, 'b, F: Field> Add<&'a SparseMultilinearPolynomial<F>>
+    for &'b SparseMultilinearPolynomial<F>
+{
+    type Output = SparseMultilinearPolynomial<F>;
+
+    fn add(self, other: &'a SparseMultilinearPolynomial<F>) -> SparseMultilinearPolynomial<F> {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "trying to add two multilinear polynomials with different number of variables"
+        );
+        let mut evaluations = self.evaluations.clone();
+        for (index, eval) in &other.evaluations {
+            *evaluations.entry(*index).or_insert_with(F::zero) += eval;
+        }
+        SparseMultilinearPolynomial::from_map(self.num_vars, evaluations)
+    }
+}
+
+impl<'a, 'b, F: Field> Sub<&'a SparseMultilinearPolynomial<F>>
+    for &'b SparseMultilinearPolynomial<F>
+{
+    type Output = SparseMultilinearPolynomial<F>;
+
+    fn sub(self, other: &'a SparseMultilinearPolynomial<F>) -> SparseMultilinearPolynomial<F> {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "trying to subtract two multilinear polynomials with different number of variables"
+        );
+        let mut evaluations = self.evaluations.clone();
+        for (index, eval) in &other.evaluations {
+            *evaluations.entry(*index).or_insert_with(F::zero) -= eval;
+        }
+        SparseMultilinearPolynomial::from_map(self.num_vars, evaluations)
+    }
+}
+
+// Only the nonzero evaluations are written, as (index, value) pairs in
+// increasing order of index.
+impl<F: Field> CanonicalSerialize for SparseMultilinearPolynomial<F> {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.num_vars.serialize_with_mode(writer, compress)?;
+        self.evaluations
+            .len()
+            .serialize_with_mode(writer, compress)?;
+        for (index, eval) in &self.evaluations {
+            index.serialize_with_mode(writer, compress)?;
+            eval.serialize_with_mode(writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        let header = self.num_vars.serialized_size(compress)
+            + self.evaluations.len().serialized_size(compress);
+        self.evaluations.iter().fold(header, |acc, (index, eval)| {
+            acc + index.serialized_size(compress) + eval.serialized_size(compress)
+        })
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for SparseMultilinearPolynomial<F> {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let num_vars = usize::deserialize_with_mode(reader, compress, validate)?;
+        let evaluations: Vec<(usize, F)> = Vec::deserialize_with_mode(reader, compress, validate)?;
+        if num_vars >= 64 || evaluations.iter().any(|(i, _)| *i as u64 >= 1 << num_vars) {
+            return Err(Error("evaluation index out of range"));
+        }
+        if validate == Validate::Yes && evaluations.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return Err(Error("evaluations are not in increasing order of index"));
+        }
+        Ok(Self::from_evaluations(num_vars, &evaluations))
+    }
+}
+
+impl_from_canonical!([F: Field], SparseMultilinearPolynomial<F>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomial::{DenseMultilinearPolynomial, SparseMultilinearPolynomial},
+        serialize::{CanonicalDeserialize, CanonicalSerialize},
+    };
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn sparse_matches_dense() {
+        let rng = &mut test_rng();
+        for nv in 1..10 {
+            let sparse = SparseMultilinearPolynomial::<Fr>::rand(nv, 1 << (nv / 2), rng);
+            let dense: DenseMultilinearPolynomial<Fr> = sparse.to_dense();
+            let point: Vec<_> = (0..nv).map(|_| Fr::rand(rng)).collect();
+            assert_eq!(sparse.evaluate(&point), dense.evaluate(&point));
+            assert_eq!(
+                sparse.fix_variables(&point[..1]).to_dense(),
+                dense.fix_variables(&point[..1])
+            );
+            assert_eq!(SparseMultilinearPolynomial::from(&dense), sparse);
+
+            let mut bytes = Vec::new();
+            sparse.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(sparse.compressed_size(), bytes.len());
+            assert_eq!(
+                SparseMultilinearPolynomial::deserialize_compressed(&mut &bytes[..]).unwrap(),
+                sparse
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Tensor products of vectors, as the Lagrange-like bases of multilinear
+//! evaluation points that Ligero- and Brakedown-style commitments, and
+//! vector-OLE constructions, combine rows and columns with.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Field, Vec};
+
+/// The number of variables up to which `expand` runs serially.
+const SERIAL_EXPAND_VARS: usize = 10;
+
+/// Returns the tensor product of `a` and `b`, whose `i + j a.len()`-th
+/// entry is `a_i b_j`, i.e. `b_0 a, b_1 a, ...` one after the other, in
+/// parallel over the blocks of `b` if the `parallel` feature is enabled.
+pub fn tensor_product<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![F::zero(); a.len() * b.len()];
+    cfg_chunks_mut!(result, a.len())
+        .zip(b)
+        .for_each(|(block, b_j)| {
+            for (entry, a_i) in block.iter_mut().zip(a) {
+                *entry = *a_i * b_j;
+            }
+        });
+    result
+}
+
+/// Returns the tensor product expansion
+/// `(1, r_0, r_1, r_0 r_1, r_2, r_0 r_2, ...)` of `r`, i.e. the vector whose
+/// `i`-th entry is the product of the `r_j` for the bits `j` set in `i`.
+///
+/// The halves of `r` are expanded recursively, in parallel if the
+/// `parallel` feature is enabled, and joined with `tensor_product`, since
+/// `expand(r ‖ s) = tensor_product(expand(r), expand(s))`.
+pub fn expand<F: Field>(r: &[F]) -> Vec<F> {
+    if r.len() <= SERIAL_EXPAND_VARS {
+        let mut result = Vec::with_capacity(1 << r.len());
+        result.push(F::one());
+        for r_j in r {
+            let len = result.len();
+            for i in 0..len {
+                let t = result[i] * r_j;
+                result.push(t);
+            }
+        }
+        return result;
+    }
+
+    let (low, high) = r.split_at(r.len() / 2);
+    #[cfg(feature = "parallel")]
+    let (low, high) = rayon::join(|| expand(low), || expand(high));
+    #[cfg(not(feature = "parallel"))]
+    let (low, high) = (expand(low), expand(high));
+    tensor_product(&low, &high)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::multilinear::{build_eq_x_r_vec, expand, tensor_product};
+    use crate::Field;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn tensor_test() {
+        let rng = &mut test_rng();
+        let a: Vec<Fr> = (0..3).map(|_| Fr::rand(rng)).collect();
+        let b: Vec<Fr> = (0..5).map(|_| Fr::rand(rng)).collect();
+        let t = tensor_product(&a, &b);
+        assert_eq!(t.len(), 15);
+        assert_eq!(t[2 + 3 * 4], a[2] * b[4]);
+        assert!(tensor_product(&a, &[]).is_empty());
+
+        // Large enough to recurse.
+        let r: Vec<Fr> = (0..13).map(|_| Fr::rand(rng)).collect();
+        let e = expand(&r);
+        assert_eq!(e.len(), 1 << 13);
+        assert_eq!(e[0], Fr::one());
+        assert_eq!(e[0b1_0000_0000_0101], r[0] * r[2] * r[12]);
+        assert_eq!(e, tensor_product(&expand(&r[..4]), &expand(&r[4..])));
+
+        // The equality polynomial is the expansion of `r_j / (1 - r_j)`,
+        // scaled by the product of the `1 - r_j`.
+        let ratios: Vec<Fr> = r
+            .iter()
+            .map(|r_j| *r_j * (Fr::one() - r_j).inverse().unwrap())
+            .collect();
+        let scale: Fr = r.iter().map(|r_j| Fr::one() - r_j).product();
+        let eq: Vec<Fr> = expand(&ratios).iter().map(|e| *e * scale).collect();
+        assert_eq!(eq, build_eq_x_r_vec(&r));
+    }
+}
@@ -0,0 +1,260 @@
+//! A rational function represented as a pair of polynomials.
+
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    fft::{DenseOrSparsePolynomial, DensePolynomial},
+    FftField, Field,
+};
+
+/// Stores a rational function `numerator / denominator` in lowest terms.
+///
+/// The numerator and denominator are always coprime and the denominator is
+/// monic, so two equal rational functions have the same representation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RationalFunction<F: Field> {
+    numerator: DensePolynomial<F>,
+    denominator: DensePolynomial<F>,
+}
+
+impl<F: Field> fmt::Debug for RationalFunction<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "({:?}) / ({:?})", self.numerator, self.denominator)
+    }
+}
+
+impl<F: Field> RationalFunction<F> {
+    /// Constructs the rational function `numerator / denominator`, reduced to
+    /// lowest terms.
+    ///
+    /// # Panics
+    /// This method panics if `denominator` is the zero polynomial.
+    pub fn new(numerator: DensePolynomial<F>, denominator: DensePolynomial<F>) -> Self {
+        assert!(!denominator.is_zero(), "Denominator is the zero polynomial");
+        let mut result = Self {
+            numerator,
+            denominator,
+        };
+        result.normalize();
+        result
+    }
+
+    /// Returns the zero rational function.
+    pub fn zero() -> Self {
+        Self::from_polynomial(DensePolynomial::zero())
+    }
+
+    /// Returns the rational function with constant value one.
+    pub fn one() -> Self {
+        Self::from_polynomial(DensePolynomial::from_coefficients_vec(vec![F::one()]))
+    }
+
+    /// Constructs the rational function `poly / 1`.
+    pub fn from_polynomial(poly: DensePolynomial<F>) -> Self {
+        Self {
+            numerator: poly,
+            denominator: DensePolynomial::from_coefficients_vec(vec![F::one()]),
+        }
+    }
+
+    /// Returns the numerator.
+    pub fn numerator(&self) -> &DensePolynomial<F> {
+        &self.numerator
+    }
+
+    /// Returns the (monic) denominator.
+    pub fn denominator(&self) -> &DensePolynomial<F> {
+        &self.denominator
+    }
+
+    /// Checks if the rational function is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// Checks if the denominator is constant, i.e. `self` is a polynomial.
+    pub fn is_polynomial(&self) -> bool {
+        self.denominator.degree() == 0
+    }
+
+    /// Returns the polynomial `self` if the denominator is constant.
+    pub fn into_polynomial(self) -> Option<DensePolynomial<F>> {
+        if self.is_polynomial() {
+            Some(self.numerator)
+        } else {
+            None
+        }
+    }
+
+    /// Evaluates `self` at the given `point` in the field. Returns `None` if
+    /// `point` is a pole, i.e. a root of the denominator.
+    pub fn evaluate(&self, point: F) -> Option<F> {
+        let denominator = self.denominator.evaluate(point);
+        denominator
+            .inverse()
+            .map(|inv| self.numerator.evaluate(point) * &inv)
+    }
+
+    /// Returns the multiplicative inverse of `self` if `self` is nonzero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(Self::new(self.denominator.clone(), self.numerator.clone()))
+        }
+    }
+
+    /// Divides out the gcd of the numerator and the denominator and scales
+    /// both so that the denominator is monic.
+    fn normalize(&mut self) {
+        if self.numerator.is_zero() {
+            self.numerator = DensePolynomial::zero();
+            self.denominator = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+            return;
+        }
+
+        let gcd = self.numerator.gcd(&self.denominator);
+        if gcd.degree() > 0 {
+            let gcd: DenseOrSparsePolynomial<F> = gcd.into();
+            let numerator: DenseOrSparsePolynomial<F> = (&self.numerator).into();
+            let denominator: DenseOrSparsePolynomial<F> = (&self.denominator).into();
+            // The gcd divides both, so the remainders are zero.
+            self.numerator = numerator.divide_with_q_and_r(&gcd).unwrap().0;
+            self.denominator = denominator.divide_with_q_and_r(&gcd).unwrap().0;
+        }
+
+        // Can unwrap here because the denominator is nonzero.
+        let lc_inv = self.denominator.last().unwrap().inverse().unwrap();
+        self.numerator.iter_mut().for_each(|c| *c *= &lc_inv);
+        self.denominator.iter_mut().for_each(|c| *c *= &lc_inv);
+    }
+}
+
+impl<F: Field> From<DensePolynomial<F>> for RationalFunction<F> {
+    fn from(other: DensePolynomial<F>) -> Self {
+        Self::from_polynomial(other)
+    }
+}
+
+impl<F: Field> Neg for RationalFunction<F> {
+    type Output = RationalFunction<F>;
+
+    #[inline]
+    fn neg(mut self) -> RationalFunction<F> {
+        self.numerator = -self.numerator;
+        self
+    }
+}
+
+impl<'a, 'b, F: FftField> Add<&'a RationalFunction<F>> for &'b RationalFunction<F> {
+    type Output = RationalFunction<F>;
+
+    fn add(self, other: &'a RationalFunction<F>) -> RationalFunction<F> {
+        if self.denominator == other.denominator {
+            return RationalFunction::new(
+                &self.numerator + &other.numerator,
+                self.denominator.clone(),
+            );
+        }
+        let numerator =
+            &(&self.numerator * &other.denominator) + &(&other.numerator * &self.denominator);
+        RationalFunction::new(numerator, &self.denominator * &other.denominator)
+    }
+}
+
+impl<'a, 'b, F: FftField> Sub<&'a RationalFunction<F>> for &'b RationalFunction<F> {
+    type Output = RationalFunction<F>;
+
+    fn sub(self, other: &'a RationalFunction<F>) -> RationalFunction<F> {
+        if self.denominator == other.denominator {
+            return RationalFunction::new(
+                &self.numerator - &other.numerator,
+                self.denominator.clone(),
+            );
+        }
+        let numerator =
+            &(&self.numerator * &other.denominator) - &(&other.numerator * &self.denominator);
+        RationalFunction::new(numerator, &self.denominator * &other.denominator)
+    }
+}
+
+impl<'a, 'b, F: FftField> Mul<&'a RationalFunction<F>> for &'b RationalFunction<F> {
+    type Output = RationalFunction<F>;
+
+    fn mul(self, other: &'a RationalFunction<F>) -> RationalFunction<F> {
+        RationalFunction::new(
+            &self.numerator * &other.numerator,
+            &self.denominator * &other.denominator,
+        )
+    }
+}
+
+impl<'a, 'b, F: FftField> Div<&'a RationalFunction<F>> for &'b RationalFunction<F> {
+    type Output = RationalFunction<F>;
+
+    /// # Panics
+    /// This method panics if `other` is the zero rational function.
+    fn div(self, other: &'a RationalFunction<F>) -> RationalFunction<F> {
+        RationalFunction::new(
+            &self.numerator * &other.denominator,
+            &self.denominator * &other.numerator,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, One, UniformRand, Zero};
+
+    #[test]
+    fn normalize_common_factor() {
+        let rng = &mut test_rng();
+        for degree in 1..10 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let q = DensePolynomial::<Fr>::rand(degree, rng);
+            let r = DensePolynomial::<Fr>::rand(degree, rng);
+            let f = RationalFunction::new(&p * &r, &q * &r);
+            let g = RationalFunction::new(p, q);
+            assert_eq!(f, g);
+            assert!(f.denominator().last().unwrap().is_one());
+        }
+    }
+
+    #[test]
+    fn arithmetic_and_evaluation() {
+        let rng = &mut test_rng();
+        for degree in 0..10 {
+            let a = RationalFunction::new(
+                DensePolynomial::<Fr>::rand(degree, rng),
+                DensePolynomial::<Fr>::rand(degree + 1, rng),
+            );
+            let b = RationalFunction::new(
+                DensePolynomial::<Fr>::rand(degree + 1, rng),
+                DensePolynomial::<Fr>::rand(degree, rng),
+            );
+            let point = Fr::rand(rng);
+            let (a_eval, b_eval) = (a.evaluate(point).unwrap(), b.evaluate(point).unwrap());
+            assert_eq!((&a + &b).evaluate(point), Some(a_eval + &b_eval));
+            assert_eq!((&a - &b).evaluate(point), Some(a_eval - &b_eval));
+            assert_eq!((&a * &b).evaluate(point), Some(a_eval * &b_eval));
+            assert_eq!((&a / &b).evaluate(point), Some(a_eval / &b_eval));
+            assert_eq!(&a - &a, RationalFunction::zero());
+        }
+    }
+
+    #[test]
+    fn evaluate_at_pole() {
+        // (x + 1) / x has a pole at zero.
+        let f = RationalFunction::new(
+            DensePolynomial::from_coefficients_vec(vec![Fr::one(), Fr::one()]),
+            DensePolynomial::from_coefficients_vec(vec![Fr::zero(), Fr::one()]),
+        );
+        assert_eq!(f.evaluate(Fr::zero()), None);
+        assert_eq!(f.evaluate(Fr::one()), Some(Fr::from(2u64)));
+    }
+}
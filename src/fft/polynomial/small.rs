@@ -0,0 +1,198 @@
+//! A polynomial of small degree with its coefficients stored inline.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{fft::DensePolynomial, Field};
+
+use super::dense::horner_evaluate;
+
+/// Stores a polynomial with at most `N` coefficients in an array, so that
+/// constraint expressions of tiny degree can be built and evaluated
+/// without allocating. Coefficients beyond the degree are zero, so equal
+/// polynomials have equal arrays.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SmallPolynomial<F: Field, const N: usize> {
+    /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
+    pub coeffs: [F; N],
+}
+
+impl<F: Field, const N: usize> SmallPolynomial<F, N> {
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self {
+            coeffs: [F::zero(); N],
+        }
+    }
+
+    /// Checks if the given polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|coeff| coeff.is_zero())
+    }
+
+    /// Constructs a new polynomial from its coefficients.
+    pub fn from_coefficients(coeffs: [F; N]) -> Self {
+        Self { coeffs }
+    }
+
+    /// Constructs a new polynomial from a list of coefficients, or returns
+    /// None if it has more than `N` coefficients up to its leading one.
+    pub fn from_coefficients_slice(coeffs: &[F]) -> Option<Self> {
+        let len = coeffs
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .map_or(0, |i| i + 1);
+        if len > N {
+            return None;
+        }
+        let mut result = Self::zero();
+        result.coeffs[..len].copy_from_slice(&coeffs[..len]);
+        Some(result)
+    }
+
+    /// Returns the degree of the polynomial.
+    pub fn degree(&self) -> usize {
+        self.coeffs.iter().rposition(|c| !c.is_zero()).unwrap_or(0)
+    }
+
+    /// Evaluates `self` at the given `point` in the field.
+    pub fn evaluate(&self, point: F) -> F {
+        horner_evaluate(&self.coeffs, &point)
+    }
+
+    /// Returns `self * other`, or None if the product has more than `M`
+    /// coefficients.
+    pub fn checked_mul<const K: usize, const M: usize>(
+        &self,
+        other: &SmallPolynomial<F, K>,
+    ) -> Option<SmallPolynomial<F, M>> {
+        if self.is_zero() || other.is_zero() {
+            return Some(SmallPolynomial::zero());
+        }
+        if self.degree() + other.degree() >= M {
+            return None;
+        }
+        let mut result = SmallPolynomial::zero();
+        for (i, self_coeff) in self.coeffs[..=self.degree()].iter().enumerate() {
+            for (j, other_coeff) in other.coeffs[..=other.degree()].iter().enumerate() {
+                result.coeffs[i + j] += &(*self_coeff * other_coeff);
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns the polynomial with the same coefficients, stored in a
+    /// vector.
+    pub fn to_dense(&self) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_slice(&self.coeffs)
+    }
+}
+
+impl<F: Field, const N: usize> From<SmallPolynomial<F, N>> for DensePolynomial<F> {
+    fn from(poly: SmallPolynomial<F, N>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: Field, const N: usize> Add for SmallPolynomial<F, N> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<F: Field, const N: usize> AddAssign for SmallPolynomial<F, N> {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
+            *a += b;
+        }
+    }
+}
+
+impl<F: Field, const N: usize> Sub for SmallPolynomial<F, N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<F: Field, const N: usize> SubAssign for SmallPolynomial<F, N> {
+    fn sub_assign(&mut self, other: Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
+            *a -= b;
+        }
+    }
+}
+
+impl<F: Field, const N: usize> Neg for SmallPolynomial<F, N> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for coeff in self.coeffs.iter_mut() {
+            *coeff = -*coeff;
+        }
+        self
+    }
+}
+
+impl<F: Field, const N: usize> Mul<F> for SmallPolynomial<F, N> {
+    type Output = Self;
+
+    fn mul(mut self, scalar: F) -> Self {
+        self *= scalar;
+        self
+    }
+}
+
+impl<F: Field, const N: usize> MulAssign<F> for SmallPolynomial<F, N> {
+    fn mul_assign(&mut self, scalar: F) {
+        for coeff in self.coeffs.iter_mut() {
+            *coeff *= scalar;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, Field, One, UniformRand, Zero};
+    use rand::Rng;
+
+    #[test]
+    fn small_polynomial_arithmetic() {
+        let rng = &mut test_rng();
+        let a = SmallPolynomial::<Fr, 3>::from_coefficients([rng.gen(), rng.gen(), rng.gen()]);
+        let b = SmallPolynomial::<Fr, 3>::from_coefficients([rng.gen(), rng.gen(), Fr::zero()]);
+        let (da, db) = (a.to_dense(), b.to_dense());
+        assert_eq!(a.degree(), 2);
+        assert_eq!(b.degree(), 1);
+
+        let point = Fr::rand(rng);
+        assert_eq!(a.evaluate(point), da.evaluate(point));
+        assert_eq!((a + b).to_dense(), &da + &db);
+        assert_eq!((a - b).to_dense(), &da - &db);
+        assert_eq!((-a).to_dense(), -da.clone());
+        assert_eq!(
+            (a * point).evaluate(Fr::one()),
+            a.evaluate(Fr::one()) * point
+        );
+        assert!((a - a).is_zero());
+
+        let product: SmallPolynomial<Fr, 4> = a.checked_mul(&b).unwrap();
+        assert_eq!(product.to_dense(), da.naive_mul(&db));
+        assert!(a.checked_mul::<3, 4>(&a).is_none());
+
+        let padded = [a.coeffs[0], a.coeffs[1], a.coeffs[2], Fr::zero()];
+        let c = SmallPolynomial::<Fr, 3>::from_coefficients_slice(&padded).unwrap();
+        assert_eq!(c, a);
+        assert!(SmallPolynomial::<Fr, 2>::from_coefficients_slice(&padded).is_none());
+        assert_eq!(
+            DensePolynomial::from(SmallPolynomial::<Fr, 2>::zero()),
+            DensePolynomial::zero()
+        );
+    }
+}
@@ -4,7 +4,8 @@ use core::fmt;
 
 use crate::{
     fft::{DenseOrSparsePolynomial, DensePolynomial, EvaluationDomain, Evaluations},
-    BTreeMap, FftField, Field, Vec,
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    BTreeMap, Error, FftField, Field, Vec,
 };
 
 /// Stores a sparse polynomial in coefficient form.
@@ -137,11 +138,38 @@ impl<F: Field> Into<DensePolynomial<F>> for SparsePolynomial<F> {
     }
 }
 
+impl<F: Field> CanonicalSerialize for SparsePolynomial<F> {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.coeffs.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coeffs.serialized_size(compress)
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for SparsePolynomial<F> {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let coeffs: Vec<(usize, F)> = Vec::deserialize_with_mode(reader, compress, validate)?;
+        if validate == Validate::Yes && coeffs.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return Err(Error("sparse polynomial terms are not in increasing order"));
+        }
+        Ok(Self::from_coefficients_vec(coeffs))
+    }
+}
+
+impl_from_canonical!([F: Field], SparsePolynomial<F>);
+
 #[cfg(test)]
 mod tests {
     use crate::{DensePolynomial, EvaluationDomain, GeneralEvaluationDomain, SparsePolynomial};
     use algebra::bls12_381::fr::Fr;
-    use algebra_core::One;
+    use algebra_core::{test_rng, One};
+    use rand::Rng;
 
     #[test]
     fn evaluate_over_domain() {
@@ -159,4 +187,23 @@ mod tests {
             assert_eq!(evals2.interpolate(), dense_poly);
         }
     }
+
+    #[test]
+    fn sparse_fft() {
+        let rng = &mut test_rng();
+        for &num_coeffs in &[1, 16, 1 << 12, 96] {
+            let domain = GeneralEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            let n = domain.size();
+            for num_terms in 0..4 {
+                // Exponents beyond the domain wrap around, and may collide.
+                let terms = (0..num_terms)
+                    .map(|_| (rng.gen_range(0, 3 * n), rng.gen()))
+                    .collect();
+                let sparse_poly = SparsePolynomial::from_coefficients_vec(terms);
+                let expected: Vec<Fr> =
+                    domain.elements().map(|x| sparse_poly.evaluate(x)).collect();
+                assert_eq!(domain.sparse_fft(&sparse_poly), expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,70 @@
+//! Evaluate polynomials whose coefficients are produced incrementally.
+
+use crate::Field;
+
+/// Evaluates a polynomial at a fixed point while its coefficients are fed
+/// in, lowest degree first, without storing them. Memory use is constant in
+/// the degree of the polynomial.
+#[derive(Clone, Copy, Debug)]
+pub struct StreamingEvaluator<F: Field> {
+    point: F,
+    power: F,
+    result: F,
+    num_coeffs: usize,
+}
+
+impl<F: Field> StreamingEvaluator<F> {
+    /// Construct an evaluator at the given `point`.
+    pub fn new(point: F) -> Self {
+        Self {
+            point,
+            power: F::one(),
+            result: F::zero(),
+            num_coeffs: 0,
+        }
+    }
+
+    /// Absorb the next coefficient.
+    #[inline]
+    pub fn absorb(&mut self, coeff: &F) {
+        self.result += &(self.power * coeff);
+        self.power *= &self.point;
+        self.num_coeffs += 1;
+    }
+
+    /// Absorb the next `coeffs.len()` coefficients.
+    pub fn absorb_slice(&mut self, coeffs: &[F]) {
+        coeffs.iter().for_each(|c| self.absorb(c));
+    }
+
+    /// Absorb consecutive chunks of coefficients, e.g. from
+    /// `DensePolynomial::coeffs_chunked` or from a generator.
+    pub fn absorb_chunks<I, C>(&mut self, chunks: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: AsRef<[F]>,
+    {
+        chunks
+            .into_iter()
+            .for_each(|chunk| self.absorb_slice(chunk.as_ref()));
+    }
+
+    /// Return the number of coefficients absorbed so far.
+    pub fn num_coeffs(&self) -> usize {
+        self.num_coeffs
+    }
+
+    /// Return the evaluation of the polynomial made of the coefficients
+    /// absorbed so far.
+    pub fn finalize(&self) -> F {
+        self.result
+    }
+
+    /// Evaluate the polynomial whose coefficients, lowest degree first, are
+    /// yielded by `coeffs` at `point`.
+    pub fn evaluate<I: IntoIterator<Item = F>>(coeffs: I, point: F) -> F {
+        let mut evaluator = Self::new(point);
+        coeffs.into_iter().for_each(|c| evaluator.absorb(&c));
+        evaluator.finalize()
+    }
+}
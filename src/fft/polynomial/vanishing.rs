@@ -0,0 +1,174 @@
+//! The vanishing polynomial of an arbitrary set of points, for lookup
+//! arguments and batch openings over points that do not form a domain.
+
+use crate::{
+    fft::{DenseOrSparsePolynomial, DensePolynomial},
+    FftField, Vec,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Stores the subproduct tree of a list of points `x_0, ..., x_{n-1}`,
+/// whose root is the vanishing polynomial `Z(X) = ∏ (X - x_i)`. A repeated
+/// point is a root of `Z` of the same multiplicity.
+///
+/// Points are the leaves of the tree, in the order they were given, and
+/// each inner node is the product of its two children, or a copy of its only
+/// child. Adding a point only recomputes the nodes above it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VanishingPoly<F: FftField> {
+    points: Vec<F>,
+    /// `tree[0]` holds the leaves `X - x_i`, and the last layer the root.
+    /// Without points, the tree is the constant `1` alone.
+    tree: Vec<Vec<DensePolynomial<F>>>,
+}
+
+impl<F: FftField> VanishingPoly<F> {
+    /// Builds the subproduct tree of `points`.
+    pub fn new(points: &[F]) -> Self {
+        if points.is_empty() {
+            return Self {
+                points: Vec::new(),
+                tree: vec![vec![DensePolynomial::from_coefficients_vec(vec![F::one()])]],
+            };
+        }
+        let mut layer: Vec<_> = points.iter().map(|x| linear_factor(*x)).collect();
+        let mut tree = Vec::new();
+        while layer.len() > 1 {
+            let next = cfg_chunks!(layer, 2).map(product_of_children).collect();
+            tree.push(core::mem::replace(&mut layer, next));
+        }
+        tree.push(layer);
+        Self {
+            points: points.to_vec(),
+            tree,
+        }
+    }
+
+    /// Returns the points, in the order they were added.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the vanishing polynomial, i.e. the root of the tree.
+    pub fn poly(&self) -> &DensePolynomial<F> {
+        &self.tree.last().unwrap()[0]
+    }
+
+    /// Returns the degree of the vanishing polynomial, i.e. the number of
+    /// points.
+    pub fn degree(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Evaluates the vanishing polynomial at `tau`, as a product of the
+    /// linear factors.
+    pub fn evaluate(&self, tau: F) -> F {
+        self.points.iter().map(|x| tau - x).product()
+    }
+
+    /// Divides `p` by the vanishing polynomial, and returns the quotient and
+    /// the remainder.
+    pub fn divide_with_q_and_r(
+        &self,
+        p: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        let p: DenseOrSparsePolynomial<_> = p.into();
+        p.divide_with_q_and_r(&self.poly().into()).unwrap()
+    }
+
+    /// Returns the quotient of `p` by the vanishing polynomial, or None if
+    /// `p` does not vanish on all points.
+    pub fn divide_exact(&self, p: &DensePolynomial<F>) -> Option<DensePolynomial<F>> {
+        let (quotient, remainder) = self.divide_with_q_and_r(p);
+        if remainder.is_zero() {
+            Some(quotient)
+        } else {
+            None
+        }
+    }
+
+    /// Adds the point `x`, and updates the nodes on the path from its leaf to
+    /// the root.
+    pub fn add_point(&mut self, x: F) {
+        if self.points.is_empty() {
+            *self = Self::new(&[x]);
+            return;
+        }
+        self.points.push(x);
+        self.tree[0].push(linear_factor(x));
+        let mut index = self.points.len() - 1;
+        let mut level = 0;
+        while self.tree[level].len() > 1 {
+            let first_child = index & !1;
+            let end = self.tree[level].len().min(first_child + 2);
+            let node = product_of_children(&self.tree[level][first_child..end]);
+            index /= 2;
+            if level + 1 == self.tree.len() {
+                self.tree.push(Vec::new());
+            }
+            let parents = &mut self.tree[level + 1];
+            if index < parents.len() {
+                parents[index] = node;
+            } else {
+                parents.push(node);
+            }
+            level += 1;
+        }
+    }
+}
+
+/// Returns `X - x`.
+fn linear_factor<F: FftField>(x: F) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(vec![-x, F::one()])
+}
+
+fn product_of_children<F: FftField>(children: &[DensePolynomial<F>]) -> DensePolynomial<F> {
+    match children {
+        [left, right] => left * right,
+        [only] => only.clone(),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, One, UniformRand, Zero};
+    use rand::Rng;
+
+    #[test]
+    fn vanishing_poly_test() {
+        let rng = &mut test_rng();
+        let points: Vec<Fr> = (0..13).map(|_| rng.gen()).collect();
+
+        let mut incremental = VanishingPoly::new(&[]);
+        assert_eq!(incremental.degree(), 0);
+        assert_eq!(incremental.poly().coeffs, [Fr::one()]);
+        for (i, x) in points.iter().enumerate() {
+            incremental.add_point(*x);
+            assert_eq!(incremental, VanishingPoly::new(&points[..=i]));
+        }
+
+        let z = VanishingPoly::new(&points);
+        assert_eq!(z.points(), &points[..]);
+        assert_eq!(z.poly().degree(), points.len());
+        let tau = Fr::rand(rng);
+        assert_eq!(z.evaluate(tau), z.poly().evaluate(tau));
+        for x in &points {
+            assert!(z.evaluate(*x).is_zero());
+        }
+
+        let q = DensePolynomial::rand(20, rng);
+        let p = &q * z.poly();
+        assert_eq!(z.divide_exact(&p), Some(q.clone()));
+        let mut shifted = p.clone();
+        shifted.coeffs[0] += Fr::one();
+        let (quotient, remainder) = z.divide_with_q_and_r(&shifted);
+        assert_eq!(quotient, q);
+        assert_eq!(remainder.coeffs, [Fr::one()]);
+        assert!(z.divide_exact(&shifted).is_none());
+    }
+}
@@ -0,0 +1,139 @@
+//! Transposes of matrices stored as flat vectors, for switching between the
+//! row-major layout in which provers hash rows and the column-major layout
+//! in which they run FFTs over columns, as the six-step FFT does too.
+//!
+//! A matrix with `rows` rows and `cols` columns is row-major in a slice
+//! whose `i * cols + j`-th element is at row `i` and column `j`; its
+//! transpose is the same matrix in column-major order.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::Vec;
+
+/// The side of the square blocks the transposes move at a time, small
+/// enough for a block of the source and one of the destination to stay in
+/// the L1 cache for elements of a few limbs.
+const BLOCK_SIZE: usize = 16;
+
+/// Writes the transpose of the `rows` by `cols` row-major matrix `src` to
+/// `dst`, a `cols` by `rows` row-major matrix, block by block and in
+/// parallel over the rows of `dst` if the `parallel` feature is enabled.
+///
+/// Panics if either slice does not have `rows * cols` elements.
+pub fn transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    assert_eq!(src.len(), rows * cols, "source has the wrong size");
+    assert_eq!(dst.len(), rows * cols, "destination has the wrong size");
+    if rows == 0 || cols == 0 {
+        return;
+    }
+    cfg_chunks_mut!(dst, BLOCK_SIZE * rows)
+        .enumerate()
+        .for_each(|(block, dst)| {
+            // `dst` holds the rows of the transpose from `j0`, which are
+            // the columns of `src` from `j0`.
+            let j0 = block * BLOCK_SIZE;
+            let width = dst.len() / rows;
+            for i in 0..rows {
+                let src_row = &src[i * cols + j0..i * cols + j0 + width];
+                for (j, x) in src_row.iter().enumerate() {
+                    dst[j * rows + i] = *x;
+                }
+            }
+        });
+}
+
+/// Transposes the `rows` by `cols` row-major matrix `matrix` in place, so
+/// that it becomes the `cols` by `rows` row-major matrix of its transpose.
+/// Square matrices are transposed by swapping blocks across the diagonal;
+/// others are copied to a scratch buffer first and use `transpose`.
+///
+/// Panics if `matrix` does not have `rows * cols` elements.
+pub fn transpose_in_place<T: Copy + Send + Sync>(matrix: &mut [T], rows: usize, cols: usize) {
+    assert_eq!(matrix.len(), rows * cols, "matrix has the wrong size");
+    if rows != cols {
+        let src = matrix.to_vec();
+        transpose(&src, matrix, rows, cols);
+        return;
+    }
+    let n = rows;
+    for i0 in (0..n).step_by(BLOCK_SIZE) {
+        for j0 in (i0..n).step_by(BLOCK_SIZE) {
+            for i in i0..(i0 + BLOCK_SIZE).min(n) {
+                // On the diagonal block, only the elements above the
+                // diagonal are swapped with those below.
+                let start = if i0 == j0 { i + 1 } else { j0 };
+                for j in start..(j0 + BLOCK_SIZE).min(n) {
+                    matrix.swap(i * n + j, j * n + i);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the rows of the matrix with columns `columns`, or equally the
+/// columns of the matrix with rows `columns`.
+///
+/// Panics if the vectors do not all have the same length.
+pub fn transpose_columns<T: Copy + Send + Sync>(columns: &[Vec<T>]) -> Vec<Vec<T>> {
+    let len = columns.first().map_or(0, |c| c.len());
+    assert!(
+        columns.iter().all(|c| c.len() == len),
+        "columns have unequal lengths"
+    );
+    let mut rows: Vec<Vec<T>> = (0..len)
+        .map(|_| Vec::with_capacity(columns.len()))
+        .collect();
+    for j0 in (0..columns.len()).step_by(BLOCK_SIZE) {
+        let block = &columns[j0..(j0 + BLOCK_SIZE).min(columns.len())];
+        cfg_iter_mut!(rows).enumerate().for_each(|(i, row)| {
+            row.extend(block.iter().map(|c| c[i]));
+        });
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_transpose(src: &[u64], rows: usize, cols: usize) -> Vec<u64> {
+        (0..cols)
+            .flat_map(|j| (0..rows).map(move |i| src[i * cols + j]))
+            .collect()
+    }
+
+    #[test]
+    fn transpose_test() {
+        for &(rows, cols) in &[
+            (0, 3),
+            (1, 1),
+            (1, 7),
+            (5, 1),
+            (16, 16),
+            (33, 33),
+            (17, 40),
+            (64, 3),
+        ] {
+            let matrix: Vec<u64> = (0..(rows * cols) as u64).collect();
+            let expected = naive_transpose(&matrix, rows, cols);
+
+            let mut dst = vec![0; rows * cols];
+            transpose(&matrix, &mut dst, rows, cols);
+            assert_eq!(dst, expected);
+
+            let mut in_place = matrix.clone();
+            transpose_in_place(&mut in_place, rows, cols);
+            assert_eq!(in_place, expected);
+            transpose_in_place(&mut in_place, cols, rows);
+            assert_eq!(in_place, matrix);
+
+            let columns: Vec<Vec<u64>> = matrix.chunks(cols.max(1)).map(|r| r.to_vec()).collect();
+            let rows_of_transpose = transpose_columns(&columns);
+            assert_eq!(rows_of_transpose.concat(), expected);
+            if rows > 0 {
+                assert_eq!(transpose_columns(&rows_of_transpose), columns);
+            }
+        }
+    }
+}
@@ -7,6 +7,11 @@ use num_traits::Zero;
 
 use crate::{ff::PrimeField, UniformRand};
 
+mod prime_order;
+mod wnaf;
+pub use prime_order::{AdditiveFieldGroup, MultiplicativeSubgroup, PrimeOrderGroup};
+pub use wnaf::WnafContext;
+
 pub trait Group:
     'static
     + serde::ser::Serialize
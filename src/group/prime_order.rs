@@ -0,0 +1,262 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    ff::{Field, PrimeField},
+    utils::BitIterator,
+};
+
+use super::Group;
+
+/// A cyclic group of prime order, with only the operations that protocols
+/// such as Pedersen commitments and sigma protocols need. It is implemented
+/// by every `Group`, hence by the curves, and by groups of field elements,
+/// so that such protocols can be written once and tested without curves.
+/// Code that is generic over groups, such as `WnafContext`, takes this trait
+/// rather than `Group`.
+pub trait PrimeOrderGroup: 'static + Copy + Clone + Debug + Eq + Send + Sync {
+    /// The field of the exponents, whose modulus is the order of the group.
+    type ScalarField: PrimeField;
+
+    fn identity() -> Self;
+
+    fn add(&self, other: &Self) -> Self;
+
+    fn neg(&self) -> Self;
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// Returns `self + self`.
+    #[must_use]
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Returns `scalar * self`, by double-and-add by default.
+    #[must_use]
+    fn mul(&self, scalar: &Self::ScalarField) -> Self {
+        let mut res = Self::identity();
+        for bit in BitIterator::new(scalar.into_repr()) {
+            res = res.double();
+            if bit {
+                res = res.add(self);
+            }
+        }
+        res
+    }
+}
+
+impl<G: Group> PrimeOrderGroup for G {
+    type ScalarField = G::ScalarField;
+
+    #[inline]
+    fn identity() -> Self {
+        G::zero()
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        *self + other
+    }
+
+    #[inline]
+    fn neg(&self) -> Self {
+        -*self
+    }
+
+    #[inline]
+    fn sub(&self, other: &Self) -> Self {
+        *self - other
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        Group::double(self)
+    }
+
+    #[inline]
+    fn mul(&self, scalar: &Self::ScalarField) -> Self {
+        Group::mul(self, scalar)
+    }
+}
+
+/// The additive group of a prime field, with the field as its scalars.
+/// Discrete logarithms are trivial in it, so it is only meant for testing
+/// protocol code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdditiveFieldGroup<F: PrimeField>(pub F);
+
+impl<F: PrimeField> PrimeOrderGroup for AdditiveFieldGroup<F> {
+    type ScalarField = F;
+
+    #[inline]
+    fn identity() -> Self {
+        AdditiveFieldGroup(F::zero())
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        AdditiveFieldGroup(self.0 + other.0)
+    }
+
+    #[inline]
+    fn neg(&self) -> Self {
+        AdditiveFieldGroup(-self.0)
+    }
+
+    #[inline]
+    fn mul(&self, scalar: &F) -> Self {
+        AdditiveFieldGroup(self.0 * scalar)
+    }
+}
+
+/// The subgroup of the multiplicative group of `F` whose order is the
+/// modulus of `S`, such as the target group of a pairing, written
+/// additively: `add` multiplies and `mul` exponentiates.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Hash(bound = "")
+)]
+pub struct MultiplicativeSubgroup<F: Field, S: PrimeField> {
+    element: F,
+    #[derivative(Debug = "ignore")]
+    _scalar: PhantomData<S>,
+}
+
+impl<F: Field, S: PrimeField> MultiplicativeSubgroup<F, S> {
+    /// Returns `element` as a member of the subgroup, or None if its order
+    /// does not divide the modulus of `S`.
+    pub fn new(element: F) -> Option<Self> {
+        if element.pow(S::characteristic()) == F::one() {
+            Some(Self::new_unchecked(element))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `element` as a member of the subgroup, without checking that
+    /// it is one.
+    pub fn new_unchecked(element: F) -> Self {
+        MultiplicativeSubgroup {
+            element,
+            _scalar: PhantomData,
+        }
+    }
+
+    /// Returns the field element.
+    pub fn element(&self) -> F {
+        self.element
+    }
+}
+
+impl<F: Field, S: PrimeField> PrimeOrderGroup for MultiplicativeSubgroup<F, S> {
+    type ScalarField = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Self::new_unchecked(F::one())
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        Self::new_unchecked(self.element * other.element)
+    }
+
+    #[inline]
+    fn neg(&self) -> Self {
+        Self::new_unchecked(self.element.inverse().unwrap())
+    }
+
+    #[inline]
+    fn mul(&self, scalar: &S) -> Self {
+        Self::new_unchecked(self.element.pow(scalar.into_repr()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    /// A Pedersen commitment `m * g + r * h`, written once for all groups.
+    fn commit<G: PrimeOrderGroup>(g: &G, h: &G, m: &G::ScalarField, r: &G::ScalarField) -> G {
+        g.mul(m).add(&h.mul(r))
+    }
+
+    fn check_group<G: PrimeOrderGroup>(g: G, h: G) {
+        let scalar = |seed: &[u8]| G::ScalarField::from_seed(seed);
+        let (a, b) = (scalar(b"a"), scalar(b"b"));
+        assert!(G::identity().is_identity());
+        assert_eq!(g.add(&G::identity()), g);
+        assert!(g.add(&g.neg()).is_identity());
+        assert_eq!(g.sub(&h).add(&h), g);
+        assert_eq!(g.mul(&(a + b)), g.mul(&a).add(&g.mul(&b)));
+        assert_eq!(g.mul(&a).mul(&b), g.mul(&(a * b)));
+        assert!(g.mul(&G::ScalarField::zero()).is_identity());
+        assert_eq!(g.mul(&G::ScalarField::one()), g);
+
+        // Commitments are additively homomorphic.
+        let (m1, r1) = (scalar(b"m1"), scalar(b"r1"));
+        let (m2, r2) = (scalar(b"m2"), scalar(b"r2"));
+        assert_eq!(
+            commit(&g, &h, &m1, &r1).add(&commit(&g, &h, &m2, &r2)),
+            commit(&g, &h, &(m1 + m2), &(r1 + r2))
+        );
+    }
+
+    /// The additive group of `Fr` with the default scalar multiplication.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct DoubleAndAdd(Fr);
+
+    impl PrimeOrderGroup for DoubleAndAdd {
+        type ScalarField = Fr;
+
+        fn identity() -> Self {
+            DoubleAndAdd(Fr::zero())
+        }
+
+        fn add(&self, other: &Self) -> Self {
+            DoubleAndAdd(self.0 + other.0)
+        }
+
+        fn neg(&self) -> Self {
+            DoubleAndAdd(-self.0)
+        }
+    }
+
+    #[test]
+    fn additive_field_group_test() {
+        let rng = &mut test_rng();
+        check_group(
+            AdditiveFieldGroup::<Fr>(rng.gen()),
+            AdditiveFieldGroup(rng.gen()),
+        );
+        check_group(DoubleAndAdd(rng.gen()), DoubleAndAdd(rng.gen()));
+
+        let (g, a): (Fr, Fr) = (rng.gen(), rng.gen());
+        assert_eq!(DoubleAndAdd(g).mul(&a), DoubleAndAdd(g * a));
+    }
+
+    #[test]
+    fn multiplicative_subgroup_test() {
+        let rng = &mut test_rng();
+        // `Fr^*` has no element of order `r`.
+        let x: Fr = rng.gen();
+        assert!(MultiplicativeSubgroup::<Fr, Fr>::new(x).is_none());
+        let one = MultiplicativeSubgroup::<Fr, Fr>::new(Fr::one()).unwrap();
+        assert!(one.is_identity());
+        assert_eq!(one.mul(&rng.gen()), one);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{ff::PrimeField, Uint, Vec};
+
+use super::PrimeOrderGroup;
+
+/// A context for wNAF scalar multiplication with a fixed window size.
+///
+/// For a variable base, `mul` computes the table of odd multiples and
+/// multiplies in one go. For a fixed base, the table can be computed once
+/// with `table` and reused with `mul_with_table`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WnafContext {
+    pub window_size: usize,
+}
+
+impl WnafContext {
+    /// Constructs a new context for the given window size.
+    ///
+    /// # Panics
+    /// This method panics if `window_size` is not in `2..=62`.
+    pub fn new(window_size: usize) -> Self {
+        assert!(
+            (2..=62).contains(&window_size),
+            "window size must be in 2..=62"
+        );
+        Self { window_size }
+    }
+
+    /// Returns the odd multiples `base, 3 * base, ..., (2^(w - 1) - 1) * base`
+    /// used by `mul_with_table`, where `w` is the window size.
+    pub fn table<G: PrimeOrderGroup>(&self, mut base: G) -> Vec<G> {
+        let mut table = Vec::with_capacity(1 << (self.window_size - 2));
+        let double = base.double();
+        for _ in 0..(1 << (self.window_size - 2)) {
+            table.push(base);
+            base = base.add(&double);
+        }
+        table
+    }
+
+    /// Computes `scalar * base`.
+    pub fn mul<G: PrimeOrderGroup>(&self, base: G, scalar: &G::ScalarField) -> G {
+        let table = self.table(base);
+        self.mul_with_table(&table, scalar).unwrap()
+    }
+
+    /// Computes `scalar * base`, where `base_table` is the output of
+    /// `self.table(base)`. Returns `None` if the table does not match the
+    /// window size.
+    pub fn mul_with_table<G: PrimeOrderGroup>(
+        &self,
+        base_table: &[G],
+        scalar: &G::ScalarField,
+    ) -> Option<G> {
+        if base_table.len() != 1 << (self.window_size - 2) {
+            return None;
+        }
+        let naf = scalar.into_repr().find_wnaf_with_window(self.window_size);
+
+        let mut result = G::identity();
+        let mut found_nonzero = false;
+        for n in naf.iter().rev() {
+            if found_nonzero {
+                result = result.double();
+            }
+            if *n != 0 {
+                found_nonzero = true;
+                if *n > 0 {
+                    result = result.add(&base_table[(n / 2) as usize]);
+                } else {
+                    result = result.sub(&base_table[((-n) / 2) as usize]);
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WnafContext;
+    use crate::{
+        group::{AdditiveFieldGroup, Group},
+        One, Zero,
+    };
+    use algebra::bls12_381::{fr::Fr, G1Projective};
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn wnaf_mul_test() {
+        let rng = &mut test_rng();
+        let base = G1Projective::rand(rng);
+        let mut scalars: Vec<Fr> = (0..10).map(|_| Fr::rand(rng)).collect();
+        scalars.extend(&[Fr::zero(), Fr::one(), -Fr::one()]);
+        for window_size in 2..=8 {
+            let context = WnafContext::new(window_size);
+            let table = context.table(base);
+            for scalar in &scalars {
+                let expected = Group::mul(&base, scalar);
+                assert_eq!(context.mul(base, scalar), expected);
+                assert_eq!(context.mul_with_table(&table, scalar), Some(expected));
+            }
+            assert_eq!(context.mul_with_table(&table[1..], &scalars[0]), None);
+
+            let field = AdditiveFieldGroup(scalars[0]);
+            for scalar in &scalars {
+                assert_eq!(
+                    context.mul(field, scalar),
+                    AdditiveFieldGroup(scalars[0] * scalar)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use digest::{core_api::BlockSizeUser, Digest};
+
+use crate::{
+    ff::{Field, PrimeField},
+    pairing::{
+        curves::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+        AffineCurve, ProjectiveCurve,
+    },
+    Error, Uint,
+};
+
+use super::{hash_to_field, ExpanderXmd, FromBasePrimeField};
+
+pub mod svdw;
+pub mod swu;
+pub mod wb;
+
+pub use self::{
+    svdw::{SVDWMap, SVDWParameters},
+    swu::{SWUMap, SWUParameters},
+    wb::{WBMap, WBParameters},
+};
+
+/// The target security level `k` of the hash-to-curve suites for the
+/// pairing-friendly curves, such as `BLS12381G1_XMD:SHA-256_SSWU_RO_`.
+const SECURITY_BITS: usize = 128;
+
+/// A deterministic map from field elements to points on a curve, as in
+/// RFC 9380, Section 6.
+pub trait MapToCurve<P: SWModelParameters> {
+    /// Maps `u` to a point on the curve. The point need not lie in the prime
+    /// order subgroup.
+    fn map_to_curve(u: P::BaseField) -> Result<GroupAffine<P>, Error>;
+}
+
+/// Hashes `msg` to a point in the prime order subgroup with the random
+/// oracle construction of RFC 9380, Section 3: two field elements are
+/// derived with `expand_message_xmd` over `H` and the domain separation tag
+/// `dst`, both are mapped with `M`, and their sum is mapped into the
+/// subgroup by `P::clear_cofactor`.
+///
+/// The suites of RFC 9380 clear the cofactor by multiplying with an
+/// effective cofactor `h_eff` rather than with the cofactor, so the output
+/// matches a suite only if `P` overrides `clear_cofactor` with that map. The
+/// BLS12 instances do, with `1 - x` on G1 and the map of Budroni and Pintore
+/// on G2, which are the `h_eff` of the `BLS12381G1` and `BLS12381G2` suites.
+pub fn hash_to_curve<P, M, H>(msg: &[u8], dst: &[u8]) -> Result<GroupAffine<P>, Error>
+where
+    P: SWModelParameters,
+    P::BaseField: FromBasePrimeField,
+    M: MapToCurve<P>,
+    H: Digest + BlockSizeUser,
+{
+    let expander = ExpanderXmd::<H>::new(dst);
+    let u = hash_to_field::<P::BaseField, _>(&expander, msg, 2, SECURITY_BITS)?;
+    let mut r = M::map_to_curve(u[0])?.into_projective();
+    r.add_assign_mixed(&M::map_to_curve(u[1])?);
+    Ok(P::clear_cofactor(&r.into_affine()))
+}
+
+/// Returns the sign of `x` as defined by `sgn0` in RFC 9380, Section 4.1.
+pub fn sgn0<F: FromBasePrimeField>(x: &F) -> bool {
+    let mut sign = false;
+    let mut zero = true;
+    for x_i in x.to_base_prime_field_elems() {
+        sign |= zero && x_i.into_repr().is_odd();
+        zero &= x_i.is_zero();
+    }
+    sign
+}
+
+/// Returns `g(x) = x^3 + A * x + B`.
+fn curve_rhs<P: SWModelParameters>(x: &P::BaseField) -> P::BaseField {
+    P::add_b(&(x.square() * x + &P::mul_by_a(x)))
+}
+
+/// Returns `y` or `-y`, whichever has the same sign as `u`.
+fn with_sign_of<F: FromBasePrimeField>(y: F, u: &F) -> F {
+    if sgn0(&y) == sgn0(u) {
+        y
+    } else {
+        -y
+    }
+}
+
+#[cfg(all(test, feature = "bls12_381"))]
+mod tests {
+    use super::*;
+    use crate::{
+        pairing::curves::bls12_381::{g1, g1_swu_iso, g2, g2_swu_iso, Fq, Fq2},
+        uint::U384,
+        One, Vec, Zero,
+    };
+    use algebra_core::test_rng;
+    use rand::Rng;
+    use sha2::Sha256;
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// The messages of the test vectors of RFC 9380, Appendix J.
+    fn messages() -> Vec<Vec<u8>> {
+        vec![
+            b"".to_vec(),
+            b"abc".to_vec(),
+            b"abcdef0123456789".to_vec(),
+            [&b"q128_"[..], &[b'q'; 128]].concat(),
+            [&b"a512_"[..], &[b'a'; 512]].concat(),
+        ]
+    }
+
+    /// An SVDW constant for G1, found as in RFC 9380, Appendix H.1.
+    impl SVDWParameters for g1::Parameters {
+        /// ZETA = -3
+        const ZETA: Fq = Fq::const_from_repr(U384::new([
+            0xb9feffffffffaaa8,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]));
+    }
+
+    #[test]
+    fn sgn0_of_small_elements() {
+        assert!(!sgn0(&Fq::zero()));
+        assert!(sgn0(&Fq::one()));
+        assert!(!sgn0(&-Fq::one()));
+        assert!(sgn0(&Fq2::new(Fq::zero(), Fq::one())));
+        assert!(!sgn0(&Fq2::new(Fq::from(2u64), Fq::one())));
+        assert!(sgn0(&Fq2::new(-Fq::from(2u64), Fq::one())));
+    }
+
+    #[test]
+    fn maps_land_on_the_curves() {
+        let rng = &mut test_rng();
+        for i in 0..20 {
+            let u: Fq = if i == 0 { Fq::zero() } else { rng.gen() };
+            let p = <SWUMap as MapToCurve<g1_swu_iso::Parameters>>::map_to_curve(u).unwrap();
+            assert!(p.is_on_curve() && sgn0(&p.y) == sgn0(&u));
+            let p = <WBMap as MapToCurve<g1::Parameters>>::map_to_curve(u).unwrap();
+            assert!(p.is_on_curve());
+            let p = <SVDWMap as MapToCurve<g1::Parameters>>::map_to_curve(u).unwrap();
+            assert!(p.is_on_curve() && sgn0(&p.y) == sgn0(&u));
+
+            let u: Fq2 = if i == 0 { Fq2::zero() } else { rng.gen() };
+            let p = <SWUMap as MapToCurve<g2_swu_iso::Parameters>>::map_to_curve(u).unwrap();
+            assert!(p.is_on_curve() && sgn0(&p.y) == sgn0(&u));
+            let p = <WBMap as MapToCurve<g2::Parameters>>::map_to_curve(u).unwrap();
+            assert!(p.is_on_curve());
+        }
+    }
+
+    #[test]
+    fn swu_map_rejects_curves_with_a_zero() {
+        assert!(<SWUMap as MapToCurve<g1::Parameters>>::map_to_curve(Fq::one()).is_err());
+    }
+
+    // RFC 9380, Appendix J.9.1.
+    #[test]
+    fn hash_to_g1() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let outputs = [
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1\
+             08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903\
+             0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98\
+             03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+            "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488\
+             1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+            "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe\
+             05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+        ];
+        for (msg, output) in messages().iter().zip(&outputs) {
+            let p = hash_to_curve::<g1::Parameters, WBMap, Sha256>(msg, dst).unwrap();
+            assert_eq!(p.to_uncompressed().unwrap(), from_hex(output));
+        }
+    }
+
+    // RFC 9380, Appendix J.10.1.
+    #[test]
+    fn hash_to_g2() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let outputs = [
+            "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d\
+             0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a\
+             12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6\
+             0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8\
+             02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6\
+             00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16\
+             1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+            "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c\
+             121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0\
+             0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be\
+             05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+            "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91\
+             19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da\
+             09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662\
+             14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+            "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569\
+             01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534\
+             03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52\
+             0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+        ];
+        for (msg, output) in messages().iter().zip(&outputs) {
+            let p = hash_to_curve::<g2::Parameters, WBMap, Sha256>(msg, dst).unwrap();
+            assert_eq!(p.to_uncompressed().unwrap(), from_hex(output));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    ff::{Field, SquareRootField},
+    hash::FromBasePrimeField,
+    pairing::curves::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    Error,
+};
+
+use super::{curve_rhs, sgn0, with_sign_of, MapToCurve};
+
+const INVALID_ZETA: &str = "the SVDW constant Z is invalid for this curve";
+
+/// Parameters of the Shallue-van de Woestijne map, which applies to any
+/// curve `y^2 = g(x) = x^3 + A * x + B`.
+pub trait SVDWParameters: SWModelParameters {
+    /// The constant `Z`, chosen as in RFC 9380, Section 6.6.1: `g(Z)` and
+    /// `3 * Z^2 + 4 * A` are nonzero, `-g(Z) * (3 * Z^2 + 4 * A)` is a
+    /// square, and one of `g(Z)` and `g(-Z / 2)` is a square.
+    const ZETA: Self::BaseField;
+}
+
+/// The Shallue-van de Woestijne map of RFC 9380, Section 6.6.1.
+pub struct SVDWMap;
+
+impl<P: SVDWParameters> MapToCurve<P> for SVDWMap
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn map_to_curve(u: P::BaseField) -> Result<GroupAffine<P>, Error> {
+        let one = P::BaseField::one();
+        let z = P::ZETA;
+
+        // c1 = g(Z), c2 = -Z / 2, c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A)) with
+        // sgn0(c3) = 0, and c4 = -4 * g(Z) / (3 * Z^2 + 4 * A).
+        let c1 = curve_rhs::<P>(&z);
+        let c2 = -(z * &one.double().inverse().unwrap());
+        let h = z.square() * &(one.double() + &one) + &P::COEFF_A.double().double();
+        let h_inv = h.inverse().ok_or(Error(INVALID_ZETA))?;
+        let c3 = (-(c1 * &h)).sqrt().ok_or(Error(INVALID_ZETA))?;
+        let c3 = if sgn0(&c3) { -c3 } else { c3 };
+        let c4 = -(c1.double().double() * &h_inv);
+
+        let tv1 = u.square() * &c1;
+        let tv2 = one + &tv1;
+        let tv1 = one - &tv1;
+        let tv3 = (tv1 * &tv2).inverse().unwrap_or_else(P::BaseField::zero);
+        let tv4 = u * &tv1 * &tv3 * &c3;
+
+        // The first of x1, x2 and x3 with g(x) square.
+        let x1 = c2 - &tv4;
+        let x2 = c2 + &tv4;
+        let x = if !curve_rhs::<P>(&x1).legendre().is_qnr() {
+            x1
+        } else if !curve_rhs::<P>(&x2).legendre().is_qnr() {
+            x2
+        } else {
+            (tv2.square() * &tv3).square() * &c4 + &z
+        };
+        let y = curve_rhs::<P>(&x).sqrt().ok_or(Error(INVALID_ZETA))?;
+        Ok(GroupAffine::new(x, with_sign_of(y, &u), false))
+    }
+}
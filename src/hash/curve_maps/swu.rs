@@ -0,0 +1,54 @@
+use crate::{
+    ff::{Field, SquareRootField},
+    hash::FromBasePrimeField,
+    pairing::curves::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    Error,
+};
+
+use super::{curve_rhs, with_sign_of, MapToCurve};
+
+/// Parameters of the simplified Shallue-van de Woestijne-Ulas map, which
+/// applies to curves `y^2 = x^3 + A * x + B` with `A * B != 0`.
+pub trait SWUParameters: SWModelParameters {
+    /// The constant `Z`, chosen as in RFC 9380, Section 6.6.2: a non-square
+    /// other than `-1` such that `g(x) - Z` is irreducible and
+    /// `g(B / (Z * A))` is a square.
+    const ZETA: Self::BaseField;
+}
+
+/// The simplified SWU map of RFC 9380, Section 6.6.2.
+pub struct SWUMap;
+
+impl<P: SWUParameters> MapToCurve<P> for SWUMap
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn map_to_curve(u: P::BaseField) -> Result<GroupAffine<P>, Error> {
+        let (a, b) = (P::COEFF_A, P::COEFF_B);
+        let neg_b_div_a = match a.inverse() {
+            Some(a_inv) if !b.is_zero() => -(b * &a_inv),
+            _ => return Err(Error("the SWU map requires a curve with A * B != 0")),
+        };
+
+        // x1 = (-B / A) * (1 + 1 / (Z^2 * u^4 + Z * u^2)), or B / (Z * A) if
+        // the denominator vanishes.
+        let zu2 = P::ZETA * &u.square();
+        let x1 = match (zu2.square() + &zu2).inverse() {
+            Some(tv1) => neg_b_div_a * &(P::BaseField::one() + &tv1),
+            None => b * &(P::ZETA * &a).inverse().unwrap(),
+        };
+
+        // Either g(x1) or g(Z * u^2 * x1) is a square.
+        let gx1 = curve_rhs::<P>(&x1);
+        let (x, gx) = if gx1.legendre().is_qnr() {
+            let x2 = zu2 * &x1;
+            (x2, curve_rhs::<P>(&x2))
+        } else {
+            (x1, gx1)
+        };
+        let y = gx
+            .sqrt()
+            .ok_or(Error("the SWU constant Z is invalid for this curve"))?;
+        Ok(GroupAffine::new(x, with_sign_of(y, &u), false))
+    }
+}
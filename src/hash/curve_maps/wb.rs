@@ -0,0 +1,68 @@
+use crate::{
+    ff::Field,
+    hash::FromBasePrimeField,
+    pairing::curves::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    Error, Zero,
+};
+
+use super::{MapToCurve, SWUMap, SWUParameters};
+
+/// Parameters of the Wahby-Boneh map, which applies the simplified SWU map
+/// on an isogenous curve with `A * B != 0` and then an isogeny to the
+/// target curve. This handles curves with `A = 0`, such as the BLS12-381
+/// G1 and G2 curves.
+pub trait WBParameters: SWModelParameters + Sized {
+    /// The curve isogenous to `Self` on which the simplified SWU map is
+    /// evaluated.
+    type IsogenousCurve: SWUParameters<BaseField = Self::BaseField>;
+
+    /// The coefficients of the numerator of the x-coordinate map, in
+    /// increasing order of degree.
+    const PHI_X_NUM: &'static [Self::BaseField];
+    /// The coefficients of the denominator of the x-coordinate map.
+    const PHI_X_DEN: &'static [Self::BaseField];
+    /// The coefficients of the numerator of the y-coordinate map.
+    const PHI_Y_NUM: &'static [Self::BaseField];
+    /// The coefficients of the denominator of the y-coordinate map.
+    const PHI_Y_DEN: &'static [Self::BaseField];
+
+    /// Maps `p` to `(x_num(x) / x_den(x), y * y_num(x) / y_den(x))`, sending
+    /// the points where a denominator vanishes to the identity as in
+    /// RFC 9380, Section 6.6.3.
+    fn isogeny_map(p: &GroupAffine<Self::IsogenousCurve>) -> GroupAffine<Self> {
+        if p.infinity {
+            return GroupAffine::zero();
+        }
+        let eval = |coeffs: &[Self::BaseField]| {
+            coeffs
+                .iter()
+                .rev()
+                .fold(Self::BaseField::zero(), |acc, c| acc * &p.x + c)
+        };
+        match (
+            eval(Self::PHI_X_DEN).inverse(),
+            eval(Self::PHI_Y_DEN).inverse(),
+        ) {
+            (Some(x_den_inv), Some(y_den_inv)) => GroupAffine::new(
+                eval(Self::PHI_X_NUM) * &x_den_inv,
+                p.y * &eval(Self::PHI_Y_NUM) * &y_den_inv,
+                false,
+            ),
+            _ => GroupAffine::zero(),
+        }
+    }
+}
+
+/// The simplified SWU map for curves with `A = 0`, through an isogeny, as in
+/// RFC 9380, Section 6.6.3.
+pub struct WBMap;
+
+impl<P: WBParameters> MapToCurve<P> for WBMap
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn map_to_curve(u: P::BaseField) -> Result<GroupAffine<P>, Error> {
+        let p = <SWUMap as MapToCurve<P::IsogenousCurve>>::map_to_curve(u)?;
+        Ok(P::isogeny_map(&p))
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    ff::{
+        Field, Fp2, Fp256, Fp256Parameters, Fp2Parameters, Fp3, Fp320, Fp320Parameters, Fp384,
+        Fp384Parameters, Fp3Parameters, Fp768, Fp768Parameters, Fp832, Fp832Parameters, PrimeField,
+    },
+    Error, Vec,
+};
+
+pub mod expander;
+pub use self::expander::Expander;
+#[cfg(feature = "digest")]
+pub use self::expander::{ExpanderXmd, ExpanderXof};
+
+pub mod polyval;
+pub use self::polyval::{Ghash, Polyval};
+
+#[cfg(all(feature = "pairing", feature = "digest"))]
+pub mod curve_maps;
+#[cfg(all(feature = "pairing", feature = "digest"))]
+pub use self::curve_maps::{hash_to_curve, MapToCurve};
+
+/// A field that `hash_to_field` can output: an extension of degree
+/// `EXTENSION_DEGREE` over a prime field, with elements given by their
+/// coordinates over that prime field.
+pub trait FromBasePrimeField: Field {
+    /// The prime subfield.
+    type BasePrimeField: PrimeField;
+
+    /// The degree `m` of the field over its prime subfield.
+    const EXTENSION_DEGREE: usize;
+
+    /// Constructs an element from its `EXTENSION_DEGREE` coordinates.
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Self;
+
+    /// Returns the `EXTENSION_DEGREE` coordinates of `self`.
+    fn to_base_prime_field_elems(&self) -> Vec<Self::BasePrimeField>;
+}
+
+macro_rules! impl_from_base_prime_field {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> FromBasePrimeField for $field<P> {
+            type BasePrimeField = Self;
+
+            const EXTENSION_DEGREE: usize = 1;
+
+            fn from_base_prime_field_elems(elems: &[Self]) -> Self {
+                elems[0]
+            }
+
+            fn to_base_prime_field_elems(&self) -> Vec<Self> {
+                vec![*self]
+            }
+        }
+    };
+}
+
+impl_from_base_prime_field!(Fp256, Fp256Parameters);
+impl_from_base_prime_field!(Fp320, Fp320Parameters);
+impl_from_base_prime_field!(Fp384, Fp384Parameters);
+impl_from_base_prime_field!(Fp768, Fp768Parameters);
+impl_from_base_prime_field!(Fp832, Fp832Parameters);
+
+impl<P: Fp2Parameters> FromBasePrimeField for Fp2<P> {
+    type BasePrimeField = P::Fp;
+
+    const EXTENSION_DEGREE: usize = 2;
+
+    fn from_base_prime_field_elems(elems: &[P::Fp]) -> Self {
+        Fp2::new(elems[0], elems[1])
+    }
+
+    fn to_base_prime_field_elems(&self) -> Vec<P::Fp> {
+        vec![self.c0, self.c1]
+    }
+}
+
+impl<P: Fp3Parameters> FromBasePrimeField for Fp3<P> {
+    type BasePrimeField = P::Fp;
+
+    const EXTENSION_DEGREE: usize = 3;
+
+    fn from_base_prime_field_elems(elems: &[P::Fp]) -> Self {
+        Fp3::new(elems[0], elems[1], elems[2])
+    }
+
+    fn to_base_prime_field_elems(&self) -> Vec<P::Fp> {
+        vec![self.c0, self.c1, self.c2]
+    }
+}
+
+/// Hashes `msg` to `count` field elements as specified by `hash_to_field`
+/// in RFC 9380, Section 5.2, where `expander` carries the domain separation
+/// tag and `security_bits` is the target security level `k` of the suite.
+pub fn hash_to_field<F: FromBasePrimeField, E: Expander>(
+    expander: &E,
+    msg: &[u8],
+    count: usize,
+    security_bits: usize,
+) -> Result<Vec<F>, Error> {
+    let m = F::EXTENSION_DEGREE;
+    // L = ceil((ceil(log2(p)) + k) / 8)
+    let modulus_bits = <F::BasePrimeField as PrimeField>::size_in_bits();
+    let len_per_elem = (modulus_bits + security_bits + 7) / 8;
+    let uniform_bytes = expander.expand(msg, count * m * len_per_elem)?;
+
+    let elems = uniform_bytes
+        .chunks(len_per_elem)
+        .map(<F::BasePrimeField as PrimeField>::from_be_bytes_mod_order)
+        .collect::<Vec<_>>();
+    Ok(elems
+        .chunks(m)
+        .map(F::from_base_prime_field_elems)
+        .collect())
+}
+
+#[cfg(all(test, feature = "digest"))]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::Fq;
+    use core::str::FromStr;
+    use sha2::Sha256;
+
+    // Test vector from RFC 9380, Appendix J.9.1.
+    #[test]
+    fn hash_to_field_test_vector() {
+        let expander =
+            ExpanderXmd::<Sha256>::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_");
+        let u: Vec<Fq> = hash_to_field(&expander, b"", 2, 128).unwrap();
+        assert_eq!(
+            u[0],
+            Fq::from_str(
+                "17900306165685619802071342183448993387369008851184931832482558756821237377\
+                 56800213955590674957414534085508415116879",
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! The universal hashes POLYVAL of AES-GCM-SIV ([RFC 8452]) and GHASH of
+//! AES-GCM, by Horner's rule over `GF(2^128)`.
+//!
+//! [RFC 8452]: https://www.rfc-editor.org/rfc/rfc8452
+
+use crate::ff::{binary::Gf128, ring::Ring};
+
+/// The size of the blocks, and of the keys and hashes, in bytes.
+pub const BLOCK_SIZE: usize = 16;
+
+/// POLYVAL keyed with `H`: `S_j = (S_{j-1} + X_j) H x^(-128)` over the
+/// blocks `X_j`, from `S_0 = 0`.
+#[derive(Clone, Debug)]
+pub struct Polyval {
+    /// `H x^(-128)`, so that each block costs a single product.
+    key: Gf128,
+    acc: Gf128,
+}
+
+impl Polyval {
+    pub fn new(h: [u8; BLOCK_SIZE]) -> Self {
+        // `x^128` is the low part of the modulus, plus `x^128` itself.
+        let x_128 = Gf128(1 << 127) * Gf128::X;
+        Polyval {
+            key: Gf128::from_le_bytes(h) * x_128.inverse().unwrap(),
+            acc: Gf128::zero_element(),
+        }
+    }
+
+    /// Absorbs one block.
+    pub fn update(&mut self, block: &[u8; BLOCK_SIZE]) {
+        self.acc = (self.acc + Gf128::from_le_bytes(*block)) * self.key;
+    }
+
+    /// Absorbs `data`, with its last block padded with zeros.
+    pub fn update_padded(&mut self, data: &[u8]) {
+        for chunk in data.chunks(BLOCK_SIZE) {
+            let mut block = [0u8; BLOCK_SIZE];
+            block[..chunk.len()].copy_from_slice(chunk);
+            self.update(&block);
+        }
+    }
+
+    /// Returns the hash of the blocks absorbed so far.
+    pub fn finalize(&self) -> [u8; BLOCK_SIZE] {
+        self.acc.to_le_bytes()
+    }
+}
+
+/// GHASH keyed with `H`, which is POLYVAL on byte-reversed blocks, keyed
+/// with the byte-reversal of `H` times `x`, as in appendix A of RFC 8452.
+#[derive(Clone, Debug)]
+pub struct Ghash(Polyval);
+
+impl Ghash {
+    pub fn new(mut h: [u8; BLOCK_SIZE]) -> Self {
+        h.reverse();
+        let h = Gf128::from_le_bytes(h) * Gf128::X;
+        Ghash(Polyval::new(h.to_le_bytes()))
+    }
+
+    /// Absorbs one block.
+    pub fn update(&mut self, block: &[u8; BLOCK_SIZE]) {
+        let mut block = *block;
+        block.reverse();
+        self.0.update(&block);
+    }
+
+    /// Absorbs `data`, with its last block padded with zeros.
+    pub fn update_padded(&mut self, data: &[u8]) {
+        for chunk in data.chunks(BLOCK_SIZE) {
+            let mut block = [0u8; BLOCK_SIZE];
+            block[..chunk.len()].copy_from_slice(chunk);
+            self.update(&block);
+        }
+    }
+
+    /// Returns the hash of the blocks absorbed so far.
+    pub fn finalize(&self) -> [u8; BLOCK_SIZE] {
+        let mut hash = self.0.finalize();
+        hash.reverse();
+        hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(hex: &str) -> [u8; BLOCK_SIZE] {
+        let mut block = [0u8; BLOCK_SIZE];
+        for (i, b) in block.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        block
+    }
+
+    #[test]
+    fn polyval_test() {
+        // Appendix A of RFC 8452.
+        let mut polyval = Polyval::new(block("25629347589242761d31f826ba4b757b"));
+        polyval.update(&block("4f4f95668c83dfb6401762bb2d01a262"));
+        polyval.update(&block("d1a24ddd2721d006bbe45f20d3c9f362"));
+        assert_eq!(
+            polyval.finalize(),
+            block("f7a3b47b846119fae5b7866cf5e5b77e")
+        );
+    }
+
+    #[test]
+    fn ghash_test() {
+        // Test case 2 of the GCM specification: the ciphertext block, then
+        // the block of lengths.
+        let mut ghash = Ghash::new(block("66e94bd4ef8a2c3b884cfa59ca342b2e"));
+        ghash.update(&block("0388dace60b6a392f328c2b971b2fe78"));
+        ghash.update_padded(&block("00000000000000000000000000000080"));
+        assert_eq!(ghash.finalize(), block("f38cbb1ad69223dcc3457ae5b6b0f885"));
+    }
+}
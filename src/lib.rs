@@ -50,14 +50,78 @@ extern crate serde_crate;
 #[macro_use]
 extern crate uint_derive;
 
+/// Reports an event to the recorder of the `metrics` feature, as
+/// `record!(hook, args...)` for a method `hook` of `metrics::Recorder`.
+#[cfg(feature = "metrics")]
+macro_rules! record {
+    ($hook:ident $(, $arg:expr)*) => {
+        $crate::metrics::with_recorder(|recorder| recorder.$hook($($arg),*))
+    };
+}
+
+#[cfg(not(feature = "metrics"))]
+#[allow(unused_macros)]
+macro_rules! record {
+    ($hook:ident $(, $arg:expr)*) => {};
+}
+
+/// Times the rest of the enclosing block as the instrumented path `path` of
+/// the `ct-audit` feature, as `ct_probe!("inverse")`.
+#[cfg(feature = "ct-audit")]
+macro_rules! ct_probe {
+    ($path:expr) => {
+        let _probe = $crate::ct_audit::Probe::new($path);
+    };
+}
+
+#[cfg(not(feature = "ct-audit"))]
+#[allow(unused_macros)]
+macro_rules! ct_probe {
+    ($path:expr) => {};
+}
+
+/// Asserts that the prime field elements `elems` are below the modulus, as
+/// `assert_canonical!(self, other)`, if the `debug-assert-canonical` feature
+/// is enabled. Elements that are not, e.g. corrupted in memory or read
+/// through FFI without checks, make every later result silently wrong.
+#[cfg(feature = "debug-assert-canonical")]
+macro_rules! assert_canonical {
+    ($($elem:expr),+) => {
+        $(
+            assert!(
+                $elem.is_valid(),
+                "non-canonical field element {:?}, not below the modulus",
+                $elem.0
+            );
+        )+
+    };
+}
+
+#[cfg(not(feature = "debug-assert-canonical"))]
+#[allow(unused_macros)]
+macro_rules! assert_canonical {
+    ($($elem:expr),+) => {};
+}
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[macro_use]
+pub mod serialize;
+
 #[macro_use]
 pub mod uint;
 
+#[cfg(feature = "ct-audit")]
+pub mod ct_audit;
+
 //#[macro_use]
 //pub mod ff;
 
 //pub mod fft;
 
+//pub mod codes;
+
 //pub mod group;
 
 //#[cfg(feature = "pairing")]
@@ -66,13 +130,26 @@ pub mod uint;
 //mod rand;
 mod utils;
 
-//mod to_field_vec;
+//pub mod to_field_vec;
+
+//#[cfg(feature = "test-helpers")]
+//pub mod test_helpers;
 
 //pub mod msm;
 
+//pub mod sss;
+
+//pub mod hash;
+
+//pub mod sponge;
+
+//pub mod dlog;
+
 pub mod prelude {
     pub use crate::uint::Uint;
 
+    pub use crate::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
     //pub use crate::ff::{Field, FpParameters, PrimeField, SquareRootField};
 
     //pub use crate::group::Group;
@@ -81,7 +158,7 @@ pub mod prelude {
 
     //pub use crate::rand::UniformRand;
 
-    pub use crate::Error;
+    pub use crate::{Error, SunziError};
 }
 
 pub(crate) type Result<T> = core::result::Result<T, Error>;
@@ -95,6 +172,71 @@ impl core::fmt::Display for Error {
     }
 }
 
+/// The causes of failure of the `try_*` constructors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SunziError {
+    /// An integer is not smaller than the modulus of the field.
+    NotInField,
+    /// An encoding is not the canonical one of any value, e.g. it has bits
+    /// set outside of the encoded value.
+    NonCanonical,
+    /// The field has no root of unity of the requested order.
+    NoRootOfUnity,
+    /// The requested domain is larger than the subgroups of the field allow.
+    DomainTooLarge,
+    /// An input does not have the expected length.
+    SizeMismatch { expected: usize, found: usize },
+    /// A polynomial has more coefficients than the domain it is evaluated
+    /// over has elements.
+    DegreeTooLarge { degree: usize, domain_size: usize },
+    /// An input could not be parsed, for the given reason.
+    ParseError(&'static str),
+    /// The parameters of a construction are invalid, for the given reason.
+    InvalidParameters(&'static str),
+}
+
+impl core::fmt::Display for SunziError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            SunziError::SizeMismatch { expected, found } => {
+                write!(f, "expected a length of {}, found {}", expected, found)
+            }
+            SunziError::DegreeTooLarge {
+                degree,
+                domain_size,
+            } => write!(
+                f,
+                "polynomial of degree {} does not fit a domain of size {}",
+                degree, domain_size
+            ),
+            _ => f.write_str(Error::from(*self).0),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SunziError {}
+
+impl From<Error> for SunziError {
+    fn from(e: Error) -> Self {
+        SunziError::ParseError(e.0)
+    }
+}
+
+impl From<SunziError> for Error {
+    fn from(e: SunziError) -> Self {
+        Error(match e {
+            SunziError::NotInField => "integer is not smaller than the modulus",
+            SunziError::NonCanonical => "non-canonical encoding",
+            SunziError::NoRootOfUnity => "no root of unity of the requested order",
+            SunziError::DomainTooLarge => "evaluation domain is too large",
+            SunziError::SizeMismatch { .. } => "input has the wrong length",
+            SunziError::DegreeTooLarge { .. } => "polynomial degree is too large for the domain",
+            SunziError::ParseError(reason) | SunziError::InvalidParameters(reason) => reason,
+        })
+    }
+}
+
 #[cfg(feature = "asm")]
 pub mod asm;
 
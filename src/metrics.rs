@@ -0,0 +1,186 @@
+//! Counters of the arithmetic done by the crate, enabled by the `metrics`
+//! feature. A `Recorder` installed with `set_recorder` is told about every
+//! multiplication and inversion in a prime field, every FFT, and the time
+//! spent in the phases that callers delimit with `phase`.
+//!
+//! Without the feature, the hooks compile to nothing.
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex, MutexGuard, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// Receives the events of the crate. All methods do nothing by default.
+pub trait Recorder: Send + Sync {
+    /// A multiplication or a squaring in a prime field.
+    fn field_mul(&self) {}
+
+    /// An inversion in a prime field.
+    fn field_inversion(&self) {}
+
+    /// An FFT or inverse FFT over a domain of size `size`.
+    fn fft(&self, _size: usize) {}
+
+    /// The end of a call to `phase`.
+    fn phase(&self, _name: &'static str, _elapsed: Duration) {}
+}
+
+static RECORDER: RwLock<Option<&'static dyn Recorder>> = RwLock::new(None);
+
+// Checked before taking the lock, so that hooks stay cheap while no
+// recorder is installed.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Installs `recorder` for all threads, and returns the previous one.
+pub fn set_recorder(recorder: &'static dyn Recorder) -> Option<&'static dyn Recorder> {
+    swap_recorder(Some(recorder))
+}
+
+/// Uninstalls the recorder, and returns it.
+pub fn clear_recorder() -> Option<&'static dyn Recorder> {
+    swap_recorder(None)
+}
+
+fn swap_recorder(recorder: Option<&'static dyn Recorder>) -> Option<&'static dyn Recorder> {
+    let mut current = RECORDER.write().unwrap_or_else(|e| e.into_inner());
+    ENABLED.store(recorder.is_some(), Ordering::Release);
+    core::mem::replace(&mut *current, recorder)
+}
+
+/// Calls `f` with the recorder, if one is installed.
+#[inline]
+pub(crate) fn with_recorder(f: impl FnOnce(&dyn Recorder)) {
+    if !ENABLED.load(Ordering::Acquire) {
+        return;
+    }
+    if let Some(recorder) = *RECORDER.read().unwrap_or_else(|e| e.into_inner()) {
+        f(recorder)
+    }
+}
+
+/// Runs `f`, and reports its running time to the recorder as the phase
+/// `name`.
+pub fn phase<R>(name: &'static str, f: impl FnOnce() -> R) -> R {
+    let start = Instant::now();
+    let result = f();
+    record!(phase, name, start.elapsed());
+    result
+}
+
+/// A `Recorder` that sums up the events, e.g. to be installed from a
+/// `static`.
+#[derive(Debug, Default)]
+pub struct Counters {
+    field_muls: AtomicU64,
+    field_inversions: AtomicU64,
+    ffts: Mutex<BTreeMap<usize, u64>>,
+    phases: Mutex<BTreeMap<&'static str, Duration>>,
+}
+
+impl Counters {
+    pub const fn new() -> Self {
+        Counters {
+            field_muls: AtomicU64::new(0),
+            field_inversions: AtomicU64::new(0),
+            ffts: Mutex::new(BTreeMap::new()),
+            phases: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the number of multiplications and squarings in prime fields.
+    pub fn field_muls(&self) -> u64 {
+        self.field_muls.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of inversions in prime fields.
+    pub fn field_inversions(&self) -> u64 {
+        self.field_inversions.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of FFTs by size of the domain.
+    pub fn ffts(&self) -> BTreeMap<usize, u64> {
+        lock(&self.ffts).clone()
+    }
+
+    /// Returns the total time spent in each phase.
+    pub fn phases(&self) -> BTreeMap<&'static str, Duration> {
+        lock(&self.phases).clone()
+    }
+
+    /// Sets all counters back to zero.
+    pub fn reset(&self) {
+        self.field_muls.store(0, Ordering::Relaxed);
+        self.field_inversions.store(0, Ordering::Relaxed);
+        lock(&self.ffts).clear();
+        lock(&self.phases).clear();
+    }
+}
+
+impl Recorder for Counters {
+    fn field_mul(&self) {
+        self.field_muls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn field_inversion(&self) {
+        self.field_inversions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn fft(&self, size: usize) {
+        *lock(&self.ffts).entry(size).or_insert(0) += 1;
+    }
+
+    fn phase(&self, name: &'static str, elapsed: Duration) {
+        *lock(&self.phases).entry(name).or_default() += elapsed;
+    }
+}
+
+// A panic while holding the lock cannot leave the counts inconsistent, so
+// poisoning is ignored.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_test() {
+        static COUNTERS: Counters = Counters::new();
+        assert!(set_recorder(&COUNTERS).is_none());
+
+        // Other tests may run arithmetic concurrently, hence the lower
+        // bounds.
+        let value = phase("counters_test setup", || {
+            record!(field_mul);
+            record!(field_mul);
+            record!(field_inversion);
+            record!(fft, 8);
+            1
+        });
+        phase("counters_test setup", || record!(fft, 8));
+        phase("counters_test prove", || record!(fft, 16));
+        assert_eq!(value, 1);
+
+        assert!(COUNTERS.field_muls() >= 2);
+        assert!(COUNTERS.field_inversions() >= 1);
+        let ffts = COUNTERS.ffts();
+        assert!(ffts[&8] >= 2 && ffts[&16] >= 1);
+        let phases = COUNTERS.phases();
+        assert!(phases.contains_key("counters_test setup"));
+        assert!(phases.contains_key("counters_test prove"));
+
+        assert!(clear_recorder().is_some());
+        let field_muls = COUNTERS.field_muls();
+        record!(field_mul);
+        assert_eq!(COUNTERS.field_muls(), field_muls);
+
+        COUNTERS.reset();
+        assert_eq!(COUNTERS.field_muls(), 0);
+        assert!(COUNTERS.ffts().is_empty());
+    }
+}
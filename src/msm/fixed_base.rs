@@ -88,3 +88,130 @@ impl FixedBaseMSM {
             .collect::<Vec<_>>()
     }
 }
+
+/// Precomputed window tables for a fixed set of bases, for repeated
+/// multi-scalar multiplications against the same bases.
+///
+/// For every base `g` and every window `j`, the table stores the affine
+/// multiples `i * 2^(j * window) * g` for all `i < 2^window`, so that a
+/// scalar multiplication costs one mixed addition per window.
+#[derive(Clone, Debug)]
+pub struct FixedBase<T: ProjectiveCurve> {
+    window: usize,
+    tables: Vec<Vec<Vec<T::Affine>>>,
+}
+
+impl<T: ProjectiveCurve> FixedBase<T> {
+    /// Precomputes the window tables for `bases` with the given window size.
+    pub fn new(bases: &[T], window: usize) -> Self {
+        assert!(window > 0, "window size must be positive");
+        let scalar_size = <T::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+
+        #[cfg(feature = "parallel")]
+        let bases_iter = bases.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let bases_iter = bases.iter();
+
+        let tables = bases_iter
+            .map(|g| {
+                FixedBaseMSM::get_window_table(scalar_size, window, *g)
+                    .iter()
+                    .map(|row| T::batch_normalization_into_affine(row))
+                    .collect()
+            })
+            .collect();
+        Self { window, tables }
+    }
+
+    /// Returns the window size of the tables.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of bases.
+    pub fn num_bases(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Multiplies the `index`-th base by `scalar`.
+    pub fn mul(&self, index: usize, scalar: &T::ScalarField) -> T {
+        Self::windowed_mul(self.window, &self.tables[index], scalar)
+    }
+
+    fn windowed_mul(window: usize, table: &[Vec<T::Affine>], scalar: &T::ScalarField) -> T {
+        let scalar_size = <T::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let mut scalar_val = scalar.into_repr().to_bits();
+        scalar_val.reverse();
+
+        let mut res = T::zero();
+        for (outer, row) in table.iter().enumerate() {
+            let mut inner = 0usize;
+            for i in 0..window {
+                let bit = outer * window + i;
+                if bit < scalar_size && scalar_val[bit] {
+                    inner |= 1 << i;
+                }
+            }
+            res.add_assign_mixed(&row[inner]);
+        }
+        res
+    }
+}
+
+/// Computes `sum_i scalars[i] * bases[i]` for the bases precomputed in
+/// `table`.
+///
+/// # Panics
+/// This method panics if `scalars.len()` differs from the number of bases.
+pub fn fixed_base_msm<T: ProjectiveCurve>(table: &FixedBase<T>, scalars: &[T::ScalarField]) -> T {
+    assert_eq!(
+        scalars.len(),
+        table.num_bases(),
+        "number of scalars must equal the number of bases"
+    );
+
+    #[cfg(feature = "parallel")]
+    let tables_iter = table.tables.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let tables_iter = table.tables.iter();
+
+    tables_iter
+        .zip(scalars)
+        .map(|(t, s)| FixedBase::windowed_mul(table.window, t, s))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fixed_base_msm, FixedBase};
+    use crate::{One, PrimeField, ProjectiveCurve, Zero};
+    use algebra::bls12_381::{fr::Fr, G1Projective};
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn fixed_base_msm_test() {
+        let rng = &mut test_rng();
+        let bases: Vec<G1Projective> = (0..8).map(|_| G1Projective::rand(rng)).collect();
+        let scalars: Vec<Fr> = (0..8).map(|_| Fr::rand(rng)).collect();
+        let naive: G1Projective = bases
+            .iter()
+            .zip(&scalars)
+            .map(|(g, s)| g.mul(s.into_repr()))
+            .sum();
+
+        for window in [1, 2, 3, 4, 7, 8] {
+            let table = FixedBase::new(&bases, window);
+            assert_eq!(table.window(), window);
+            assert_eq!(table.num_bases(), bases.len());
+            for (i, (g, s)) in bases.iter().zip(&scalars).enumerate() {
+                assert_eq!(table.mul(i, s), g.mul(s.into_repr()));
+            }
+            assert!(table.mul(0, &Fr::zero()).is_zero());
+            assert_eq!(table.mul(0, &-Fr::one()), -bases[0]);
+            assert_eq!(fixed_base_msm(&table, &scalars), naive);
+
+            let empty = FixedBase::<G1Projective>::new(&[], window);
+            assert!(fixed_base_msm(&empty, &[]).is_zero());
+        }
+    }
+}
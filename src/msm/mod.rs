@@ -1,6 +1,8 @@
 mod fixed_base;
+mod srs;
 mod variable_base;
 pub use fixed_base::*;
+pub use srs::*;
 pub use variable_base::*;
 
 /// The result of this function is only approximately `ln(a)`
@@ -0,0 +1,53 @@
+//! Generation of structured reference strings of powers of a secret `τ`,
+//! as KZG-style commitments use, from a known `τ`. This is not a trusted
+//! setup, since whoever runs it learns `τ`, but it is what tests and
+//! simulations of trusted setups need.
+
+use crate::{fft::powers, FpParameters, PrimeField, ProjectiveCurve, Vec};
+
+use super::FixedBaseMSM;
+
+/// Returns `[1, τ, τ^2, ..., τ^(n - 1)]`, computed as prefix products in
+/// parallel chunks if the `parallel` feature is enabled, each chunk from its
+/// own power of `τ`.
+pub fn generate_powers_of_tau<F: PrimeField>(tau: F, n: usize) -> Vec<F> {
+    powers(tau, n)
+}
+
+/// Returns `[s_0] g, [s_1] g, ...` for the scalars `s_i` of `powers`, in
+/// affine form, with one window table of `g` for all of them and in
+/// parallel if the `parallel` feature is enabled.
+pub fn scale_powers<G: ProjectiveCurve>(g: G, powers: &[G::ScalarField]) -> Vec<G::Affine> {
+    let scalar_size = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+    let window = FixedBaseMSM::get_mul_window_size(powers.len());
+    let table = FixedBaseMSM::get_window_table(scalar_size, window, g);
+    let scaled = FixedBaseMSM::multi_scalar_mul(scalar_size, window, &table, powers);
+    G::batch_normalization_into_affine(&scaled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_powers_of_tau, scale_powers};
+    use crate::{AffineCurve, Field, PrimeField, ProjectiveCurve};
+    use algebra::bls12_381::{fr::Fr, G1Projective};
+    use algebra_core::{test_rng, UniformRand};
+
+    #[test]
+    fn powers_of_tau_test() {
+        let rng = &mut test_rng();
+        let tau = Fr::rand(rng);
+        let powers = generate_powers_of_tau(tau, 1000);
+        assert_eq!(powers.len(), 1000);
+        assert_eq!(powers[0], Fr::one());
+        for i in [1, 17, 999] {
+            assert_eq!(powers[i], tau.pow([i as u64]));
+        }
+        assert!(generate_powers_of_tau(tau, 0).is_empty());
+
+        let g = G1Projective::rand(rng);
+        let srs = scale_powers(g, &powers[..40]);
+        for (point, power) in srs.iter().zip(&powers) {
+            assert_eq!(point.into_projective(), g.mul(power.into_repr()));
+        }
+    }
+}
@@ -103,4 +103,29 @@ impl VariableBaseMSM {
     ) -> G::Projective {
         Self::msm_inner(bases, scalars)
     }
+
+    /// Computes the MSM of `bases` with scalars given in consecutive chunks,
+    /// e.g. from `DensePolynomial::coeffs_chunked`, so that only one chunk
+    /// of scalars is converted out of Montgomery form at a time. Scalars
+    /// beyond the number of bases are ignored.
+    pub fn multi_scalar_mul_chunked<G, I, C>(bases: &[G], scalar_chunks: I) -> G::Projective
+    where
+        G: AffineCurve,
+        I: IntoIterator<Item = C>,
+        C: AsRef<[G::ScalarField]>,
+    {
+        let mut result = G::Projective::zero();
+        let mut start = 0;
+        for chunk in scalar_chunks {
+            if start >= bases.len() {
+                break;
+            }
+            let chunk = chunk.as_ref();
+            let end = bases.len().min(start + chunk.len());
+            let scalars: Vec<_> = chunk[..end - start].iter().map(|s| s.into_repr()).collect();
+            result += Self::msm_inner(&bases[start..end], &scalars);
+            start = end;
+        }
+        result
+    }
 }
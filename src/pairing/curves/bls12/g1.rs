@@ -1,12 +1,15 @@
 use crate::{
     pairing::{
-        curves::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        curves::{
+            glv::GLVParameters,
+            short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        },
         AffineCurve,
     },
     Zero,
 };
 
-use super::Bls12Parameters;
+use super::{mul_by_x, Bls12Parameters};
 
 pub type G1Affine<P> = GroupAffine<<P as Bls12Parameters>::G1Parameters>;
 pub type G1Projective<P> = GroupProjective<<P as Bls12Parameters>::G1Parameters>;
@@ -38,3 +41,25 @@ impl<P: Bls12Parameters> Default for G1Prepared<P> {
         G1Prepared(G1Affine::<P>::prime_subgroup_generator())
     }
 }
+
+/// Checks if `p` is in G1, assuming that it is on the curve, by testing
+/// `phi(p) == -x^2 * p` for the endomorphism `phi(x, y) = (beta * x, y)`.
+/// This is Bowe's method (<https://eprint.iacr.org/2019/814>) in the
+/// simplified form of Scott (<https://eprint.iacr.org/2021/1130>).
+///
+/// The endomorphism is the one of the GLV parameters of G1, whose `LAMBDA`
+/// must be `-x^2`.
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Parameters>(p: &G1Affine<P>) -> bool
+where
+    P::G1Parameters: GLVParameters,
+{
+    let x_p = mul_by_x::<P, _>(&p.into_projective());
+    G1Projective::<P>::from(p.endomorphism()) == -mul_by_x::<P, _>(&x_p)
+}
+
+/// Maps `p` into G1 by multiplying it with the effective cofactor `1 - x`
+/// (<https://eprint.iacr.org/2019/403>, Section 5).
+pub fn clear_cofactor<P: Bls12Parameters>(p: &G1Affine<P>) -> G1Affine<P> {
+    let p = p.into_projective();
+    (p - &mul_by_x::<P, _>(&p)).into()
+}
@@ -1,14 +1,14 @@
 use crate::{
-    ff::{BitIterator, Field, Fp2},
+    ff::{fp12_2over3over2::Fp12Parameters, fp6_3over2::Fp6Parameters, BitIterator, Field, Fp2},
     pairing::{
         curves::SWModelParameters,
         short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-        AffineCurve,
+        AffineCurve, ProjectiveCurve,
     },
     One, Vec, Zero,
 };
 
-use super::{Bls12Parameters, TwistType};
+use super::{mul_by_x, Bls12Parameters, TwistType};
 
 pub type G2Affine<P> = GroupAffine<<P as Bls12Parameters>::G2Parameters>;
 pub type G2Projective<P> = GroupProjective<<P as Bls12Parameters>::G2Parameters>;
@@ -135,3 +135,49 @@ fn addition_step<B: Bls12Parameters>(
         TwistType::D => (lambda, -theta, j),
     }
 }
+
+/// Applies the untwist-Frobenius-twist endomorphism `psi` to `p`.
+pub fn psi<P: Bls12Parameters>(p: &G2Projective<P>) -> G2Projective<P> {
+    // For a D-type twist, psi(x, y) = (c_x * conj(x), c_y * conj(y)) with
+    // c_x = xi^((p - 1) / 3) and c_y = xi^((p - 1) / 2). For an M-type twist
+    // the coefficients are inverted.
+    let mut c_x = P::Fp6Params::FROBENIUS_COEFF_FP6_C1[1];
+    let xi_to_p_minus_one_over_6 = P::Fp12Params::FROBENIUS_COEFF_FP12_C1[1];
+    let mut c_y = xi_to_p_minus_one_over_6.square() * &xi_to_p_minus_one_over_6;
+    if let TwistType::M = P::TWIST_TYPE {
+        c_x = c_x.inverse().unwrap();
+        c_y = c_y.inverse().unwrap();
+    }
+
+    let mut res = *p;
+    res.x.frobenius_map(1);
+    res.x *= &c_x;
+    res.y.frobenius_map(1);
+    res.y *= &c_y;
+    res.z.frobenius_map(1);
+    res
+}
+
+/// Checks if `p` is in G2, assuming that it is on the curve, by testing
+/// `psi(p) == x * p` (<https://eprint.iacr.org/2021/1130>).
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Parameters>(p: &G2Affine<P>) -> bool {
+    let p = p.into_projective();
+    psi::<P>(&p) == mul_by_x::<P, _>(&p)
+}
+
+/// Maps `p` into G2 by multiplying it with the effective cofactor of Budroni
+/// and Pintore (<https://eprint.iacr.org/2017/419>), i.e. computing
+/// `[x^2 - x - 1] p + [x - 1] psi(p) + psi^2(2p)`.
+pub fn clear_cofactor<P: Bls12Parameters>(p: &G2Affine<P>) -> G2Affine<P> {
+    let p = p.into_projective();
+    let t1 = mul_by_x::<P, _>(&p);
+    let mut t2 = psi::<P>(&p);
+    let mut t3 = psi::<P>(&psi::<P>(&p.double()));
+    t3 -= &t2;
+    t2 += &t1;
+    t2 = mul_by_x::<P, _>(&t2);
+    t3 += &t2;
+    t3 -= &t1;
+    t3 -= &p;
+    t3.into()
+}
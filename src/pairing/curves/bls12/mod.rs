@@ -6,19 +6,28 @@ use crate::{
         BitIterator, Field, Fp2, PrimeField, SquareRootField,
     },
     pairing::{
-        curves::{ModelParameters, SWModelParameters},
-        PairingEngine,
+        curves::{short_weierstrass_jacobian::GroupProjective, ModelParameters, SWModelParameters},
+        PairingEngine, ProjectiveCurve,
     },
-    One,
+    One, Zero,
 };
 
+/// The kind of sextic twist that hosts G2: a multiplicative (`M`) twist
+/// `y^2 = x^3 + b * xi`, or a divisive (`D`) twist `y^2 = x^3 + b / xi`.
 pub enum TwistType {
     M,
     D,
 }
 
+/// Parameters of a BLS12 curve, which are all derived from the curve
+/// parameter `x`. For example, BLS12-381 uses `x = -0xd201000000010000`
+/// with an M-type twist, and BLS12-377 uses `x = 0x8508c00000000001` with a
+/// D-type twist; these instances are in the `bls12_381` and `bls12_377`
+/// modules, behind the features of the same names.
 pub trait Bls12Parameters: 'static {
+    /// The little-endian limbs of the absolute value of `x`.
     const X: &'static [u64];
+    /// Whether `x` is negative.
     const X_IS_NEGATIVE: bool;
     const TWIST_TYPE: TwistType;
     type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInt>;
@@ -35,6 +44,24 @@ pub trait Bls12Parameters: 'static {
 pub mod g1;
 pub mod g2;
 
+/// Multiplies `p` by the curve parameter `x`, taking its sign into account.
+pub(crate) fn mul_by_x<P: Bls12Parameters, C: SWModelParameters>(
+    p: &GroupProjective<C>,
+) -> GroupProjective<C> {
+    let mut res = GroupProjective::zero();
+    for bit in BitIterator::new(P::X) {
+        res.double_in_place();
+        if bit {
+            res += p;
+        }
+    }
+    if P::X_IS_NEGATIVE {
+        -res
+    } else {
+        res
+    }
+}
+
 pub use self::{
     g1::{G1Affine, G1Prepared, G1Projective},
     g2::{G2Affine, G2Prepared, G2Projective},
@@ -0,0 +1,107 @@
+use crate::{
+    ff::{FftParameters, Fp384, Fp384Parameters, FpParameters},
+    uint::U384,
+};
+
+pub type Fq = Fp384<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp384Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInt = U384;
+
+    const TWO_ADICITY: u32 = 46;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U384 = U384::new([
+        0xdfcae622791aab1e,
+        0x720bc7a4bf05c59c,
+        0x259d41860d7882d6,
+        0xd82b4258b1e4da96,
+        0xb7f9a1cc67b4e064,
+        0x00fda47f566e4289,
+    ]);
+}
+
+impl FpParameters for FqParameters {
+    /// MODULUS = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
+    const MODULUS: U384 = U384::new([
+        0x8508c00000000001,
+        0x170b5d4430000000,
+        0x1ef3622fba094800,
+        0x1a22d9f300f5138f,
+        0xc63b05c06ca1493b,
+        0x01ae3a4617c510ea,
+    ]);
+
+    const MODULUS_BITS: u32 = 377;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 7;
+
+    /// R = 2^384 mod MODULUS
+    const R: U384 = U384::new([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U384 = U384::new([
+        0xb786686c9400cd22,
+        0x0329fcaab00431b1,
+        0x22a5f11162d6b46d,
+        0xbfdf7d03827dc3ac,
+        0x837e92f041790bf9,
+        0x006dfccb1e914b88,
+    ]);
+
+    const INV: u64 = 0x8508bfffffffffff;
+
+    /// GENERATOR = 15, in Montgomery form
+    const GENERATOR: U384 = U384::new([
+        0x15eefffffffff714,
+        0x669be3a3bffffb5d,
+        0xdc8ffe3035319f32,
+        0xd10f7bf375757f17,
+        0x6968af36d106a4b2,
+        0x019016a3edcd115f,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U384 = U384::new([
+        0x4284600000000000,
+        0x0b85aea218000000,
+        0x8f79b117dd04a400,
+        0x8d116cf9807a89c7,
+        0x631d82e03650a49d,
+        0x00d71d230be28875,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U384 = U384::new([
+        0x7510c00000021423,
+        0x88bee82520005c2d,
+        0x67cc03d44e3c7bcd,
+        0x1701b28524ec688b,
+        0xe9185f1443ab18ec,
+        0x00000000000006b8,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U384 = U384::new([
+        0xba88600000010a11,
+        0xc45f741290002e16,
+        0xb3e601ea271e3de6,
+        0x0b80d94292763445,
+        0x748c2f8a21d58c76,
+        0x000000000000035c,
+    ]);
+}
+
+pub const FQ_ONE: Fq = Fq::new(FqParameters::R);
+pub const FQ_ZERO: Fq = Fq::new(U384::new([0; 6]));
@@ -0,0 +1,141 @@
+use crate::{
+    ff::{Fp12, Fp12Parameters},
+    uint::U384,
+};
+
+use super::{Fq, Fq2, Fq6Parameters, FQ2_ONE, FQ_ONE, FQ_ZERO};
+
+pub type Fq12 = Fp12<Fq12Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq12Parameters;
+
+impl Fp12Parameters for Fq12Parameters {
+    type Fp6Params = Fq6Parameters;
+
+    /// Coefficients for the Frobenius automorphism, Fq6::NONRESIDUE^((q^i - 1) / 6).
+    const FROBENIUS_COEFF_FP12_C1: [Fq2; 12] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xe938a9d1104f2031,
+                0xb57668e558eb0188,
+                0xc681bf34a3aa559d,
+                0x5c8a45e0f94ebc8e,
+                0x33c1e30682567f91,
+                0x009a9975399c0196
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8508c00000000002,
+                0x452217cc90000000,
+                0xc5ed1347970dec00,
+                0x619aaf7d34594aab,
+                0x09b3af05dd14f6ec,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x6e76d5ecf1391c63,
+                0x99588459bff27d8e,
+                0xbce649cf436b0f62,
+                0x400398f50ad1dec1,
+                0xc0c534db1a79beb1,
+                0x01680a40796537ca
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8508c00000000001,
+                0x452217cc90000000,
+                0xc5ed1347970dec00,
+                0x619aaf7d34594aab,
+                0x09b3af05dd14f6ec,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x853e2c1be0e9fc32,
+                0xe3e21b7467077c05,
+                0xf6648a9a9fc0b9c4,
+                0xe379531411832232,
+                0x8d0351d498233f1f,
+                0x00cd70cb3fc93634
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(Fq2, FQ_ONE.const_neg(), FQ_ZERO),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x9bd0162eefb0dfd0,
+                0x6194f45ed714fe77,
+                0x5871a2fb165ef262,
+                0xbd98941207a65700,
+                0x927922b9ea4ac9a9,
+                0x0113a0d0de290f54
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xffffffffffffffff,
+                0xd1e945779fffffff,
+                0x59064ee822fb5bff,
+                0xb8882a75cc9bc8e3,
+                0xbc8756ba8f8c524e,
+                0x01ae3a4617c510ea
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x1691ea130ec6e39e,
+                0x7db2d8ea700d8272,
+                0x620d1860769e389d,
+                0xda1f40fdf62334cd,
+                0x0575d0e552278a89,
+                0x004630059e5fd920
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x0000000000000000,
+                0xd1e94577a0000000,
+                0x59064ee822fb5bff,
+                0xb8882a75cc9bc8e3,
+                0xbc8756ba8f8c524e,
+                0x01ae3a4617c510ea
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xffca93e41f1603cf,
+                0x332941cfc8f883fa,
+                0x288ed7951a488e3b,
+                0x36a986deef71f15c,
+                0x3937b3ebd47e0a1b,
+                0x00e0c97ad7fbdab6
+            ])),
+            FQ_ZERO
+        ),
+    ];
+}
@@ -0,0 +1,35 @@
+use crate::{
+    ff::{Fp2, Fp2Parameters},
+    uint::U384,
+};
+
+use super::{Fq, FQ_ONE, FQ_ZERO};
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = -5
+    const NONRESIDUE: Fq = Fq::const_from_repr(U384::new([
+        0x8508bffffffffffc,
+        0x170b5d4430000000,
+        0x1ef3622fba094800,
+        0x1a22d9f300f5138f,
+        0xc63b05c06ca1493b,
+        0x01ae3a4617c510ea,
+    ]));
+
+    /// QUADRATIC_NONRESIDUE = u
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (FQ_ZERO, FQ_ONE);
+
+    /// Coefficients for the Frobenius automorphism, NONRESIDUE^((q^i - 1) / 2).
+    const FROBENIUS_COEFF_FP2_C1: [Fq; 2] = [FQ_ONE, FQ_ONE.const_neg()];
+
+    const SMALL_NONRESIDUE: Option<i64> = Some(-5);
+}
+
+pub const FQ2_ZERO: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ZERO);
+pub const FQ2_ONE: Fq2 = field_new!(Fq2, FQ_ONE, FQ_ZERO);
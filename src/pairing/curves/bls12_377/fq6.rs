@@ -0,0 +1,133 @@
+use crate::{
+    ff::{Fp6, Fp6Parameters},
+    uint::U384,
+};
+
+use super::{Fq, Fq2, Fq2Parameters, FQ2_ONE, FQ_ONE, FQ_ZERO};
+
+pub type Fq6 = Fp6<Fq6Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq6Parameters;
+
+impl Fp6Parameters for Fq6Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    /// NONRESIDUE = u
+    const NONRESIDUE: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ONE);
+
+    /// Coefficients for the Frobenius automorphism, NONRESIDUE^((q^i - 1) / 3).
+    const FROBENIUS_COEFF_FP6_C1: [Fq2; 6] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8508c00000000002,
+                0x452217cc90000000,
+                0xc5ed1347970dec00,
+                0x619aaf7d34594aab,
+                0x09b3af05dd14f6ec,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8508c00000000001,
+                0x452217cc90000000,
+                0xc5ed1347970dec00,
+                0x619aaf7d34594aab,
+                0x09b3af05dd14f6ec,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(Fq2, FQ_ONE.const_neg(), FQ_ZERO),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xffffffffffffffff,
+                0xd1e945779fffffff,
+                0x59064ee822fb5bff,
+                0xb8882a75cc9bc8e3,
+                0xbc8756ba8f8c524e,
+                0x01ae3a4617c510ea
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x0000000000000000,
+                0xd1e94577a0000000,
+                0x59064ee822fb5bff,
+                0xb8882a75cc9bc8e3,
+                0xbc8756ba8f8c524e,
+                0x01ae3a4617c510ea
+            ])),
+            FQ_ZERO
+        ),
+    ];
+
+    /// Coefficients for the Frobenius automorphism, NONRESIDUE^((2 q^i - 2) / 3).
+    const FROBENIUS_COEFF_FP6_C2: [Fq2; 6] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8508c00000000001,
+                0x452217cc90000000,
+                0xc5ed1347970dec00,
+                0x619aaf7d34594aab,
+                0x09b3af05dd14f6ec,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xffffffffffffffff,
+                0xd1e945779fffffff,
+                0x59064ee822fb5bff,
+                0xb8882a75cc9bc8e3,
+                0xbc8756ba8f8c524e,
+                0x01ae3a4617c510ea
+            ])),
+            FQ_ZERO
+        ),
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8508c00000000001,
+                0x452217cc90000000,
+                0xc5ed1347970dec00,
+                0x619aaf7d34594aab,
+                0x09b3af05dd14f6ec,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xffffffffffffffff,
+                0xd1e945779fffffff,
+                0x59064ee822fb5bff,
+                0xb8882a75cc9bc8e3,
+                0xbc8756ba8f8c524e,
+                0x01ae3a4617c510ea
+            ])),
+            FQ_ZERO
+        ),
+    ];
+
+    /// Multiplies `fe` by `NONRESIDUE = u` with additions.
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue(fe: &Fq2) -> Fq2 {
+        // (c0 + c1 u) u = c1 NONRESIDUE_FP2 + c0 u
+        Fq2::new(Fq2Parameters::mul_fp_by_nonresidue(&fe.c1), fe.c0)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    ff::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+    uint::U256,
+};
+
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+/// The `2^k`-th roots of unity for `k` from 0 to `TWO_ADICITY`, in Montgomery
+/// form.
+const ROOTS_OF_UNITY: [U256; 48] = Fr::two_adic_roots();
+
+impl FftParameters for FrParameters {
+    type BigInt = U256;
+
+    const TWO_ADICITY: u32 = 47;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+        0xaf80da4dda3ad648,
+        0x5e223adbfc381dac,
+        0x03ba0666b2f92525,
+        0x0f906c5b3befb0ce,
+    ]);
+
+    const TWO_ADIC_ROOTS: Option<&'static [U256]> = Some(&ROOTS_OF_UNITY);
+}
+
+impl FpParameters for FrParameters {
+    /// MODULUS = 8444461749428370424248824938781546531375899335154063827935233455917409239041
+    const MODULUS: U256 = U256::new([
+        0x0a11800000000001,
+        0x59aa76fed0000001,
+        0x60b44d1e5c37b001,
+        0x12ab655e9a2ca556,
+    ]);
+
+    const MODULUS_BITS: u32 = 253;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 3;
+
+    /// R = 2^256 mod MODULUS
+    const R: U256 = U256::new([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U256 = U256::new([
+        0x25d577bab861857b,
+        0xcc2c27b58860591f,
+        0xa7cc008fe5dc8593,
+        0x011fdae7eff1c939,
+    ]);
+
+    const INV: u64 = 0x0a117fffffffffff;
+
+    /// GENERATOR = 22, in Montgomery form
+    const GENERATOR: U256 = U256::new([
+        0x296c7ffffffffed3,
+        0x929216656ffffec7,
+        0x4c01534d92860e69,
+        0x0c79cfc4b9819970,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x8508c00000000000,
+        0xacd53b7f68000000,
+        0x305a268f2e1bd800,
+        0x0955b2af4d1652ab,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U256 = U256::new([
+        0xedfda00000021423,
+        0x9a3cb86f6002b354,
+        0xcabd34594aacc168,
+        0x0000000000002556,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x76fed00000010a11,
+        0x4d1e5c37b00159aa,
+        0x655e9a2ca55660b4,
+        0x00000000000012ab,
+    ]);
+}
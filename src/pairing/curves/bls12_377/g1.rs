@@ -0,0 +1,142 @@
+use crate::{
+    pairing::curves::{
+        bls12,
+        glv::{self, GLVParameters},
+        ModelParameters, SWModelParameters,
+    },
+    uint::{U256, U384},
+};
+
+use super::{Fq, Fr, G1Affine, G1Projective, FQ_ONE, FQ_ZERO};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = FQ_ZERO;
+
+    /// COEFF_B = 1
+    const COEFF_B: Fq = FQ_ONE;
+
+    /// COFACTOR = (x - 1)^2 / 3 = 30631250834960419227450344600217059328
+    const COFACTOR: &'static [u64] = &[0x0000000000000000, 0x170b5d4430000000];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x5a9d7ffffffffffd,
+        0xabcbf3c7d0000009,
+        0xd78e73ad8a538805,
+        0x0000000000000000,
+    ]));
+
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Fq) -> Fq {
+        FQ_ZERO
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        bls12::g1::is_in_correct_subgroup_assuming_on_curve::<super::Parameters>(p)
+    }
+
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        bls12::g1::clear_cofactor::<super::Parameters>(p)
+    }
+
+    fn mul_projective(p: &G1Projective, scalar: U256) -> G1Projective {
+        glv::mul_projective(p, scalar)
+    }
+}
+
+/// On BLS12-377 G1, `lambda = -x^2` for `x = 0x8508c00000000001`.
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+    const ENDO_COEFF: Fq = Fq::const_from_repr(U384::new([
+        0xffffffffffffffff,
+        0xd1e945779fffffff,
+        0x59064ee822fb5bff,
+        0xb8882a75cc9bc8e3,
+        0xbc8756ba8f8c524e,
+        0x01ae3a4617c510ea,
+    ]));
+
+    /// LAMBDA = -x^2
+    const LAMBDA: Fr = Fr::const_from_repr(U256::new([
+        0x0000000000000000,
+        0x14885f3240000000,
+        0x60b44d1e5c37b001,
+        0x12ab655e9a2ca556,
+    ]));
+
+    /// SCALAR_DECOMP_COEFFS = [1 - x^2, -x^2, x^2, 1]
+    const SCALAR_DECOMP_COEFFS: [Fr; 4] = [
+        Fr::const_from_repr(U256::new([
+            0x0000000000000001,
+            0x14885f3240000000,
+            0x60b44d1e5c37b001,
+            0x12ab655e9a2ca556,
+        ])),
+        Self::LAMBDA,
+        Fr::const_from_repr(U256::new([
+            0x0a11800000000001,
+            0x452217cc90000001,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Fr::const_from_repr(U256::new([
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round(2^512 / r), round(x^2 * 2^512 / r)]
+    const SCALAR_DECOMP_ROUNDING: [&'static [u64]; 2] = [
+        &[
+            0x48130845479e7a85,
+            0x428602e35a78963d,
+            0x3947927eaa01523f,
+            0xb65247b102cb27b9,
+            0x000000000000000d,
+        ],
+        &[
+            0x6722fc5e8bdf8128,
+            0x9f02ddf33abf947e,
+            0x5cc5a03b7b820cf6,
+            0x3366fc876f25c6b5,
+            0x7f72ed32af90182c,
+            0xb3f7aa969fd37160,
+            0x0000000000000003,
+        ],
+    ];
+
+    const SCALAR_DECOMP_SHIFT: u32 = 512;
+}
+
+/// G1_GENERATOR_X = 81937999373150964239938255573465948239988671502647976594219695644855304257327692006745978603320413799295628339695
+pub const G1_GENERATOR_X: Fq = Fq::const_from_repr(U384::new([
+    0xeab9b16eb21be9ef,
+    0xd5481512ffcd394e,
+    0x188282c8bd37cb5c,
+    0x85951e2caa9d41bb,
+    0xc8fc6225bf87ff54,
+    0x008848defe740a67,
+]));
+
+/// G1_GENERATOR_Y = 241266749859715473739788878240585681733927191168601896383759122102112907357779751001206799952863815012735208165030
+pub const G1_GENERATOR_Y: Fq = Fq::const_from_repr(U384::new([
+    0xfd82de55559c8ea6,
+    0xc2fe3d3634a9591a,
+    0x6d182ad44fb82305,
+    0xbd7fb348ca3e52d9,
+    0x1f674f5d30afeec4,
+    0x01914a69c5102eff,
+]));
@@ -0,0 +1,110 @@
+use crate::{
+    pairing::curves::{bls12, ModelParameters, SWModelParameters},
+    uint::{U256, U384},
+};
+
+use super::{Fq, Fq2, Fr, G2Affine, FQ2_ZERO, FQ_ZERO};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq2 = FQ2_ZERO;
+
+    /// COEFF_B = 1 / (u)
+    const COEFF_B: Fq2 = field_new!(
+        Fq2,
+        FQ_ZERO,
+        Fq::const_from_repr(U384::new([
+            0x1c9ed9999999999a,
+            0x0dd39e5c1ccccccd,
+            0x129207b63c6bf800,
+            0xdc7b4f91cd5fd889,
+            0x43bd03737460c589,
+            0x010222f6db0fd6f3
+        ]))
+    );
+
+    /// COFACTOR = 7923214915284317143930293550643874566881017850177945424769256759165301436616933228209277966774092486467289478618404761412630691835764674559376407658497
+    const COFACTOR: &'static [u64] = &[
+        0x0000000000000001,
+        0x452217cc90000000,
+        0xa0f3622fba094800,
+        0xd693e8c36676bd09,
+        0x8c505634fae2e189,
+        0xfbb36b00e1dcc40c,
+        0xddd88d99a6f6a829,
+        0x0026ba558ae9562a,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x8bb073fa57b0cbad,
+        0x7e2625834dca4110,
+        0x06619857527114ff,
+        0x0ef4cc41e2c22cc3,
+    ]));
+
+    const AFFINE_GENERATOR_COEFFS: (Fq2, Fq2) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Fq2) -> Fq2 {
+        FQ2_ZERO
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<super::Parameters>(p)
+    }
+
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        bls12::g2::clear_cofactor::<super::Parameters>(p)
+    }
+}
+
+/// G2_GENERATOR_X = (233578398248691099356572568220835526895379068987715365179118596935057653620464273615301663571204657964920925606294, 140913150380207355837477652521042157274541796891053068589147167627541651775299824604154852141315666357241556069118)
+pub const G2_GENERATOR_X: Fq2 = field_new!(
+    Fq2,
+    Fq::const_from_repr(U384::new([
+        0x74e3e48f7c005196,
+        0x71889f52bb535402,
+        0x7ea501f557db6b9b,
+        0xc565f071203e5031,
+        0xc89630a2a3841d01,
+        0x018480be71c785fe
+    ])),
+    Fq::const_from_repr(U384::new([
+        0xb26bfefa6ea16afe,
+        0x5cf89984bff76fe6,
+        0xe7223ece0799c9de,
+        0x532777ee6651cecb,
+        0x70dc5a51b1b140d5,
+        0x00ea6040e7004031
+    ]))
+);
+
+/// G2_GENERATOR_Y = (63160294768292073209381361943935198908131692476676907196754037919244929611450776219210369229519898517858833747423, 149157405641012693445398062341192467754805999074082136895788947234480009303640899064710353187729182149407503257491)
+pub const G2_GENERATOR_Y: Fq2 = field_new!(
+    Fq2,
+    Fq::const_from_repr(U384::new([
+        0xf094094409fd4ddf,
+        0xf2cf88886d8c7c2e,
+        0xe458c282f832d204,
+        0xde03ed7274b49a58,
+        0xd960736bcbb2efb4,
+        0x00690d665d446f7b
+    ])),
+    Fq::const_from_repr(U384::new([
+        0xd9a1cdd185eb8f93,
+        0x4279b83f5e52270b,
+        0x2463b01acee304c2,
+        0x61ef11ac3d591bf1,
+        0x9e549da3151a70aa,
+        0x00f8169fd2835518
+    ]))
+);
@@ -0,0 +1,183 @@
+use crate::{
+    ff::{Fp12, Fp12Parameters},
+    uint::U384,
+};
+
+use super::{Fq, Fq2, Fq6Parameters, FQ2_ONE, FQ_ONE, FQ_ZERO};
+
+pub type Fq12 = Fp12<Fq12Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq12Parameters;
+
+impl Fp12Parameters for Fq12Parameters {
+    type Fp6Params = Fq6Parameters;
+
+    /// Coefficients for the Frobenius automorphism, Fq6::NONRESIDUE^((q^i - 1) / 6).
+    const FROBENIUS_COEFF_FP12_C1: [Fq2; 12] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8d0775ed92235fb8,
+                0xf67ea53d63e7813d,
+                0x7b2443d784bab9c4,
+                0x0fd603fd3cbd5f4f,
+                0xc231beb4202c0d1f,
+                0x1904d3bf02bb0667
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x2cf78a126ddc4af3,
+                0x282d5ac14d6c7ec2,
+                0xec0c8ec971f63c5f,
+                0x54a14787b6c7b36f,
+                0x88e9e902231f9fb8,
+                0x00fc3e2b36c4e032
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x2e01fffffffeffff,
+                0xde17d813620a0002,
+                0xddb3a93be6f89688,
+                0xba69c6076a0f77ea,
+                0x5f19672fdf76ce51,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xf1ee7b04121bdea2,
+                0x304466cf3e67fa0a,
+                0xef396489f61eb45e,
+                0x1c3dedd930b1cf60,
+                0xe2e9c448d77a2cd9,
+                0x135203e60180a68e
+            ])),
+            Fq::const_from_repr(U384::new([
+                0xc81084fbede3cc09,
+                0xee67992f72ec05f4,
+                0x77f76e17009241c5,
+                0x48395dabc2d3435e,
+                0x6831e36d6bd17ffe,
+                0x06af0e0437ff400b
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x2e01fffffffefffe,
+                0xde17d813620a0002,
+                0xddb3a93be6f89688,
+                0xba69c6076a0f77ea,
+                0x5f19672fdf76ce51,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x1ee605167ff82995,
+                0x5871c1908bd478cd,
+                0xdb45f3536814f0bd,
+                0x70df3560e77982d0,
+                0x6bd3ad4afa99cc91,
+                0x144e4211384586c1
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x9b18fae980078116,
+                0xc63a3e6e257f8732,
+                0x8beadf4d8e9c0566,
+                0xf39816240c0b8fee,
+                0xdf47fa6b48b1e045,
+                0x05b2cfd9013a5fd8
+            ]))
+        ),
+        field_new!(Fq2, FQ_ONE.const_neg(), FQ_ZERO),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x2cf78a126ddc4af3,
+                0x282d5ac14d6c7ec2,
+                0xec0c8ec971f63c5f,
+                0x54a14787b6c7b36f,
+                0x88e9e902231f9fb8,
+                0x00fc3e2b36c4e032
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x8d0775ed92235fb8,
+                0xf67ea53d63e7813d,
+                0x7b2443d784bab9c4,
+                0x0fd603fd3cbd5f4f,
+                0xc231beb4202c0d1f,
+                0x1904d3bf02bb0667
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8bfd00000000aaac,
+                0x409427eb4f49fffd,
+                0x897d29650fb85f9b,
+                0xaa0d857d89759ad4,
+                0xec02408663d4de85,
+                0x1a0111ea397fe699
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xc81084fbede3cc09,
+                0xee67992f72ec05f4,
+                0x77f76e17009241c5,
+                0x48395dabc2d3435e,
+                0x6831e36d6bd17ffe,
+                0x06af0e0437ff400b
+            ])),
+            Fq::const_from_repr(U384::new([
+                0xf1ee7b04121bdea2,
+                0x304466cf3e67fa0a,
+                0xef396489f61eb45e,
+                0x1c3dedd930b1cf60,
+                0xe2e9c448d77a2cd9,
+                0x135203e60180a68e
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8bfd00000000aaad,
+                0x409427eb4f49fffd,
+                0x897d29650fb85f9b,
+                0xaa0d857d89759ad4,
+                0xec02408663d4de85,
+                0x1a0111ea397fe699
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x9b18fae980078116,
+                0xc63a3e6e257f8732,
+                0x8beadf4d8e9c0566,
+                0xf39816240c0b8fee,
+                0xdf47fa6b48b1e045,
+                0x05b2cfd9013a5fd8
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x1ee605167ff82995,
+                0x5871c1908bd478cd,
+                0xdb45f3536814f0bd,
+                0x70df3560e77982d0,
+                0x6bd3ad4afa99cc91,
+                0x144e4211384586c1
+            ]))
+        ),
+    ];
+}
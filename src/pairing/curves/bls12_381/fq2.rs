@@ -0,0 +1,25 @@
+use crate::ff::{Fp2, Fp2Parameters};
+
+use super::{Fq, FQ_ONE, FQ_ZERO};
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = -1
+    const NONRESIDUE: Fq = FQ_ONE.const_neg();
+
+    /// QUADRATIC_NONRESIDUE = u + 1
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (FQ_ONE, FQ_ONE);
+
+    /// Coefficients for the Frobenius automorphism, NONRESIDUE^((q^i - 1) / 2).
+    const FROBENIUS_COEFF_FP2_C1: [Fq; 2] = [FQ_ONE, FQ_ONE.const_neg()];
+
+    const SMALL_NONRESIDUE: Option<i64> = Some(-1);
+}
+
+pub const FQ2_ZERO: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ZERO);
+pub const FQ2_ONE: Fq2 = field_new!(Fq2, FQ_ONE, FQ_ZERO);
@@ -0,0 +1,133 @@
+use crate::{
+    ff::{Fp6, Fp6Parameters},
+    uint::U384,
+};
+
+use super::{Fq, Fq2, Fq2Parameters, FQ2_ONE, FQ_ONE, FQ_ZERO};
+
+pub type Fq6 = Fp6<Fq6Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq6Parameters;
+
+impl Fp6Parameters for Fq6Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    /// NONRESIDUE = u + 1
+    const NONRESIDUE: Fq2 = field_new!(Fq2, FQ_ONE, FQ_ONE);
+
+    /// Coefficients for the Frobenius automorphism, NONRESIDUE^((q^i - 1) / 3).
+    const FROBENIUS_COEFF_FP6_C1: [Fq2; 6] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            FQ_ZERO,
+            Fq::const_from_repr(U384::new([
+                0x8bfd00000000aaac,
+                0x409427eb4f49fffd,
+                0x897d29650fb85f9b,
+                0xaa0d857d89759ad4,
+                0xec02408663d4de85,
+                0x1a0111ea397fe699
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x2e01fffffffefffe,
+                0xde17d813620a0002,
+                0xddb3a93be6f89688,
+                0xba69c6076a0f77ea,
+                0x5f19672fdf76ce51,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(Fq2, FQ_ZERO, FQ_ONE),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8bfd00000000aaac,
+                0x409427eb4f49fffd,
+                0x897d29650fb85f9b,
+                0xaa0d857d89759ad4,
+                0xec02408663d4de85,
+                0x1a0111ea397fe699
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            FQ_ZERO,
+            Fq::const_from_repr(U384::new([
+                0x2e01fffffffefffe,
+                0xde17d813620a0002,
+                0xddb3a93be6f89688,
+                0xba69c6076a0f77ea,
+                0x5f19672fdf76ce51,
+                0x0000000000000000
+            ]))
+        ),
+    ];
+
+    /// Coefficients for the Frobenius automorphism, NONRESIDUE^((2 q^i - 2) / 3).
+    const FROBENIUS_COEFF_FP6_C2: [Fq2; 6] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8bfd00000000aaad,
+                0x409427eb4f49fffd,
+                0x897d29650fb85f9b,
+                0xaa0d857d89759ad4,
+                0xec02408663d4de85,
+                0x1a0111ea397fe699
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x8bfd00000000aaac,
+                0x409427eb4f49fffd,
+                0x897d29650fb85f9b,
+                0xaa0d857d89759ad4,
+                0xec02408663d4de85,
+                0x1a0111ea397fe699
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(Fq2, FQ_ONE.const_neg(), FQ_ZERO),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x2e01fffffffefffe,
+                0xde17d813620a0002,
+                0xddb3a93be6f89688,
+                0xba69c6076a0f77ea,
+                0x5f19672fdf76ce51,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x2e01fffffffeffff,
+                0xde17d813620a0002,
+                0xddb3a93be6f89688,
+                0xba69c6076a0f77ea,
+                0x5f19672fdf76ce51,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+    ];
+
+    /// Multiplies `fe` by `NONRESIDUE = u + 1` with additions.
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue(fe: &Fq2) -> Fq2 {
+        // (c0 + c1 u)(u + 1) = (c0 - c1) + (c0 + c1) u
+        Fq2::new(fe.c0 - &fe.c1, fe.c0 + &fe.c1)
+    }
+}
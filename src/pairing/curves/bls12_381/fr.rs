@@ -0,0 +1,94 @@
+use crate::{
+    ff::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+    uint::U256,
+};
+
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+/// The `2^k`-th roots of unity for `k` from 0 to `TWO_ADICITY`, in Montgomery
+/// form.
+const ROOTS_OF_UNITY: [U256; 33] = Fr::two_adic_roots();
+
+impl FftParameters for FrParameters {
+    type BigInt = U256;
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+        0xb9b58d8c5f0e466a,
+        0x5b1b4c801819d7ec,
+        0x0af53ae352a31e64,
+        0x5bf3adda19e9b27b,
+    ]);
+
+    const TWO_ADIC_ROOTS: Option<&'static [U256]> = Some(&ROOTS_OF_UNITY);
+}
+
+impl FpParameters for FrParameters {
+    /// MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    const MODULUS: U256 = U256::new([
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ]);
+
+    const MODULUS_BITS: u32 = 255;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 1;
+
+    /// R = 2^256 mod MODULUS
+    const R: U256 = U256::new([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U256 = U256::new([
+        0xc999e990f3f29c6d,
+        0x2b6cedcb87925c23,
+        0x05d314967254398f,
+        0x0748d9d99f59ff11,
+    ]);
+
+    const INV: u64 = 0xfffffffeffffffff;
+
+    /// GENERATOR = 7, in Montgomery form
+    const GENERATOR: U256 = U256::new([
+        0x0000000efffffff1,
+        0x17e363d300189c0f,
+        0xff9c57876f8457b0,
+        0x351332208fc5a8c4,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x7fffffff80000000,
+        0xa9ded2017fff2dff,
+        0x199cec0404d0ec02,
+        0x39f6d3a994cebea4,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U256 = U256::new([
+        0xfffe5bfeffffffff,
+        0x09a1d80553bda402,
+        0x299d7d483339d808,
+        0x0000000073eda753,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x7fff2dff7fffffff,
+        0x04d0ec02a9ded201,
+        0x94cebea4199cec04,
+        0x0000000039f6d3a9,
+    ]);
+}
@@ -0,0 +1,149 @@
+use crate::{
+    pairing::curves::{
+        bls12,
+        glv::{self, GLVParameters},
+        ModelParameters, SWModelParameters,
+    },
+    uint::{U256, U384},
+};
+
+use super::{Fq, Fr, G1Affine, G1Projective, FQ_ZERO};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = FQ_ZERO;
+
+    /// COEFF_B = 4
+    const COEFF_B: Fq = Fq::const_from_repr(U384::new([
+        0x0000000000000004,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    /// COFACTOR = (x - 1)^2 / 3 = 76329603384216526031706109802092473003
+    const COFACTOR: &'static [u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0xec0000020005fffb,
+        0xd07c8ff73bf14809,
+        0xe34f0b31458fbb21,
+        0x73eda753299d7d44,
+    ]));
+
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Fq) -> Fq {
+        FQ_ZERO
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        bls12::g1::is_in_correct_subgroup_assuming_on_curve::<super::Parameters>(p)
+    }
+
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        bls12::g1::clear_cofactor::<super::Parameters>(p)
+    }
+
+    fn mul_projective(p: &G1Projective, scalar: U256) -> G1Projective {
+        glv::mul_projective(p, scalar)
+    }
+}
+
+/// On BLS12-381 G1, `lambda = -x^2` for `x = -0xd201000000010000`.
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = 793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350
+    const ENDO_COEFF: Fq = Fq::const_from_repr(U384::new([
+        0x2e01fffffffefffe,
+        0xde17d813620a0002,
+        0xddb3a93be6f89688,
+        0xba69c6076a0f77ea,
+        0x5f19672fdf76ce51,
+        0x0000000000000000,
+    ]));
+
+    /// LAMBDA = -x^2
+    const LAMBDA: Fr = Fr::const_from_repr(U256::new([
+        0xfffffffe00000001,
+        0xa7780001fffcb7fc,
+        0x3339d80809a1d804,
+        0x73eda753299d7d48,
+    ]));
+
+    /// SCALAR_DECOMP_COEFFS = [1 - x^2, -x^2, x^2, 1]
+    const SCALAR_DECOMP_COEFFS: [Fr; 4] = [
+        Fr::const_from_repr(U256::new([
+            0xfffffffe00000002,
+            0xa7780001fffcb7fc,
+            0x3339d80809a1d804,
+            0x73eda753299d7d48,
+        ])),
+        Self::LAMBDA,
+        Fr::const_from_repr(U256::new([
+            0x0000000100000000,
+            0xac45a4010001a402,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Fr::const_from_repr(U256::new([
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round(2^512 / r), round(x^2 * 2^512 / r)]
+    const SCALAR_DECOMP_ROUNDING: [&'static [u64]; 2] = [
+        &[
+            0x42737a020c0d6393,
+            0x65043eb4be4bad71,
+            0x38b5dcb707e08ed3,
+            0x355094edfede377c,
+            0x0000000000000002,
+        ],
+        &[
+            0x61dcc84bc4d43c67,
+            0xfca7c25e7334f876,
+            0xda5e4f8d896c72d9,
+            0x389f49a7268bf7a3,
+            0x63f6e522f6cfee30,
+            0x7c6becf1e01faadd,
+            0x0000000000000001,
+        ],
+    ];
+
+    const SCALAR_DECOMP_SHIFT: u32 = 512;
+}
+
+/// G1_GENERATOR_X = 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
+pub const G1_GENERATOR_X: Fq = Fq::const_from_repr(U384::new([
+    0xfb3af00adb22c6bb,
+    0x6c55e83ff97a1aef,
+    0xa14e3a3f171bac58,
+    0xc3688c4f9774b905,
+    0x2695638c4fa9ac0f,
+    0x17f1d3a73197d794,
+]));
+
+/// G1_GENERATOR_Y = 1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
+pub const G1_GENERATOR_Y: Fq = Fq::const_from_repr(U384::new([
+    0x0caa232946c5e7e1,
+    0xd03cc744a2888ae4,
+    0x00db18cb2c04b3ed,
+    0xfcf5e095d5d00af6,
+    0xa09e30ed741d8ae4,
+    0x08b3f481e3aaa0f1,
+]));
@@ -0,0 +1,117 @@
+use crate::{
+    pairing::curves::{bls12, ModelParameters, SWModelParameters},
+    uint::{U256, U384},
+};
+
+use super::{Fq, Fq2, Fr, G2Affine, FQ2_ZERO};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq2 = FQ2_ZERO;
+
+    /// COEFF_B = 4 * (u + 1)
+    const COEFF_B: Fq2 = field_new!(
+        Fq2,
+        Fq::const_from_repr(U384::new([
+            0x0000000000000004,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000
+        ])),
+        Fq::const_from_repr(U384::new([
+            0x0000000000000004,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000
+        ]))
+    );
+
+    /// COFACTOR = 305502333931268344200999753193121504214466019254188142667664032982267604182971884026507427359259977847832272839041616661285803823378372096355777062779109
+    const COFACTOR: &'static [u64] = &[
+        0xcf1c38e31c7238e5,
+        0x1616ec6e786f0c70,
+        0x21537e293a6691ae,
+        0xa628f1cb4d9e82ef,
+        0xa68a205b2e5a7ddf,
+        0xcd91de4547085aba,
+        0x091d50792876a202,
+        0x05d543a95414e7f1,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x003e197554dc24c7,
+        0x6d8a8d345f5ced8c,
+        0x2fdde180df4f1ef0,
+        0x3aecc632c1b47841,
+    ]));
+
+    const AFFINE_GENERATOR_COEFFS: (Fq2, Fq2) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Fq2) -> Fq2 {
+        FQ2_ZERO
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<super::Parameters>(p)
+    }
+
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        bls12::g2::clear_cofactor::<super::Parameters>(p)
+    }
+}
+
+/// G2_GENERATOR_X = (352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160, 3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758)
+pub const G2_GENERATOR_X: Fq2 = field_new!(
+    Fq2,
+    Fq::const_from_repr(U384::new([
+        0xd48056c8c121bdb8,
+        0x0bac0326a805bbef,
+        0xb4510b647ae3d177,
+        0xc6e47ad4fa403b02,
+        0x260805272dc51051,
+        0x024aa2b2f08f0a91
+    ])),
+    Fq::const_from_repr(U384::new([
+        0xe5ac7d055d042b7e,
+        0x334cf11213945d57,
+        0xb5da61bbdc7f5049,
+        0x596bd0d09920b61a,
+        0x7dacd3a088274f65,
+        0x13e02b6052719f60
+    ]))
+);
+
+/// G2_GENERATOR_Y = (1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905, 927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582)
+pub const G2_GENERATOR_Y: Fq2 = field_new!(
+    Fq2,
+    Fq::const_from_repr(U384::new([
+        0xe193548608b82801,
+        0x923ac9cc3baca289,
+        0x6d429a695160d12c,
+        0xadfd9baa8cbdd3a7,
+        0x8cc9cdc6da2e351a,
+        0x0ce5d527727d6e11
+    ])),
+    Fq::const_from_repr(U384::new([
+        0xaaa9075ff05f79be,
+        0x3f370d275cec1da1,
+        0x267492ab572e99ab,
+        0xcb3e287e85a763af,
+        0x32acd2b02bc28b99,
+        0x0606c4a02ea734cc
+    ]))
+);
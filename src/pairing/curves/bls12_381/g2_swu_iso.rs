@@ -0,0 +1,361 @@
+use crate::{
+    hash::curve_maps::{SWUParameters, WBParameters},
+    pairing::curves::{ModelParameters, SWModelParameters},
+    uint::{U256, U384},
+};
+
+use super::{g2, Fq, Fq2, Fr, FQ2_ONE, FQ_ONE, FQ_ZERO};
+
+/// The curve `y^2 = x^3 + A' * x + B'` that is 3-isogenous to G2, on which
+/// the suite `BLS12381G2_XMD:SHA-256_SSWU_RO_` of RFC 9380, Section 8.8.2,
+/// evaluates the simplified SWU map.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = A' = 240 * u
+    const COEFF_A: Fq2 = field_new!(
+        Fq2,
+        FQ_ZERO,
+        Fq::const_from_repr(U384::new([
+            0x00000000000000f0,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000
+        ]))
+    );
+
+    /// COEFF_B = B' = 1012 * u + 1012
+    const COEFF_B: Fq2 = field_new!(
+        Fq2,
+        Fq::const_from_repr(U384::new([
+            0x00000000000003f4,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000
+        ])),
+        Fq::const_from_repr(U384::new([
+            0x00000000000003f4,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000
+        ]))
+    );
+
+    /// COFACTOR = 305502333931268344200999753193121504214466019254188142667664032982267604182971884026507427359259977847832272839041616661285803823378372096355777062779109, as the curve has as many points as G2
+    const COFACTOR: &'static [u64] = &[
+        0xcf1c38e31c7238e5,
+        0x1616ec6e786f0c70,
+        0x21537e293a6691ae,
+        0xa628f1cb4d9e82ef,
+        0xa68a205b2e5a7ddf,
+        0xcd91de4547085aba,
+        0x091d50792876a202,
+        0x05d543a95414e7f1,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x003e197554dc24c7,
+        0x6d8a8d345f5ced8c,
+        0x2fdde180df4f1ef0,
+        0x3aecc632c1b47841,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = COFACTOR * (1, y), where y is the square root with sgn0(y) = 0
+    const AFFINE_GENERATOR_COEFFS: (Fq2, Fq2) = (
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xe314435bde275487,
+                0xd6ce8d96d6bbc32b,
+                0x308a17c9b44c0eb3,
+                0x8ce98260b1660b1e,
+                0x987fe42c1317abe8,
+                0x10dd1f426ede2368
+            ])),
+            Fq::const_from_repr(U384::new([
+                0xa69b30902ea42598,
+                0x385715aa34ba0992,
+                0xb020e57de0e2e4bc,
+                0x497220f7a6d22b3b,
+                0x0aad374a9ed1e846,
+                0x06bcf06345c6e68c
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x6a9cf6b67a4e2634,
+                0x343d54091d5ca1bb,
+                0xd8c56ab2c98a9aa2,
+                0x1ac40a9e2a5e2b80,
+                0x643cfbca3d99e865,
+                0x007be151e4fc9765
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x77558db2fffee472,
+                0xdf53d9fc2a42076d,
+                0x4d27f709e5e3c683,
+                0x9c5e5848dff2ffd0,
+                0x49ad98d6f6f0ad8f,
+                0x048fc039029d29b4
+            ]))
+        ),
+    );
+}
+
+impl SWUParameters for Parameters {
+    /// ZETA = -1 * u - 2
+    const ZETA: Fq2 = field_new!(
+        Fq2,
+        Fq::const_from_repr(U384::new([
+            0xb9feffffffffaaa9,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a
+        ])),
+        FQ_ONE.const_neg()
+    );
+}
+
+/// The 3-isogeny to G2 of RFC 9380, Appendix E.3.
+impl WBParameters for g2::Parameters {
+    type IsogenousCurve = Parameters;
+
+    /// The constants `k_(1,0)` to `k_(1,3)`.
+    const PHI_X_NUM: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x6238aaaaaaaa97d6,
+                0x5c2638e343d9c71c,
+                0x88b58423c50ae15d,
+                0x32c52d39fd3a042a,
+                0xbb5b7a9a47d7ed85,
+                0x05c759507e8e333e
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x6238aaaaaaaa97d6,
+                0x5c2638e343d9c71c,
+                0x88b58423c50ae15d,
+                0x32c52d39fd3a042a,
+                0xbb5b7a9a47d7ed85,
+                0x05c759507e8e333e
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            FQ_ZERO,
+            Fq::const_from_repr(U384::new([
+                0x26a9ffffffffc71a,
+                0x1472aaa9cb8d5555,
+                0x9a208c6b4f20a418,
+                0x984f87adf7ae0c7f,
+                0x32126fced787c88f,
+                0x11560bf17baa99bc
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x26a9ffffffffc71e,
+                0x1472aaa9cb8d5555,
+                0x9a208c6b4f20a418,
+                0x984f87adf7ae0c7f,
+                0x32126fced787c88f,
+                0x11560bf17baa99bc
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x9354ffffffffe38d,
+                0x0a395554e5c6aaaa,
+                0xcd104635a790520c,
+                0xcc27c3d6fbd7063f,
+                0x190937e76bc3e447,
+                0x08ab05f8bdd54cde
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x88e2aaaaaaaa5ed1,
+                0x7098e38d0f671c71,
+                0x22d6108f142b8575,
+                0xcb14b4e7f4e810aa,
+                0xed6dea691f5fb614,
+                0x171d6541fa38ccfa
+            ])),
+            FQ_ZERO
+        ),
+    ];
+
+    /// The constants `k_(2,0)` and `k_(2,1)`, followed by `1`.
+    const PHI_X_DEN: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            FQ_ZERO,
+            Fq::const_from_repr(U384::new([
+                0xb9feffffffffaa63,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x000000000000000c,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000
+            ])),
+            Fq::const_from_repr(U384::new([
+                0xb9feffffffffaa9f,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a
+            ]))
+        ),
+        FQ2_ONE,
+    ];
+
+    /// The constants `k_(3,0)` to `k_(3,3)`.
+    const PHI_Y_NUM: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x12cfc71c71c6d706,
+                0xfc8c25ebf8c92f68,
+                0xf54439d87d27e500,
+                0x0f7da5d4a07f649b,
+                0x59a4c18b076d1193,
+                0x1530477c7ab4113b
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x12cfc71c71c6d706,
+                0xfc8c25ebf8c92f68,
+                0xf54439d87d27e500,
+                0x0f7da5d4a07f649b,
+                0x59a4c18b076d1193,
+                0x1530477c7ab4113b
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            FQ_ZERO,
+            Fq::const_from_repr(U384::new([
+                0x6238aaaaaaaa97be,
+                0x5c2638e343d9c71c,
+                0x88b58423c50ae15d,
+                0x32c52d39fd3a042a,
+                0xbb5b7a9a47d7ed85,
+                0x05c759507e8e333e
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x26a9ffffffffc71c,
+                0x1472aaa9cb8d5555,
+                0x9a208c6b4f20a418,
+                0x984f87adf7ae0c7f,
+                0x32126fced787c88f,
+                0x11560bf17baa99bc
+            ])),
+            Fq::const_from_repr(U384::new([
+                0x9354ffffffffe38f,
+                0x0a395554e5c6aaaa,
+                0xcd104635a790520c,
+                0xcc27c3d6fbd7063f,
+                0x190937e76bc3e447,
+                0x08ab05f8bdd54cde
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xe1b371c71c718b10,
+                0x4e79097a56dc4bd9,
+                0xb0e977c69aa27452,
+                0x761b0f37a1e26286,
+                0xfbf7043de3811ad0,
+                0x124c9ad43b6cf79b
+            ])),
+            FQ_ZERO
+        ),
+    ];
+
+    /// The constants `k_(4,0)` to `k_(4,2)`, followed by `1`.
+    const PHI_Y_DEN: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0xb9feffffffffa8fb,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a
+            ])),
+            Fq::const_from_repr(U384::new([
+                0xb9feffffffffa8fb,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            FQ_ZERO,
+            Fq::const_from_repr(U384::new([
+                0xb9feffffffffa9d3,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U384::new([
+                0x0000000000000012,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000
+            ])),
+            Fq::const_from_repr(U384::new([
+                0xb9feffffffffaa99,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a
+            ]))
+        ),
+        FQ2_ONE,
+    ];
+}
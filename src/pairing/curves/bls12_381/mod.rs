@@ -0,0 +1,219 @@
+//! The BLS12-381 curve, with `x = -0xd201000000010000` and an M-type twist.
+
+use crate::pairing::curves::bls12::{self, Bls12, Bls12Parameters, TwistType};
+
+pub mod fq;
+pub mod fq12;
+pub mod fq2;
+pub mod fq6;
+pub mod fr;
+pub mod g1;
+#[cfg(feature = "digest")]
+pub mod g1_swu_iso;
+pub mod g2;
+#[cfg(feature = "digest")]
+pub mod g2_swu_iso;
+
+pub use self::{fq::*, fq12::*, fq2::*, fq6::*, fr::*};
+
+pub type Bls12_381 = Bls12<Parameters>;
+
+pub type G1Affine = bls12::G1Affine<Parameters>;
+pub type G1Projective = bls12::G1Projective<Parameters>;
+pub type G2Affine = bls12::G2Affine<Parameters>;
+pub type G2Projective = bls12::G2Projective<Parameters>;
+
+pub struct Parameters;
+
+impl Bls12Parameters for Parameters {
+    const X: &'static [u64] = &[0xd201000000010000];
+    const X_IS_NEGATIVE: bool = true;
+    const TWIST_TYPE: TwistType = TwistType::M;
+    type Fp = Fq;
+    type Fp2Params = Fq2Parameters;
+    type Fp6Params = Fq6Parameters;
+    type Fp12Params = Fq12Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ff::{BitIterator, Field, Fp2Parameters, PrimeField, SquareRootField},
+        pairing::{
+            curves::{
+                glv::{decompose, GLVParameters},
+                short_weierstrass_jacobian::GroupAffine,
+                SWModelParameters,
+            },
+            AffineCurve, PairingEngine, ProjectiveCurve,
+        },
+        uint::Uint,
+        One, Zero,
+    };
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    #[test]
+    fn generators_are_in_the_subgroups() {
+        let g1 = G1Affine::prime_subgroup_generator();
+        assert!(g1.is_on_curve() && g1.is_in_correct_subgroup_assuming_on_curve());
+        let g2 = G2Affine::prime_subgroup_generator();
+        assert!(g2.is_on_curve() && g2.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn frobenius_map_is_the_power_of_the_characteristic() {
+        let rng = &mut test_rng();
+        for _ in 0..3 {
+            let a: Fq12 = rng.gen();
+            let mut power = a;
+            for i in 0..12 {
+                let mut b = a;
+                b.frobenius_map(i);
+                assert_eq!(b, power);
+                power = power.pow(Fq::characteristic());
+            }
+        }
+    }
+
+    #[test]
+    fn small_nonresidue_is_the_nonresidue() {
+        let k = Fq2Parameters::SMALL_NONRESIDUE.unwrap();
+        let small = Fq::from(k.unsigned_abs());
+        let small = if k < 0 { -small } else { small };
+        assert_eq!(small, Fq2Parameters::NONRESIDUE);
+
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a: Fq = rng.gen();
+            assert_eq!(
+                Fq2Parameters::mul_fp_by_nonresidue(&a),
+                Fq2Parameters::NONRESIDUE * &a
+            );
+        }
+    }
+
+    #[test]
+    fn sqrt_of_squares() {
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a: Fq = rng.gen();
+            assert_eq!(a.square().sqrt().unwrap().square(), a.square());
+            let b: Fq2 = rng.gen();
+            assert_eq!(b.square().sqrt().unwrap().square(), b.square());
+            let c: Fr = rng.gen();
+            assert_eq!(c.square().sqrt().unwrap().square(), c.square());
+        }
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let rng = &mut test_rng();
+        let p = G1Projective::prime_subgroup_generator();
+        let q = G2Projective::prime_subgroup_generator();
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        let e = Bls12_381::pairing(p, q);
+        assert!(!e.is_one());
+        assert!(e.pow(Fr::characteristic()).is_one());
+        let e_ab = Bls12_381::pairing(p.mul(a.into_repr()), q.mul(b.into_repr()));
+        assert_eq!(e_ab, e.pow((a * &b).into_repr()));
+    }
+
+    #[test]
+    fn glv_decomposition() {
+        let rng = &mut test_rng();
+        let lambda = <g1::Parameters as GLVParameters>::LAMBDA;
+        let g = G1Affine::prime_subgroup_generator();
+        let mut scalars: Vec<Fr> = (0..100).map(|_| rng.gen()).collect();
+        scalars.extend(&[Fr::zero(), Fr::one(), -Fr::one(), lambda]);
+        for k in scalars {
+            let ((neg1, k1), (neg2, k2)) = decompose::<g1::Parameters>(&k);
+            // Both halves are at most about the square root of r.
+            assert!(k1.into_repr().num_bits() <= 128 && k2.into_repr().num_bits() <= 128);
+            let k1 = if neg1 { -k1 } else { k1 };
+            let k2 = if neg2 { -k2 } else { k2 };
+            assert_eq!(k1 + &(k2 * &lambda), k);
+            assert_eq!(g.glv_mul(&k), g.mul(k.into_repr()));
+            // `ProjectiveCurve::mul` goes through `glv_mul`.
+            assert_eq!(g.into_projective().mul(k.into_repr()), g.mul(k.into_repr()));
+        }
+        assert_eq!(g.endomorphism(), g.mul(lambda.into_repr()).into_affine());
+    }
+
+    /// Checks the subgroup check of `P` against multiplication by `r`, on
+    /// `p`, which is not in the subgroup, on its component outside the
+    /// subgroup, and on its images by the cofactor maps.
+    fn check_subgroup<P: SWModelParameters>(p: GroupAffine<P>) {
+        let in_subgroup = |p: &GroupAffine<P>| {
+            p.mul_bits(BitIterator::new(P::ScalarField::characteristic()))
+                .is_zero()
+        };
+        let torsion: GroupAffine<P> = p
+            .mul_bits(BitIterator::new(P::ScalarField::characteristic()))
+            .into();
+        for q in [p, torsion] {
+            assert!(!q.is_zero() && !in_subgroup(&q));
+            assert!(!q.is_in_correct_subgroup_assuming_on_curve());
+        }
+        for q in [p.clear_cofactor(), p.scale_by_cofactor().into()] {
+            assert!(!q.is_zero() && in_subgroup(&q));
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+
+    #[test]
+    fn subgroup_checks_match_multiplication_by_r() {
+        let rng = &mut test_rng();
+        for p in (0..)
+            .filter_map(|_| G1Affine::get_point_from_x(rng.gen(), rng.gen()))
+            .take(5)
+        {
+            check_subgroup(p);
+        }
+        for p in (0..)
+            .filter_map(|_| G2Affine::get_point_from_x(rng.gen(), rng.gen()))
+            .take(5)
+        {
+            check_subgroup(p);
+        }
+    }
+
+    /// The effective cofactors `h_eff` of RFC 9380, Section 8.8, by which
+    /// the hash-to-curve suites map points into G1 and G2.
+    const G1_H_EFF: &[u64] = &[0xd201000000010001];
+    const G2_H_EFF: &[u64] = &[
+        0xe8020005aaa95551,
+        0x59894c0adebbf6b4,
+        0xe954cbc06689f6a3,
+        0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0,
+        0x329c2f178731db95,
+        0x9986ff031508ffe1,
+        0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528,
+        0x0bc69f08f2ee75b3,
+    ];
+
+    #[test]
+    fn clear_cofactor_multiplies_by_the_effective_cofactor() {
+        let rng = &mut test_rng();
+        for p in (0..)
+            .filter_map(|_| G1Affine::get_point_from_x(rng.gen(), rng.gen()))
+            .take(5)
+        {
+            let expected: G1Affine = p.mul_bits(BitIterator::new(G1_H_EFF)).into();
+            assert_eq!(p.clear_cofactor(), expected);
+        }
+        for p in (0..)
+            .filter_map(|_| G2Affine::get_point_from_x(rng.gen(), rng.gen()))
+            .take(5)
+        {
+            let expected: G2Affine = p.mul_bits(BitIterator::new(G2_H_EFF)).into();
+            assert_eq!(p.clear_cofactor(), expected);
+        }
+    }
+}
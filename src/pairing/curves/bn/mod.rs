@@ -8,9 +8,19 @@ use crate::{
     pairing::{ModelParameters, PairingEngine, SWModelParameters},
 };
 
+/// Parameters of a Barreto-Naehrig curve with a D-type sextic twist, which
+/// are all derived from the curve parameter `u`. For example, BN254 (also
+/// known as alt_bn128, the curve of the EVM precompiles) uses
+/// `u = 0x44e992b44a6909f1`; this instance is in the `bn_256` module,
+/// behind the feature of the same name.
 pub trait BnParameters: 'static + Send + Sync {
+    /// The signed binary (NAF) digits of `6 * u + 2`, least significant
+    /// first, which drive the Miller loop.
     const SIX_U_PLUS_2_NAF: &'static [i8];
+    /// The little-endian limbs of `u`.
     const U: &'static [u64];
+    /// `xi^((q - 1) / 2)` for the sextic non-residue `xi` in `Fp2`, used to
+    /// compute the Frobenius image of a G2 point on the twist.
     const CUBIC_NONRESIDUE_TO_Q_MINUS_1_OVER_2: Fp2<Self::Fp2Params>;
 
     type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInt>;
@@ -107,7 +117,8 @@ impl<P: BnParameters> PairingEngine for Bn<P> {
             }
         }
 
-        // two additional steps: for q1 and minus q2
+        // two additional steps: the lines through the Frobenius images
+        // q1 = pi(q) and -q2 = -pi^2(q) of the G2 point
 
         for &mut (p, ref mut coeffs) in &mut pairs {
             Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
@@ -0,0 +1,91 @@
+use crate::{
+    ff::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+    uint::U256,
+};
+
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInt = U256;
+
+    const TWO_ADICITY: u32 = 1;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ]);
+}
+
+impl FpParameters for FqParameters {
+    /// MODULUS = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+    const MODULUS: U256 = U256::new([
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    const MODULUS_BITS: u32 = 254;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 2;
+
+    /// R = 2^256 mod MODULUS
+    const R: U256 = U256::new([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U256 = U256::new([
+        0xf32cfc5b538afa89,
+        0xb5e71911d44501fb,
+        0x47ab1eff0a417ff6,
+        0x06d89f71cab8351f,
+    ]);
+
+    const INV: u64 = 0x87d20782e4866389;
+
+    /// GENERATOR = 3, in Montgomery form
+    const GENERATOR: U256 = U256::new([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U256 = U256::new([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x4f082305b61f3f51,
+        0x65e05aa45a1c72a3,
+        0x6e14116da0605617,
+        0x0c19139cb84c680a,
+    ]);
+}
+
+pub const FQ_ONE: Fq = Fq::new(FqParameters::R);
+pub const FQ_ZERO: Fq = Fq::new(U256::new([0; 4]));
@@ -0,0 +1,151 @@
+use crate::{
+    ff::{Fp12, Fp12Parameters},
+    uint::U256,
+};
+
+use super::{Fq, Fq2, Fq6Parameters, FQ2_ONE, FQ_ONE, FQ_ZERO};
+
+pub type Fq12 = Fp12<Fq12Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq12Parameters;
+
+impl Fp12Parameters for Fq12Parameters {
+    type Fp6Params = Fq6Parameters;
+
+    /// Coefficients for the Frobenius automorphism, Fq6::NONRESIDUE^((q^i - 1) / 6).
+    const FROBENIUS_COEFF_FP12_C1: [Fq2; 12] = [
+        FQ2_ONE,
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0xd60b35dadcc9e470,
+                0x5c521e08292f2176,
+                0xe8b99fdd76e68b60,
+                0x1284b71c2865a7df
+            ])),
+            Fq::const_from_repr(U256::new([
+                0xca5cf05f80f362ac,
+                0x747992778eeec7e5,
+                0xa6327cfe12150b8e,
+                0x246996f3b4fae7e6
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0xe4bd44e5607cfd49,
+                0xc28f069fbb966e3d,
+                0x5e6dd9e7e0acccb0,
+                0x30644e72e131a029
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0xe86f7d391ed4a67f,
+                0x894cb38dbe55d24a,
+                0xefe9608cd0acaa90,
+                0x19dc81cfcc82e4bb
+            ])),
+            Fq::const_from_repr(U256::new([
+                0x7694aa2bf4c0c101,
+                0x7f03a5e397d439ec,
+                0x06cbeee33576139d,
+                0x00abf8b60be77d73
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0xe4bd44e5607cfd48,
+                0xc28f069fbb966e3d,
+                0x5e6dd9e7e0acccb0,
+                0x30644e72e131a029
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0x1264475e420ac20f,
+                0x2cfa95859526b0d4,
+                0x072fc0af59c61f30,
+                0x0757cab3a41d3cdc
+            ])),
+            Fq::const_from_repr(U256::new([
+                0xe85845e34c4a5b9c,
+                0xa20b7dfd71573c93,
+                0x18e9b79ba4e2606c,
+                0x0ca6b035381e35b6
+            ]))
+        ),
+        field_new!(Fq2, FQ_ONE.const_neg(), FQ_ZERO),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0x6615563bfbb318d7,
+                0x3b2f4c893f42a916,
+                0xcf96a5d90a9accfd,
+                0x1ddf9756b8cbf849
+            ])),
+            Fq::const_from_repr(U256::new([
+                0x71c39bb757899a9b,
+                0x2307d819d98302a7,
+                0x121dc8b86f6c4ccf,
+                0x0bfab77f2c36b843
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0x5763473177fffffe,
+                0xd4f263f1acdb5c4f,
+                0x59e26bcea0d48bac,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0x53b10eddb9a856c8,
+                0x0e34b703aa1bf842,
+                0xc866e529b0d4adcd,
+                0x1687cca314aebb6d
+            ])),
+            Fq::const_from_repr(U256::new([
+                0xc58be1eae3bc3c46,
+                0x187dc4add09d90a0,
+                0xb18456d34c0b44c0,
+                0x2fb855bcd54a22b6
+            ]))
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0x5763473177ffffff,
+                0xd4f263f1acdb5c4f,
+                0x59e26bcea0d48bac,
+                0x0000000000000000
+            ])),
+            FQ_ZERO
+        ),
+        field_new!(
+            Fq2,
+            Fq::const_from_repr(U256::new([
+                0x29bc44b896723b38,
+                0x6a86d50bd34b19b9,
+                0xb120850727bb392d,
+                0x290c83bf3d14634d
+            ])),
+            Fq::const_from_repr(U256::new([
+                0x53c846338c32a1ab,
+                0xf575ec93f71a8df9,
+                0x9f668e1adc9ef7f0,
+                0x23bd9e3da9136a73
+            ]))
+        ),
+    ];
+}
@@ -0,0 +1,94 @@
+use crate::{
+    ff::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+    uint::U256,
+};
+
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+/// The `2^k`-th roots of unity for `k` from 0 to `TWO_ADICITY`, in Montgomery
+/// form.
+const ROOTS_OF_UNITY: [U256; 29] = Fr::two_adic_roots();
+
+impl FftParameters for FrParameters {
+    type BigInt = U256;
+
+    const TWO_ADICITY: u32 = 28;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+        0x636e735580d13d9c,
+        0xa22bf3742445ffd6,
+        0x56452ac01eb203d8,
+        0x1860ef942963f9e7,
+    ]);
+
+    const TWO_ADIC_ROOTS: Option<&'static [U256]> = Some(&ROOTS_OF_UNITY);
+}
+
+impl FpParameters for FrParameters {
+    /// MODULUS = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+    const MODULUS: U256 = U256::new([
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    const MODULUS_BITS: u32 = 254;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 2;
+
+    /// R = 2^256 mod MODULUS
+    const R: U256 = U256::new([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U256 = U256::new([
+        0x1bb8e645ae216da7,
+        0x53fe3ab1e35c59e3,
+        0x8c49833d53bb8085,
+        0x0216d0b17f4e44a5,
+    ]);
+
+    const INV: u64 = 0xc2e1f593efffffff;
+
+    /// GENERATOR = 5, in Montgomery form
+    const GENERATOR: U256 = U256::new([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0xa1f0fac9f8000000,
+        0x9419f4243cdcb848,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U256 = U256::new([
+        0x9b9709143e1f593f,
+        0x181585d2833e8487,
+        0x131a029b85045b68,
+        0x000000030644e72e,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0xcdcb848a1f0fac9f,
+        0x0c0ac2e9419f4243,
+        0x098d014dc2822db4,
+        0x0000000183227397,
+    ]);
+}
@@ -0,0 +1,135 @@
+use crate::{
+    pairing::curves::{
+        glv::{self, GLVParameters},
+        ModelParameters, SWModelParameters,
+    },
+    uint::U256,
+};
+
+use super::{Fq, Fr, G1Projective, FQ_ONE, FQ_ZERO};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = FQ_ZERO;
+
+    /// COEFF_B = 3
+    const COEFF_B: Fq = Fq::const_from_repr(U256::new([
+        0x0000000000000003,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x0000000000000001];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Fq) -> Fq {
+        FQ_ZERO
+    }
+
+    fn mul_projective(p: &G1Projective, scalar: U256) -> G1Projective {
+        glv::mul_projective(p, scalar)
+    }
+}
+
+/// On BN254 G1, `lambda = 36u^3 + 18u^2 + 6u + 1`, and the short lattice
+/// basis is built from `2u + 1` and `6u^2 + 2u`.
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = 2203960485148121921418603742825762020974279258880205651966
+    const ENDO_COEFF: Fq = Fq::const_from_repr(U256::new([
+        0x5763473177fffffe,
+        0xd4f263f1acdb5c4f,
+        0x59e26bcea0d48bac,
+        0x0000000000000000,
+    ]));
+
+    /// LAMBDA = 36u^3 + 18u^2 + 6u + 1
+    const LAMBDA: Fr = Fr::const_from_repr(U256::new([
+        0x8b17ea66b99c90dd,
+        0x5bfc41088d8daaa7,
+        0xb3c4d79d41a91758,
+        0x0000000000000000,
+    ]));
+
+    /// SCALAR_DECOMP_COEFFS = [2u + 1, -(6u^2 + 2u), 6u^2 + 4u + 1, 2u + 1]
+    const SCALAR_DECOMP_COEFFS: [Fr; 4] = [
+        Fr::const_from_repr(U256::new([
+            0x89d3256894d213e3,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Fr::const_from_repr(U256::new([
+            0xc1d039a872b0eed9,
+            0xb8e665ff8b011694,
+            0xb85045b68181585c,
+            0x30644e72e131a029,
+        ])),
+        Fr::const_from_repr(U256::new([
+            0x0be4e1541221250b,
+            0x6f4d8248eeb859fd,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Fr::const_from_repr(U256::new([
+            0x89d3256894d213e3,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round((2u + 1) * 2^512 / r),
+    /// round((6u^2 + 2u) * 2^512 / r)]
+    const SCALAR_DECOMP_ROUNDING: [&'static [u64]; 2] = [
+        &[
+            0x96ce4aece61f0339,
+            0x2e3ff027efccd68a,
+            0x8fa7d32d2fafba64,
+            0x6eb9c714773a6ef2,
+            0xd91d232ec7e0b3d7,
+            0x0000000000000002,
+        ],
+        &[
+            0xd073ced5f11aeea9,
+            0x7abf2e6fc85f00fa,
+            0x869375169b9bdffa,
+            0xa5e38cfb5eaa26d9,
+            0x7a7bd9d4391eb18d,
+            0x4ccef014a773d2cf,
+            0x0000000000000002,
+        ],
+    ];
+
+    const SCALAR_DECOMP_SHIFT: u32 = 512;
+}
+
+/// G1_GENERATOR_X = 1
+pub const G1_GENERATOR_X: Fq = FQ_ONE;
+
+/// G1_GENERATOR_Y = 2
+pub const G1_GENERATOR_Y: Fq = Fq::const_from_repr(U256::new([
+    0x0000000000000002,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]));
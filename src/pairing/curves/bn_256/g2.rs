@@ -0,0 +1,93 @@
+use crate::{
+    pairing::curves::{ModelParameters, SWModelParameters},
+    uint::U256,
+};
+
+use super::{Fq, Fq2, Fr, FQ2_ZERO};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq2 = FQ2_ZERO;
+
+    /// COEFF_B = 3 / (u + 9)
+    const COEFF_B: Fq2 = field_new!(
+        Fq2,
+        Fq::const_from_repr(U256::new([
+            0x3267e6dc24a138e5,
+            0xb5b4c5e559dbefa3,
+            0x81be18991be06ac3,
+            0x2b149d40ceb8aaae
+        ])),
+        Fq::const_from_repr(U256::new([
+            0xe4a2bd0685c315d2,
+            0xa74fa084e52d1852,
+            0xcd2cafadeed8fdf4,
+            0x009713b03af0fed4
+        ]))
+    );
+
+    /// COFACTOR = 21888242871839275222246405745257275088844257914179612981679871602714643921549
+    const COFACTOR: &'static [u64] = &[
+        0x345f2299c0f9fa8d,
+        0x06ceecda572a2489,
+        0xb85045b68181585e,
+        0x30644e72e131a029,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x2e783e9d1c866389,
+        0x316596f1725be799,
+        0x360a8ea9e19537db,
+        0x183227397098d015,
+    ]));
+
+    const AFFINE_GENERATOR_COEFFS: (Fq2, Fq2) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Fq2) -> Fq2 {
+        FQ2_ZERO
+    }
+}
+
+/// G2_GENERATOR_X = (10857046999023057135944570762232829481370756359578518086990519993285655852781, 11559732032986387107991004021392285783925812861821192530917403151452391805634)
+pub const G2_GENERATOR_X: Fq2 = field_new!(
+    Fq2,
+    Fq::const_from_repr(U256::new([
+        0x46debd5cd992f6ed,
+        0x674322d4f75edadd,
+        0x426a00665e5c4479,
+        0x1800deef121f1e76
+    ])),
+    Fq::const_from_repr(U256::new([
+        0x97e485b7aef312c2,
+        0xf1aa493335a9e712,
+        0x7260bfb731fb5d25,
+        0x198e9393920d483a
+    ]))
+);
+
+/// G2_GENERATOR_Y = (8495653923123431417604973247489272438418190587263600148770280649306958101930, 4082367875863433681332203403145435568316851327593401208105741076214120093531)
+pub const G2_GENERATOR_Y: Fq2 = field_new!(
+    Fq2,
+    Fq::const_from_repr(U256::new([
+        0x4ce6cc0166fa7daa,
+        0xe3d1e7690c43d37b,
+        0x4aab71808dcb408f,
+        0x12c85ea5db8c6deb
+    ])),
+    Fq::const_from_repr(U256::new([
+        0x55acdadcd122975b,
+        0xbc4b313370b38ef3,
+        0xec9e99ad690c3395,
+        0x090689d0585ff075
+    ]))
+);
@@ -0,0 +1,303 @@
+//! The BN254 curve, also known as alt_bn128, of the EVM precompiles
+//! (EIP-196 and EIP-197), with `u = 0x44e992b44a6909f1`.
+
+use crate::{
+    pairing::curves::bn::{self, Bn, BnParameters},
+    uint::U256,
+};
+
+pub mod fq;
+pub mod fq12;
+pub mod fq2;
+pub mod fq6;
+pub mod fr;
+pub mod g1;
+pub mod g2;
+
+pub use self::{fq::*, fq12::*, fq2::*, fq6::*, fr::*};
+
+pub type Bn256 = Bn<Parameters>;
+
+pub type G1Affine = bn::G1Affine<Parameters>;
+pub type G1Projective = bn::G1Projective<Parameters>;
+pub type G2Affine = bn::G2Affine<Parameters>;
+pub type G2Projective = bn::G2Projective<Parameters>;
+
+pub struct Parameters;
+
+impl BnParameters for Parameters {
+    /// The NAF of `6 * u + 2 = 29793968203157093288`.
+    const SIX_U_PLUS_2_NAF: &'static [i8] = &[
+        0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0,
+        0, -1, 0, 0, 1, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0,
+        -1, 0, 0, 0, 1, 0, -1, 0, 1,
+    ];
+    const U: &'static [u64] = &[0x44e992b44a6909f1];
+    const CUBIC_NONRESIDUE_TO_Q_MINUS_1_OVER_2: Fq2 = field_new!(
+        Fq2,
+        Fq::const_from_repr(U256::new([
+            0xdc54014671a0135a,
+            0xdbaae0eda9c95998,
+            0xdc5ec698b6e2f9b9,
+            0x063cf305489af5dc
+        ])),
+        Fq::const_from_repr(U256::new([
+            0x82d37f632623b0e3,
+            0x21807dc98fa25bd2,
+            0x0704b5a7ec796f2b,
+            0x07c03cbcac41049a
+        ]))
+    );
+    type Fp = Fq;
+    type Fp2Params = Fq2Parameters;
+    type Fp6Params = Fq6Parameters;
+    type Fp12Params = Fq12Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ff::{Field, Fp2Parameters, PrimeField, SquareRootField},
+        pairing::{
+            curves::glv::{decompose, GLVParameters},
+            AffineCurve, PairingEngine, ProjectiveCurve,
+        },
+        uint::Uint,
+        One, Zero,
+    };
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    /// Inputs of the pairing check precompile (EIP-197) from the test suite
+    /// of go-ethereum, with whether the product of their pairings is one.
+    const EVM_PAIRING_VECTORS: &[(&str, bool)] = &[
+        // jeff1
+        (
+            concat!(
+                "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59",
+                "3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41",
+                "209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7",
+                "04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678",
+                "2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d",
+                "120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550",
+                "111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c",
+                "2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            ),
+            true,
+        ),
+        // jeff4
+        (
+            concat!(
+                "2f2ea0b3da1e8ef11914acf8b2e1b32d99df51f5f4f206fc6b947eae860eddb6",
+                "068134ddb33dc888ef446b648d72338684d678d2eb2371c61a50734d78da4b72",
+                "25f83c8b6ab9de74e7da488ef02645c5a16a6652c3c71a15dc37fe3a5dcb7cb1",
+                "22acdedd6308e3bb230d226d16a105295f523a8a02bfc5e8bd2da135ac4c245d",
+                "065bbad92e7c4e31bf3757f1fe7362a63fbfee50e7dc68da116e67d600d9bf68",
+                "06d302580dc0661002994e7cd3a7f224e7ddc27802777486bf80f40e4ca3cfdb",
+                "186bac5188a98c45e6016873d107f5cd131f3a3e339d0375e58bd6219347b008",
+                "122ae2b09e539e152ec5364e7e2204b03d11d3caa038bfc7cd499f8176aacbee",
+                "1f39e4e4afc4bc74790a4a028aff2c3d2538731fb755edefd8cb48d6ea589b5e",
+                "283f150794b6736f670d6a1033f9b46c6f5204f50813eb85c8dc4b59db1c5d39",
+                "140d97ee4d2b36d99bc49974d18ecca3e7ad51011956051b464d9e27d46cc25e",
+                "0764bb98575bd466d32db7b15f582b2d5c452b36aa394b789366e5e3ca5aabd4",
+                "15794ab061441e51d01e94640b7e3084a07e02c78cf3103c542bc5b298669f21",
+                "1b88da1679b0b64a63b7e0e7bfe52aae524f73a55be7fe70c7e9bfc94b4cf0da",
+                "1213d2149b006137fcfb23036606f848d638d576a120ca981b5b1a5f9300b3ee",
+                "2276cf730cf493cd95d64677bbb75fc42db72513a4c1e387b476d056f80aa75f",
+                "21ee6226d31426322afcda621464d0611d226783262e21bb3bc86b537e986237",
+                "096df1f82dff337dd5972e32a8ad43e28a78a96a823ef1cd4debe12b6552ea5f",
+            ),
+            true,
+        ),
+        // jeff6
+        (
+            concat!(
+                "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59",
+                "3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41",
+                "209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7",
+                "04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678",
+                "2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d",
+                "120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550",
+                "111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c",
+                "103188585e2364128fe25c70558f1560f4f9350baf3959e603cc91486e110936",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            ),
+            false,
+        ),
+        // one_point
+        (
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            ),
+            false,
+        ),
+        // two_point_match_2
+        (
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec",
+                "1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d",
+            ),
+            true,
+        ),
+    ];
+
+    fn decode_fq(hex: &str) -> Fq {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        Fq::from_be_bytes_mod_order(&bytes)
+    }
+
+    /// Decodes a pairing check input: pairs of a G1 point `(x, y)` and a G2
+    /// point `(x, y)`, whose coordinates `c1 * u + c0` are encoded as
+    /// `(c1, c0)`, all as 32-byte big-endian integers, with `(0, 0)` for the
+    /// point at infinity.
+    fn decode_pairs(input: &str) -> Vec<(G1Affine, G2Affine)> {
+        let fq: Vec<Fq> = (0..input.len())
+            .step_by(64)
+            .map(|i| decode_fq(&input[i..i + 64]))
+            .collect();
+        fq.chunks(6)
+            .map(|c| {
+                let g1 = if c[0].is_zero() && c[1].is_zero() {
+                    G1Affine::zero()
+                } else {
+                    G1Affine::new(c[0], c[1], false)
+                };
+                let x = Fq2::new(c[3], c[2]);
+                let y = Fq2::new(c[5], c[4]);
+                let g2 = if x.is_zero() && y.is_zero() {
+                    G2Affine::zero()
+                } else {
+                    G2Affine::new(x, y, false)
+                };
+                assert!(g1.is_on_curve() && g2.is_on_curve());
+                assert!(g2.is_in_correct_subgroup_assuming_on_curve());
+                (g1, g2)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn generators_are_in_the_subgroups() {
+        let g1 = G1Affine::prime_subgroup_generator();
+        assert!(g1.is_on_curve() && g1.is_in_correct_subgroup_assuming_on_curve());
+        let g2 = G2Affine::prime_subgroup_generator();
+        assert!(g2.is_on_curve() && g2.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn frobenius_map_is_the_power_of_the_characteristic() {
+        let rng = &mut test_rng();
+        for _ in 0..3 {
+            let a: Fq12 = rng.gen();
+            let mut power = a;
+            for i in 0..12 {
+                let mut b = a;
+                b.frobenius_map(i);
+                assert_eq!(b, power);
+                power = power.pow(Fq::characteristic());
+            }
+        }
+    }
+
+    #[test]
+    fn small_nonresidue_is_the_nonresidue() {
+        let k = Fq2Parameters::SMALL_NONRESIDUE.unwrap();
+        let small = Fq::from(k.unsigned_abs());
+        let small = if k < 0 { -small } else { small };
+        assert_eq!(small, Fq2Parameters::NONRESIDUE);
+
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a: Fq = rng.gen();
+            assert_eq!(
+                Fq2Parameters::mul_fp_by_nonresidue(&a),
+                Fq2Parameters::NONRESIDUE * &a
+            );
+        }
+    }
+
+    #[test]
+    fn sqrt_of_squares() {
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a: Fq = rng.gen();
+            assert_eq!(a.square().sqrt().unwrap().square(), a.square());
+            let b: Fq2 = rng.gen();
+            assert_eq!(b.square().sqrt().unwrap().square(), b.square());
+            let c: Fr = rng.gen();
+            assert_eq!(c.square().sqrt().unwrap().square(), c.square());
+        }
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let rng = &mut test_rng();
+        let p = G1Projective::prime_subgroup_generator();
+        let q = G2Projective::prime_subgroup_generator();
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        let e = Bn256::pairing(p, q);
+        assert!(!e.is_one());
+        assert!(e.pow(Fr::characteristic()).is_one());
+        let e_ab = Bn256::pairing(p.mul(a.into_repr()), q.mul(b.into_repr()));
+        assert_eq!(e_ab, e.pow((a * &b).into_repr()));
+    }
+
+    #[test]
+    fn glv_decomposition() {
+        let rng = &mut test_rng();
+        let lambda = <g1::Parameters as GLVParameters>::LAMBDA;
+        let g = G1Affine::prime_subgroup_generator();
+        let mut scalars: Vec<Fr> = (0..100).map(|_| rng.gen()).collect();
+        scalars.extend(&[Fr::zero(), Fr::one(), -Fr::one(), lambda]);
+        for k in scalars {
+            let ((neg1, k1), (neg2, k2)) = decompose::<g1::Parameters>(&k);
+            // Both halves are at most about the square root of r.
+            assert!(k1.into_repr().num_bits() <= 128 && k2.into_repr().num_bits() <= 128);
+            let k1 = if neg1 { -k1 } else { k1 };
+            let k2 = if neg2 { -k2 } else { k2 };
+            assert_eq!(k1 + &(k2 * &lambda), k);
+            assert_eq!(g.glv_mul(&k), g.mul(k.into_repr()));
+            // `ProjectiveCurve::mul` goes through `glv_mul`.
+            assert_eq!(g.into_projective().mul(k.into_repr()), g.mul(k.into_repr()));
+        }
+        assert_eq!(g.endomorphism(), g.mul(lambda.into_repr()).into_affine());
+    }
+
+    #[test]
+    fn pairing_check_matches_the_evm_precompile() {
+        for (input, expected) in EVM_PAIRING_VECTORS {
+            let pairs = decode_pairs(input);
+            let (g1, g2): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+            assert_eq!(Bn256::multi_pairing(&g1, &g2).is_one(), *expected);
+        }
+    }
+}
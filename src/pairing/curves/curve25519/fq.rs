@@ -0,0 +1,88 @@
+use crate::{
+    ff::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+    uint::U256,
+};
+
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInt = U256;
+
+    const TWO_ADICITY: u32 = 2;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+        0x3b5807d4fe2bdb04,
+        0x03f590fdb51be9ed,
+        0x6d6e16bf336202d1,
+        0x75776b0bd6c71ba8,
+    ]);
+}
+
+impl FpParameters for FqParameters {
+    /// MODULUS = 57896044618658097711785492504343953926634992332820282019728792003956564819949
+    const MODULUS: U256 = U256::new([
+        0xffffffffffffffed,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    const MODULUS_BITS: u32 = 255;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 1;
+
+    /// R = 2^256 mod MODULUS
+    const R: U256 = U256::new([
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U256 = U256::new([
+        0x00000000000005a4,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const INV: u64 = 0x86bca1af286bca1b;
+
+    /// GENERATOR = 2, in Montgomery form
+    const GENERATOR: U256 = U256::new([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0xfffffffffffffff6,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U256 = U256::new([
+        0xfffffffffffffffb,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x1fffffffffffffff,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0fffffffffffffff,
+    ]);
+}
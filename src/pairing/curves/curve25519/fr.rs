@@ -0,0 +1,88 @@
+use crate::{
+    ff::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+    uint::U256,
+};
+
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInt = U256;
+
+    const TWO_ADICITY: u32 = 2;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: U256 = U256::new([
+        0x7c790e32b42f0e7d,
+        0x4c8ce706a7ae2cc8,
+        0xd73823cc921779ad,
+        0x05599959893f562a,
+    ]);
+}
+
+impl FpParameters for FrParameters {
+    /// MODULUS = 7237005577332262213973186563042994240857116359379907606001950938285454250989
+    const MODULUS: U256 = U256::new([
+        0x5812631a5cf5d3ed,
+        0x14def9dea2f79cd6,
+        0x0000000000000000,
+        0x1000000000000000,
+    ]);
+
+    const MODULUS_BITS: u32 = 253;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 3;
+
+    /// R = 2^256 mod MODULUS
+    const R: U256 = U256::new([
+        0xd6ec31748d98951d,
+        0xc6ef5bf4737dcf70,
+        0xfffffffffffffffe,
+        0x0fffffffffffffff,
+    ]);
+
+    /// R2 = R^2 mod MODULUS
+    const R2: U256 = U256::new([
+        0xa40611e3449c0f01,
+        0xd00e1ba768859347,
+        0xceec73d217f5be65,
+        0x0399411b7c309a3d,
+    ]);
+
+    const INV: u64 = 0xd2b51da312547e1b;
+
+    /// GENERATOR = 2, in Montgomery form
+    const GENERATOR: U256 = U256::new([
+        0x55c5ffcebe3b564d,
+        0x78ffbe0a4404020b,
+        0xfffffffffffffffd,
+        0x0fffffffffffffff,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0x2c09318d2e7ae9f6,
+        0x0a6f7cef517bce6b,
+        0x0000000000000000,
+        0x0800000000000000,
+    ]);
+
+    /// T = (MODULUS - 1) / 2^TWO_ADICITY
+    const T: U256 = U256::new([
+        0x960498c6973d74fb,
+        0x0537be77a8bde735,
+        0x0000000000000000,
+        0x0400000000000000,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: U256 = U256::new([
+        0xcb024c634b9eba7d,
+        0x029bdf3bd45ef39a,
+        0x0000000000000000,
+        0x0200000000000000,
+    ]);
+}
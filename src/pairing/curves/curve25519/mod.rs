@@ -0,0 +1,102 @@
+//! Curve25519 ([RFC 7748]), the Montgomery curve `y^2 = x^3 + 486662 x^2 + x`
+//! over the field modulo `2^255 - 19`, and the twisted Edwards curve
+//! edwards25519 birationally equivalent to it. Both have the cofactor 8 over
+//! a subgroup of prime order `2^252 + 27742317777372353535851937790883648493`.
+//!
+//! [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
+
+use crate::{
+    pairing::curves::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+    uint::U256,
+};
+
+pub mod fq;
+pub mod fr;
+
+pub use self::{fq::*, fr::*};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Curve25519Parameters;
+
+impl ModelParameters for Curve25519Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl MontgomeryModelParameters for Curve25519Parameters {
+    /// COEFF_A = 486662
+    const COEFF_A: Fq = Fq::const_from_repr(U256::new([
+        0x0000000000076d06,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    /// COEFF_B = 1
+    const COEFF_B: Fq = Fq::const_from_repr(U256::new([
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    type TEModelParameters = Edwards25519Parameters;
+}
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Edwards25519Parameters;
+
+impl ModelParameters for Edwards25519Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for Edwards25519Parameters {
+    /// COEFF_A = -1
+    const COEFF_A: Fq = Fq::const_from_repr(U256::new([
+        0xffffffffffffffec,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]));
+
+    /// COEFF_D = -121665 / 121666
+    const COEFF_D: Fq = Fq::const_from_repr(U256::new([
+        0x75eb4dca135978a3,
+        0x00700a4d4141d8ab,
+        0x8cc740797779e898,
+        0x52036cee2b6ffe73,
+    ]));
+
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[0x0000000000000008];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::const_from_repr(U256::new([
+        0x6106e529e2dc2f79,
+        0x07d39db37d1cdad0,
+        0x0000000000000000,
+        0x0600000000000000,
+    ]));
+
+    /// The base point of RFC 8032, with `y = 4 / 5` and an even `x`.
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = Curve25519Parameters;
+}
+
+/// GENERATOR_X = 15112221349535400772501151409588531511454012693041857206046113283949847762202
+pub const GENERATOR_X: Fq = Fq::const_from_repr(U256::new([
+    0xc9562d608f25d51a,
+    0x692cc7609525a7b2,
+    0xc0a4e231fdd6dc5c,
+    0x216936d3cd6e53fe,
+]));
+
+/// GENERATOR_Y = 46316835694926478169428394003475163141307993866256225615783033603165251855960
+pub const GENERATOR_Y: Fq = Fq::const_from_repr(U256::new([
+    0x6666666666666658,
+    0x6666666666666666,
+    0x6666666666666666,
+    0x6666666666666666,
+]));
@@ -0,0 +1,443 @@
+use crate::{
+    ff::{Field, PrimeField},
+    hash::FromBasePrimeField,
+    serialize::{read_bytes, CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    Error, Uint, Vec, Zero,
+};
+
+use super::{
+    short_weierstrass_jacobian, twisted_edwards_extended, SWModelParameters, TEModelParameters,
+};
+
+const COMPRESSION_FLAG: u8 = 1 << 7;
+const INFINITY_FLAG: u8 = 1 << 6;
+const SIGN_FLAG: u8 = 1 << 5;
+
+/// Returns the number of bytes of a coordinate over the prime field.
+fn coordinate_size<F: FromBasePrimeField>() -> usize {
+    (F::BasePrimeField::size_in_bits() + 7) / 8
+}
+
+/// Returns the number of bytes of an element of `F`.
+fn element_size<F: FromBasePrimeField>() -> usize {
+    F::EXTENSION_DEGREE * coordinate_size::<F>()
+}
+
+/// Returns the number of unused bits at the top of an encoded coordinate.
+fn spare_bits<F: FromBasePrimeField>() -> usize {
+    8 * coordinate_size::<F>() - F::BasePrimeField::size_in_bits()
+}
+
+/// Appends the little-endian coordinates of `x` to `out`, lowest first.
+fn write_le<F: FromBasePrimeField>(x: &F, out: &mut Vec<u8>) {
+    for c in x.to_base_prime_field_elems() {
+        let mut bytes = c.into_repr().to_bytes();
+        bytes.truncate(coordinate_size::<F>());
+        out.extend(bytes);
+    }
+}
+
+/// Appends the big-endian coordinates of `x` to `out`, highest first.
+fn write_be<F: FromBasePrimeField>(x: &F, out: &mut Vec<u8>) {
+    let mut bytes = Vec::with_capacity(element_size::<F>());
+    write_le(x, &mut bytes);
+    bytes.reverse();
+    out.extend(bytes);
+}
+
+/// Reads an element of `F` written by `write_le`, rejecting non-canonical
+/// coordinates.
+fn read_le<F: FromBasePrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    let len = <F::BasePrimeField as PrimeField>::BigInt::LIMBS * 8;
+    let coords = bytes
+        .chunks(coordinate_size::<F>())
+        .map(|chunk| {
+            let mut le = chunk.to_vec();
+            le.resize(len, 0);
+            let repr = <F::BasePrimeField as PrimeField>::BigInt::from_bytes(&le)?;
+            F::BasePrimeField::from_repr(repr).ok_or(Error("non-canonical field element"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(F::from_base_prime_field_elems(&coords))
+}
+
+/// Reads an element of `F` written by `write_be`.
+fn read_be<F: FromBasePrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    let mut le = bytes.to_vec();
+    le.reverse();
+    read_le(&le)
+}
+
+/// Short Weierstrass points use the Zcash encoding of BLS12-381 points, as
+/// implemented by blst: coordinates are big-endian, elements of quadratic
+/// extensions are written as `c1 || c0`, and the three most significant bits
+/// of the first byte flag compression, the point at infinity, and whether
+/// `y` is the lexicographically largest of `y` and `-y`.
+impl<P: SWModelParameters> short_weierstrass_jacobian::GroupAffine<P>
+where
+    P::BaseField: FromBasePrimeField,
+{
+    /// Returns the compressed encoding of `self`: `x` with the compression,
+    /// infinity and sign flags. Fails if the base field leaves no room for
+    /// the flags.
+    pub fn to_compressed(&self) -> Result<Vec<u8>, Error> {
+        self.encode(true)
+    }
+
+    /// Returns the uncompressed encoding of `self`: `x || y` with the
+    /// infinity flag. Fails if the base field leaves no room for the flags.
+    pub fn to_uncompressed(&self) -> Result<Vec<u8>, Error> {
+        self.encode(false)
+    }
+
+    /// Decodes a point written by `to_compressed`, checking that it lies in
+    /// the prime order subgroup.
+    pub fn from_compressed(bytes: &[u8]) -> Result<Self, Error> {
+        Self::decode(bytes, true, true)
+    }
+
+    /// Decodes a point written by `to_uncompressed`, checking that it lies
+    /// on the curve and in the prime order subgroup.
+    pub fn from_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        Self::decode(bytes, false, true)
+    }
+
+    fn encode(&self, compressed: bool) -> Result<Vec<u8>, Error> {
+        if spare_bits::<P::BaseField>() < 3 {
+            return Err(Error("the base field has no room for the point flags"));
+        }
+        let size = element_size::<P::BaseField>();
+        let len = if compressed { size } else { 2 * size };
+        let mut bytes = Vec::with_capacity(len);
+        if self.infinity {
+            bytes.resize(len, 0);
+            bytes[0] |= INFINITY_FLAG;
+        } else {
+            write_be(&self.x, &mut bytes);
+            if compressed {
+                if self.y > -self.y {
+                    bytes[0] |= SIGN_FLAG;
+                }
+            } else {
+                write_be(&self.y, &mut bytes);
+            }
+        }
+        if compressed {
+            bytes[0] |= COMPRESSION_FLAG;
+        }
+        Ok(bytes)
+    }
+
+    fn decode(bytes: &[u8], compressed: bool, check_subgroup: bool) -> Result<Self, Error> {
+        if spare_bits::<P::BaseField>() < 3 {
+            return Err(Error("the base field has no room for the point flags"));
+        }
+        let size = element_size::<P::BaseField>();
+        if bytes.len() != if compressed { size } else { 2 * size } {
+            return Err(Error("invalid length of an encoded point"));
+        }
+        let flags = bytes[0] & (COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG);
+        if (flags & COMPRESSION_FLAG != 0) != compressed {
+            return Err(Error("invalid compression flag of an encoded point"));
+        }
+
+        let mut bytes = bytes.to_vec();
+        bytes[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG);
+        if flags & INFINITY_FLAG != 0 {
+            // The point at infinity has no sign and is otherwise all zeros.
+            if flags & SIGN_FLAG != 0 || bytes.iter().any(|b| *b != 0) {
+                return Err(Error("invalid encoding of the point at infinity"));
+            }
+            return Ok(Self::zero());
+        }
+
+        let x = read_be(&bytes[..size])?;
+        let point = if compressed {
+            Self::get_point_from_x(x, flags & SIGN_FLAG != 0)
+                .ok_or(Error("encoded x-coordinate is not on the curve"))?
+        } else {
+            if flags & SIGN_FLAG != 0 {
+                return Err(Error("unexpected sign flag of an uncompressed point"));
+            }
+            let point = Self::new(x, read_be(&bytes[size..])?, false);
+            if !point.is_on_curve() {
+                return Err(Error("encoded point is not on the curve"));
+            }
+            point
+        };
+        if check_subgroup && !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(Error("encoded point is not in the prime order subgroup"));
+        }
+        Ok(point)
+    }
+}
+
+/// Twisted Edwards points use the encoding of RFC 8032 and Jubjub:
+/// coordinates are little-endian, and a compressed point is `y` with the
+/// most significant bit set if `x` is odd.
+impl<P: TEModelParameters> twisted_edwards_extended::GroupAffine<P>
+where
+    P::BaseField: FromBasePrimeField,
+{
+    /// Returns the compressed encoding of `self`: `y` with the most
+    /// significant bit set if `x` is odd. Fails if the base field leaves no
+    /// room for the sign bit.
+    pub fn to_compressed(&self) -> Result<Vec<u8>, Error> {
+        if spare_bits::<P::BaseField>() < 1 {
+            return Err(Error("the base field has no room for the sign bit"));
+        }
+        let mut bytes = Vec::with_capacity(element_size::<P::BaseField>());
+        write_le(&self.y, &mut bytes);
+        if is_odd(&self.x) {
+            *bytes.last_mut().unwrap() |= 1 << 7;
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the uncompressed encoding of `self`: `x || y`.
+    pub fn to_uncompressed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * element_size::<P::BaseField>());
+        write_le(&self.x, &mut bytes);
+        write_le(&self.y, &mut bytes);
+        bytes
+    }
+
+    /// Decodes a point written by `to_compressed`, checking that it lies in
+    /// the prime order subgroup.
+    pub fn from_compressed(bytes: &[u8]) -> Result<Self, Error> {
+        Self::decode_compressed(bytes, true)
+    }
+
+    /// Decodes a point written by `to_uncompressed`, checking that it lies
+    /// on the curve and in the prime order subgroup.
+    pub fn from_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        Self::decode_uncompressed(bytes, true)
+    }
+
+    fn decode_compressed(bytes: &[u8], check_subgroup: bool) -> Result<Self, Error> {
+        if spare_bits::<P::BaseField>() < 1 {
+            return Err(Error("the base field has no room for the sign bit"));
+        }
+        if bytes.len() != element_size::<P::BaseField>() {
+            return Err(Error("invalid length of an encoded point"));
+        }
+        let mut bytes = bytes.to_vec();
+        let x_is_odd = bytes.last().unwrap() >> 7 == 1;
+        *bytes.last_mut().unwrap() &= !(1 << 7);
+        let y: P::BaseField = read_le(&bytes)?;
+
+        // x^2 = (1 - y^2) / (a - d * y^2)
+        let y2 = y.square();
+        let x2 = (P::BaseField::one() - &y2)
+            * &(P::COEFF_A - &(P::COEFF_D * &y2))
+                .inverse()
+                .ok_or(Error("encoded y-coordinate is not on the curve"))?;
+        let x = x2
+            .sqrt()
+            .ok_or(Error("encoded y-coordinate is not on the curve"))?;
+        let x = if is_odd(&x) == x_is_odd { x } else { -x };
+        if x.is_zero() && x_is_odd {
+            return Err(Error("invalid sign of a zero x-coordinate"));
+        }
+        let point = Self::new(x, y);
+        if check_subgroup && !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(Error("encoded point is not in the prime order subgroup"));
+        }
+        Ok(point)
+    }
+
+    fn decode_uncompressed(bytes: &[u8], check_subgroup: bool) -> Result<Self, Error> {
+        let size = element_size::<P::BaseField>();
+        if bytes.len() != 2 * size {
+            return Err(Error("invalid length of an encoded point"));
+        }
+        let point = Self::new(read_le(&bytes[..size])?, read_le(&bytes[size..])?);
+        if !point.is_on_curve() {
+            return Err(Error("encoded point is not on the curve"));
+        }
+        if check_subgroup && !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(Error("encoded point is not in the prime order subgroup"));
+        }
+        Ok(point)
+    }
+}
+
+/// Returns whether the lowest coordinate of `x` is odd.
+fn is_odd<F: FromBasePrimeField>(x: &F) -> bool {
+    x.to_base_prime_field_elems()[0].into_repr().is_odd()
+}
+
+// The canonical serialization of points is their encoding above. Unchecked
+// deserialization still verifies that a point lies on the curve, but skips
+// the subgroup check.
+impl<P: SWModelParameters> CanonicalSerialize for short_weierstrass_jacobian::GroupAffine<P>
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        writer.extend(self.encode(compress == Compress::Yes)?);
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => element_size::<P::BaseField>(),
+            Compress::No => 2 * element_size::<P::BaseField>(),
+        }
+    }
+}
+
+impl<P: SWModelParameters> CanonicalDeserialize for short_weierstrass_jacobian::GroupAffine<P>
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let compressed = compress == Compress::Yes;
+        let size = element_size::<P::BaseField>();
+        let bytes = read_bytes(reader, if compressed { size } else { 2 * size })?;
+        Self::decode(bytes, compressed, validate == Validate::Yes)
+    }
+}
+
+impl<P: TEModelParameters> CanonicalSerialize for twisted_edwards_extended::GroupAffine<P>
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        match compress {
+            Compress::Yes => writer.extend(self.to_compressed()?),
+            Compress::No => writer.extend(self.to_uncompressed()),
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => element_size::<P::BaseField>(),
+            Compress::No => 2 * element_size::<P::BaseField>(),
+        }
+    }
+}
+
+impl<P: TEModelParameters> CanonicalDeserialize for twisted_edwards_extended::GroupAffine<P>
+where
+    P::BaseField: FromBasePrimeField,
+{
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let size = element_size::<P::BaseField>();
+        match compress {
+            Compress::Yes => {
+                Self::decode_compressed(read_bytes(reader, size)?, validate == Validate::Yes)
+            }
+            Compress::No => {
+                Self::decode_uncompressed(read_bytes(reader, 2 * size)?, validate == Validate::Yes)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bls12_381"))]
+mod tests {
+    use super::*;
+    use crate::pairing::{
+        curves::bls12_381::{G1Affine, G2Affine},
+        AffineCurve,
+    };
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Returns the encoding of the point at infinity of `len` bytes.
+    fn infinity(len: usize, compressed: bool) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+        bytes[0] = INFINITY_FLAG | if compressed { COMPRESSION_FLAG } else { 0 };
+        bytes
+    }
+
+    // The generators as encoded by the zcash and blst implementations.
+    const G1_COMPRESSED: &str =
+        "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+    const G1_UNCOMPRESSED: &str =
+        "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb\
+         08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+    const G2_COMPRESSED: &str =
+        "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+         024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+    const G2_UNCOMPRESSED: &str =
+        "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+         024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8\
+         0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be\
+         0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801";
+
+    #[test]
+    fn bls12_381_generators() {
+        let g1 = G1Affine::prime_subgroup_generator();
+        assert_eq!(g1.to_compressed().unwrap(), from_hex(G1_COMPRESSED));
+        assert_eq!(g1.to_uncompressed().unwrap(), from_hex(G1_UNCOMPRESSED));
+        assert_eq!(
+            G1Affine::from_compressed(&from_hex(G1_COMPRESSED)).unwrap(),
+            g1
+        );
+        assert_eq!(
+            G1Affine::from_uncompressed(&from_hex(G1_UNCOMPRESSED)).unwrap(),
+            g1
+        );
+
+        let g2 = G2Affine::prime_subgroup_generator();
+        assert_eq!(g2.to_compressed().unwrap(), from_hex(G2_COMPRESSED));
+        assert_eq!(g2.to_uncompressed().unwrap(), from_hex(G2_UNCOMPRESSED));
+        assert_eq!(
+            G2Affine::from_compressed(&from_hex(G2_COMPRESSED)).unwrap(),
+            g2
+        );
+        assert_eq!(
+            G2Affine::from_uncompressed(&from_hex(G2_UNCOMPRESSED)).unwrap(),
+            g2
+        );
+
+        // -g has the other sign.
+        let mut neg = from_hex(G1_COMPRESSED);
+        neg[0] ^= SIGN_FLAG;
+        assert_eq!(G1Affine::from_compressed(&neg).unwrap(), -g1);
+    }
+
+    #[test]
+    fn bls12_381_points_at_infinity() {
+        for compressed in [true, false] {
+            let len = if compressed { 48 } else { 96 };
+            let zero = G1Affine::zero();
+            let encoded = infinity(len, compressed);
+            assert_eq!(zero.encode(compressed).unwrap(), encoded);
+            assert!(G1Affine::decode(&encoded, compressed, true)
+                .unwrap()
+                .is_zero());
+
+            let zero = G2Affine::zero();
+            let encoded = infinity(2 * len, compressed);
+            assert_eq!(zero.encode(compressed).unwrap(), encoded);
+            assert!(G2Affine::decode(&encoded, compressed, true)
+                .unwrap()
+                .is_zero());
+
+            // The point at infinity has no sign and no coordinates.
+            let mut signed = infinity(len, compressed);
+            signed[0] |= SIGN_FLAG;
+            assert!(G1Affine::decode(&signed, compressed, true).is_err());
+            let mut nonzero = infinity(len, compressed);
+            nonzero[len - 1] = 1;
+            assert!(G1Affine::decode(&nonzero, compressed, true).is_err());
+        }
+    }
+}
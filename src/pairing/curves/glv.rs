@@ -0,0 +1,135 @@
+use crate::{
+    ff::{BitIterator, PrimeField},
+    pairing::{AffineCurve, ProjectiveCurve},
+    uint::lattice::mul_shift,
+    Zero,
+};
+
+use super::{
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    SWModelParameters,
+};
+
+/// Parameters for curves with an efficiently computable endomorphism
+/// `phi(x, y) = (beta * x, y)` that acts on the prime order subgroup as
+/// multiplication by `lambda`, as used by the GLV method.
+pub trait GLVParameters: SWModelParameters {
+    /// The nontrivial cube root of unity `beta` in the base field.
+    const ENDO_COEFF: Self::BaseField;
+
+    /// The eigenvalue `lambda` of the endomorphism, a nontrivial cube root of
+    /// unity in the scalar field.
+    const LAMBDA: Self::ScalarField;
+
+    /// A short basis `[a1, b1, a2, b2]` of the lattice of pairs `(a, b)` with
+    /// `a + b * lambda = 0` modulo the group order, with negative entries
+    /// given by their representative in the scalar field.
+    const SCALAR_DECOMP_COEFFS: [Self::ScalarField; 4];
+
+    /// The little-endian limbs of `round(b2 * 2^SHIFT / r)` and
+    /// `round(-b1 * 2^SHIFT / r)`, where `r` is the group order and `SHIFT`
+    /// is `SCALAR_DECOMP_SHIFT`.
+    const SCALAR_DECOMP_ROUNDING: [&'static [u64]; 2];
+
+    /// The precision of `SCALAR_DECOMP_ROUNDING`, at least twice the bit size
+    /// of the group order.
+    const SCALAR_DECOMP_SHIFT: u32;
+
+    /// Applies the endomorphism to `p`.
+    #[inline]
+    fn endomorphism(p: &GroupAffine<Self>) -> GroupAffine<Self> {
+        let mut res = *p;
+        res.x *= &Self::ENDO_COEFF;
+        res
+    }
+}
+
+/// Decomposes `k` into `k1 + k2 * lambda` with `k1` and `k2` of about half
+/// the bit size of the group order. Each half is returned as a sign
+/// (`true` for negative) and an absolute value.
+pub fn decompose<P: GLVParameters>(
+    k: &P::ScalarField,
+) -> ((bool, P::ScalarField), (bool, P::ScalarField)) {
+    let [a1, b1, a2, b2] = P::SCALAR_DECOMP_COEFFS;
+    let [g1, g2] = P::SCALAR_DECOMP_ROUNDING;
+    let repr = k.into_repr();
+
+    // c1 = round(b2 * k / r) and c2 = round(-b1 * k / r).
+    let c1 = P::ScalarField::from(mul_shift(&repr, g1, P::SCALAR_DECOMP_SHIFT));
+    let c2 = P::ScalarField::from(mul_shift(&repr, g2, P::SCALAR_DECOMP_SHIFT));
+
+    // (k1, k2) = (k, 0) - c1 * (a1, b1) - c2 * (a2, b2)
+    let k1 = *k - &(c1 * &a1) - &(c2 * &a2);
+    let k2 = -(c1 * &b1) - &(c2 * &b2);
+    (signed(k1), signed(k2))
+}
+
+/// Returns `(true, -x)` if `x` is the representative of a negative number,
+/// i.e. larger than `(r - 1) / 2`, and `(false, x)` otherwise.
+#[inline]
+fn signed<F: PrimeField>(x: F) -> (bool, F) {
+    if x.into_repr() > F::modulus_minus_one_div_two() {
+        (true, -x)
+    } else {
+        (false, x)
+    }
+}
+
+/// Multiplies `base` by `scalar` with `GroupAffine::glv_mul`, for the
+/// `SWModelParameters::mul_projective` of curves with GLV parameters. The
+/// endomorphism acts as `lambda` only on the prime order subgroup, so `base`
+/// must be in it. Scalars from the group order up are multiplied by
+/// double-and-add.
+pub fn mul_projective<P: GLVParameters>(
+    base: &GroupProjective<P>,
+    scalar: <P::ScalarField as PrimeField>::BigInt,
+) -> GroupProjective<P> {
+    match P::ScalarField::from_repr(scalar) {
+        Some(k) => base.into_affine().glv_mul(&k),
+        None => base.into_affine().mul_bits(BitIterator::new(scalar)),
+    }
+}
+
+impl<P: GLVParameters> GroupAffine<P> {
+    /// Applies the endomorphism `(x, y) -> (beta * x, y)`.
+    #[inline]
+    pub fn endomorphism(&self) -> Self {
+        P::endomorphism(self)
+    }
+
+    /// Multiplies `self` by `scalar` with the GLV method: the scalar is split
+    /// into two half-length scalars that are applied to `self` and its image
+    /// under the endomorphism in a joint double-and-add loop, which halves
+    /// the number of doublings.
+    pub fn glv_mul(&self, scalar: &P::ScalarField) -> GroupProjective<P> {
+        let ((neg1, k1), (neg2, k2)) = decompose::<P>(scalar);
+        let p1 = if neg1 { -*self } else { *self };
+        let p2 = if neg2 {
+            -self.endomorphism()
+        } else {
+            self.endomorphism()
+        };
+        let p1_plus_p2 = p1.into_projective().add_mixed(&p2).into_affine();
+
+        let mut res = GroupProjective::zero();
+        let mut found_one = false;
+        let bits1 = BitIterator::new(k1.into_repr());
+        let bits2 = BitIterator::new(k2.into_repr());
+        for (bit1, bit2) in bits1.zip(bits2) {
+            if found_one {
+                res.double_in_place();
+            } else if bit1 || bit2 {
+                found_one = true;
+            } else {
+                continue;
+            }
+            match (bit1, bit2) {
+                (true, true) => res.add_assign_mixed(&p1_plus_p2),
+                (true, false) => res.add_assign_mixed(&p1),
+                (false, true) => res.add_assign_mixed(&p2),
+                (false, false) => {}
+            }
+        }
+        res
+    }
+}
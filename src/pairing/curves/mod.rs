@@ -1,4 +1,8 @@
-use crate::ff::{Field, PrimeField, SquareRootField};
+use crate::{
+    ff::{BitIterator, Field, PrimeField, SquareRootField},
+    pairing::ProjectiveCurve,
+    Zero,
+};
 
 pub mod bls12;
 pub mod bn;
@@ -6,11 +10,22 @@ pub mod bw6;
 pub mod mnt4;
 pub mod mnt6;
 
+#[cfg(feature = "bls12_377")]
+pub mod bls12_377;
+#[cfg(feature = "bls12_381")]
+pub mod bls12_381;
+#[cfg(feature = "bn_256")]
+pub mod bn_256;
+#[cfg(feature = "curve25519")]
+pub mod curve25519;
+
+pub mod glv;
+pub mod montgomery;
 pub mod short_weierstrass_jacobian;
 pub mod short_weierstrass_projective;
 pub mod twisted_edwards_extended;
 
-mod flags;
+mod encoding;
 
 pub trait ModelParameters: Send + Sync + 'static {
     type BaseField: Field + SquareRootField;
@@ -37,6 +52,51 @@ pub trait SWModelParameters: ModelParameters {
         copy += &Self::COEFF_B;
         copy
     }
+
+    /// Checks if `item` is in the prime order subgroup, assuming that it is
+    /// on the curve. The default multiplies by the group order; curves with
+    /// an efficient endomorphism should override it with a faster check.
+    fn is_in_correct_subgroup_assuming_on_curve(
+        item: &short_weierstrass_jacobian::GroupAffine<Self>,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        item.mul_bits(BitIterator::new(Self::ScalarField::characteristic()))
+            .is_zero()
+    }
+
+    /// Maps `item` into the prime order subgroup. The default multiplies by
+    /// the cofactor; curves can override it with a faster map by an
+    /// effective cofactor.
+    fn clear_cofactor(
+        item: &short_weierstrass_jacobian::GroupAffine<Self>,
+    ) -> short_weierstrass_jacobian::GroupAffine<Self>
+    where
+        Self: Sized,
+    {
+        item.scale_by_cofactor().into()
+    }
+
+    /// Multiplies `base` by `scalar`, for `ProjectiveCurve::mul`. The default
+    /// is double-and-add; curves with an efficient endomorphism can override
+    /// it with `glv::mul_projective`.
+    fn mul_projective(
+        base: &short_weierstrass_jacobian::GroupProjective<Self>,
+        scalar: <Self::ScalarField as PrimeField>::BigInt,
+    ) -> short_weierstrass_jacobian::GroupProjective<Self>
+    where
+        Self: Sized,
+    {
+        let mut res = short_weierstrass_jacobian::GroupProjective::zero();
+        for bit in BitIterator::new(scalar) {
+            res.double_in_place();
+            if bit {
+                res += base;
+            }
+        }
+        res
+    }
 }
 
 pub trait TEModelParameters: ModelParameters {
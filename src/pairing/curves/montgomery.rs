@@ -0,0 +1,236 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    ff::{BitIterator, Field},
+    One, Zero,
+};
+
+use super::MontgomeryModelParameters as Parameters;
+
+/// A point on the Montgomery curve `B * y^2 = x^3 + A * x^2 + x`, represented
+/// only by its projective x-coordinate `X / Z`. A point and its negation have
+/// the same representation, and the point at infinity has `Z = 0`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+pub struct XOnlyPoint<P: Parameters> {
+    pub x: P::BaseField,
+    pub z: P::BaseField,
+}
+
+impl<P: Parameters> Display for XOnlyPoint<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.to_x() {
+            Some(x) => write!(f, "XOnlyPoint(x={})", x),
+            None => write!(f, "XOnlyPoint(Infinity)"),
+        }
+    }
+}
+
+impl<P: Parameters> PartialEq for XOnlyPoint<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() || other.is_zero() {
+            return self.is_zero() && other.is_zero();
+        }
+        // x1/z1 == x2/z2  <==> x1 * z2 == x2 * z1
+        self.x * &other.z == other.x * &self.z
+    }
+}
+
+impl<P: Parameters> Eq for XOnlyPoint<P> {}
+
+impl<P: Parameters> Zero for XOnlyPoint<P> {
+    // The point at infinity is always represented by Z = 0.
+    #[inline]
+    fn zero() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl<P: Parameters> XOnlyPoint<P> {
+    pub fn new(x: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, z }
+    }
+
+    /// Constructs the point with affine x-coordinate `x`.
+    pub fn from_x(x: P::BaseField) -> Self {
+        Self::new(x, P::BaseField::one())
+    }
+
+    /// Returns the affine x-coordinate, or `None` for the point at infinity.
+    pub fn to_x(&self) -> Option<P::BaseField> {
+        self.z.inverse().map(|z_inv| self.x * &z_inv)
+    }
+
+    /// Returns `(A - 2) / 4`, the curve constant used by the doubling
+    /// formula.
+    #[inline]
+    fn a24() -> P::BaseField {
+        let two = P::BaseField::one().double();
+        (P::COEFF_A - &two) * &two.double().inverse().unwrap()
+    }
+
+    /// Doubles `self`.
+    pub fn double(&self) -> Self {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-montgom-xz.html#doubling-dbl-1987-m-3
+        let aa = (self.x + &self.z).square();
+        let bb = (self.x - &self.z).square();
+        let e = aa - &bb;
+        Self::new(aa * &bb, e * &(aa + &(Self::a24() * &e)))
+    }
+
+    /// Returns `self + other`, given the difference `self - other`. The
+    /// difference must not be the point at infinity.
+    pub fn differential_add(&self, other: &Self, difference: &Self) -> Self {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-montgom-xz.html#diffadd-dadd-1987-m-3
+        let da = (self.x - &self.z) * &(other.x + &other.z);
+        let cb = (self.x + &self.z) * &(other.x - &other.z);
+        Self::new(
+            difference.z * &(da + &cb).square(),
+            difference.x * &(da - &cb).square(),
+        )
+    }
+
+    /// Multiplies `self` by the scalar whose little-endian limbs are `by`,
+    /// using the Montgomery ladder.
+    ///
+    /// Every bit of `by` is processed, including leading zeros, and the two
+    /// ladder registers are swapped arithmetically rather than by branching,
+    /// so the sequence of field operations does not depend on the scalar.
+    /// The running time is therefore independent of the scalar as long as
+    /// the field arithmetic is.
+    pub fn mul<S: AsRef<[u64]>>(&self, by: S) -> Self {
+        let a24 = Self::a24();
+        let (x1, z1) = (self.x, self.z);
+        let (mut x2, mut z2) = (P::BaseField::one(), P::BaseField::zero());
+        let (mut x3, mut z3) = (x1, z1);
+        let mut swap = false;
+        for bit in BitIterator::new(by) {
+            let choice = swap ^ bit;
+            conditional_swap(&mut x2, &mut x3, choice);
+            conditional_swap(&mut z2, &mut z3, choice);
+            swap = bit;
+
+            let a = x2 + &z2;
+            let aa = a.square();
+            let b = x2 - &z2;
+            let bb = b.square();
+            let e = aa - &bb;
+            let da = (x3 - &z3) * &a;
+            let cb = (x3 + &z3) * &b;
+            x3 = z1 * &(da + &cb).square();
+            z3 = x1 * &(da - &cb).square();
+            x2 = aa * &bb;
+            z2 = e * &(aa + &(a24 * &e));
+        }
+        conditional_swap(&mut x2, &mut x3, swap);
+        conditional_swap(&mut z2, &mut z3, swap);
+        Self::new(x2, z2)
+    }
+}
+
+/// Swaps `a` and `b` if `choice` is set, using the same field operations
+/// either way.
+#[inline]
+fn conditional_swap<F: Field>(a: &mut F, b: &mut F, choice: bool) {
+    let mask = F::from(choice as u64);
+    let t = mask * &(*a - &*b);
+    *a -= &t;
+    *b += &t;
+}
+
+#[cfg(all(test, feature = "curve25519"))]
+mod tests {
+    use super::*;
+    use crate::{
+        ff::PrimeField,
+        pairing::curves::curve25519::{Curve25519Parameters, Fq},
+        uint::Uint,
+    };
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// The X25519 function of RFC 7748, on the ladder.
+    fn x25519(scalar: &[u8], u: &[u8]) -> Vec<u8> {
+        let mut scalar = scalar.to_vec();
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let limbs: Vec<u64> = scalar
+            .chunks(8)
+            .map(|chunk| {
+                u64::from_le_bytes([
+                    chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7],
+                ])
+            })
+            .collect();
+        let mut u = u.to_vec();
+        u[31] &= 127;
+        let point = XOnlyPoint::<Curve25519Parameters>::from_x(Fq::from_le_bytes_mod_order(&u));
+        point.mul(limbs).to_x().unwrap().into_repr().to_bytes()
+    }
+
+    #[test]
+    fn x25519_test() {
+        // Section 5.2 of RFC 7748. The second u-coordinate has its top bit
+        // set, which is ignored.
+        let vectors = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+        for (scalar, u, output) in vectors.iter() {
+            assert_eq!(x25519(&from_hex(scalar), &from_hex(u)), from_hex(output));
+        }
+
+        // The iterated test, from `k = u = 9`.
+        let mut k = from_hex("0900000000000000000000000000000000000000000000000000000000000000");
+        let mut u = k.clone();
+        for i in 1..=1000 {
+            let next = x25519(&k, &u);
+            u = core::mem::replace(&mut k, next);
+            if i == 1 {
+                assert_eq!(
+                    k,
+                    from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(
+            k,
+            from_hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[test]
+    fn ladder_matches_double_and_add_test() {
+        let p = XOnlyPoint::<Curve25519Parameters>::from_x(Fq::from(9u64));
+        let p2 = p.double();
+        let p3 = p2.differential_add(&p, &p);
+        assert_eq!(p.mul([2u64]), p2);
+        assert_eq!(p.mul([3u64]), p3);
+        assert_eq!(p.mul([5u64]), p3.differential_add(&p2, &p));
+        assert!(p.mul([0u64]).is_zero());
+    }
+}
@@ -1,5 +1,6 @@
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
 };
 use rand::{
@@ -8,12 +9,13 @@ use rand::{
 };
 
 use crate::{
-    ff::{BitIterator, Field, PrimeField, SquareRootField},
+    ff::{batch_inversion, BitIterator, Field, PrimeField, SquareRootField},
     pairing::{AffineCurve, ProjectiveCurve},
+    serialize::SWFlags,
     One, UniformRand, Vec, Zero,
 };
 
-use super::{flags::SWFlags, SWModelParameters as Parameters};
+use super::SWModelParameters as Parameters;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -95,8 +97,12 @@ impl<P: Parameters> GroupAffine<P> {
     }
 
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-        self.mul_bits(BitIterator::new(P::ScalarField::characteristic()))
-            .is_zero()
+        P::is_in_correct_subgroup_assuming_on_curve(self)
+    }
+
+    /// Maps `self` into the prime order subgroup.
+    pub fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
     }
 }
 
@@ -145,16 +151,13 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     }
 
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        P::BaseField::from_random_bytes_with_flags(bytes).and_then(|(x, flags)| {
-            let infinity_flag_mask = SWFlags::Infinity.u8_bitmask();
-            let positive_flag_mask = SWFlags::PositiveY.u8_bitmask();
+        P::BaseField::from_random_bytes_with_flags::<SWFlags>(bytes).and_then(|(x, flags)| {
             // if x is valid and is zero and only the infinity flag is set, then parse this
             // point as infinity. For all other choices, get the original point.
-            if x.is_zero() && flags == infinity_flag_mask {
+            if x.is_zero() && flags.is_infinity() {
                 Some(Self::zero())
             } else {
-                let is_positive = flags & positive_flag_mask != 0;
-                Self::get_point_from_x(x, is_positive)
+                Self::get_point_from_x(x, flags.is_positive())
             }
         })
     }
@@ -200,8 +203,7 @@ impl<P: Parameters> Default for GroupAffine<P> {
     Copy(bound = "P: Parameters"),
     Clone(bound = "P: Parameters"),
     Eq(bound = "P: Parameters"),
-    Debug(bound = "P: Parameters"),
-    Hash(bound = "P: Parameters")
+    Debug(bound = "P: Parameters")
 )]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupProjective<P: Parameters> {
@@ -216,6 +218,14 @@ impl<P: Parameters> Display for GroupProjective<P> {
     }
 }
 
+// Projective coordinates are not unique, so equal points must be hashed in
+// affine form.
+impl<P: Parameters> Hash for GroupProjective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        GroupAffine::from(*self).hash(state)
+    }
+}
+
 impl<P: Parameters> PartialEq for GroupProjective<P> {
     fn eq(&self, other: &Self) -> bool {
         if self.is_zero() {
@@ -301,44 +311,15 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
 
     #[inline]
     fn batch_normalization(v: &mut [Self]) {
-        // Montgomery’s Trick and Fast Implementation of Masked AES
-        // Genelle, Prouff and Quisquater
-        // Section 3.2
-
-        // First pass: compute [a, ab, abc, ...]
-        let mut prod = Vec::with_capacity(v.len());
-        let mut tmp = P::BaseField::one();
-        for g in v
-            .iter_mut()
-            // Ignore normalized elements
+        // Invert all the z-coordinates at once with Montgomery's trick.
+        let mut z_s: Vec<_> = v
+            .iter()
             .filter(|g| !g.is_normalized())
-        {
-            tmp *= &g.z;
-            prod.push(tmp);
-        }
-
-        // Invert `tmp`.
-        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-        // Second pass: iterate backwards to compute inverses
-        for (g, s) in v
-            .iter_mut()
-            // Backwards
-            .rev()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-            // Backwards, skip last element, fill in one for last term.
-            .zip(
-                prod.into_iter()
-                    .rev()
-                    .skip(1)
-                    .chain(Some(P::BaseField::one())),
-            )
-        {
-            // tmp := tmp * g.z; g.z := tmp * s = 1/z
-            let newtmp = tmp * &g.z;
-            g.z = tmp * &s;
-            tmp = newtmp;
+            .map(|g| g.z)
+            .collect();
+        batch_inversion(&mut z_s);
+        for (g, z_inv) in v.iter_mut().filter(|g| !g.is_normalized()).zip(z_s) {
+            g.z = z_inv;
         }
 
         #[cfg(not(feature = "parallel"))]
@@ -355,6 +336,11 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
         });
     }
 
+    #[inline]
+    fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(self, other: S) -> Self {
+        P::mul_projective(&self, other.into())
+    }
+
     fn double_in_place(&mut self) -> &mut Self {
         if self.is_zero() {
             return self;
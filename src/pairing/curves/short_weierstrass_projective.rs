@@ -1,5 +1,6 @@
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
 };
 use rand::{
@@ -8,12 +9,13 @@ use rand::{
 };
 
 use crate::{
-    ff::{BitIterator, Field, PrimeField, SquareRootField},
+    ff::{batch_inversion, BitIterator, Field, PrimeField, SquareRootField},
     pairing::{AffineCurve, ProjectiveCurve},
+    serialize::SWFlags,
     One, UniformRand, Vec, Zero,
 };
 
-use super::{flags::SWFlags, SWModelParameters as Parameters};
+use super::SWModelParameters as Parameters;
 
 #[derive(Derivative)]
 #[derivative(
@@ -144,16 +146,13 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     }
 
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        P::BaseField::from_random_bytes_with_flags(bytes).and_then(|(x, flags)| {
-            let infinity_flag_mask = SWFlags::Infinity.u8_bitmask();
-            let positive_flag_mask = SWFlags::PositiveY.u8_bitmask();
+        P::BaseField::from_random_bytes_with_flags::<SWFlags>(bytes).and_then(|(x, flags)| {
             // if x is valid and is zero and only the infinity flag is set, then parse this
             // point as infinity. For all other choices, get the original point.
-            if x.is_zero() && flags == infinity_flag_mask {
+            if x.is_zero() && flags.is_infinity() {
                 Some(Self::zero())
             } else {
-                let is_positive = flags & positive_flag_mask != 0;
-                Self::get_point_from_x(x, is_positive)
+                Self::get_point_from_x(x, flags.is_positive())
             }
         })
     }
@@ -197,8 +196,7 @@ impl<P: Parameters> Default for GroupAffine<P> {
     Copy(bound = "P: Parameters"),
     Clone(bound = "P: Parameters"),
     Eq(bound = "P: Parameters"),
-    Debug(bound = "P: Parameters"),
-    Hash(bound = "P: Parameters")
+    Debug(bound = "P: Parameters")
 )]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupProjective<P: Parameters> {
@@ -213,6 +211,14 @@ impl<P: Parameters> Display for GroupProjective<P> {
     }
 }
 
+// Projective coordinates are not unique, so equal points must be hashed in
+// affine form.
+impl<P: Parameters> Hash for GroupProjective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        GroupAffine::from(*self).hash(state)
+    }
+}
+
 impl<P: Parameters> PartialEq for GroupProjective<P> {
     fn eq(&self, other: &Self) -> bool {
         if self.is_zero() {
@@ -253,6 +259,49 @@ impl<P: Parameters> GroupProjective<P> {
     pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, z }
     }
+
+    /// Adds `self` and `other` with the complete formulas of Renes, Costello
+    /// and Batina (Algorithm 1 of <https://eprint.iacr.org/2015/1060>). The
+    /// formulas have no exceptional cases: they are also correct for
+    /// doubling, for the point at infinity and for `self == -other`.
+    pub fn complete_add(&self, other: &Self) -> Self {
+        let b3 = P::COEFF_B.double() + &P::COEFF_B;
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let mut t0 = x1 * &x2;
+        let mut t1 = y1 * &y2;
+        let mut t2 = z1 * &z2;
+        let t3 = (x1 + &y1) * &(x2 + &y2) - &(t0 + &t1);
+        let mut t4 = (x1 + &z1) * &(x2 + &z2) - &(t0 + &t2);
+        let t5 = (y1 + &z1) * &(y2 + &z2) - &(t1 + &t2);
+        let mut z3 = P::mul_by_a(&t4) + &(b3 * &t2);
+        let mut x3 = t1 - &z3;
+        z3 += &t1;
+        let mut y3 = x3 * &z3;
+        t1 = t0.double() + &t0;
+        t2 = P::mul_by_a(&t2);
+        t4 *= &b3;
+        t1 += &t2;
+        t2 = P::mul_by_a(&(t0 - &t2));
+        t4 += &t2;
+        t0 = t1 * &t4;
+        y3 += &t0;
+        t0 = t5 * &t4;
+        x3 *= &t3;
+        x3 -= &t0;
+        t0 = t3 * &t1;
+        z3 *= &t5;
+        z3 += &t0;
+
+        Self::new(x3, y3, z3)
+    }
+
+    /// Doubles `self` with the complete addition formulas.
+    #[inline]
+    pub fn complete_double(&self) -> Self {
+        self.complete_add(self)
+    }
 }
 
 impl<P: Parameters> Zero for GroupProjective<P> {
@@ -291,49 +340,20 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
     }
 
     fn batch_normalization(v: &mut [Self]) {
-        // Montgomery’s Trick and Fast Implementation of Masked AES
-        // Genelle, Prouff and Quisquater
-        // Section 3.2
-
-        // First pass: compute [a, ab, abc, ...]
-        let mut prod = Vec::with_capacity(v.len());
-        let mut tmp = P::BaseField::one();
-        for g in v
-            .iter_mut()
-            // Ignore normalized elements
+        // Invert all the z-coordinates at once with Montgomery's trick.
+        let mut z_s: Vec<_> = v
+            .iter()
             .filter(|g| !g.is_normalized())
-        {
-            tmp *= &g.z;
-            prod.push(tmp);
-        }
-
-        // Invert `tmp`.
-        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-        // Second pass: iterate backwards to compute inverses
-        for (g, s) in v
-            .iter_mut()
-            // Backwards
-            .rev()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-            // Backwards, skip last element, fill in one for last term.
-            .zip(
-                prod.into_iter()
-                    .rev()
-                    .skip(1)
-                    .chain(Some(P::BaseField::one())),
-            )
-        {
-            // tmp := tmp * g.z; g.z := tmp * s = 1/z
-            let newtmp = tmp * &g.z;
-            g.z = tmp * &s;
-            tmp = newtmp;
+            .map(|g| g.z)
+            .collect();
+        batch_inversion(&mut z_s);
+        for (g, z_inv) in v.iter_mut().filter(|g| !g.is_normalized()).zip(z_s) {
+            g.z = z_inv;
         }
 
         // Perform affine transformations
         for g in v.iter_mut().filter(|g| !g.is_normalized()) {
-            g.x *= &g.z; // x/z^2
+            g.x *= &g.z; // x/z
             g.y *= &g.z;
             g.z = P::BaseField::one(); // z = 1
         }
@@ -535,3 +555,38 @@ impl<P: Parameters> From<GroupProjective<P>> for GroupAffine<P> {
         }
     }
 }
+
+#[cfg(all(test, feature = "bls12_381"))]
+mod tests {
+    use super::{GroupAffine, GroupProjective};
+    use crate::{
+        pairing::{curves::bls12_381::g1::Parameters, ProjectiveCurve},
+        Zero,
+    };
+    use algebra_core::test_rng;
+    use rand::Rng;
+
+    type G1Projective = GroupProjective<Parameters>;
+
+    #[test]
+    fn complete_add_test() {
+        let rng = &mut test_rng();
+        let zero = G1Projective::zero();
+        for _ in 0..10 {
+            let p: G1Projective = rng.gen();
+            let q: G1Projective = rng.gen();
+            assert_eq!(p.complete_add(&q), p + &q);
+            let mut mixed = p;
+            mixed.add_assign_mixed(&GroupAffine::from(q));
+            assert_eq!(p.complete_add(&q), mixed);
+
+            assert_eq!(p.complete_add(&p), p.double());
+            assert_eq!(p.complete_double(), p.double());
+            assert!(p.complete_add(&-p).is_zero());
+            assert_eq!(p.complete_add(&zero), p);
+            assert_eq!(zero.complete_add(&p), p);
+        }
+        assert!(zero.complete_add(&zero).is_zero());
+        assert!(zero.complete_double().is_zero());
+    }
+}
@@ -1,5 +1,6 @@
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
 };
 use rand::{
@@ -8,15 +9,13 @@ use rand::{
 };
 
 use crate::{
-    ff::{BitIterator, Field, PrimeField, SquareRootField},
+    ff::{batch_inversion, BitIterator, Field, PrimeField, SquareRootField},
     pairing::{AffineCurve, ProjectiveCurve},
+    serialize::EdwardsFlags,
     One, UniformRand, Vec, Zero,
 };
 
-use super::{
-    flags::EdwardsFlags, MontgomeryModelParameters as MontgomeryParameters,
-    TEModelParameters as Parameters,
-};
+use super::{MontgomeryModelParameters as MontgomeryParameters, TEModelParameters as Parameters};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -130,13 +129,12 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     }
 
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        let x = P::BaseField::from_random_bytes_with_flags(bytes);
+        let x = P::BaseField::from_random_bytes_with_flags::<EdwardsFlags>(bytes);
         if let Some((x, flags)) = x {
-            let parsed_flags = EdwardsFlags::from_u8(flags);
             if x.is_zero() {
                 Some(Self::zero())
             } else {
-                Self::get_point_from_x(x, parsed_flags.is_positive())
+                Self::get_point_from_x(x, flags.is_positive())
             }
         } else {
             None
@@ -263,8 +261,7 @@ mod group_impl {
     Copy(bound = "P: Parameters"),
     Clone(bound = "P: Parameters"),
     Eq(bound = "P: Parameters"),
-    Debug(bound = "P: Parameters"),
-    Hash(bound = "P: Parameters")
+    Debug(bound = "P: Parameters")
 )]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupProjective<P: Parameters> {
@@ -280,6 +277,14 @@ impl<P: Parameters> Display for GroupProjective<P> {
     }
 }
 
+// Projective coordinates are not unique, so equal points must be hashed in
+// affine form.
+impl<P: Parameters> Hash for GroupProjective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        GroupAffine::from(*self).hash(state)
+    }
+}
+
 impl<P: Parameters> PartialEq for GroupProjective<P> {
     fn eq(&self, other: &Self) -> bool {
         if self.is_zero() {
@@ -352,44 +357,15 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
     }
 
     fn batch_normalization(v: &mut [Self]) {
-        // Montgomery’s Trick and Fast Implementation of Masked AES
-        // Genelle, Prouff and Quisquater
-        // Section 3.2
-
-        // First pass: compute [a, ab, abc, ...]
-        let mut prod = Vec::with_capacity(v.len());
-        let mut tmp = P::BaseField::one();
-        for g in v
-            .iter_mut()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-        {
-            tmp *= &g.z;
-            prod.push(tmp);
-        }
-
-        // Invert `tmp`.
-        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-        // Second pass: iterate backwards to compute inverses
-        for (g, s) in v
-            .iter_mut()
-            // Backwards
-            .rev()
-            // Ignore normalized elements
+        // Invert all the z-coordinates at once with Montgomery's trick.
+        let mut z_s: Vec<_> = v
+            .iter()
             .filter(|g| !g.is_normalized())
-            // Backwards, skip last element, fill in one for last term.
-            .zip(
-                prod.into_iter()
-                    .rev()
-                    .skip(1)
-                    .chain(Some(P::BaseField::one())),
-            )
-        {
-            // tmp := tmp * g.z; g.z := tmp * s = 1/z
-            let newtmp = tmp * &g.z;
-            g.z = tmp * &s;
-            tmp = newtmp;
+            .map(|g| g.z)
+            .collect();
+        batch_inversion(&mut z_s);
+        for (g, z_inv) in v.iter_mut().filter(|g| !g.is_normalized()).zip(z_s) {
+            g.z = z_inv;
         }
 
         #[cfg(not(feature = "parallel"))]
@@ -554,7 +530,7 @@ impl<P: Parameters> From<GroupProjective<P>> for GroupAffine<P> {
 
 impl<P: Parameters> core::str::FromStr for GroupAffine<P>
 where
-    P::BaseField: core::str::FromStr<Err = ()>,
+    P::BaseField: core::str::FromStr,
 {
     type Err = ();
 
@@ -572,7 +548,7 @@ where
         let mut point = Vec::new();
         for substr in s.split(|c| c == '(' || c == ')' || c == ',' || c == ' ') {
             if !substr.is_empty() {
-                point.push(P::BaseField::from_str(substr)?);
+                point.push(P::BaseField::from_str(substr).map_err(|_| ())?);
             }
         }
         if point.len() != 2 {
@@ -87,6 +87,29 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send {
         let g2_prep = Self::G2Prepared::from(q.into());
         Self::product_of_pairings(core::iter::once(&(g1_prep, g2_prep)))
     }
+
+    /// Computes the product of the pairings of `a[i]` and `b[i]`, sharing a
+    /// single Miller loop accumulator and a single final exponentiation.
+    /// Since the inputs may already be prepared, G2 elements that are paired
+    /// repeatedly, such as verification keys, can be prepared once and reused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[must_use]
+    fn multi_pairing<G1, G2>(a: &[G1], b: &[G2]) -> Self::Fqk
+    where
+        G1: Clone + Into<Self::G1Prepared>,
+        G2: Clone + Into<Self::G2Prepared>,
+    {
+        assert_eq!(a.len(), b.len());
+        let pairs = a
+            .iter()
+            .zip(b)
+            .map(|(p, q)| (p.clone().into(), q.clone().into()))
+            .collect::<Vec<_>>();
+        Self::product_of_pairings(&pairs)
+    }
 }
 
 /// Projective representation of an elliptic curve point guaranteed to be
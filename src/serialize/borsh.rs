@@ -0,0 +1,73 @@
+//! `borsh` support through the canonical serialization.
+//!
+//! A value is written as its compressed canonical bytes in a borsh
+//! `Vec<u8>`, that is with a little-endian `u32` length prefix, so values of
+//! variable size, such as polynomials, can be read back from a stream.
+//!
+//! `serialize` and `deserialize` can be used with
+//! `#[borsh(serialize_with = "...", deserialize_with = "...")]` for any type
+//! implementing the canonical serialization traits.
+
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::Vec;
+
+use super::{CanonicalDeserialize, CanonicalSerialize};
+
+/// Writes the compressed canonical bytes of `value` as a borsh `Vec<u8>`.
+pub fn serialize<T, W>(value: &T, writer: &mut W) -> Result<()>
+where
+    T: CanonicalSerialize + ?Sized,
+    W: Write,
+{
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e.0))?;
+    BorshSerialize::serialize(&bytes, writer)
+}
+
+/// Reads a value written by `serialize`, with the validity checks of
+/// `CanonicalDeserialize::deserialize_checked`.
+pub fn deserialize<T, R>(reader: &mut R) -> Result<T>
+where
+    T: CanonicalDeserialize,
+    R: Read,
+{
+    let bytes = Vec::<u8>::deserialize_reader(reader)?;
+    let mut slice = &bytes[..];
+    let value = T::deserialize_compressed(&mut slice)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e.0))?;
+    if !slice.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "trailing bytes after a serialized value",
+        ));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borsh_test() {
+        let value = vec![(1u64, true), (u64::MAX, false)];
+        let mut bytes = Vec::new();
+        serialize(&value, &mut bytes).unwrap();
+        assert_eq!(&bytes[..4], &[26, 0, 0, 0]);
+        assert_eq!(bytes.len(), 4 + value.compressed_size());
+
+        let decoded: Vec<(u64, bool)> = deserialize(&mut &bytes[..]).unwrap();
+        assert_eq!(decoded, value);
+
+        let mut truncated = &bytes[..bytes.len() - 1];
+        assert!(deserialize::<Vec<(u64, bool)>, _>(&mut truncated).is_err());
+        let mut extra = &[9u8, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0][..];
+        assert!(deserialize::<u64, _>(&mut extra).is_err());
+    }
+}
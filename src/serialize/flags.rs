@@ -0,0 +1,149 @@
+/// Flags stored in the unused top bits of a serialized field element, such
+/// as the sign of a point's y-coordinate. A set of flags occupies the
+/// `BIT_SIZE` most significant bits of a byte.
+pub trait Flags: Default + Clone + Copy + Sized {
+    /// The number of bits needed to store the flags, at most 8.
+    const BIT_SIZE: usize;
+
+    /// Returns the flags as the most significant bits of a byte.
+    fn u8_bitmask(&self) -> u8;
+
+    /// Parses the flags from the `BIT_SIZE` most significant bits of
+    /// `value`, ignoring the others. Returns None for an invalid combination
+    /// of flags.
+    fn from_u8(value: u8) -> Option<Self>;
+
+    /// Parses the flags from `value` and clears their bits.
+    fn from_u8_remove_flags(value: &mut u8) -> Option<Self> {
+        let flags = Self::from_u8(*value)?;
+        *value &= !Self::mask();
+        Some(flags)
+    }
+
+    /// Returns the bits of a byte occupied by the flags.
+    fn mask() -> u8 {
+        u8::MAX.checked_shl(8 - Self::BIT_SIZE as u32).unwrap_or(0)
+    }
+}
+
+/// Flags to be encoded into the serialization.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmptyFlags;
+
+impl Flags for EmptyFlags {
+    const BIT_SIZE: usize = 0;
+
+    #[inline]
+    fn u8_bitmask(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn from_u8(_: u8) -> Option<Self> {
+        Some(EmptyFlags)
+    }
+}
+
+/// Flags to be encoded into the serialization.
+/// The default flags (empty) should not change the binary representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SWFlags {
+    Infinity,
+    PositiveY,
+    NegativeY,
+}
+
+impl SWFlags {
+    #[inline]
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, SWFlags::Infinity)
+    }
+
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        matches!(self, SWFlags::PositiveY)
+    }
+}
+
+impl Default for SWFlags {
+    #[inline]
+    fn default() -> Self {
+        // NegativeY doesn't change the serialization
+        SWFlags::NegativeY
+    }
+}
+
+impl Flags for SWFlags {
+    const BIT_SIZE: usize = 2;
+
+    #[inline]
+    fn u8_bitmask(&self) -> u8 {
+        let mut mask = 0;
+        match self {
+            SWFlags::Infinity => mask |= 1 << 6,
+            SWFlags::PositiveY => mask |= 1 << 7,
+            _ => (),
+        }
+        mask
+    }
+
+    #[inline]
+    fn from_u8(value: u8) -> Option<Self> {
+        let is_positive = (value >> 7) & 1 == 1;
+        let is_infinity = (value >> 6) & 1 == 1;
+        match (is_positive, is_infinity) {
+            (true, true) => None,
+            (false, true) => Some(SWFlags::Infinity),
+            (true, false) => Some(SWFlags::PositiveY),
+            (false, false) => Some(SWFlags::NegativeY),
+        }
+    }
+}
+
+/// Flags to be encoded into the serialization.
+/// The default flags (empty) should not change the binary representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdwardsFlags {
+    PositiveY,
+    NegativeY,
+}
+
+impl EdwardsFlags {
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        match self {
+            EdwardsFlags::PositiveY => true,
+            EdwardsFlags::NegativeY => false,
+        }
+    }
+}
+
+impl Default for EdwardsFlags {
+    #[inline]
+    fn default() -> Self {
+        // NegativeY doesn't change the serialization
+        EdwardsFlags::NegativeY
+    }
+}
+
+impl Flags for EdwardsFlags {
+    const BIT_SIZE: usize = 1;
+
+    #[inline]
+    fn u8_bitmask(&self) -> u8 {
+        match self {
+            EdwardsFlags::PositiveY => 1 << 7,
+            EdwardsFlags::NegativeY => 0,
+        }
+    }
+
+    #[inline]
+    fn from_u8(value: u8) -> Option<Self> {
+        let x_sign = (value >> 7) & 1 == 1;
+        if x_sign {
+            Some(EdwardsFlags::PositiveY)
+        } else {
+            Some(EdwardsFlags::NegativeY)
+        }
+    }
+}
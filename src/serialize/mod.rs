@@ -0,0 +1,403 @@
+//! Canonical serialization of the types of this crate.
+//!
+//! `CanonicalSerialize` and `CanonicalDeserialize` define the byte encoding
+//! of each type. The `serde` and `borsh` features implement those crates'
+//! traits on top of it. The `rkyv` feature instead archives the internal
+//! representation of field elements, Montgomery form included, so archived
+//! values can be accessed in place but are only meaningful for the same
+//! field parameters.
+
+use crate::{Error, SunziError, Vec};
+
+mod flags;
+pub use flags::{EdwardsFlags, EmptyFlags, Flags, SWFlags};
+
+#[cfg(feature = "borsh")]
+pub mod borsh;
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// Implements the `serde` and `borsh` traits for a type through its
+/// canonical serialization, when the corresponding features are enabled. The
+/// generic parameters of the impls are given in brackets. It is exported for
+/// `uint_impl!`, which calls it through `$crate`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_from_canonical {
+    ([$($generics:tt)*], $type:ty) => {
+        #[cfg(feature = "serde")]
+        impl<$($generics)*> serde_crate::Serialize for $type {
+            fn serialize<S: serde_crate::Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                $crate::serialize::serde::serialize(self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($generics)*> serde_crate::Deserialize<'de> for $type {
+            fn deserialize<D: serde_crate::Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<Self, D::Error> {
+                $crate::serialize::serde::deserialize(deserializer)
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        impl<$($generics)*> borsh::BorshSerialize for $type {
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                $crate::serialize::borsh::serialize(self, writer)
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        impl<$($generics)*> borsh::BorshDeserialize for $type {
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                $crate::serialize::borsh::deserialize(reader)
+            }
+        }
+    };
+}
+
+/// Whether group elements are written in compressed form. Types without a
+/// compressed form ignore it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compress {
+    Yes,
+    No,
+}
+
+/// Whether deserialization checks the validity of the result: that field
+/// elements are encoded canonically, below the modulus, and that group
+/// elements are on the curve and in the prime order subgroup. Checks that
+/// are needed to construct a value at all, such as the length of the input,
+/// are always performed. `Validate::No` is meant for inputs whose validity
+/// is enforced elsewhere, e.g. data the caller wrote itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Validate {
+    Yes,
+    No,
+}
+
+/// Serializes values into a canonical byte representation, which
+/// `CanonicalDeserialize` reads back.
+pub trait CanonicalSerialize {
+    /// Appends the encoding of `self` to `writer`.
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error>;
+
+    /// Returns the number of bytes written by `serialize_with_mode`.
+    fn serialized_size(&self, compress: Compress) -> usize;
+
+    fn serialize_compressed(&self, writer: &mut Vec<u8>) -> Result<(), Error> {
+        self.serialize_with_mode(writer, Compress::Yes)
+    }
+
+    fn serialize_uncompressed(&self, writer: &mut Vec<u8>) -> Result<(), Error> {
+        self.serialize_with_mode(writer, Compress::No)
+    }
+
+    fn compressed_size(&self) -> usize {
+        self.serialized_size(Compress::Yes)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.serialized_size(Compress::No)
+    }
+}
+
+/// Deserializes values from the representation of `CanonicalSerialize`.
+/// The reader is advanced past the bytes that were read.
+pub trait CanonicalDeserialize: Sized {
+    /// Reads a value from the front of `reader`.
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error>;
+
+    /// Reads a value in the given form and checks its validity.
+    fn deserialize_checked(reader: &mut &[u8], compress: Compress) -> Result<Self, Error> {
+        Self::deserialize_with_mode(reader, compress, Validate::Yes)
+    }
+
+    /// Reads a value in the given form without the optional validity
+    /// checks. Only use this for trusted inputs.
+    fn deserialize_unchecked(reader: &mut &[u8], compress: Compress) -> Result<Self, Error> {
+        Self::deserialize_with_mode(reader, compress, Validate::No)
+    }
+
+    fn deserialize_compressed(reader: &mut &[u8]) -> Result<Self, Error> {
+        Self::deserialize_checked(reader, Compress::Yes)
+    }
+
+    fn deserialize_uncompressed(reader: &mut &[u8]) -> Result<Self, Error> {
+        Self::deserialize_checked(reader, Compress::No)
+    }
+
+    /// Reads a checked value in the given form that spans all of `bytes`.
+    fn try_from_bytes(bytes: &[u8], compress: Compress) -> core::result::Result<Self, SunziError> {
+        Self::try_from_bytes_with_mode(bytes, compress, Validate::Yes)
+    }
+
+    /// Reads a value in the given form that spans all of `bytes`, with the
+    /// given validation.
+    fn try_from_bytes_with_mode(
+        bytes: &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> core::result::Result<Self, SunziError> {
+        let mut reader = bytes;
+        let value = Self::deserialize_with_mode(&mut reader, compress, validate)?;
+        if !reader.is_empty() {
+            return Err(SunziError::SizeMismatch {
+                expected: bytes.len() - reader.len(),
+                found: bytes.len(),
+            });
+        }
+        Ok(value)
+    }
+}
+
+/// Removes `len` bytes from the front of `reader` and returns them.
+pub fn read_bytes<'a>(reader: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    if reader.len() < len {
+        return Err(Error("unexpected end of input"));
+    }
+    let (bytes, rest) = reader.split_at(len);
+    *reader = rest;
+    Ok(bytes)
+}
+
+macro_rules! impl_uint_serialize {
+    ($type: ty) => {
+        impl CanonicalSerialize for $type {
+            #[inline]
+            fn serialize_with_mode(&self, writer: &mut Vec<u8>, _: Compress) -> Result<(), Error> {
+                writer.extend_from_slice(&self.to_le_bytes());
+                Ok(())
+            }
+
+            #[inline]
+            fn serialized_size(&self, _: Compress) -> usize {
+                core::mem::size_of::<$type>()
+            }
+        }
+
+        impl CanonicalDeserialize for $type {
+            #[inline]
+            fn deserialize_with_mode(
+                reader: &mut &[u8],
+                _: Compress,
+                _: Validate,
+            ) -> Result<Self, Error> {
+                let mut bytes = [0u8; core::mem::size_of::<$type>()];
+                bytes.copy_from_slice(read_bytes(reader, core::mem::size_of::<$type>())?);
+                Ok(<$type>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_uint_serialize!(u8);
+impl_uint_serialize!(u16);
+impl_uint_serialize!(u32);
+impl_uint_serialize!(u64);
+
+// `usize` is always written as a `u64`, independent of the platform.
+impl CanonicalSerialize for usize {
+    #[inline]
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        (*self as u64).serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _: Compress) -> usize {
+        8
+    }
+}
+
+impl CanonicalDeserialize for usize {
+    #[inline]
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let value = u64::deserialize_with_mode(reader, compress, validate)?;
+        if value > usize::MAX as u64 {
+            return Err(Error("length does not fit in usize"));
+        }
+        Ok(value as usize)
+    }
+}
+
+impl CanonicalSerialize for bool {
+    #[inline]
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        (*self as u8).serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _: Compress) -> usize {
+        1
+    }
+}
+
+impl CanonicalDeserialize for bool {
+    #[inline]
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        match u8::deserialize_with_mode(reader, compress, validate)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error("invalid encoding of a bool")),
+        }
+    }
+}
+
+// Vectors are prefixed with their length.
+impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.len().serialize_with_mode(writer, compress)?;
+        for item in self {
+            item.serialize_with_mode(writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.iter()
+            .fold(self.len().serialized_size(compress), |acc, item| {
+                acc + item.serialized_size(compress)
+            })
+    }
+}
+
+impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        let len = usize::deserialize_with_mode(reader, compress, validate)?;
+        // Every item takes at least one byte, which bounds the allocation by
+        // the size of the input.
+        let mut items = Vec::with_capacity(core::cmp::min(len, reader.len()));
+        for _ in 0..len {
+            items.push(T::deserialize_with_mode(reader, compress, validate)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<A: CanonicalSerialize, B: CanonicalSerialize> CanonicalSerialize for (A, B) {
+    fn serialize_with_mode(&self, writer: &mut Vec<u8>, compress: Compress) -> Result<(), Error> {
+        self.0.serialize_with_mode(writer, compress)?;
+        self.1.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0.serialized_size(compress) + self.1.serialized_size(compress)
+    }
+}
+
+impl<A: CanonicalDeserialize, B: CanonicalDeserialize> CanonicalDeserialize for (A, B) {
+    fn deserialize_with_mode(
+        reader: &mut &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, Error> {
+        Ok((
+            A::deserialize_with_mode(reader, compress, validate)?,
+            B::deserialize_with_mode(reader, compress, validate)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_serialize<
+        T: CanonicalSerialize + CanonicalDeserialize + PartialEq + core::fmt::Debug,
+    >(
+        value: T,
+    ) {
+        for compress in [Compress::Yes, Compress::No].iter() {
+            let mut bytes = Vec::new();
+            value.serialize_with_mode(&mut bytes, *compress).unwrap();
+            assert_eq!(bytes.len(), value.serialized_size(*compress));
+
+            let mut reader = &bytes[..];
+            let decoded = T::deserialize_checked(&mut reader, *compress).unwrap();
+            assert_eq!(decoded, value);
+            assert!(reader.is_empty());
+
+            let mut truncated = &bytes[..bytes.len() - 1];
+            assert!(T::deserialize_checked(&mut truncated, *compress).is_err());
+        }
+    }
+
+    #[test]
+    fn serialize_test() {
+        test_serialize(7u8);
+        test_serialize(0x1234u16);
+        test_serialize(u64::MAX);
+        test_serialize(42usize);
+        test_serialize(true);
+        test_serialize(vec![(1usize, 2u32), (3, 4)]);
+
+        let mut invalid_bool = &[2u8][..];
+        assert!(bool::deserialize_compressed(&mut invalid_bool).is_err());
+    }
+
+    #[test]
+    fn try_from_bytes_test() {
+        assert_eq!(
+            u16::try_from_bytes(&[0x34, 0x12], Compress::Yes),
+            Ok(0x1234)
+        );
+        assert_eq!(
+            u16::try_from_bytes(&[0x34, 0x12, 0], Compress::Yes),
+            Err(SunziError::SizeMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            bool::try_from_bytes(&[2], Compress::Yes),
+            Err(SunziError::ParseError("invalid encoding of a bool"))
+        );
+        assert_eq!(
+            u16::try_from_bytes_with_mode(&[0x34, 0x12], Compress::No, Validate::No),
+            Ok(0x1234)
+        );
+    }
+
+    #[test]
+    fn flags_test() {
+        for flags in [SWFlags::Infinity, SWFlags::PositiveY, SWFlags::NegativeY].iter() {
+            let mut byte = flags.u8_bitmask() | 0x15;
+            assert_eq!(SWFlags::from_u8_remove_flags(&mut byte), Some(*flags));
+            assert_eq!(byte, 0x15);
+        }
+        assert_eq!(SWFlags::from_u8(0xc0), None);
+
+        for flags in [EdwardsFlags::PositiveY, EdwardsFlags::NegativeY].iter() {
+            let mut byte = flags.u8_bitmask() | 0x7f;
+            assert_eq!(EdwardsFlags::from_u8_remove_flags(&mut byte), Some(*flags));
+            assert_eq!(byte, 0x7f);
+        }
+
+        let mut byte = 0xff;
+        assert_eq!(
+            EmptyFlags::from_u8_remove_flags(&mut byte),
+            Some(EmptyFlags)
+        );
+        assert_eq!(byte, 0xff);
+    }
+}
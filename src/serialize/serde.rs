@@ -0,0 +1,141 @@
+//! `serde` support through the canonical serialization.
+//!
+//! Human-readable formats, such as JSON, get the compressed canonical bytes
+//! as a hex string, and binary formats, such as CBOR or bincode, get the
+//! bytes themselves. The canonical bytes hold the standard representation of
+//! field elements, not their Montgomery form, so the encoding does not depend
+//! on the internal representation of a type.
+//!
+//! `serialize` and `deserialize` can be used with `#[serde(with = "...")]`
+//! for any type implementing the canonical serialization traits.
+
+use core::{fmt, marker::PhantomData};
+use serde_crate::{
+    de::{self, SeqAccess, Visitor},
+    ser::Error as _,
+    Deserializer, Serializer,
+};
+
+use crate::{String, Vec};
+
+use super::{CanonicalDeserialize, CanonicalSerialize};
+
+/// Serializes `value` as a hex string in human-readable formats, and as
+/// bytes otherwise.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CanonicalSerialize + ?Sized,
+    S: Serializer,
+{
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(|e| S::Error::custom(e.0))?;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&to_hex(&bytes))
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Deserializes a value written by `serialize`, with the validity checks of
+/// `CanonicalDeserialize::deserialize_checked`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: CanonicalDeserialize,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(CanonicalVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(CanonicalVisitor(PhantomData))
+    }
+}
+
+struct CanonicalVisitor<T>(PhantomData<T>);
+
+impl<'de, T: CanonicalDeserialize> Visitor<'de> for CanonicalVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("canonically serialized bytes or their hex string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        let bytes = from_hex(v).ok_or_else(|| E::custom("invalid hex string"))?;
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        let mut reader = v;
+        let value = T::deserialize_compressed(&mut reader).map_err(|e| E::custom(e.0))?;
+        if !reader.is_empty() {
+            return Err(E::custom("trailing bytes after a serialized value"));
+        }
+        Ok(value)
+    }
+
+    // Some binary formats write bytes as a sequence of integers.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut s = String::with_capacity(2 * bytes.len());
+    for byte in bytes {
+        s.push(DIGITS[(byte >> 4) as usize] as char);
+        s.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+    s
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    let digit = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+    s.chunks(2)
+        .map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_test() {
+        let bytes = [0x00, 0x1f, 0xa0, 0xff];
+        assert_eq!(to_hex(&bytes), "001fa0ff");
+        assert_eq!(from_hex("001fa0ff").unwrap(), bytes);
+        assert_eq!(from_hex("0x001FA0FF").unwrap(), bytes);
+        assert!(from_hex("001").is_none());
+        assert!(from_hex("0g").is_none());
+    }
+
+    #[test]
+    fn serde_test() {
+        let value = vec![(1u64, true), (u64::MAX, false)];
+        let json = serde_json::to_string(&SerdeWrapper(value.clone())).unwrap();
+        assert_eq!(
+            json,
+            "\"02000000000000000100000000000000\
+             01ffffffffffffffff00\""
+        );
+        let decoded: SerdeWrapper<Vec<(u64, bool)>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.0, value);
+
+        assert!(serde_json::from_str::<SerdeWrapper<u64>>("\"0100\"").is_err());
+    }
+
+    #[derive(serde_crate::Serialize, serde_crate::Deserialize)]
+    #[serde(crate = "serde_crate")]
+    struct SerdeWrapper<T: CanonicalSerialize + CanonicalDeserialize>(#[serde(with = "super")] T);
+}
@@ -0,0 +1,44 @@
+//! Algebraic sponges over prime fields, for building transcripts and
+//! Fiat–Shamir challenges natively on field elements.
+
+use crate::{
+    ff::{FpParameters, PrimeField},
+    to_field_vec::pack_bytes,
+    uint::Uint,
+    Vec,
+};
+
+pub mod poseidon;
+pub use self::poseidon::{PoseidonConfig, PoseidonSponge};
+
+/// A sponge in duplex mode: inputs can be absorbed and outputs squeezed in
+/// any order, and each output depends on everything absorbed before it.
+pub trait CryptographicSponge<F: PrimeField> {
+    /// Absorbs field elements.
+    fn absorb(&mut self, elems: &[F]);
+
+    /// Squeezes `n` field elements.
+    fn squeeze(&mut self, n: usize) -> Vec<F>;
+
+    /// Absorbs bytes, as their length followed by the bytes packed into
+    /// `CAPACITY` bits per element, so that distinct byte strings are
+    /// absorbed as distinct sequences of elements.
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.absorb(&[F::from(bytes.len() as u64)]);
+        self.absorb(&pack_bytes(bytes));
+    }
+
+    /// Squeezes `n` bits, taking the low `CAPACITY` bits of each squeezed
+    /// element.
+    fn squeeze_bits(&mut self, n: usize) -> Vec<bool> {
+        let capacity = F::Params::CAPACITY as usize;
+        let elems = self.squeeze((n + capacity - 1) / capacity);
+        let mut bits = Vec::with_capacity(n);
+        for elem in elems {
+            let repr = elem.into_repr();
+            let count = capacity.min(n - bits.len());
+            bits.extend((0..count).map(|i| repr.get_bit(i)));
+        }
+        bits
+    }
+}
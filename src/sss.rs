@@ -0,0 +1,216 @@
+//! Shamir secret sharing over prime fields.
+//!
+//! A secret `s` is shared with threshold `t` by sampling a random polynomial
+//! `f` of degree less than `t` with `f(0) = s`, and handing out the shares
+//! `(i, f(i))` for `i = 1, ..., n`. Any `t` shares determine `s` by Lagrange
+//! interpolation at zero, while fewer reveal nothing about it.
+
+use rand::Rng;
+
+use crate::{
+    ff::batch_inversion,
+    fft::DensePolynomial,
+    prelude::{Field, PrimeField},
+    Vec,
+};
+
+/// Splits `secret` into `n` shares `(x, y)` with `x = 1, ..., n`, any
+/// `threshold` of which suffice to reconstruct it.
+///
+/// # Panics
+/// This method panics if `threshold` is zero or larger than `n`.
+pub fn share<F: PrimeField, R: Rng>(
+    secret: F,
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> Vec<(F, F)> {
+    assert!(threshold <= n, "threshold exceeds the number of shares");
+    let poly = sharing_polynomial(secret, threshold, rng);
+    (1..=n as u64)
+        .map(|i| {
+            let x = F::from(i);
+            (x, poly.evaluate(x))
+        })
+        .collect()
+}
+
+/// Reconstructs the secret from `shares` by Lagrange interpolation at zero.
+/// Returns `None` if two shares have the same `x`-coordinate or a share has
+/// `x = 0`.
+///
+/// The result is only meaningful if at least `threshold` shares are given.
+pub fn reconstruct<F: PrimeField>(shares: &[(F, F)]) -> Option<F> {
+    let xs: Vec<F> = shares.iter().map(|(x, _)| *x).collect();
+    if xs.iter().any(|x| x.is_zero()) {
+        return None;
+    }
+    let coefficients = lagrange_coefficients_at(&xs, F::zero())?;
+    Some(
+        coefficients
+            .iter()
+            .zip(shares)
+            .fold(F::zero(), |acc, (l, (_, y))| acc + &(*l * y)),
+    )
+}
+
+/// Returns the Lagrange coefficients `l_i(point)` for the interpolation
+/// nodes `xs`, so that `f(point) = sum_i l_i(point) * f(xs[i])` for every
+/// polynomial `f` of degree less than `xs.len()`. The denominators are
+/// inverted with a single batch inversion. Returns `None` if the nodes are
+/// not distinct.
+pub fn lagrange_coefficients_at<F: Field>(xs: &[F], point: F) -> Option<Vec<F>> {
+    let mut denominators: Vec<F> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(F::one(), |acc, (_, x_j)| acc * &(*x_i - x_j))
+        })
+        .collect();
+    if denominators.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    batch_inversion(&mut denominators);
+
+    // The numerator of `l_i` is the product of `point - x_j` over `j != i`,
+    // computed from prefix and suffix products.
+    let mut suffix = vec![F::one(); xs.len() + 1];
+    for (i, x) in xs.iter().enumerate().rev() {
+        suffix[i] = suffix[i + 1] * &(point - x);
+    }
+    let mut prefix = F::one();
+    let coefficients = xs
+        .iter()
+        .zip(denominators)
+        .enumerate()
+        .map(|(i, (x, inv))| {
+            let l = prefix * &suffix[i + 1] * &inv;
+            prefix *= &(point - x);
+            l
+        })
+        .collect();
+    Some(coefficients)
+}
+
+/// Proactively refreshes `shares` by adding a fresh sharing of zero with the
+/// same `threshold`. The secret is unchanged, but the new shares are
+/// independent of the old ones. Returns the polynomial of the zero sharing,
+/// which can be used to check the update with `verify_refresh`.
+///
+/// # Panics
+/// This method panics if `threshold` is zero.
+pub fn refresh<F: PrimeField, R: Rng>(
+    shares: &mut [(F, F)],
+    threshold: usize,
+    rng: &mut R,
+) -> DensePolynomial<F> {
+    let update = sharing_polynomial(F::zero(), threshold, rng);
+    for (x, y) in shares.iter_mut() {
+        *y += &update.evaluate(*x);
+    }
+    update
+}
+
+/// Checks that `update` is a valid refresh polynomial for threshold
+/// `threshold`, i.e. that it has degree less than `threshold` and vanishes at
+/// zero, so that adding it does not change the secret.
+pub fn verify_refresh<F: PrimeField>(update: &DensePolynomial<F>, threshold: usize) -> bool {
+    threshold > 0
+        && update.is_degree_at_most(threshold - 1)
+        && update.coeffs.first().map_or(true, |c| c.is_zero())
+}
+
+/// Checks that all `shares` lie on a single polynomial of degree less than
+/// `threshold`, i.e. that every subset of `threshold` shares reconstructs the
+/// same secret. Returns `false` if there are fewer than `threshold` shares or
+/// the `x`-coordinates are not distinct.
+pub fn verify_shares<F: PrimeField>(shares: &[(F, F)], threshold: usize) -> bool {
+    if threshold == 0 || shares.len() < threshold {
+        return false;
+    }
+    let mut all_xs: Vec<F> = shares.iter().map(|(x, _)| *x).collect();
+    all_xs.sort_unstable();
+    if all_xs.windows(2).any(|w| w[0] == w[1]) {
+        return false;
+    }
+    let (basis, rest) = shares.split_at(threshold);
+    let xs: Vec<F> = basis.iter().map(|(x, _)| *x).collect();
+    rest.iter()
+        .all(|(x, y)| match lagrange_coefficients_at(&xs, *x) {
+            Some(coefficients) => {
+                let expected = coefficients
+                    .iter()
+                    .zip(basis)
+                    .fold(F::zero(), |acc, (l, (_, y))| acc + &(*l * y));
+                expected == *y
+            }
+            None => false,
+        })
+}
+
+/// Samples a random polynomial of degree less than `threshold` with constant
+/// term `secret`.
+fn sharing_polynomial<F: PrimeField, R: Rng>(
+    secret: F,
+    threshold: usize,
+    rng: &mut R,
+) -> DensePolynomial<F> {
+    assert!(threshold > 0, "threshold must be positive");
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(secret);
+    coeffs.extend((1..threshold).map(|_| F::rand(rng)));
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sss::*;
+    use algebra::bls12_381::fr::Fr;
+    use algebra_core::{test_rng, One, UniformRand};
+
+    #[test]
+    fn share_and_reconstruct() {
+        let rng = &mut test_rng();
+        let secret = Fr::rand(rng);
+        let shares = share(secret, 3, 5, rng);
+        assert!(verify_shares(&shares, 3));
+        assert_eq!(reconstruct(&shares[..3]), Some(secret));
+        assert_eq!(reconstruct(&shares[2..]), Some(secret));
+        assert_ne!(reconstruct(&shares[..2]), Some(secret));
+    }
+
+    #[test]
+    fn refresh_keeps_secret() {
+        let rng = &mut test_rng();
+        let secret = Fr::rand(rng);
+        let mut shares = share(secret, 4, 7, rng);
+        let old = shares.clone();
+        let update = refresh(&mut shares, 4, rng);
+        assert!(verify_refresh(&update, 4));
+        assert!(verify_shares(&shares, 4));
+        assert_ne!(shares, old);
+        assert_eq!(reconstruct(&shares[3..]), Some(secret));
+    }
+
+    #[test]
+    fn verify_rejects_duplicate_points() {
+        let rng = &mut test_rng();
+        let mut shares = share(Fr::rand(rng), 2, 5, rng);
+        assert!(verify_shares(&shares, 2));
+
+        // A second share at the same point outside the basis.
+        let (x, y) = shares[3];
+        shares[4] = (x, y + Fr::one());
+        assert!(!verify_shares(&shares, 2));
+        shares[4] = (x, y);
+        assert!(!verify_shares(&shares, 2));
+
+        // And one inside it.
+        let mut shares = share(Fr::rand(rng), 2, 3, rng);
+        shares[1] = shares[0];
+        assert!(!verify_shares(&shares, 2));
+    }
+}
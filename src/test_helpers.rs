@@ -0,0 +1,291 @@
+//! `proptest` and `quickcheck` generators for the types of this crate.
+//!
+//! Field elements are drawn from random limbs reduced below the modulus,
+//! polynomials have at most `MAX_DEGREE + 1` coefficients and domains have a
+//! power of two size of at most `2^MAX_LOG_DOMAIN_SIZE`, further bounded by
+//! the two-adicity of the field.
+
+use num_traits::Zero;
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::{btree_map, vec},
+    strategy::{BoxedStrategy, Strategy},
+};
+use quickcheck::{Arbitrary as QcArbitrary, Gen};
+
+use crate::{
+    ff::{
+        fp6_2over3::{Fp6 as Fp6_2over3, Fp6Parameters as Fp6_2over3Parameters},
+        FftField, FftParameters, Field, Fp12, Fp12Parameters, Fp2, Fp256, Fp256Parameters,
+        Fp2Parameters, Fp3, Fp320, Fp320Parameters, Fp384, Fp384Parameters, Fp3Parameters, Fp4,
+        Fp4Parameters, Fp6, Fp6Parameters, Fp768, Fp768Parameters, Fp832, Fp832Parameters,
+        FpParameters, PrimeField,
+    },
+    fft::{
+        DensePolynomial, EvaluationDomain, GeneralEvaluationDomain, Radix2EvaluationDomain,
+        SparsePolynomial,
+    },
+    uint::{Uint, U256, U320, U384, U768, U832},
+    BTreeMap, Box, Vec,
+};
+
+/// The largest degree of a generated polynomial.
+pub const MAX_DEGREE: usize = 64;
+
+/// The log2 of the largest size of a generated domain.
+pub const MAX_LOG_DOMAIN_SIZE: u32 = 10;
+
+/// Reduces arbitrary limbs to a field element. The limbs are first cut to
+/// `MODULUS_BITS` bits, so a single subtraction of the modulus is enough.
+fn field_from_limbs<F: PrimeField>(limbs: &[u64]) -> F {
+    let mut repr = F::BigInt::from(limbs);
+    repr.as_mut()[limbs.len() - 1] &= u64::MAX >> F::Params::REPR_SHAVE_BITS;
+    if repr >= F::Params::MODULUS {
+        repr.sub_noborrow(&F::Params::MODULUS);
+    }
+    F::from_repr(repr).unwrap()
+}
+
+fn qc_limbs<const N: usize>(g: &mut Gen) -> [u64; N] {
+    let mut limbs = [0u64; N];
+    for limb in limbs.iter_mut() {
+        *limb = <u64 as QcArbitrary>::arbitrary(g);
+    }
+    limbs
+}
+
+fn max_log_domain_size<F: FftField>() -> u32 {
+    F::FftParams::TWO_ADICITY.min(MAX_LOG_DOMAIN_SIZE)
+}
+
+macro_rules! impl_arbitrary_uint {
+    ($($uint:ident),*) => {
+        $(
+            impl Arbitrary for $uint {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    any::<[u64; $uint::LIMBS]>().prop_map($uint::new).boxed()
+                }
+            }
+
+            impl QcArbitrary for $uint {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    $uint::new(qc_limbs(g))
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_uint!(U256, U320, U384, U768, U832);
+
+macro_rules! impl_arbitrary_fp {
+    ($($Fp:ident, $FpParameters:ident, $limbs:expr);*) => {
+        $(
+            impl<P: $FpParameters> Arbitrary for $Fp<P> {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    any::<[u64; $limbs]>()
+                        .prop_map(|limbs| field_from_limbs(&limbs))
+                        .boxed()
+                }
+            }
+
+            impl<P: $FpParameters> QcArbitrary for $Fp<P> {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    field_from_limbs(&qc_limbs::<$limbs>(g))
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_fp!(
+    Fp256, Fp256Parameters, 4;
+    Fp320, Fp320Parameters, 5;
+    Fp384, Fp384Parameters, 6;
+    Fp768, Fp768Parameters, 12;
+    Fp832, Fp832Parameters, 13
+);
+
+macro_rules! impl_arbitrary_extension {
+    ($Fp:ident, $FpParameters:ident, $Coeff:ty, ($($c:ident),+)) => {
+        impl<P: $FpParameters> Arbitrary for $Fp<P>
+        where
+            $Coeff: Arbitrary,
+        {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                ($(impl_arbitrary_extension!(@any $c, $Coeff)),+)
+                    .prop_map(|($($c),+)| $Fp::new($($c),+))
+                    .boxed()
+            }
+        }
+
+        impl<P: $FpParameters> QcArbitrary for $Fp<P>
+        where
+            $Coeff: QcArbitrary,
+        {
+            fn arbitrary(g: &mut Gen) -> Self {
+                $Fp::new($(impl_arbitrary_extension!(@qc $c, $Coeff, g)),+)
+            }
+        }
+    };
+    (@any $c:ident, $Coeff:ty) => {
+        any::<$Coeff>()
+    };
+    (@qc $c:ident, $Coeff:ty, $g:ident) => {
+        <$Coeff as QcArbitrary>::arbitrary($g)
+    };
+}
+
+impl_arbitrary_extension!(Fp2, Fp2Parameters, P::Fp, (c0, c1));
+impl_arbitrary_extension!(Fp3, Fp3Parameters, P::Fp, (c0, c1, c2));
+impl_arbitrary_extension!(Fp4, Fp4Parameters, Fp2<P::Fp2Params>, (c0, c1));
+impl_arbitrary_extension!(
+    Fp6_2over3,
+    Fp6_2over3Parameters,
+    Fp3<P::Fp3Params>,
+    (c0, c1)
+);
+impl_arbitrary_extension!(Fp6, Fp6Parameters, Fp2<P::Fp2Params>, (c0, c1, c2));
+impl_arbitrary_extension!(Fp12, Fp12Parameters, Fp6<P::Fp6Params>, (c0, c1));
+
+impl<F: Field + Arbitrary> Arbitrary for DensePolynomial<F> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(any::<F>(), 0..=MAX_DEGREE + 1)
+            .prop_map(DensePolynomial::from_coefficients_vec)
+            .boxed()
+    }
+}
+
+impl<F: Field + QcArbitrary> QcArbitrary for DensePolynomial<F> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = <usize as QcArbitrary>::arbitrary(g) % (MAX_DEGREE + 2);
+        let coeffs = (0..len).map(|_| <F as QcArbitrary>::arbitrary(g)).collect();
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.coeffs
+                .shrink()
+                .map(DensePolynomial::from_coefficients_vec),
+        )
+    }
+}
+
+fn sparse_from_terms<F: Field>(terms: impl IntoIterator<Item = (usize, F)>) -> SparsePolynomial<F> {
+    let terms = terms.into_iter().filter(|(_, c)| !c.is_zero()).collect();
+    SparsePolynomial::from_coefficients_vec(terms)
+}
+
+impl<F: Field + Arbitrary> Arbitrary for SparsePolynomial<F> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        btree_map(0..=MAX_DEGREE, any::<F>(), 0..8)
+            .prop_map(sparse_from_terms)
+            .boxed()
+    }
+}
+
+impl<F: Field + QcArbitrary> QcArbitrary for SparsePolynomial<F> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let terms: Vec<(usize, F)> = (0..<usize as QcArbitrary>::arbitrary(g) % 8)
+            .map(|_| {
+                (
+                    <usize as QcArbitrary>::arbitrary(g) % (MAX_DEGREE + 1),
+                    <F as QcArbitrary>::arbitrary(g),
+                )
+            })
+            .collect();
+        // Keep the last coefficient of each degree, as a map would.
+        sparse_from_terms(terms.into_iter().collect::<BTreeMap<_, _>>())
+    }
+}
+
+macro_rules! impl_arbitrary_domain {
+    ($($Domain:ident),*) => {
+        $(
+            impl<F: FftField> Arbitrary for $Domain<F> {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    (0..=max_log_domain_size::<F>())
+                        .prop_map(|log_size| $Domain::new(1 << log_size).unwrap())
+                        .boxed()
+                }
+            }
+
+            impl<F: FftField> QcArbitrary for $Domain<F> {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    let log_size = <u32 as QcArbitrary>::arbitrary(g);
+                    let log_size = log_size % (max_log_domain_size::<F>() + 1);
+                    $Domain::new(1 << log_size).unwrap()
+                }
+
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                    let log_sizes = 0..self.size().trailing_zeros();
+                    Box::new(log_sizes.map(|log_size| $Domain::new(1 << log_size).unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_domain!(Radix2EvaluationDomain, GeneralEvaluationDomain);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::{fr::Fr, Fq, Fq2};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn arbitrary_field_test(x in any::<Fr>(), y in any::<Fq2>()) {
+            prop_assert_eq!(Fr::from_repr(x.into_repr()), Some(x));
+            prop_assert_eq!(Fq::from_repr(y.c1.into_repr()), Some(y.c1));
+        }
+
+        #[test]
+        fn arbitrary_polynomial_test(
+            p in any::<DensePolynomial<Fr>>(),
+            q in any::<SparsePolynomial<Fr>>(),
+        ) {
+            prop_assert!(p.coeffs.len() <= MAX_DEGREE + 1);
+            prop_assert!(p.coeffs.last().map_or(true, |c| !c.is_zero()));
+            prop_assert!(q.degree() <= MAX_DEGREE);
+        }
+
+        #[test]
+        fn arbitrary_domain_test(domain in any::<Radix2EvaluationDomain<Fr>>()) {
+            prop_assert!(domain.size().is_power_of_two());
+            prop_assert!(domain.size() <= 1 << MAX_LOG_DOMAIN_SIZE);
+        }
+    }
+
+    #[test]
+    fn quickcheck_test() {
+        fn prop(x: Fr, p: DensePolynomial<Fr>, domain: GeneralEvaluationDomain<Fr>) -> bool {
+            Fr::from_repr(x.into_repr()) == Some(x)
+                && p.coeffs.len() <= MAX_DEGREE + 1
+                && domain.size().is_power_of_two()
+        }
+        quickcheck::quickcheck(
+            prop as fn(Fr, DensePolynomial<Fr>, GeneralEvaluationDomain<Fr>) -> bool,
+        );
+    }
+}
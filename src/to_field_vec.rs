@@ -1,123 +1,395 @@
+//! Encoding of arbitrary data into prime field elements.
+//!
+//! Data is packed little-endian, bit by bit, into `CAPACITY` bits per field
+//! element, so that `n` bits take `ceil(n / CAPACITY)` elements and every
+//! element is below the modulus. This is the representation expected when
+//! absorbing transcripts and public inputs into field-native hash functions.
+//!
+//! Elements of a prime field with the same characteristic as the target
+//! field are kept as they are rather than packed.
+
+use core::cmp::min;
+use num_traits::Zero;
+
 use crate::{
+    ff::{Field, Fp2, Fp2Parameters, Fp3, Fp3Parameters, FpParameters, PrimeField},
     pairing::{
         curves::{SWModelParameters, TEModelParameters},
         short_weierstrass_jacobian::{GroupAffine as SWAffine, GroupProjective as SWProjective},
         twisted_edwards_extended::{GroupAffine as TEAffine, GroupProjective as TEProjective},
     },
-    uint::Uint,
-    Box, Error, Field, Fp2, Fp2Parameters, FpParameters, PrimeField, Vec,
+    uint::{Uint, U256, U320, U384, U768, U832},
+    SunziError, Vec,
 };
 
-/// Types that can be converted to a vector of `F` elements. Useful for
-/// specifying how public inputs to a constraint system should be represented
-/// inside that constraint system.
-pub trait ToConstraintField<F: Field> {
-    fn to_field_elements(&self) -> Result<Vec<F>, Error>;
+/// Types that can be converted to a vector of `F` elements.
+pub trait ToFieldElements<F: Field> {
+    fn to_field_elements(&self) -> Vec<F>;
+}
+
+/// Types that can be recovered from the output of `ToFieldElements`.
+pub trait FromFieldElements<F: Field>: Sized {
+    /// Unpacks a value, failing if `elems` was not produced by
+    /// `to_field_elements`.
+    fn from_field_elements(elems: &[F]) -> Result<Self, SunziError>;
+}
+
+/// Packs the first `num_bits` bits of the little-endian `bytes` into
+/// `CAPACITY` bits per field element.
+pub fn pack_bits<F: PrimeField>(bytes: &[u8], num_bits: usize) -> Vec<F> {
+    assert!(num_bits <= 8 * bytes.len());
+    let capacity = F::Params::CAPACITY as usize;
+    (0..num_bits)
+        .step_by(capacity)
+        .map(|start| {
+            let mut repr = F::zero().to_bytes_le();
+            let out = repr.as_mut();
+            for (i, bit) in (start..min(start + capacity, num_bits)).enumerate() {
+                out[i / 8] |= ((bytes[bit / 8] >> (bit % 8)) & 1) << (i % 8);
+            }
+            // Fewer than `MODULUS_BITS` bits are set.
+            F::from_bytes_le(&repr).unwrap()
+        })
+        .collect()
+}
+
+/// Inverse of `pack_bits`: returns the `num_bits` packed bits as
+/// `ceil(num_bits / 8)` little-endian bytes.
+pub fn unpack_bits<F: PrimeField>(elems: &[F], num_bits: usize) -> Result<Vec<u8>, SunziError> {
+    let capacity = F::Params::CAPACITY as usize;
+    let expected = (num_bits + capacity - 1) / capacity;
+    if elems.len() != expected {
+        return Err(SunziError::SizeMismatch {
+            expected,
+            found: elems.len(),
+        });
+    }
+    let mut bytes = vec![0u8; (num_bits + 7) / 8];
+    for (elem, start) in elems.iter().zip((0..num_bits).step_by(capacity)) {
+        let repr = elem.to_bytes_le();
+        let repr = repr.as_ref();
+        let width = min(capacity, num_bits - start);
+        for (i, bit) in (start..start + width).enumerate() {
+            bytes[bit / 8] |= ((repr[i / 8] >> (i % 8)) & 1) << (bit % 8);
+        }
+        let excess = (width..8 * repr.len()).any(|i| (repr[i / 8] >> (i % 8)) & 1 == 1);
+        if excess {
+            return Err(SunziError::NonCanonical);
+        }
+    }
+    Ok(bytes)
+}
+
+/// Packs `bytes` into `CAPACITY` bits per field element.
+pub fn pack_bytes<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    pack_bits(bytes, 8 * bytes.len())
+}
+
+/// Inverse of `pack_bytes`, given the number of packed bytes.
+pub fn unpack_bytes<F: PrimeField>(elems: &[F], len: usize) -> Result<Vec<u8>, SunziError> {
+    unpack_bits(elems, 8 * len)
+}
+
+impl<F: PrimeField, G: PrimeField> ToFieldElements<F> for G {
+    #[inline]
+    fn to_field_elements(&self) -> Vec<F> {
+        let bytes = self.to_bytes_le();
+        if F::characteristic() == G::characteristic() {
+            let mut repr = F::zero().to_bytes_le();
+            repr.as_mut().copy_from_slice(bytes.as_ref());
+            vec![F::from_bytes_le(&repr).unwrap()]
+        } else {
+            pack_bits(bytes.as_ref(), G::Params::MODULUS_BITS as usize)
+        }
+    }
+}
+
+impl<F: PrimeField, G: PrimeField> FromFieldElements<F> for G {
+    fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+        let mut repr = G::zero().to_bytes_le();
+        if F::characteristic() == G::characteristic() {
+            match elems {
+                [elem] => repr.as_mut().copy_from_slice(elem.to_bytes_le().as_ref()),
+                _ => {
+                    return Err(SunziError::SizeMismatch {
+                        expected: 1,
+                        found: elems.len(),
+                    })
+                }
+            }
+        } else {
+            let bytes = unpack_bits(elems, G::Params::MODULUS_BITS as usize)?;
+            repr.as_mut()[..bytes.len()].copy_from_slice(&bytes);
+        }
+        G::from_bytes_le(&repr).ok_or(SunziError::NotInField)
+    }
 }
 
-impl<F: PrimeField> ToConstraintField<F> for F {
-    fn to_field_elements(&self) -> Result<Vec<F>, Error> {
-        Ok(vec![*self])
+impl<F: Field> ToFieldElements<F> for [F] {
+    #[inline]
+    fn to_field_elements(&self) -> Vec<F> {
+        self.to_vec()
     }
 }
 
-// Impl for base field
-impl<F: Field> ToConstraintField<F> for [F] {
+impl<F: Field> ToFieldElements<F> for () {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<F>, Error> {
-        Ok(self.to_vec())
+    fn to_field_elements(&self) -> Vec<F> {
+        Vec::new()
+    }
+}
+
+impl<F: Field> FromFieldElements<F> for () {
+    fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+        match elems {
+            [] => Ok(()),
+            _ => Err(SunziError::SizeMismatch {
+                expected: 0,
+                found: elems.len(),
+            }),
+        }
     }
 }
 
-impl<ConstraintF: Field> ToConstraintField<ConstraintF> for () {
+impl<F: Field, P: Fp2Parameters> ToFieldElements<F> for Fp2<P>
+where
+    P::Fp: ToFieldElements<F>,
+{
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        Ok(Vec::new())
+    fn to_field_elements(&self) -> Vec<F> {
+        let mut elems = self.c0.to_field_elements();
+        elems.extend(self.c1.to_field_elements());
+        elems
     }
 }
 
-// Impl for Fp2<ConstraintF>
-impl<P: Fp2Parameters> ToConstraintField<P::Fp> for Fp2<P> {
+impl<F: Field, P: Fp2Parameters> FromFieldElements<F> for Fp2<P>
+where
+    P::Fp: FromFieldElements<F>,
+{
+    // Coefficients take the same number of elements, so a length that is
+    // not a multiple of 2 fails to unpack one of them.
+    fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+        let (c0, c1) = elems.split_at(elems.len() / 2);
+        Ok(Fp2::new(
+            P::Fp::from_field_elements(c0)?,
+            P::Fp::from_field_elements(c1)?,
+        ))
+    }
+}
+
+impl<F: Field, P: Fp3Parameters> ToFieldElements<F> for Fp3<P>
+where
+    P::Fp: ToFieldElements<F>,
+{
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<P::Fp>, Error> {
-        let mut c0 = self.c0.to_field_elements()?;
-        let c1 = self.c1.to_field_elements()?;
-        c0.extend_from_slice(&c1);
-        Ok(c0)
+    fn to_field_elements(&self) -> Vec<F> {
+        let mut elems = self.c0.to_field_elements();
+        elems.extend(self.c1.to_field_elements());
+        elems.extend(self.c2.to_field_elements());
+        elems
+    }
+}
+
+impl<F: Field, P: Fp3Parameters> FromFieldElements<F> for Fp3<P>
+where
+    P::Fp: FromFieldElements<F>,
+{
+    fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+        let n = elems.len() / 3;
+        Ok(Fp3::new(
+            P::Fp::from_field_elements(&elems[..n])?,
+            P::Fp::from_field_elements(&elems[n..2 * n])?,
+            P::Fp::from_field_elements(&elems[2 * n..])?,
+        ))
     }
 }
 
-impl<M: TEModelParameters, ConstraintF: Field> ToConstraintField<ConstraintF> for TEAffine<M>
+impl<M: TEModelParameters, F: Field> ToFieldElements<F> for TEAffine<M>
 where
-    M::BaseField: ToConstraintField<ConstraintF>,
+    M::BaseField: ToFieldElements<F>,
 {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        let mut x_fe = self.x.to_field_elements()?;
-        let y_fe = self.y.to_field_elements()?;
-        x_fe.extend_from_slice(&y_fe);
-        Ok(x_fe)
+    fn to_field_elements(&self) -> Vec<F> {
+        let mut elems = self.x.to_field_elements();
+        elems.extend(self.y.to_field_elements());
+        elems
     }
 }
 
-impl<M: TEModelParameters, ConstraintF: Field> ToConstraintField<ConstraintF> for TEProjective<M>
+impl<M: TEModelParameters, F: Field> ToFieldElements<F> for TEProjective<M>
 where
-    M::BaseField: ToConstraintField<ConstraintF>,
+    M::BaseField: ToFieldElements<F>,
 {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
+    fn to_field_elements(&self) -> Vec<F> {
         TEAffine::from(*self).to_field_elements()
     }
 }
 
-impl<M: SWModelParameters, ConstraintF: Field> ToConstraintField<ConstraintF> for SWAffine<M>
+impl<M: SWModelParameters, F: Field> ToFieldElements<F> for SWAffine<M>
 where
-    M::BaseField: ToConstraintField<ConstraintF>,
+    M::BaseField: ToFieldElements<F>,
 {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        let mut x_fe = self.x.to_field_elements()?;
-        let y_fe = self.y.to_field_elements()?;
-        x_fe.extend_from_slice(&y_fe);
-        Ok(x_fe)
+    fn to_field_elements(&self) -> Vec<F> {
+        let mut elems = self.x.to_field_elements();
+        elems.extend(self.y.to_field_elements());
+        elems
     }
 }
 
-impl<M: SWModelParameters, ConstraintF: Field> ToConstraintField<ConstraintF> for SWProjective<M>
+impl<M: SWModelParameters, F: Field> ToFieldElements<F> for SWProjective<M>
 where
-    M::BaseField: ToConstraintField<ConstraintF>,
+    M::BaseField: ToFieldElements<F>,
 {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
+    fn to_field_elements(&self) -> Vec<F> {
         SWAffine::from(*self).to_field_elements()
     }
 }
 
-impl<ConstraintF: PrimeField> ToConstraintField<ConstraintF> for [u8] {
+impl<F: PrimeField> ToFieldElements<F> for [u8] {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        let max_size = <ConstraintF as PrimeField>::Params::CAPACITY / 8;
-        let max_size = max_size as usize;
-        let bigint_size = <ConstraintF as PrimeField>::BigInt::NUM_LIMBS * 8;
-        let fes = self
-            .chunks(max_size)
-            .map(|chunk| {
-                let mut chunk = chunk.to_vec();
-                let len = chunk.len();
-                for _ in len..bigint_size {
-                    chunk.push(0u8);
-                }
-                ConstraintF::read(chunk.as_slice())
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| Box::new(e))?;
-        Ok(fes)
+    fn to_field_elements(&self) -> Vec<F> {
+        pack_bytes(self)
+    }
+}
+
+impl<F: PrimeField> ToFieldElements<F> for Vec<u8> {
+    #[inline]
+    fn to_field_elements(&self) -> Vec<F> {
+        pack_bytes(self)
     }
 }
 
-impl<ConstraintF: PrimeField> ToConstraintField<ConstraintF> for [u8; 32] {
+impl<F: PrimeField, const N: usize> ToFieldElements<F> for [u8; N] {
     #[inline]
-    fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        self.as_ref().to_field_elements()
+    fn to_field_elements(&self) -> Vec<F> {
+        pack_bytes(self)
+    }
+}
+
+impl<F: PrimeField, const N: usize> FromFieldElements<F> for [u8; N] {
+    fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&unpack_bytes(elems, N)?);
+        Ok(bytes)
+    }
+}
+
+macro_rules! impl_field_elements_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl<F: PrimeField> ToFieldElements<F> for $int {
+                #[inline]
+                fn to_field_elements(&self) -> Vec<F> {
+                    pack_bytes(&self.to_le_bytes())
+                }
+            }
+
+            impl<F: PrimeField> FromFieldElements<F> for $int {
+                fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+                    FromFieldElements::from_field_elements(elems).map(<$int>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_field_elements_for_int!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_field_elements_for_uint {
+    ($($uint:ident),*) => {
+        $(
+            impl<F: PrimeField> ToFieldElements<F> for $uint {
+                #[inline]
+                fn to_field_elements(&self) -> Vec<F> {
+                    pack_bytes(&self.to_bytes())
+                }
+            }
+
+            impl<F: PrimeField> FromFieldElements<F> for $uint {
+                fn from_field_elements(elems: &[F]) -> Result<Self, SunziError> {
+                    Ok($uint::from_bytes(&unpack_bytes(elems, 8 * $uint::LIMBS)?)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_field_elements_for_uint!(U256, U320, U384, U768, U832);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::bls12_381::{fr::Fr, Fq};
+    use num_traits::One;
+
+    #[test]
+    fn pack_bytes_test() {
+        // 254 bits of capacity per element.
+        let bytes: Vec<u8> = (0..100u8).collect();
+        let elems: Vec<Fr> = bytes.to_field_elements();
+        assert_eq!(elems.len(), 4);
+        assert_eq!(unpack_bytes(&elems, bytes.len()).unwrap(), bytes);
+        assert_eq!(unpack_bytes(&elems, 96), Err(SunziError::NonCanonical));
+        assert_eq!(
+            unpack_bytes(&elems[..3], bytes.len()),
+            Err(SunziError::SizeMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+
+        let mut tampered = elems.clone();
+        tampered[3] = -Fr::one();
+        assert_eq!(
+            unpack_bytes(&tampered, bytes.len()),
+            Err(SunziError::NonCanonical)
+        );
+
+        assert!(<Vec<u8> as ToFieldElements<Fr>>::to_field_elements(&vec![]).is_empty());
+    }
+
+    #[test]
+    fn int_field_elements_test() {
+        let elems: Vec<Fr> = 0x0102_0304u32.to_field_elements();
+        assert_eq!(elems, vec![Fr::from(0x0102_0304u64)]);
+        assert_eq!(u32::from_field_elements(&elems).unwrap(), 0x0102_0304);
+        assert_eq!(
+            u8::from_field_elements(&elems),
+            Err(SunziError::NonCanonical)
+        );
+
+        let value = u128::MAX - 7;
+        let elems: Vec<Fr> = value.to_field_elements();
+        assert_eq!(u128::from_field_elements(&elems).unwrap(), value);
+
+        let value = U256::from(&[1, 2, 3, u64::MAX][..]);
+        let elems: Vec<Fr> = value.to_field_elements();
+        assert_eq!(elems.len(), 2);
+        assert_eq!(U256::from_field_elements(&elems).unwrap(), value);
+    }
+
+    #[test]
+    fn field_elements_test() {
+        let x = Fr::from(5u64);
+        let elems: Vec<Fr> = x.to_field_elements();
+        assert_eq!(elems, vec![x]);
+        assert_eq!(Fr::from_field_elements(&elems).unwrap(), x);
+
+        // A 381-bit element takes two elements of the 255-bit field.
+        let y = -Fq::one();
+        let elems: Vec<Fr> = y.to_field_elements();
+        assert_eq!(elems.len(), 2);
+        assert_eq!(Fq::from_field_elements(&elems).unwrap(), y);
+        assert!(Fq::from_field_elements(&elems[..1]).is_err());
+
+        // A 255-bit element takes one element of the 381-bit field.
+        let z = -Fr::one();
+        let elems: Vec<Fq> = z.to_field_elements();
+        assert_eq!(elems.len(), 1);
+        assert_eq!(Fr::from_field_elements(&elems).unwrap(), z);
     }
 }
@@ -0,0 +1,184 @@
+//! Integer helpers for decomposing scalars along a short lattice basis, as
+//! in the GLV method for curves with an efficient endomorphism.
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, Neg, Sub},
+};
+
+use crate::Vec;
+
+use super::Uint;
+
+/// Returns `floor(a * b / 2^shift)`, truncated to the limbs of `B`. `b` is
+/// given by its little-endian limbs, so that it may be wider than `B`.
+pub fn mul_shift<B: Uint>(a: &B, b: &[u64], shift: u32) -> B {
+    shift_right(&mul_wide(a.as_ref(), b), shift)
+}
+
+/// Returns `round(a * b / 2^shift)`, truncated to the limbs of `B`, with
+/// halves rounded up.
+pub fn mul_shift_round<B: Uint>(a: &B, b: &[u64], shift: u32) -> B {
+    let wide = mul_wide(a.as_ref(), b);
+    let mut res: B = shift_right(&wide, shift);
+    // The bit below the cut decides the rounding.
+    if shift > 0 {
+        let i = (shift - 1) as usize;
+        let round_bit = i / 64 < wide.len() && (wide[i / 64] >> (i % 64)) & 1 == 1;
+        if round_bit {
+            res.add_nocarry(&B::from(1));
+        }
+    }
+    res
+}
+
+/// Returns `floor(n / d)` and `n mod d`.
+///
+/// Panics if `d` is zero.
+pub fn div_rem<B: Uint>(n: &B, d: &B) -> (B, B) {
+    assert!(!d.is_zero(), "division by zero");
+    let mut quotient = B::default();
+    let mut remainder = B::default();
+    for i in (0..64 * B::LIMBS).rev() {
+        // `remainder < d` before the shift, so a bit shifted out means that
+        // the shifted remainder exceeds `d`.
+        let overflow = remainder.get_bit(64 * B::LIMBS - 1);
+        remainder.mul2();
+        if n.get_bit(i) {
+            remainder.as_mut()[0] |= 1;
+        }
+        if overflow || remainder >= *d {
+            remainder.sub_noborrow(d);
+            quotient.as_mut()[i / 64] |= 1 << (i % 64);
+        }
+    }
+    (quotient, remainder)
+}
+
+/// Returns `round(n / d)`, with halves rounded up.
+///
+/// Panics if `d` is zero.
+pub fn round_div<B: Uint>(n: &B, d: &B) -> B {
+    let (mut quotient, remainder) = div_rem(n, d);
+    let mut rest = *d;
+    rest.sub_noborrow(&remainder);
+    if remainder >= rest {
+        quotient.add_nocarry(&B::from(1));
+    }
+    quotient
+}
+
+/// Returns the balanced representative of `x` modulo `modulus`, i.e. the
+/// integer congruent to `x` in `(-modulus / 2, modulus / 2]`, for `x` below
+/// `modulus`.
+pub fn balanced<B: Uint>(x: &B, modulus: &B) -> Signed<B> {
+    let mut half = *modulus;
+    half.div2();
+    if *x > half {
+        let mut magnitude = *modulus;
+        magnitude.sub_noborrow(x);
+        Signed::new(true, magnitude)
+    } else {
+        Signed::new(false, *x)
+    }
+}
+
+/// A signed integer given by its sign and absolute value, e.g. the
+/// coordinates of a scalar in a lattice basis. Zero is never negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Signed<B: Uint> {
+    negative: bool,
+    magnitude: B,
+}
+
+impl<B: Uint> Signed<B> {
+    pub fn new(negative: bool, magnitude: B) -> Self {
+        Signed {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value.
+    pub fn magnitude(&self) -> &B {
+        &self.magnitude
+    }
+}
+
+impl<B: Uint> Neg for Signed<B> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(!self.negative, self.magnitude)
+    }
+}
+
+/// The absolute value of the sum must fit in `B`.
+impl<B: Uint> Add for Signed<B> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.negative == other.negative {
+            let mut magnitude = self.magnitude;
+            let carry = magnitude.add_nocarry(&other.magnitude);
+            debug_assert!(!carry, "overflow in signed addition");
+            return Self::new(self.negative, magnitude);
+        }
+        let (larger, smaller) = match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less => (other, self),
+            _ => (self, other),
+        };
+        let mut magnitude = larger.magnitude;
+        magnitude.sub_noborrow(&smaller.magnitude);
+        Self::new(larger.negative, magnitude)
+    }
+}
+
+/// The absolute value of the difference must fit in `B`.
+impl<B: Uint> Sub for Signed<B> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<B: Uint> From<B> for Signed<B> {
+    fn from(magnitude: B) -> Self {
+        Self::new(false, magnitude)
+    }
+}
+
+fn mul_wide(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut wide = vec![0u64; a.len() + b.len() + 1];
+    for (i, a_i) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b_j) in b.iter().enumerate() {
+            let t = u128::from(*a_i) * u128::from(*b_j) + u128::from(wide[i + j]) + carry;
+            wide[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        wide[i + b.len()] = carry as u64;
+    }
+    wide
+}
+
+/// Returns the limbs of `wide >> shift` that fit in `B`.
+fn shift_right<B: Uint>(wide: &[u64], shift: u32) -> B {
+    let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+    let mut res = B::default();
+    for (i, limb) in res.as_mut().iter_mut().enumerate() {
+        let lo = wide.get(i + limb_shift).copied().unwrap_or(0);
+        let hi = wide.get(i + limb_shift + 1).copied().unwrap_or(0);
+        *limb = if bit_shift == 0 {
+            lo
+        } else {
+            (lo >> bit_shift) | (hi << (64 - bit_shift))
+        };
+    }
+    res
+}
@@ -2,13 +2,37 @@
 macro_rules! uint_impl {
     ($name:ident, $num_limbs:expr) => {
         #[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Hash)]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(
+            feature = "rkyv",
+            derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+            archive(check_bytes),
+            archive_attr(derive(Clone, Copy, Debug, PartialEq, Eq))
+        )]
         pub struct $name(pub [u64; $num_limbs]);
 
         impl $name {
             pub const fn new(value: [u64; $num_limbs]) -> Self {
                 $name(value)
             }
+
+            /// Returns the hexadecimal digits of the integer, without leading
+            /// zeros.
+            fn hex_digits(&self, upper: bool) -> $crate::String {
+                use core::fmt::Write;
+
+                let mut s = $crate::String::with_capacity(16 * $num_limbs);
+                for limb in self.0.iter().rev() {
+                    if upper {
+                        write!(s, "{:016X}", limb).unwrap();
+                    } else {
+                        write!(s, "{:016x}", limb).unwrap();
+                    }
+                }
+                match s.trim_start_matches('0') {
+                    "" => $crate::String::from("0"),
+                    digits => $crate::String::from(digits),
+                }
+            }
         }
 
         impl Uint for $name {
@@ -188,23 +212,41 @@ macro_rules! uint_impl {
 
             #[inline]
             fn find_wnaf(&self) -> Vec<i64> {
+                self.find_wnaf_with_window(2)
+            }
+
+            fn find_wnaf_with_window(&self, w: usize) -> Vec<i64> {
+                ct_probe!("wnaf");
+                assert!((2..=62).contains(&w), "window size must be in 2..=62");
+                let window = 1u64 << w;
+                let half = window >> 1;
+
                 let mut res = vec![];
 
                 let mut e = self.clone();
-                while !e.is_zero() {
+                // Negative digits add to `e`, which can then carry into the
+                // bit above the limbs, e.g. for scalars near `2^(64 LIMBS)`.
+                let mut top = false;
+                while !e.is_zero() || top {
                     let z: i64;
                     if e.is_odd() {
-                        z = 2 - (e.0[0] % 4) as i64;
-                        if z >= 0 {
-                            e.sub_noborrow(&Self::from(z as u64));
+                        let m = e.0[0] & (window - 1);
+                        if m >= half {
+                            z = m as i64 - window as i64;
+                            top |= e.add_nocarry(&Self::from((-z) as u64));
                         } else {
-                            e.add_nocarry(&Self::from((-z) as u64));
+                            z = m as i64;
+                            e.sub_noborrow(&Self::from(z as u64));
                         }
                     } else {
                         z = 0;
                     }
                     res.push(z);
                     e.div2();
+                    if top {
+                        e.0[$num_limbs - 1] |= 1 << 63;
+                        top = false;
+                    }
                 }
 
                 res
@@ -235,8 +277,55 @@ macro_rules! uint_impl {
             }
         }
 
+        impl crate::serialize::CanonicalSerialize for $name {
+            #[inline]
+            fn serialize_with_mode(
+                &self,
+                writer: &mut Vec<u8>,
+                _: crate::serialize::Compress,
+            ) -> crate::Result<()> {
+                writer.extend(self.to_bytes());
+                Ok(())
+            }
+
+            #[inline]
+            fn serialized_size(&self, _: crate::serialize::Compress) -> usize {
+                $num_limbs * 8
+            }
+        }
+
+        impl crate::serialize::CanonicalDeserialize for $name {
+            #[inline]
+            fn deserialize_with_mode(
+                reader: &mut &[u8],
+                _: crate::serialize::Compress,
+                _: crate::serialize::Validate,
+            ) -> crate::Result<Self> {
+                Self::from_bytes(crate::serialize::read_bytes(reader, $num_limbs * 8)?)
+            }
+        }
+
+        $crate::impl_from_canonical!([], $name);
+
+        /// Writes the zero-padded uppercase hexadecimal digits, or in
+        /// alternate mode `0x` and the digits without padding, elided down to
+        /// the precision if one is given.
         impl core::fmt::Display for $name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                if f.alternate() {
+                    // Only `p` of the digits if the precision `p` is smaller
+                    // than their number, with the middle ones elided as in
+                    // `0x1234…abcd`.
+                    let digits = self.hex_digits(false);
+                    return match f.precision() {
+                        Some(p) if p < digits.len() => {
+                            let head = (p + 1) / 2;
+                            let tail = &digits[digits.len() - (p - head)..];
+                            write!(f, "0x{}\u{2026}{}", &digits[..head], tail)
+                        }
+                        _ => write!(f, "0x{}", digits),
+                    };
+                }
                 for i in self.0.iter().rev() {
                     write!(f, "{:016X}", *i)?;
                 }
@@ -244,6 +333,18 @@ macro_rules! uint_impl {
             }
         }
 
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad_integral(true, "0x", &self.hex_digits(false))
+            }
+        }
+
+        impl core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad_integral(true, "0x", &self.hex_digits(true))
+            }
+        }
+
         impl Ord for $name {
             #[inline]
             fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
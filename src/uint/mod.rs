@@ -1,10 +1,15 @@
 use core::fmt::{Debug, Display};
 
-use crate::Vec;
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    Vec,
+};
 
 #[macro_use]
 pub mod macros;
 
+pub mod lattice;
+
 /// This defines a `Big unsigned integer`.
 pub trait Uint:
     'static
@@ -22,6 +27,8 @@ pub trait Uint:
     + AsRef<[u64]>
     + From<u64>
     + for<'a> From<&'a [u64]>
+    + CanonicalSerialize
+    + CanonicalDeserialize
 {
     /// Number of limbs.
     const LIMBS: usize;
@@ -76,6 +83,51 @@ pub trait Uint:
     /// Returns a vector for wnaf.
     fn find_wnaf(&self) -> Vec<i64>;
 
+    /// Returns the width-`w` non-adjacent form of `self`, least significant
+    /// digit first. Every nonzero digit is odd and smaller than `2^(w - 1)`
+    /// in absolute value, and any `w` consecutive digits contain at most one
+    /// nonzero digit. `find_wnaf` is the case `w = 2`.
+    ///
+    /// # Panics
+    /// This method panics if `w` is not in `2..=62`.
+    fn find_wnaf_with_window(&self, w: usize) -> Vec<i64>;
+
+    /// Returns the signed radix-`2^w` (Booth) recoding of `self`, least
+    /// significant digit first: digits `d_i` in `[-2^(w - 1), 2^(w - 1)]`
+    /// with `self = ∑ d_i 2^(w i)`. Unlike the non-adjacent forms, there are
+    /// always `ceil((64 LIMBS + 1) / w)` digits, and each is computed with
+    /// the same operations whatever the value of `self`, so that scalar
+    /// multiplications built on it do not leak the scalar through timing.
+    ///
+    /// # Panics
+    /// This method panics if `w` is not in `1..=62`.
+    fn booth_recode(&self, w: usize) -> Vec<i64> {
+        ct_probe!("booth_recode");
+        assert!((1..=62).contains(&w), "window size must be in 1..=62");
+        let limbs = self.as_ref();
+        let num_digits = (64 * Self::LIMBS + w) / w;
+        // Returns the `len` bits of `self` from bit `start`, zero beyond.
+        let bits_at = |start: usize, len: usize| -> u64 {
+            let (index, offset) = (start / 64, start % 64);
+            let lo = limbs.get(index).copied().unwrap_or(0) >> offset;
+            let hi = match offset {
+                0 => 0,
+                _ => limbs.get(index + 1).copied().unwrap_or(0) << (64 - offset),
+            };
+            (lo | hi) & ((1 << len) - 1)
+        };
+
+        let mut digits = Vec::with_capacity(num_digits);
+        let mut carry = 0i64;
+        for i in 0..num_digits {
+            let window = bits_at(w * i, w) as i64;
+            let top = window >> (w - 1);
+            digits.push(window + carry - (top << w));
+            carry = top;
+        }
+        digits
+    }
+
     /// From given litter endian bytes to big integer.
     fn from_bytes(bytes: &[u8]) -> crate::Result<Self>;
 
@@ -98,6 +150,14 @@ pub trait Uint:
     }
 }
 
+/// Splits a digit of `Uint::booth_recode` into its sign, `true` for
+/// negative, and its absolute value, without branching on it.
+#[inline]
+pub fn booth_digit_parts(digit: i64) -> (bool, u64) {
+    let mask = digit >> 63;
+    (mask != 0, ((digit ^ mask) - mask) as u64)
+}
+
 pub mod arithmetic {
     /// Calculate a + b + carry, returning the sum and modifying the
     /// carry value.
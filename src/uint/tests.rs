@@ -2,6 +2,7 @@ use rand_core::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use serde::{Deserialize, Serialize};
 
+use crate::serialize::Compress;
 use crate::uint::{arithmetic, Uint};
 use crate::utils::BitIterator;
 
@@ -60,6 +61,53 @@ fn uint_bytes_test<U: Uint>() {
     assert_eq!(x, y);
 }
 
+fn uint_wnaf_test<U: Uint>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let x: U = U::random(&mut rng);
+    assert_eq!(x.find_wnaf(), x.find_wnaf_with_window(2));
+    // The largest value has a negative digit that carries above the limbs.
+    let mut max = U::from(0u64);
+    max.sub_noborrow(&U::from(1u64));
+    for x in [x, max] {
+        for w in 2..8 {
+            let naf = x.find_wnaf_with_window(w);
+            assert!(naf.len() <= 64 * U::LIMBS + 1);
+            // The digits are summed modulo `2^(64 LIMBS)`, in which the digit
+            // above the limbs vanishes.
+            let (mut pos, mut neg) = (U::from(0u64), U::from(0u64));
+            for (i, z) in naf.iter().enumerate() {
+                assert!(*z == 0 || (z % 2 != 0 && z.abs() < 1 << (w - 1)));
+                let mut digit = U::from(z.abs() as u64);
+                digit.mul(i as u32);
+                if *z > 0 {
+                    pos.add_nocarry(&digit);
+                } else {
+                    neg.add_nocarry(&digit);
+                }
+            }
+            pos.sub_noborrow(&neg);
+            assert_eq!(pos, x);
+        }
+    }
+    let naf = max.find_wnaf_with_window(2);
+    assert_eq!(naf.len(), 64 * U::LIMBS + 1);
+    assert_eq!((naf[0], naf[64 * U::LIMBS]), (-1, 1));
+}
+
+fn uint_serialize_test<U: Uint>(a: U) {
+    let mut bytes = vec![];
+    a.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), a.serialized_size(Compress::Yes));
+    assert_eq!(bytes, a.to_bytes());
+
+    let mut reader = &bytes[..];
+    assert_eq!(U::deserialize_compressed(&mut reader).unwrap(), a);
+    assert!(reader.is_empty());
+
+    let mut truncated = &bytes[1..];
+    assert!(U::deserialize_compressed(&mut truncated).is_err());
+}
+
 fn test_uint<U: Uint>(zero: U) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: U = U::random(&mut rng);
@@ -67,6 +115,134 @@ fn test_uint<U: Uint>(zero: U) {
     uint_arithmetic_test(a, b, zero);
     uint_bytes_test::<U>();
     uint_bits_test::<U>();
+    uint_wnaf_test::<U>();
+    uint_serialize_test(a);
+}
+
+#[test]
+fn test_uint_format() {
+    crate::uint_impl!(U128, 2);
+    let a = U128::new([0x0123456789abcdef, 0xfedc]);
+    assert_eq!(format!("{}", a), "000000000000FEDC0123456789ABCDEF");
+    assert_eq!(format!("{:x}", a), "fedc0123456789abcdef");
+    assert_eq!(format!("{:#X}", a), "0xFEDC0123456789ABCDEF");
+    assert_eq!(format!("{:#024x}", a), "0x00fedc0123456789abcdef");
+    assert_eq!(format!("{:#}", a), "0xfedc0123456789abcdef");
+    assert_eq!(format!("{:#.8}", a), "0xfedc\u{2026}cdef");
+    assert_eq!(format!("{:#.7}", a), "0xfedc\u{2026}def");
+    assert_eq!(format!("{:x}", U128::new([0, 0])), "0");
+}
+
+#[test]
+fn test_lattice_helpers() {
+    use crate::uint::lattice::{balanced, div_rem, mul_shift, mul_shift_round, round_div, Signed};
+    use rand_core::RngCore;
+
+    crate::uint_impl!(U128, 2);
+    let to_u128 = |x: U128| x.0[0] as u128 | (x.0[1] as u128) << 64;
+    let from_u128 = |x: u128| U128::new([x as u64, (x >> 64) as u64]);
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5eed);
+    for _ in 0..1000 {
+        let n = U128::random(&mut rng);
+        let mut d = U128::random(&mut rng);
+        d.div(rng.next_u32() % 128);
+        if d.is_zero() {
+            continue;
+        }
+        let (q, r) = div_rem(&n, &d);
+        assert_eq!(to_u128(q), to_u128(n) / to_u128(d));
+        assert_eq!(to_u128(r), to_u128(n) % to_u128(d));
+        let (n, d) = (to_u128(n), to_u128(d));
+        let rounded = n / d + (n % d >= d - n % d) as u128;
+        assert_eq!(to_u128(round_div(&from_u128(n), &from_u128(d))), rounded);
+    }
+    assert_eq!(to_u128(round_div(&from_u128(7), &from_u128(2))), 4);
+    assert_eq!(to_u128(round_div(&from_u128(5), &from_u128(3))), 2);
+    assert_eq!(to_u128(round_div(&from_u128(4), &from_u128(3))), 1);
+
+    // 0x3_0000_0001 * 0x5 = 0xf_0000_0005.
+    let a = from_u128(0x3_0000_0001);
+    assert_eq!(to_u128(mul_shift(&a, &[5], 4)), 0xf000_0000);
+    assert_eq!(to_u128(mul_shift_round(&a, &[5], 4)), 0xf000_0000);
+    assert_eq!(to_u128(mul_shift_round(&a, &[5], 3)), 0x1_e000_0001);
+    assert_eq!(to_u128(mul_shift(&a, &[0, 1], 64)), 0x3_0000_0001);
+    assert_eq!(to_u128(mul_shift(&a, &[0, 0, 1], 160)), 3);
+
+    let modulus = from_u128(101);
+    assert_eq!(
+        balanced(&from_u128(50), &modulus),
+        Signed::from(from_u128(50))
+    );
+    assert_eq!(
+        balanced(&from_u128(51), &modulus),
+        Signed::new(true, from_u128(50))
+    );
+    assert_eq!(
+        balanced(&from_u128(0), &modulus),
+        Signed::from(from_u128(0))
+    );
+
+    let (x, y) = (Signed::from(from_u128(3)), Signed::new(true, from_u128(5)));
+    assert_eq!(x + y, Signed::new(true, from_u128(2)));
+    assert_eq!(x - y, Signed::from(from_u128(8)));
+    assert_eq!(y - y, Signed::default());
+    assert!(!(x - x).is_negative());
+    assert!((-x).is_negative());
+    assert_eq!(to_u128(*(y + y).magnitude()), 10);
+}
+
+#[test]
+fn test_booth_recode() {
+    use crate::uint::{booth_digit_parts, lattice::Signed};
+
+    crate::uint_impl!(U64, 1);
+    crate::uint_impl!(U256, 4);
+
+    let mut rng = XorShiftRng::seed_from_u64(0xb007);
+    for w in 1..=8 {
+        for _ in 0..20 {
+            let x = U64::random(&mut rng);
+            let digits = x.booth_recode(w);
+            assert_eq!(digits.len(), (64 + w) / w);
+            let bound = 1i64 << (w - 1);
+            assert!(digits.iter().all(|d| (-bound..=bound).contains(d)));
+            let sum: i128 = digits
+                .iter()
+                .enumerate()
+                .map(|(i, d)| (*d as i128) << (w * i))
+                .sum();
+            assert_eq!(sum, x.0[0] as i128);
+
+            // Recomposing from the most significant digit.
+            let y = U256::random(&mut rng);
+            let digits = y.booth_recode(w);
+            assert_eq!(digits.len(), (256 + w) / w);
+            let recomposed = digits
+                .iter()
+                .rev()
+                .fold(Signed::<U256>::default(), |acc, d| {
+                    let mut magnitude = *acc.magnitude();
+                    magnitude.mul(w as u32);
+                    let (negative, abs) = booth_digit_parts(*d);
+                    Signed::new(acc.is_negative(), magnitude)
+                        + Signed::new(negative, U256::from(abs))
+                });
+            assert_eq!(recomposed, Signed::from(y));
+        }
+    }
+
+    // The length does not depend on the value.
+    assert_eq!(U256::from(0).booth_recode(5), vec![0; 52]);
+    assert_eq!(U256::new([u64::MAX; 4]).booth_recode(5).len(), 52);
+    assert_eq!(U64::from(7).booth_recode(2), {
+        let mut digits = vec![-1, 2];
+        digits.resize(33, 0);
+        digits
+    });
+    assert_eq!(booth_digit_parts(-3), (true, 3));
+    assert_eq!(booth_digit_parts(4), (false, 4));
+    assert_eq!(booth_digit_parts(0), (false, 0));
 }
 
 #[test]
@@ -86,3 +262,17 @@ fn test_uint256() {
     crate::uint_impl!(U256, 4);
     test_uint(U256::new([0u64; 4]));
 }
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn test_uint_rkyv() {
+    use rkyv::Deserialize;
+
+    crate::uint_impl!(U256, 4);
+    let a = U256::new([1, 2, 3, u64::MAX]);
+    let bytes = rkyv::to_bytes::<_, 64>(&a).unwrap();
+    let archived = rkyv::check_archived_root::<U256>(&bytes[..]).unwrap();
+    assert_eq!(archived.0, [1, 2, 3, u64::MAX]);
+    let b: U256 = archived.deserialize(&mut rkyv::Infallible).unwrap();
+    assert_eq!(a, b);
+}